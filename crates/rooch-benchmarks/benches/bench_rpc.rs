@@ -0,0 +1,257 @@
+// Copyright (c) RoochNetwork
+// SPDX-License-Identifier: Apache-2.0
+
+use anyhow::Result;
+use criterion::{criterion_group, criterion_main, BatchSize, BenchmarkId, Criterion, Throughput};
+use futures::future::try_join_all;
+use jsonrpsee::core::client::ClientT;
+use jsonrpsee::core::params::BatchRequestBuilder;
+use jsonrpsee::http_client::HttpClientBuilder;
+use jsonrpsee::rpc_params;
+use move_core_types::account_address::AccountAddress;
+use move_core_types::identifier::Identifier;
+use moveos_types::access_path::AccessPath;
+use moveos_types::moveos_std::object::NamedTableID;
+use rooch_benchmarks::server::BenchServer;
+use rooch_benchmarks::tx::{create_publish_transaction, create_transaction};
+use rooch_key::keystore::account_keystore::AccountKeystore;
+use rooch_key::keystore::memory_keystore::InMemKeystore;
+use rooch_rpc_api::jsonrpc_types::event_view::EventFilterView;
+use rooch_rpc_api::jsonrpc_types::transaction_view::TransactionFilterView;
+use rooch_rpc_client::Client;
+use rooch_test_transaction_builder::TestTransactionBuilder;
+use rooch_types::addresses::ROOCH_FRAMEWORK_ADDRESS;
+use rooch_types::transaction::rooch::RoochTransaction;
+use serde_json::Value;
+use std::time::Duration;
+use tokio::runtime::Runtime;
+
+/// The number of concurrent requests of the read benchmarks
+const CONCURRENCY_LEVELS: [usize; 4] = [1, 8, 32, 128];
+/// The number of transactions executed before the indexer benchmarks,
+/// overridden by the `ROOCH_BENCH_INDEXER_TX_COUNT` environment variable.
+const DEFAULT_INDEXER_TX_COUNT: u64 = 2000;
+
+/// The sender of the synthetic transactions, it publishes the simple blog example first.
+struct Workload {
+    keystore: InMemKeystore,
+    builder: TestTransactionBuilder,
+    sequence_number: u64,
+}
+
+impl Workload {
+    fn new() -> Self {
+        let keystore = InMemKeystore::new_insecure_for_tests(1);
+        let sender = keystore.addresses()[0];
+        Self {
+            keystore,
+            builder: TestTransactionBuilder::new(sender.into()),
+            sequence_number: 0,
+        }
+    }
+
+    fn sender(&self) -> AccountAddress {
+        self.builder.sender
+    }
+
+    async fn publish(&self, client: &Client) -> Result<()> {
+        let tx = create_publish_transaction(&self.builder, &self.keystore)?;
+        client.rooch.execute_tx(tx).await?;
+        Ok(())
+    }
+
+    fn next_transactions(&mut self, count: usize) -> Vec<RoochTransaction> {
+        (0..count)
+            .map(|_| {
+                self.sequence_number += 1;
+                create_transaction(&mut self.builder, &self.keystore, self.sequence_number)
+                    .expect("create transaction should succeed")
+            })
+            .collect()
+    }
+}
+
+/// Execute the transactions concurrently, return the max tx order.
+async fn execute_all(client: &Client, txs: Vec<RoochTransaction>) -> u64 {
+    try_join_all(txs.into_iter().map(|tx| client.rooch.execute_tx(tx)))
+        .await
+        .expect("execute transactions should succeed")
+        .iter()
+        .map(|response| response.sequence_info.tx_order.0)
+        .max()
+        .unwrap_or_default()
+}
+
+fn start_server(rt: &Runtime) -> BenchServer {
+    rt.block_on(BenchServer::start())
+        .expect("start bench server should succeed")
+}
+
+fn execute_raw_transaction_benchmark(c: &mut Criterion) {
+    let rt = Runtime::new().unwrap();
+    let server = start_server(&rt);
+    let mut workload = Workload::new();
+    rt.block_on(workload.publish(server.client())).unwrap();
+
+    let mut group = c.benchmark_group("execute_raw_transaction");
+    for concurrency in [1, 16] {
+        group.throughput(Throughput::Elements(concurrency as u64));
+        group.bench_function(BenchmarkId::from_parameter(concurrency), |b| {
+            b.to_async(&rt).iter_batched(
+                || workload.next_transactions(concurrency),
+                |txs| execute_all(server.client(), txs),
+                BatchSize::SmallInput,
+            )
+        });
+    }
+    group.finish();
+    server.stop().unwrap();
+}
+
+fn state_read_benchmark(c: &mut Criterion) {
+    let rt = Runtime::new().unwrap();
+    let server = start_server(&rt);
+    let client = server.client();
+
+    let module_path =
+        AccessPath::module(ROOCH_FRAMEWORK_ADDRESS, Identifier::new("account").unwrap());
+    // All the modules of the framework
+    let modules_path = AccessPath::table_without_keys(
+        NamedTableID::Module(ROOCH_FRAMEWORK_ADDRESS).to_object_id(),
+    );
+
+    let mut group = c.benchmark_group("get_states");
+    for concurrency in CONCURRENCY_LEVELS {
+        group.throughput(Throughput::Elements(concurrency as u64));
+        group.bench_function(BenchmarkId::from_parameter(concurrency), |b| {
+            b.to_async(&rt).iter(|| {
+                try_join_all((0..concurrency).map(|_| client.rooch.get_states(module_path.clone())))
+            })
+        });
+    }
+    group.finish();
+
+    let mut group = c.benchmark_group("list_states");
+    for concurrency in CONCURRENCY_LEVELS {
+        group.throughput(Throughput::Elements(concurrency as u64));
+        group.bench_function(BenchmarkId::from_parameter(concurrency), |b| {
+            b.to_async(&rt).iter(|| {
+                try_join_all((0..concurrency).map(|_| {
+                    client
+                        .rooch
+                        .list_states(modules_path.clone().into(), None, None)
+                }))
+            })
+        });
+    }
+    group.finish();
+    server.stop().unwrap();
+}
+
+fn batch_request_benchmark(c: &mut Criterion) {
+    let rt = Runtime::new().unwrap();
+    let server = start_server(&rt);
+    // The batch request is not wrapped by the rooch client, send it with the raw HTTP client
+    let http = rt
+        .block_on(async { HttpClientBuilder::default().build(server.url()) })
+        .unwrap();
+    let module_path =
+        AccessPath::module(ROOCH_FRAMEWORK_ADDRESS, Identifier::new("account").unwrap())
+            .to_string();
+
+    let mut group = c.benchmark_group("batch_get_states");
+    for batch_size in [1, 10, 50, 100] {
+        group.throughput(Throughput::Elements(batch_size as u64));
+        group.bench_function(BenchmarkId::from_parameter(batch_size), |b| {
+            b.to_async(&rt).iter(|| async {
+                let mut batch = BatchRequestBuilder::new();
+                for _ in 0..batch_size {
+                    batch
+                        .insert("rooch_getStates", rpc_params![module_path.as_str()])
+                        .unwrap();
+                }
+                http.batch_request::<Value>(batch).await.unwrap()
+            })
+        });
+    }
+    group.finish();
+    server.stop().unwrap();
+}
+
+fn indexer_query_benchmark(c: &mut Criterion) {
+    let rt = Runtime::new().unwrap();
+    let server = start_server(&rt);
+    let client = server.client();
+    let mut workload = Workload::new();
+    let sender = workload.sender();
+
+    // Prepare the dataset, the transactions are executed in chunks to keep the mempool small
+    let tx_count = std::env::var("ROOCH_BENCH_INDEXER_TX_COUNT")
+        .ok()
+        .and_then(|count| count.parse().ok())
+        .unwrap_or(DEFAULT_INDEXER_TX_COUNT);
+    let last_tx_order = rt.block_on(async {
+        workload.publish(client).await.unwrap();
+        let mut last_tx_order = 0;
+        for _ in 0..tx_count / 100 {
+            last_tx_order = execute_all(client, workload.next_transactions(100)).await;
+        }
+        last_tx_order
+    });
+    // The indexer persists the transactions asynchronously, wait until it catches up
+    rt.block_on(async {
+        let filter = TransactionFilterView::TxOrderRange {
+            from_order: last_tx_order,
+            to_order: last_tx_order + 1,
+        };
+        while client
+            .rooch
+            .query_transactions(filter.clone(), None, Some(1), None)
+            .await
+            .unwrap()
+            .data
+            .is_empty()
+        {
+            tokio::time::sleep(Duration::from_millis(100)).await;
+        }
+    });
+
+    let mut group = c.benchmark_group("query_transactions_by_sender");
+    for page_size in [10, 50, 200] {
+        group.bench_function(BenchmarkId::from_parameter(page_size), |b| {
+            b.to_async(&rt).iter(|| {
+                client.rooch.query_transactions(
+                    TransactionFilterView::Sender(sender.into()),
+                    None,
+                    Some(page_size),
+                    Some(true),
+                )
+            })
+        });
+    }
+    group.finish();
+
+    let mut group = c.benchmark_group("query_events_by_sender");
+    for page_size in [10, 50, 200] {
+        group.bench_function(BenchmarkId::from_parameter(page_size), |b| {
+            b.to_async(&rt).iter(|| {
+                client.rooch.query_events(
+                    EventFilterView::Sender(sender.into()),
+                    None,
+                    Some(page_size),
+                    Some(true),
+                )
+            })
+        });
+    }
+    group.finish();
+    server.stop().unwrap();
+}
+
+criterion_group! {
+    name = rooch_rpc_benches;
+    config = Criterion::default().sample_size(50).measurement_time(Duration::from_secs(10));
+    targets = execute_raw_transaction_benchmark, state_read_benchmark, batch_request_benchmark,
+        indexer_query_benchmark
+}
+criterion_main!(rooch_rpc_benches);
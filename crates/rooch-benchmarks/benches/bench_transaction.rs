@@ -12,15 +12,17 @@ use moveos_store::{MoveOSDB, MoveOSStore};
 // use pprof::criterion::{Output, PProfProfiler};
 use raw_store::rocks::RocksDB;
 use raw_store::StoreInstance;
+use rooch_benchmarks::tx::{create_publish_transaction, create_transaction};
+use rooch_config::da_config::DAConfig;
 use rooch_config::indexer_config::IndexerConfig;
+use rooch_config::mempool_config::MempoolConfig;
 use rooch_config::store_config::StoreConfig;
 use rooch_da::actor::da::DAActor;
 use rooch_da::proxy::DAProxy;
-use rooch_da::server::serverproxy::DAServerNopProxy;
-use rooch_da::server::serverproxy::DAServerProxy;
 use rooch_executor::actor::executor::ExecutorActor;
 use rooch_executor::actor::reader_executor::ReaderExecutorActor;
 use rooch_executor::proxy::ExecutorProxy;
+use rooch_framework::natives::default_gas_schedule;
 use rooch_indexer::actor::indexer::IndexerActor;
 use rooch_indexer::actor::reader_indexer::IndexerReaderActor;
 use rooch_indexer::indexer_reader::IndexerReader;
@@ -28,6 +30,8 @@ use rooch_indexer::proxy::IndexerProxy;
 use rooch_indexer::IndexerStore;
 use rooch_key::keystore::account_keystore::AccountKeystore;
 use rooch_key::keystore::memory_keystore::InMemKeystore;
+use rooch_mempool::actor::mempool::MempoolActor;
+use rooch_mempool::proxy::MempoolProxy;
 use rooch_proposer::actor::messages::ProposeBlock;
 use rooch_proposer::actor::proposer::ProposerActor;
 use rooch_proposer::proxy::ProposerProxy;
@@ -45,14 +49,10 @@ use rooch_types::bitcoin::genesis::BitcoinGenesisContext;
 use rooch_types::bitcoin::network::Network;
 use rooch_types::chain_id::RoochChainID;
 use rooch_types::transaction::TypedTransaction;
-use std::sync::Arc;
 use std::time::Duration;
 use tokio::runtime::Runtime;
 use tracing::info;
 
-pub const EXAMPLE_SIMPLE_BLOG_PACKAGE_NAME: &'static str = "simple_blog";
-pub const EXAMPLE_SIMPLE_BLOG_NAMED_ADDRESS: &str = "simple_blog";
-
 pub struct StoreHolder {
     _moveos_store: MoveOSStore,
     _rooch_store: RoochStore,
@@ -69,11 +69,18 @@ fn transaction_write_benchmark(c: &mut Criterion) {
     let default_account = keystore.addresses()[0];
     let mut test_transaction_builder = TestTransactionBuilder::new(default_account.into());
     let tx = create_publish_transaction(&test_transaction_builder, &keystore).unwrap();
-    let _publish_result = rt.block_on(async { rpc_service.execute_tx(tx).await.unwrap() });
+    let _publish_result = rt.block_on(async {
+        rpc_service
+            .execute_tx(TypedTransaction::Rooch(tx))
+            .await
+            .unwrap()
+    });
 
-    let mut transactions = (1..500)
-        .cycle()
-        .map(|n| create_transaction(&mut test_transaction_builder, &keystore, n).unwrap());
+    let mut transactions = (1..500).cycle().map(|n| {
+        TypedTransaction::Rooch(
+            create_transaction(&mut test_transaction_builder, &keystore, n).unwrap(),
+        )
+    });
     c.bench_function("execute_tx", |b| {
         b.to_async(Runtime::new().unwrap())
             .iter(|| rpc_service.execute_tx(transactions.next().unwrap()))
@@ -92,11 +99,21 @@ fn transaction_query_benchmark(c: &mut Criterion) {
     let default_account = keystore.addresses()[0];
     let mut test_transaction_builder = TestTransactionBuilder::new(default_account.into());
     let tx = create_publish_transaction(&test_transaction_builder, &keystore).unwrap();
-    let _publish_result = rt.block_on(async { rpc_service.execute_tx(tx).await.unwrap() });
+    let _publish_result = rt.block_on(async {
+        rpc_service
+            .execute_tx(TypedTransaction::Rooch(tx))
+            .await
+            .unwrap()
+    });
     //
     for n in 1..500 {
         let tx = create_transaction(&mut test_transaction_builder, &keystore, n).unwrap();
-        let _ = rt.block_on(async { rpc_service.execute_tx(tx).await.unwrap() });
+        let _ = rt.block_on(async {
+            rpc_service
+                .execute_tx(TypedTransaction::Rooch(tx))
+                .await
+                .unwrap()
+        });
     }
 
     let mut tx_orders = (1..500).cycle().map(|v| v);
@@ -139,8 +156,10 @@ async fn setup_service(
     // Init executor
     let is_genesis = moveos_store.statedb.is_genesis();
     let btc_network = Network::default().to_num();
+    let gas_schedule_blob =
+        bcs::to_bytes(&default_gas_schedule()).expect("Failure serializing genesis gas schedule");
     let executor_actor = ExecutorActor::new(
-        chain_id.genesis_ctx(rooch_account),
+        chain_id.genesis_ctx(rooch_account, gas_schedule_blob),
         BitcoinGenesisContext::new(btc_network),
         moveos_store.clone(),
         rooch_store.clone(),
@@ -165,10 +184,9 @@ async fn setup_service(
     let sequencer_proxy = SequencerProxy::new(sequencer.into());
 
     // Init DA
-    let da_server_proxies: Vec<Arc<dyn DAServerProxy + Send + Sync>> =
-        vec![Arc::new(DAServerNopProxy {})];
     let da_proxy = DAProxy::new(
-        DAActor::new(da_server_proxies)
+        DAActor::new(DAConfig::default(), &actor_system)
+            .await?
             .into_actor(Some("DAProxy"), &actor_system)
             .await?
             .into(),
@@ -199,13 +217,21 @@ async fn setup_service(
         .await?;
     let indexer_proxy = IndexerProxy::new(indexer_executor.into(), indexer_reader_executor.into());
 
+    // Init mempool
+    let mempool = MempoolActor::new(MempoolConfig::default())
+        .into_actor(Some("Mempool"), &actor_system)
+        .await?;
+    let mempool_proxy = MempoolProxy::new(mempool.into());
+
     let rpc_service = RpcService::new(
         chain_id.chain_id().id(),
         executor_proxy.clone(),
         sequencer_proxy,
         proposer_proxy,
         indexer_proxy,
+        mempool_proxy,
     );
+    tokio::spawn(rpc_service.clone().run_mempool_dispatcher());
     let aggregate_service = AggregateService::new(rpc_service.clone());
 
     Ok((rpc_service, aggregate_service))
@@ -262,34 +288,6 @@ fn init_indexer(datadir: &DataDirPath) -> Result<(IndexerStore, IndexerReader)>
     Ok((indexer_store, indexer_reader))
 }
 
-fn create_publish_transaction(
-    test_transaction_builder: &TestTransactionBuilder,
-    keystore: &InMemKeystore,
-) -> Result<TypedTransaction> {
-    let publish_action = test_transaction_builder.new_publish_examples(
-        EXAMPLE_SIMPLE_BLOG_PACKAGE_NAME,
-        Some(EXAMPLE_SIMPLE_BLOG_NAMED_ADDRESS.to_string()),
-    )?;
-    let tx_data = test_transaction_builder.build(publish_action);
-    let rooch_tx =
-        keystore.sign_transaction(&test_transaction_builder.sender.into(), tx_data, None)?;
-    Ok(TypedTransaction::Rooch(rooch_tx))
-}
-
-fn create_transaction(
-    test_transaction_builder: &mut TestTransactionBuilder,
-    keystore: &InMemKeystore,
-    sequence_number: u64,
-) -> Result<TypedTransaction> {
-    test_transaction_builder.update_sequence_number(sequence_number);
-
-    let action = test_transaction_builder.call_article_create();
-    let tx_data = test_transaction_builder.build(action);
-    let rooch_tx =
-        keystore.sign_transaction(&test_transaction_builder.sender.into(), tx_data, None)?;
-    Ok(TypedTransaction::Rooch(rooch_tx))
-}
-
 criterion_group! {
     name = rooch_transaction_benches;
     config = Criterion::default().sample_size(200).measurement_time(Duration::from_secs(10));
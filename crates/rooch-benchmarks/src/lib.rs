@@ -2,3 +2,5 @@
 // SPDX-License-Identifier: Apache-2.0
 
 pub mod helper;
+pub mod server;
+pub mod tx;
@@ -0,0 +1,53 @@
+// Copyright (c) RoochNetwork
+// SPDX-License-Identifier: Apache-2.0
+
+use anyhow::Result;
+use rooch_config::{RoochOpt, ServerOpt};
+use rooch_rpc_client::{Client, ClientBuilder};
+use rooch_rpc_server::Service;
+use std::net::TcpListener;
+
+/// A Rooch server running in the current process with a temporary store, the benchmarks send
+/// requests to it over HTTP, so the measurements include the JSON-RPC layer.
+pub struct BenchServer {
+    service: Service,
+    url: String,
+    client: Client,
+}
+
+impl BenchServer {
+    pub async fn start() -> Result<Self> {
+        // We may start multiple servers in one benchmark run, every server takes free ports
+        let mut opt = RoochOpt::new_with_temp_store();
+        let port = free_port()?;
+        opt.port = Some(port);
+        opt.metrics_port = Some(free_port()?);
+
+        let mut service = Service::new();
+        service.start(&opt, ServerOpt::new()).await?;
+
+        let url = format!("http://127.0.0.1:{}", port);
+        let client = ClientBuilder::default().build(&url).await?;
+        Ok(Self {
+            service,
+            url,
+            client,
+        })
+    }
+
+    pub fn url(&self) -> &str {
+        &self.url
+    }
+
+    pub fn client(&self) -> &Client {
+        &self.client
+    }
+
+    pub fn stop(self) -> Result<()> {
+        self.service.stop()
+    }
+}
+
+fn free_port() -> Result<u16> {
+    Ok(TcpListener::bind("127.0.0.1:0")?.local_addr()?.port())
+}
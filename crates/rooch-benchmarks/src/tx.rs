@@ -0,0 +1,37 @@
+// Copyright (c) RoochNetwork
+// SPDX-License-Identifier: Apache-2.0
+
+use anyhow::Result;
+use rooch_key::keystore::account_keystore::AccountKeystore;
+use rooch_key::keystore::memory_keystore::InMemKeystore;
+use rooch_test_transaction_builder::TestTransactionBuilder;
+use rooch_types::transaction::rooch::RoochTransaction;
+
+pub const EXAMPLE_SIMPLE_BLOG_PACKAGE_NAME: &str = "simple_blog";
+pub const EXAMPLE_SIMPLE_BLOG_NAMED_ADDRESS: &str = "simple_blog";
+
+/// Publish the simple blog example, the `create_transaction` calls it.
+pub fn create_publish_transaction(
+    test_transaction_builder: &TestTransactionBuilder,
+    keystore: &InMemKeystore,
+) -> Result<RoochTransaction> {
+    let publish_action = test_transaction_builder.new_publish_examples(
+        EXAMPLE_SIMPLE_BLOG_PACKAGE_NAME,
+        Some(EXAMPLE_SIMPLE_BLOG_NAMED_ADDRESS.to_string()),
+    )?;
+    let tx_data = test_transaction_builder.build(publish_action);
+    keystore.sign_transaction(&test_transaction_builder.sender.into(), tx_data, None)
+}
+
+/// Create an article of the simple blog example with the given sequence number.
+pub fn create_transaction(
+    test_transaction_builder: &mut TestTransactionBuilder,
+    keystore: &InMemKeystore,
+    sequence_number: u64,
+) -> Result<RoochTransaction> {
+    test_transaction_builder.update_sequence_number(sequence_number);
+
+    let action = test_transaction_builder.call_article_create();
+    let tx_data = test_transaction_builder.build(action);
+    keystore.sign_transaction(&test_transaction_builder.sender.into(), tx_data, None)
+}
@@ -16,11 +16,16 @@ use rooch_types::chain_id::RoochChainID;
 use rooch_types::crypto::RoochKeyPair;
 
 use crate::da_config::DAConfig;
+use crate::mempool_config::MempoolConfig;
+use crate::rate_limit_config::RateLimitConfig;
 use crate::store_config::StoreConfig;
 
 pub mod config;
 pub mod da_config;
 pub mod indexer_config;
+pub mod mempool_config;
+pub mod rate_limit_config;
+pub mod relayer_config;
 pub mod server_config;
 pub mod store_config;
 
@@ -29,6 +34,7 @@ pub const ROOCH_CONFIR_DIR: &str = "rooch_config";
 pub const ROOCH_CLIENT_CONFIG: &str = "rooch.yaml";
 pub const ROOCH_SERVER_CONFIG: &str = "server.yaml";
 pub const ROOCH_KEYSTORE_FILENAME: &str = "rooch.keystore";
+pub const DEFAULT_ETH_RELAYER_BATCH_SIZE: u64 = 10;
 
 pub static R_DEFAULT_BASE_DATA_DIR: Lazy<PathBuf> = Lazy::new(|| {
     dirs_next::home_dir()
@@ -85,12 +91,46 @@ pub struct RoochOpt {
     #[clap(long, short = 'p')]
     pub port: Option<u16>,
 
+    /// Optional custom port for the Prometheus metrics endpoint `/metrics`.
+    /// The port defaults to `9184`
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[clap(long)]
+    pub metrics_port: Option<u16>,
+
+    /// Optional custom host for the Prometheus metrics endpoint `/metrics`.
+    /// The metrics server listens on `127.0.0.1` by default, set `0.0.0.0` to expose it to the scraper.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[clap(long)]
+    pub metrics_host: Option<String>,
+
+    /// Optional port for the admin JSON-RPC server, which serves the `admin_*` methods.
+    /// The admin server listens on localhost only and does not start if the port is not set.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[clap(long, requires = "admin_token")]
+    pub admin_port: Option<u16>,
+
+    /// The bearer token the admin JSON-RPC requests must carry in the `Authorization` header
+    #[serde(skip_serializing)]
+    #[clap(long, env = "ROOCH_ADMIN_TOKEN", hide_env_values = true)]
+    pub admin_token: Option<String>,
+
     /// The Ethereum RPC URL to connect to for relay L1 block and transaction to L2.
     /// If not set, the relayer service will not start.
     #[serde(skip_serializing_if = "Option::is_none")]
     #[clap(long, env = "ETH_RPC_URL")]
     pub eth_rpc_url: Option<String>,
 
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[clap(long, env = "ETH_CONFIRMATIONS")]
+    /// The number of confirmations an Ethereum block needs before it is relayed, default is 0.
+    /// The relayed blocks orphaned by a reorg are replaced by the relayer.
+    pub eth_confirmations: Option<u64>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[clap(long)]
+    /// The max number of Ethereum blocks fetched in one catch-up round, default is 10.
+    pub eth_batch_size: Option<u64>,
+
     /// The Bitcoin RPC URL to connect to for relay L1 block and transaction to L2.
     /// If not set, the relayer service will not start.
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -115,6 +155,12 @@ pub struct RoochOpt {
     /// The start block height of the Bitcoin chain to start relaying from, default is latest.
     pub btc_start_block_height: Option<u64>,
 
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[clap(long, env = "BTC_CONFIRMATIONS")]
+    /// The number of confirmations a Bitcoin block needs before it is relayed, default is 0.
+    /// The blocks relayed before a deeper reorg are rolled back by the relayer.
+    pub btc_confirmations: Option<u64>,
+
     #[serde(skip_serializing_if = "Option::is_none")]
     #[clap(long)]
     /// The bitcoin network, default is regtest.
@@ -126,13 +172,33 @@ pub struct RoochOpt {
     /// The address of the proposer account
     #[clap(long)]
     pub proposer_account: Option<String>,
-    /// The address of the relayer account
+    /// The address of the relayer account, a relayer account other than the sequencer account
+    /// should be added to the relayer whitelist by the sequencer via `onchain_config::add_relayer_entry`
     #[clap(long)]
     pub relayer_account: Option<String>,
+    /// The upstream node RPC URL to follow.
+    /// If set, the server runs as a read-only follower node, it syncs the transactions sequenced by
+    /// the `sequencer-account` from the upstream node and forwards the write requests to it.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[clap(long, env = "ROOCH_UPSTREAM_URL", requires = "sequencer_account")]
+    pub upstream_url: Option<String>,
 
     #[serde(skip_serializing_if = "Option::is_none")]
     #[clap(long)]
     pub da: Option<DAConfig>,
+
+    /// The per-client rate limit of the JSON-RPC server in JSON format, such as
+    /// `{"api_keys":["key1"],"ip":{"write":{"per_second":5,"burst":20},...},"max_page_size":100}`.
+    /// The omitted fields take the default values, the rate limit is disabled if not set.
+    #[serde(skip_serializing)]
+    #[clap(long)]
+    pub rate_limit: Option<RateLimitConfig>,
+
+    /// The limits of the transaction mempool in JSON format, such as `{"max_count":10000,"ttl_secs":600}`.
+    /// The omitted fields take the default values.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[clap(long)]
+    pub mempool: Option<MempoolConfig>,
 }
 
 impl std::fmt::Display for RoochOpt {
@@ -152,16 +218,26 @@ impl RoochOpt {
             chain_id: Some(RoochChainID::LOCAL),
             store: StoreConfig::default(),
             port: None,
+            metrics_port: None,
+            metrics_host: None,
+            admin_port: None,
+            admin_token: None,
             eth_rpc_url: None,
+            eth_confirmations: None,
+            eth_batch_size: None,
             btc_rpc_url: None,
             btc_rpc_username: None,
             btc_rpc_password: None,
             btc_start_block_height: None,
+            btc_confirmations: None,
             btc_network: Some(Network::default().to_num()),
             sequencer_account: None,
             proposer_account: None,
             relayer_account: None,
+            upstream_url: None,
             da: None,
+            rate_limit: None,
+            mempool: None,
         }
     }
 
@@ -170,9 +246,35 @@ impl RoochOpt {
             .as_ref()
             .map(|eth_rpc_url| EthereumRelayerConfig {
                 eth_rpc_url: eth_rpc_url.clone(),
+                eth_confirmations: self.eth_confirmations.unwrap_or_default(),
+                eth_batch_size: self
+                    .eth_batch_size
+                    .unwrap_or(DEFAULT_ETH_RELAYER_BATCH_SIZE),
             })
     }
 
+    pub fn follower_config(&self) -> Option<FollowerConfig> {
+        let upstream_url = self.upstream_url.clone()?;
+        Some(FollowerConfig {
+            upstream_url,
+            sequencer_account: self.sequencer_account.clone().unwrap_or_default(),
+        })
+    }
+
+    /// The admin server is only started with a non-empty token, an empty token would let any
+    /// local process call the admin methods.
+    pub fn admin_config(&self) -> Result<Option<AdminConfig>> {
+        let port = match self.admin_port {
+            Some(port) => port,
+            None => return Ok(None),
+        };
+        let token = self.admin_token.clone().unwrap_or_default();
+        if token.trim().is_empty() {
+            anyhow::bail!("The admin token must not be empty when the admin port is set");
+        }
+        Ok(Some(AdminConfig { port, token }))
+    }
+
     pub fn bitcoin_relayer_config(&self) -> Option<BitcoinRelayerConfig> {
         self.btc_rpc_url.as_ref()?;
         Some(BitcoinRelayerConfig {
@@ -180,6 +282,7 @@ impl RoochOpt {
             btc_rpc_user_name: self.btc_rpc_username.clone().unwrap(),
             btc_rpc_password: self.btc_rpc_password.clone().unwrap(),
             btc_start_block_height: self.btc_start_block_height,
+            btc_confirmations: self.btc_confirmations.unwrap_or_default(),
         })
     }
 }
@@ -187,6 +290,20 @@ impl RoochOpt {
 #[derive(Debug, Clone)]
 pub struct EthereumRelayerConfig {
     pub eth_rpc_url: String,
+    pub eth_confirmations: u64,
+    pub eth_batch_size: u64,
+}
+
+#[derive(Debug, Clone)]
+pub struct AdminConfig {
+    pub port: u16,
+    pub token: String,
+}
+
+#[derive(Debug, Clone)]
+pub struct FollowerConfig {
+    pub upstream_url: String,
+    pub sequencer_account: String,
 }
 
 #[derive(Debug, Clone)]
@@ -195,6 +312,7 @@ pub struct BitcoinRelayerConfig {
     pub btc_rpc_user_name: String,
     pub btc_rpc_password: String,
     pub btc_start_block_height: Option<u64>,
+    pub btc_confirmations: u64,
 }
 
 #[derive(Clone, Debug, PartialEq)]
@@ -0,0 +1,79 @@
+// Copyright (c) RoochNetwork
+// SPDX-License-Identifier: Apache-2.0
+
+use std::str::FromStr;
+use std::time::Duration;
+
+use serde::{Deserialize, Serialize};
+
+use crate::config::Config;
+
+/// The limits of the transaction mempool, the omitted fields take the default values.
+#[derive(Clone, Debug, PartialEq, Eq, Deserialize, Serialize)]
+#[serde(default, deny_unknown_fields)]
+pub struct MempoolConfig {
+    /// The max number of transactions in the mempool.
+    pub max_count: usize,
+    /// The max number of transactions of a single sender in the mempool.
+    pub max_count_per_sender: usize,
+    /// The max total size in bytes of the transactions in the mempool.
+    pub max_bytes: u64,
+    /// The seconds a transaction can stay in the mempool before it is evicted.
+    pub ttl_secs: u64,
+    /// The seconds a transaction waits for the missing lower sequence numbers of its sender.
+    /// After the timeout, the transaction is released anyway and the executor decides its fate.
+    pub gap_timeout_secs: u64,
+    /// The gas price bump in percent required to replace a pending transaction.
+    pub price_bump_percent: u64,
+}
+
+impl MempoolConfig {
+    pub fn ttl(&self) -> Duration {
+        Duration::from_secs(self.ttl_secs)
+    }
+
+    pub fn gap_timeout(&self) -> Duration {
+        Duration::from_secs(self.gap_timeout_secs)
+    }
+}
+
+impl Default for MempoolConfig {
+    fn default() -> Self {
+        Self {
+            max_count: 10_000,
+            max_count_per_sender: 100,
+            max_bytes: 64 * 1024 * 1024,
+            ttl_secs: 600,
+            gap_timeout_secs: 3,
+            price_bump_percent: 10,
+        }
+    }
+}
+
+impl Config for MempoolConfig {}
+
+impl FromStr for MempoolConfig {
+    type Err = anyhow::Error;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let deserialized = serde_json::from_str(s)?;
+        Ok(deserialized)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_mempool_config_with_defaults() {
+        let config = MempoolConfig::from_str(r#"{"max_count":100,"ttl_secs":60}"#).unwrap();
+        assert_eq!(config.max_count, 100);
+        assert_eq!(config.ttl(), Duration::from_secs(60));
+        assert_eq!(
+            config.max_count_per_sender,
+            MempoolConfig::default().max_count_per_sender
+        );
+        assert!(MempoolConfig::from_str(r#"{"unknown":1}"#).is_err());
+    }
+}
@@ -0,0 +1,131 @@
+// Copyright (c) RoochNetwork
+// SPDX-License-Identifier: Apache-2.0
+
+use std::net::IpAddr;
+use std::str::FromStr;
+
+use serde::{Deserialize, Serialize};
+
+use crate::config::Config;
+
+pub const DEFAULT_MAX_PAGE_SIZE: usize = 200;
+
+/// The token bucket of a client, `per_second` tokens are refilled every second, up to `burst`.
+/// Every JSON-RPC call takes one token, the calls are rejected when the bucket is empty.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Deserialize, Serialize)]
+#[serde(deny_unknown_fields)]
+pub struct RateLimitQuota {
+    pub per_second: u32,
+    pub burst: u32,
+}
+
+impl RateLimitQuota {
+    pub const fn new(per_second: u32, burst: u32) -> Self {
+        Self { per_second, burst }
+    }
+}
+
+/// The quotas of each method class, every class has its own budget.
+#[derive(Clone, Debug, PartialEq, Eq, Deserialize, Serialize)]
+#[serde(deny_unknown_fields)]
+pub struct MethodClassQuotas {
+    /// Submit, execute or dry run transactions
+    pub write: RateLimitQuota,
+    /// Read the states, execute view functions and other calls served by the executor and sequencer
+    pub view: RateLimitQuota,
+    /// Query and sync the indexer, and list the states and transactions page by page
+    pub query: RateLimitQuota,
+}
+
+impl MethodClassQuotas {
+    pub fn default_ip_quotas() -> Self {
+        Self {
+            write: RateLimitQuota::new(5, 20),
+            view: RateLimitQuota::new(50, 100),
+            query: RateLimitQuota::new(10, 20),
+        }
+    }
+
+    pub fn default_api_key_quotas() -> Self {
+        Self {
+            write: RateLimitQuota::new(50, 200),
+            view: RateLimitQuota::new(500, 1000),
+            query: RateLimitQuota::new(100, 200),
+        }
+    }
+}
+
+/// The per-client rate limit of the public JSON-RPC server.
+///
+/// A request carrying one of the `api_keys` in the `X-Api-Key` header is limited per key, other
+/// requests are limited per client IP. The client IP is the remote address of the connection,
+/// the `X-Forwarded-For` and `X-Real-IP` headers are only trusted on the connections from the
+/// `trusted_proxies`, the clients can set any value in them.
+#[derive(Clone, Debug, PartialEq, Eq, Deserialize, Serialize)]
+#[serde(default, deny_unknown_fields)]
+pub struct RateLimitConfig {
+    pub ip: MethodClassQuotas,
+    pub api_key: MethodClassQuotas,
+    pub api_keys: Vec<String>,
+    /// The addresses of the reverse proxies in front of the server
+    pub trusted_proxies: Vec<IpAddr>,
+    /// The max number of items of a page returned by the list and query methods
+    pub max_page_size: usize,
+}
+
+impl Default for RateLimitConfig {
+    fn default() -> Self {
+        Self {
+            ip: MethodClassQuotas::default_ip_quotas(),
+            api_key: MethodClassQuotas::default_api_key_quotas(),
+            api_keys: vec![],
+            trusted_proxies: vec![],
+            max_page_size: DEFAULT_MAX_PAGE_SIZE,
+        }
+    }
+}
+
+impl Config for RateLimitConfig {}
+
+impl FromStr for RateLimitConfig {
+    type Err = anyhow::Error;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let deserialized = serde_json::from_str(s)?;
+        Ok(deserialized)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_rate_limit_config_with_defaults() {
+        let config =
+            RateLimitConfig::from_str(r#"{"api_keys":["key1"],"max_page_size":50}"#).unwrap();
+        assert_eq!(config.api_keys, vec!["key1".to_string()]);
+        assert_eq!(config.max_page_size, 50);
+        assert_eq!(config.ip, MethodClassQuotas::default_ip_quotas());
+        assert_eq!(config.api_key, MethodClassQuotas::default_api_key_quotas());
+    }
+
+    #[test]
+    fn test_parse_rate_limit_config_trusted_proxies() {
+        let config =
+            RateLimitConfig::from_str(r#"{"trusted_proxies":["127.0.0.1","::1"]}"#).unwrap();
+        assert_eq!(
+            config.trusted_proxies,
+            vec![
+                IpAddr::from([127, 0, 0, 1]),
+                IpAddr::from([0, 0, 0, 0, 0, 0, 0, 1])
+            ]
+        );
+        assert!(RateLimitConfig::from_str(r#"{"trusted_proxies":["localhost"]}"#).is_err());
+    }
+
+    #[test]
+    fn test_parse_rate_limit_config_unknown_field() {
+        assert!(RateLimitConfig::from_str(r#"{"unknown":1}"#).is_err());
+    }
+}
@@ -0,0 +1,107 @@
+// Copyright (c) RoochNetwork
+// SPDX-License-Identifier: Apache-2.0
+
+use clap::Parser;
+use serde::{Deserialize, Serialize};
+
+use crate::{BitcoinRelayerConfig, EthereumRelayerConfig, DEFAULT_ETH_RELAYER_BATCH_SIZE};
+
+pub const DEFAULT_ROOCH_RPC_URL: &str = "http://127.0.0.1:50051";
+
+/// The options of the standalone relayer, which reads the light client states and submits the
+/// relay transactions via the Rooch RPC, so it runs in a separate process from the sequencer.
+#[derive(Clone, Debug, Parser, Default, Serialize, Deserialize)]
+pub struct RelayerOpt {
+    /// The Rooch RPC URL of the node to relay to, default is `http://127.0.0.1:50051`
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[clap(long, env = "ROOCH_RPC_URL")]
+    pub rooch_rpc_url: Option<String>,
+
+    /// The address of the relayer account, default is the active address of the keystore
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[clap(long)]
+    pub relayer_account: Option<String>,
+
+    /// The interval in seconds between two relay rounds, default is 1
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[clap(long)]
+    pub relay_interval: Option<u64>,
+
+    /// The Ethereum RPC URL to relay the blocks from
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[clap(long, env = "ETH_RPC_URL")]
+    pub eth_rpc_url: Option<String>,
+
+    /// The number of confirmations an Ethereum block needs before it is relayed, default is 0.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[clap(long, env = "ETH_CONFIRMATIONS")]
+    pub eth_confirmations: Option<u64>,
+
+    /// The max number of Ethereum blocks fetched in one catch-up round, default is 10.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[clap(long)]
+    pub eth_batch_size: Option<u64>,
+
+    /// The Bitcoin RPC URL to relay the blocks from
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[clap(
+        long,
+        env = "BITCOIN_RPC_URL",
+        requires = "btc-rpc-username",
+        requires = "btc-rpc-password"
+    )]
+    pub btc_rpc_url: Option<String>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[clap(long, id = "btc-rpc-username", env = "BTC_RPC_USERNAME")]
+    pub btc_rpc_username: Option<String>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[clap(long, id = "btc-rpc-password", env = "BTC_RPC_PASSWORD")]
+    pub btc_rpc_password: Option<String>,
+
+    /// The start block height of the Bitcoin chain to start relaying from, default is latest.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[clap(long, env = "BTC_START_BLOCK_HEIGHT")]
+    pub btc_start_block_height: Option<u64>,
+
+    /// The number of confirmations a Bitcoin block needs before it is relayed, default is 0.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[clap(long, env = "BTC_CONFIRMATIONS")]
+    pub btc_confirmations: Option<u64>,
+}
+
+impl RelayerOpt {
+    pub fn rooch_rpc_url(&self) -> String {
+        self.rooch_rpc_url
+            .clone()
+            .unwrap_or_else(|| DEFAULT_ROOCH_RPC_URL.to_string())
+    }
+
+    pub fn relay_interval(&self) -> u64 {
+        self.relay_interval.unwrap_or(1)
+    }
+
+    pub fn ethereum_relayer_config(&self) -> Option<EthereumRelayerConfig> {
+        self.eth_rpc_url
+            .as_ref()
+            .map(|eth_rpc_url| EthereumRelayerConfig {
+                eth_rpc_url: eth_rpc_url.clone(),
+                eth_confirmations: self.eth_confirmations.unwrap_or_default(),
+                eth_batch_size: self
+                    .eth_batch_size
+                    .unwrap_or(DEFAULT_ETH_RELAYER_BATCH_SIZE),
+            })
+    }
+
+    pub fn bitcoin_relayer_config(&self) -> Option<BitcoinRelayerConfig> {
+        self.btc_rpc_url.as_ref()?;
+        Some(BitcoinRelayerConfig {
+            btc_rpc_url: self.btc_rpc_url.clone().unwrap(),
+            btc_rpc_user_name: self.btc_rpc_username.clone().unwrap(),
+            btc_rpc_password: self.btc_rpc_password.clone().unwrap(),
+            btc_start_block_height: self.btc_start_block_height,
+            btc_confirmations: self.btc_confirmations.unwrap_or_default(),
+        })
+    }
+}
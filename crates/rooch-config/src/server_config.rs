@@ -6,6 +6,9 @@ use serde::Deserialize;
 use serde::Serialize;
 use std::fmt::{Display, Formatter, Result, Write};
 
+pub const DEFAULT_METRICS_HOST: &str = "127.0.0.1";
+pub const DEFAULT_METRICS_PORT: u16 = 9184;
+
 #[derive(Clone, Debug, Deserialize, Serialize, PartialEq, Eq, Hash)]
 pub struct ServerConfig {
     pub host: String,
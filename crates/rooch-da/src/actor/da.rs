@@ -14,7 +14,8 @@ use futures::StreamExt;
 
 use rooch_config::da_config::{DAConfig, InternalDAServerConfigType};
 
-use crate::messages::{Batch, PutBatchMessage};
+use crate::messages::{Batch, DAStatus, GetDAStatusMessage, PutBatchMessage};
+use crate::metrics::DAMetrics;
 use crate::server::celestia::actor::server::DAServerCelestiaActor;
 use crate::server::celestia::proxy::DAServerCelestiaProxy;
 use crate::server::openda::actor::server::DAServerOpenDAActor;
@@ -24,10 +25,15 @@ use crate::server::serverproxy::DAServerProxy;
 // TODO tx buffer for building batch
 pub struct DAActor {
     internal_servers: InternalServers,
+    last_submitted_block_number: Option<u128>,
+    failed_batch_count: u64,
+    metrics: Option<DAMetrics>,
 }
 
+type NamedDAServer = (String, Arc<dyn DAServerProxy + Send + Sync>);
+
 struct InternalServers {
-    servers: Arc<RwLock<Vec<Arc<dyn DAServerProxy + Send + Sync>>>>,
+    servers: Arc<RwLock<Vec<NamedDAServer>>>,
     submit_threshold: usize,
 }
 
@@ -37,7 +43,7 @@ impl DAActor {
     pub async fn new(da_config: DAConfig, actor_system: &ActorSystem) -> Result<Self> {
         // internal servers
 
-        let mut servers: Vec<Arc<dyn DAServerProxy + Send + Sync>> = Vec::new();
+        let mut servers: Vec<NamedDAServer> = Vec::new();
         let mut submit_threshold = 1;
 
         if let Some(internal_da_server_config) = &da_config.internal_da_server {
@@ -50,9 +56,10 @@ impl DAActor {
                         .await
                         .into_actor(Some("DAServerCelestia"), actor_system)
                         .await?;
-                    servers.push(Arc::new(DAServerCelestiaProxy::new(
-                        da_server.clone().into(),
-                    )));
+                    servers.push((
+                        "celestia".to_owned(),
+                        Arc::new(DAServerCelestiaProxy::new(da_server.clone().into())),
+                    ));
                 }
                 if let InternalDAServerConfigType::OpenDA(openda_config) = server_config_type {
                     let da_server = DAServerOpenDAActor::new(openda_config)
@@ -62,11 +69,17 @@ impl DAActor {
                             actor_system,
                         )
                         .await?;
-                    servers.push(Arc::new(DAServerOpenDAProxy::new(da_server.clone().into())));
+                    servers.push((
+                        format!("openda-{}", openda_config.scheme),
+                        Arc::new(DAServerOpenDAProxy::new(da_server.clone().into())),
+                    ));
                 }
             }
         } else {
-            servers.push(Arc::new(crate::server::serverproxy::DAServerNopProxy {}));
+            servers.push((
+                "nop".to_owned(),
+                Arc::new(crate::server::serverproxy::DAServerNopProxy {}),
+            ));
         }
 
         Ok(Self {
@@ -74,9 +87,17 @@ impl DAActor {
                 servers: Arc::new(RwLock::new(servers)),
                 submit_threshold,
             },
+            last_submitted_block_number: None,
+            failed_batch_count: 0,
+            metrics: None,
         })
     }
 
+    pub fn with_metrics(mut self, metrics: DAMetrics) -> Self {
+        self.metrics = Some(metrics);
+        self
+    }
+
     pub async fn submit_batch(&self, batch: Batch) -> Result<()> {
         // TODO calc checksum
         // TODO richer policy for multi servers
@@ -87,20 +108,28 @@ impl DAActor {
         let submit_threshold = self.internal_servers.submit_threshold;
 
         let mut futures_unordered = FuturesUnordered::new();
-        for server in servers {
+        for (name, server) in servers {
             let server = Arc::clone(&server);
             let batch = batch.clone();
             futures_unordered.push(async move {
-                server
+                let result = server
                     .put_batch(PutBatchMessage {
                         batch: batch.clone(),
                     })
-                    .await
+                    .await;
+                (name, result)
             });
         }
 
         let mut success_count = 0;
-        while let Some(result) = futures_unordered.next().await {
+        while let Some((name, result)) = futures_unordered.next().await {
+            if let Some(metrics) = &self.metrics {
+                let result_label = if result.is_ok() { "ok" } else { "error" };
+                metrics
+                    .da_submit_batch_total
+                    .with_label_values(&[name.as_str(), result_label])
+                    .inc();
+            }
             match result {
                 Ok(_) => {
                     success_count += 1;
@@ -109,7 +138,7 @@ impl DAActor {
                     }
                 }
                 Err(e) => {
-                    log::warn!("{:?}, fail to submit batch to da server.", e); // TODO add da server name
+                    log::warn!("{:?}, fail to submit batch to da server {}.", e, name);
                 }
             }
         }
@@ -128,6 +157,36 @@ impl DAActor {
 #[async_trait]
 impl Handler<Batch> for DAActor {
     async fn handle(&mut self, msg: Batch, _ctx: &mut ActorContext) -> Result<()> {
-        self.submit_batch(msg).await
+        let block_number = msg.meta.block_number;
+        let result = self.submit_batch(msg).await;
+        match result {
+            Ok(_) => self.last_submitted_block_number = Some(block_number),
+            Err(_) => self.failed_batch_count += 1,
+        }
+        result
+    }
+}
+
+#[async_trait]
+impl Handler<GetDAStatusMessage> for DAActor {
+    async fn handle(
+        &mut self,
+        _msg: GetDAStatusMessage,
+        _ctx: &mut ActorContext,
+    ) -> Result<DAStatus> {
+        let servers = self
+            .internal_servers
+            .servers
+            .read()
+            .unwrap()
+            .iter()
+            .map(|(name, _)| name.clone())
+            .collect();
+        Ok(DAStatus {
+            servers,
+            submit_threshold: self.internal_servers.submit_threshold,
+            last_submitted_block_number: self.last_submitted_block_number,
+            failed_batch_count: self.failed_batch_count,
+        })
     }
 }
@@ -3,5 +3,6 @@
 
 pub mod actor;
 pub mod messages;
+pub mod metrics;
 pub mod proxy;
 pub mod server;
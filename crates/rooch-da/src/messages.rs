@@ -27,6 +27,25 @@ impl Message for Batch {
     type Result = anyhow::Result<()>;
 }
 
+#[derive(Debug, Serialize, Deserialize)]
+pub struct GetDAStatusMessage {}
+
+#[derive(Debug, Serialize, Deserialize, Clone)]
+pub struct DAStatus {
+    // names of the DA servers the batches are submitted to
+    pub servers: Vec<String>,
+    // min number of servers a batch must be submitted to
+    pub submit_threshold: usize,
+    // block number of the last batch submitted successfully
+    pub last_submitted_block_number: Option<u128>,
+    // number of batches failed to submit since the node started
+    pub failed_batch_count: u64,
+}
+
+impl Message for GetDAStatusMessage {
+    type Result = anyhow::Result<DAStatus>;
+}
+
 #[derive(Debug, Serialize, Deserialize)]
 pub struct PutBatchMessage {
     pub batch: Batch,
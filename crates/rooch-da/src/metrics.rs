@@ -0,0 +1,27 @@
+// Copyright (c) RoochNetwork
+// SPDX-License-Identifier: Apache-2.0
+
+use metrics::{register, Opts, PrometheusError, Registry, UIntCounterVec};
+
+#[derive(Clone)]
+pub struct DAMetrics {
+    pub da_submit_batch_total: UIntCounterVec,
+}
+
+impl DAMetrics {
+    pub fn register(registry: &Registry) -> Result<Self, PrometheusError> {
+        let da_submit_batch_total = register(
+            UIntCounterVec::new(
+                Opts::new(
+                    "da_submit_batch_total",
+                    "Counters of the batches submitted to each DA backend",
+                ),
+                &["backend", "result"],
+            )?,
+            registry,
+        )?;
+        Ok(Self {
+            da_submit_batch_total,
+        })
+    }
+}
@@ -4,7 +4,7 @@
 use coerce::actor::ActorRef;
 
 use crate::actor::da::DAActor;
-use crate::messages::Batch;
+use crate::messages::{Batch, DAStatus, GetDAStatusMessage};
 
 #[derive(Clone)]
 pub struct DAProxy {
@@ -19,4 +19,8 @@ impl DAProxy {
     pub async fn submit_batch(&self, batch: Batch) -> anyhow::Result<()> {
         self.actor.send(batch).await?
     }
+
+    pub async fn get_status(&self) -> anyhow::Result<DAStatus> {
+        self.actor.send(GetDAStatusMessage {}).await?
+    }
 }
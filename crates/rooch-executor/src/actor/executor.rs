@@ -2,14 +2,16 @@
 // SPDX-License-Identifier: Apache-2.0
 
 use super::messages::{
-    ExecuteTransactionMessage, ExecuteTransactionResult, ResolveMessage, ValidateTransactionMessage,
+    ExecuteFollowedTransactionMessage, ExecuteTransactionMessage, ExecuteTransactionResult,
+    GetStartupInfoMessage, ResolveMessage, ValidateTransactionMessage,
 };
+use crate::metrics::ExecutorMetrics;
 use accumulator::inmemory::InMemoryAccumulator;
 use anyhow::Result;
 use async_trait::async_trait;
 use coerce::actor::{context::ActorContext, message::Handler, Actor};
 use itertools::Itertools;
-use move_binary_format::errors::{Location, PartialVMError, VMResult};
+use move_binary_format::errors::{Location, PartialVMError, VMError, VMResult};
 use move_core_types::account_address::AccountAddress;
 use move_core_types::identifier::{IdentStr, Identifier};
 use move_core_types::language_storage::ModuleId;
@@ -18,7 +20,6 @@ use move_core_types::value::MoveValue;
 use move_core_types::vm_status::{StatusCode, VMStatus};
 use moveos::gas::table::{initial_cost_schedule, MoveOSGasMeter};
 use moveos::moveos::{GasPaymentAccount, MoveOS};
-use moveos::vm::vm_status_explainer::explain_vm_status;
 use moveos_store::transaction_store::TransactionStore;
 use moveos_store::MoveOSStore;
 use moveos_types::genesis_info::GenesisInfo;
@@ -26,27 +27,30 @@ use moveos_types::h256::H256;
 use moveos_types::module_binding::MoveFunctionCaller;
 use moveos_types::move_types::FunctionId;
 use moveos_types::moveos_std::tx_context::TxContext;
+use moveos_types::startup_info::StartupInfo;
 use moveos_types::transaction::TransactionOutput;
 use moveos_types::transaction::VerifiedMoveOSTransaction;
 use moveos_types::transaction::{
     FunctionCall, MoveAction, MoveOSTransaction, TransactionExecutionInfo, VerifiedMoveAction,
 };
 use moveos_verifier::metadata::load_module_metadata;
-use rooch_genesis::RoochGenesis;
+use rooch_genesis::{error_mapping, RoochGenesis};
 use rooch_store::RoochStore;
 use rooch_types::address::MultiChainAddress;
 use rooch_types::bitcoin::genesis::BitcoinGenesisContext;
+use rooch_types::error::{VMErrorInfo, VMErrorKind};
 use rooch_types::framework::address_mapping::AddressMapping;
 use rooch_types::framework::auth_validator::{AuthValidatorCaller, TxValidateResult};
 use rooch_types::framework::genesis::GenesisContext;
 use rooch_types::framework::transaction_validator::TransactionValidator;
 use rooch_types::framework::{system_post_execute_functions, system_pre_execute_functions};
-use rooch_types::transaction::{AbstractTransaction, AuthenticatorInfo};
+use rooch_types::transaction::{AbstractTransaction, AuthenticatorInfo, FeePayerInfo};
 
 pub struct ExecutorActor {
     genesis: RoochGenesis,
     moveos: MoveOS,
     rooch_store: RoochStore,
+    metrics: Option<ExecutorMetrics>,
 }
 
 type ValidateAuthenticatorResult = Result<
@@ -74,16 +78,23 @@ impl ExecutorActor {
             genesis.config.clone(),
             system_pre_execute_functions(),
             system_post_execute_functions(),
-        )?;
+        )?
+        .with_natives_builder(genesis.natives_builder());
 
         let executor = Self {
             genesis,
             moveos,
             rooch_store,
+            metrics: None,
         };
         executor.init_or_check_genesis()
     }
 
+    pub fn with_metrics(mut self, metrics: ExecutorMetrics) -> Self {
+        self.metrics = Some(metrics);
+        self
+    }
+
     fn init_or_check_genesis(mut self) -> Result<Self> {
         if self.moveos().state().is_genesis() {
             let genesis_result = self.moveos.init_genesis(
@@ -142,17 +153,71 @@ impl ExecutorActor {
     }
 
     pub fn execute(&mut self, tx: VerifiedMoveOSTransaction) -> Result<ExecuteTransactionResult> {
+        let _timer = self.metrics.as_ref().map(|metrics| {
+            metrics
+                .executor_execute_tx_latency_seconds
+                .with_label_values(&["execute"])
+                .start_timer()
+        });
         let tx_hash = tx.ctx.tx_hash();
-        let (state_root, output) = self.moveos.execute_and_apply(tx)?;
+        let (state_root, output) = self
+            .moveos
+            .execute_and_apply(tx)
+            .map_err(|e| self.explain_vm_error(VMErrorKind::Execution, e))?;
         self.handle_tx_output(tx_hash, state_root, output)
     }
 
+    /// Execute the transaction and apply the output only if the state root after execution is the
+    /// `expected_state_root`, the state is not changed if the state root mismatches.
+    pub fn execute_followed(
+        &mut self,
+        tx: VerifiedMoveOSTransaction,
+        expected_state_root: H256,
+    ) -> Result<ExecuteTransactionResult> {
+        let _timer = self.metrics.as_ref().map(|metrics| {
+            metrics
+                .executor_execute_tx_latency_seconds
+                .with_label_values(&["execute_followed"])
+                .start_timer()
+        });
+        let tx_hash = tx.ctx.tx_hash();
+        let (state_root, output) = self
+            .moveos
+            .execute_and_apply_with_state_root(tx, expected_state_root)
+            .map_err(|e| self.explain_vm_error(VMErrorKind::Execution, e))?;
+        self.handle_tx_output(tx_hash, state_root, output)
+    }
+
+    /// Attach the explained VM status to the VM errors, so the RPC clients can see why the VM
+    /// rejected the transaction.
+    fn explain_vm_error(&self, kind: VMErrorKind, error: anyhow::Error) -> anyhow::Error {
+        match error.downcast::<VMError>() {
+            Ok(vm_error) => self.vm_error_info(kind, vm_error.into_vm_status()).into(),
+            Err(error) => error,
+        }
+    }
+
+    fn vm_error_info(&self, kind: VMErrorKind, vm_status: VMStatus) -> VMErrorInfo {
+        VMErrorInfo::new(
+            kind,
+            vm_status,
+            self.moveos.moveos_resolver(),
+            error_mapping(),
+        )
+    }
+
     fn handle_tx_output(
         &mut self,
         tx_hash: H256,
         state_root: H256,
         output: TransactionOutput,
     ) -> Result<ExecuteTransactionResult> {
+        if let Some(metrics) = &self.metrics {
+            metrics
+                .executor_gas_used
+                .with_label_values(&["execute"])
+                .inc_by(output.gas_used);
+        }
         let event_hashes: Vec<_> = output.events.iter().map(|e| e.hash()).collect();
         let event_root = InMemoryAccumulator::from_leaves(event_hashes.as_slice()).root_hash();
 
@@ -180,21 +245,27 @@ impl ExecutorActor {
     }
 
     pub fn validate<T: AbstractTransaction>(&self, tx: T) -> Result<VerifiedMoveOSTransaction> {
+        let _timer = self.metrics.as_ref().map(|metrics| {
+            metrics
+                .executor_validate_tx_latency_seconds
+                .with_label_values(&["validate"])
+                .start_timer()
+        });
         let multi_chain_address_sender = tx.sender();
 
         let resolved_sender = self.resolve_or_generate(multi_chain_address_sender.clone())?;
         let authenticator = tx.authenticator_info()?;
+        let fee_payer = tx.fee_payer_info()?;
 
         let mut moveos_tx = tx.construct_moveos_transaction(resolved_sender)?;
 
-        let vm_result = self.validate_authenticator(&moveos_tx.ctx, authenticator)?;
-
-        let can_pay_gas = self.validate_gas_function(&moveos_tx)?;
-
         let mut pay_by_module_account = false;
         let mut gas_payment_account = moveos_tx.ctx.sender;
 
-        if let Some(pay_gas) = can_pay_gas {
+        if let Some(fee_payer) = &fee_payer {
+            // A sponsored transaction is always paid by the fee payer.
+            gas_payment_account = fee_payer.address;
+        } else if let Some(pay_gas) = self.validate_gas_function(&moveos_tx)? {
             if pay_gas {
                 let account_balance = self.get_account_balance(&moveos_tx)?;
                 let module_account = {
@@ -225,6 +296,15 @@ impl ExecutorActor {
             })
             .expect("adding GasPaymentAccount to tx context failed.");
 
+        let fee_payer_result = match fee_payer {
+            Some(fee_payer) => self.validate_fee_payer(&moveos_tx.ctx, fee_payer)?,
+            None => Ok(()),
+        };
+        let vm_result = match fee_payer_result {
+            Ok(()) => self.validate_authenticator(&moveos_tx.ctx, authenticator)?,
+            Err(vm_status) => Err(vm_status),
+        };
+
         match vm_result {
             Ok((
                 tx_validate_result,
@@ -246,21 +326,48 @@ impl ExecutorActor {
 
                 moveos_tx.append_pre_execute_functions(pre_execute_functions);
                 moveos_tx.append_post_execute_functions(post_execute_functions);
-                Ok(self.moveos().verify(moveos_tx)?)
+                self.moveos().verify(moveos_tx).map_err(|e| {
+                    self.vm_error_info(VMErrorKind::Validation, e.into_vm_status())
+                        .into()
+                })
             }
             Err(e) => {
-                let status_view = explain_vm_status(self.moveos.moveos_resolver(), e.clone())?;
+                let error = self.vm_error_info(VMErrorKind::Validation, e);
                 log::warn!(
                     "transaction validate vm error, tx_hash: {}, error:{:?}",
                     moveos_tx.ctx.tx_hash(),
-                    status_view,
+                    error,
                 );
-                //TODO how to return the vm status to rpc client.
-                Err(e.into())
+                Err(error.into())
             }
         }
     }
 
+    /// Check the fee payer's account and verify its signature over the sponsored transaction.
+    pub fn validate_fee_payer(
+        &self,
+        ctx: &TxContext,
+        fee_payer: FeePayerInfo,
+    ) -> Result<Result<(), VMStatus>> {
+        let tx_validator = self.moveos().as_module_binding::<TransactionValidator>();
+        let auth_validator = match tx_validator
+            .validate_fee_payer(ctx, &fee_payer)?
+            .into_result()
+        {
+            Ok(auth_validator) => auth_validator,
+            Err(vm_status) => return Ok(Err(vm_status)),
+        };
+        // The auth validator checks the signature against the context sender,
+        // so run it in the fee payer's context.
+        let mut fee_payer_ctx = ctx.clone();
+        fee_payer_ctx.sender = fee_payer.address;
+        let auth_validator_caller = AuthValidatorCaller::new(self.moveos(), auth_validator);
+        Ok(auth_validator_caller
+            .validate(&fee_payer_ctx, fee_payer.authenticator.payload)?
+            .into_result()
+            .map(|_| ()))
+    }
+
     pub fn validate_authenticator(
         &self,
         ctx: &TxContext,
@@ -398,8 +505,10 @@ impl ExecutorActor {
                                 vec![],
                             );
 
-                            let function_execution_result =
-                                self.moveos.execute_view_function(gas_validate_func_call);
+                            let function_execution_result = self.moveos.execute_readonly_function(
+                                &TxContext::new_readonly_ctx(AccountAddress::ZERO),
+                                gas_validate_func_call,
+                            );
 
                             return if function_execution_result.vm_status == VMStatus::Executed {
                                 let return_value = function_execution_result.return_values.unwrap();
@@ -460,8 +569,10 @@ impl ExecutorActor {
                         .unwrap()],
                 );
 
-                let function_execution_result =
-                    self.moveos.execute_view_function(gas_balance_func_call);
+                let function_execution_result = self.moveos.execute_readonly_function(
+                    &TxContext::new_readonly_ctx(AccountAddress::ZERO),
+                    gas_balance_func_call,
+                );
 
                 if function_execution_result.vm_status == VMStatus::Executed {
                     let return_value = function_execution_result.return_values.unwrap();
@@ -519,3 +630,25 @@ impl Handler<ExecuteTransactionMessage> for ExecutorActor {
         self.execute(msg.tx)
     }
 }
+
+#[async_trait]
+impl Handler<ExecuteFollowedTransactionMessage> for ExecutorActor {
+    async fn handle(
+        &mut self,
+        msg: ExecuteFollowedTransactionMessage,
+        _ctx: &mut ActorContext,
+    ) -> Result<ExecuteTransactionResult> {
+        self.execute_followed(msg.tx, msg.expected_state_root)
+    }
+}
+
+#[async_trait]
+impl Handler<GetStartupInfoMessage> for ExecutorActor {
+    async fn handle(
+        &mut self,
+        _msg: GetStartupInfoMessage,
+        _ctx: &mut ActorContext,
+    ) -> Result<Option<StartupInfo>> {
+        self.moveos().config_store().get_startup_info()
+    }
+}
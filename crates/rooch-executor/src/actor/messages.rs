@@ -6,11 +6,15 @@ use coerce::actor::message::Message;
 use move_core_types::account_address::AccountAddress;
 use move_core_types::language_storage::StructTag;
 use moveos_types::access_path::AccessPath;
-use moveos_types::function_return_value::AnnotatedFunctionResult;
+use moveos_types::function_return_value::{AnnotatedFunctionResult, FunctionResult};
+use moveos_types::gas_profile::GasProfile;
 use moveos_types::h256::H256;
 use moveos_types::moveos_std::event::{AnnotatedEvent, Event, EventID};
+use moveos_types::moveos_std::tx_context::TxContext;
+use moveos_types::startup_info::StartupInfo;
 use moveos_types::state::{AnnotatedState, State};
 use moveos_types::transaction::FunctionCall;
+use moveos_types::transaction::RawTransactionOutput;
 use moveos_types::transaction::TransactionExecutionInfo;
 use moveos_types::transaction::TransactionOutput;
 use moveos_types::transaction::VerifiedMoveOSTransaction;
@@ -45,15 +49,58 @@ impl Message for ExecuteTransactionMessage {
     type Result = Result<ExecuteTransactionResult>;
 }
 
+/// Execute a transaction sequenced by the upstream node, the output is applied only if the state
+/// root after execution is the `expected_state_root` of the upstream node.
+#[derive(Debug)]
+pub struct ExecuteFollowedTransactionMessage {
+    pub tx: VerifiedMoveOSTransaction,
+    pub expected_state_root: H256,
+}
+
+impl Message for ExecuteFollowedTransactionMessage {
+    type Result = Result<ExecuteTransactionResult>;
+}
+
+/// Execute the transaction without applying the state changes.
+#[derive(Debug)]
+pub struct DryRunTransactionMessage {
+    pub tx: VerifiedMoveOSTransaction,
+    pub profile_gas: bool,
+}
+
+#[derive(Debug)]
+pub struct DryRunTransactionResult {
+    pub output: RawTransactionOutput,
+    pub gas_profile: Option<GasProfile>,
+}
+
+impl Message for DryRunTransactionMessage {
+    type Result = Result<DryRunTransactionResult>;
+}
+
 #[derive(Debug, Serialize, Deserialize)]
 pub struct ExecuteViewFunctionMessage {
     pub call: FunctionCall,
+    pub sender: Option<AccountAddress>,
+    pub max_gas_amount: Option<u64>,
 }
 
 impl Message for ExecuteViewFunctionMessage {
     type Result = Result<AnnotatedFunctionResult, anyhow::Error>;
 }
 
+/// Execute a readonly function without the view function verification,
+/// it is used by the Rust module bindings on the node side.
+#[derive(Debug, Serialize, Deserialize)]
+pub struct ExecuteReadonlyFunctionMessage {
+    pub call: FunctionCall,
+    pub tx_context: TxContext,
+}
+
+impl Message for ExecuteReadonlyFunctionMessage {
+    type Result = Result<FunctionResult, anyhow::Error>;
+}
+
 #[derive(Debug, Serialize, Deserialize)]
 pub struct StatesMessage {
     pub access_path: AccessPath,
@@ -161,3 +208,10 @@ pub struct RefreshStateMessage {
 impl Message for RefreshStateMessage {
     type Result = Result<()>;
 }
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct GetStartupInfoMessage {}
+
+impl Message for GetStartupInfoMessage {
+    type Result = Result<Option<StartupInfo>>;
+}
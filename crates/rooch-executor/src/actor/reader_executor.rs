@@ -2,9 +2,10 @@
 // SPDX-License-Identifier: Apache-2.0
 
 use super::messages::{
-    AnnotatedStatesMessage, ExecuteViewFunctionMessage, GetAnnotatedEventsByEventHandleMessage,
-    GetAnnotatedStatesByStateMessage, GetEventsByEventHandleMessage, RefreshStateMessage,
-    StatesMessage,
+    AnnotatedStatesMessage, DryRunTransactionMessage, DryRunTransactionResult,
+    ExecuteReadonlyFunctionMessage, ExecuteViewFunctionMessage,
+    GetAnnotatedEventsByEventHandleMessage, GetAnnotatedStatesByStateMessage,
+    GetEventsByEventHandleMessage, RefreshStateMessage, StatesMessage,
 };
 use crate::actor::messages::{
     GetEventsByEventIDsMessage, GetTxExecutionInfosByHashMessage, ListAnnotatedStatesMessage,
@@ -13,19 +14,22 @@ use crate::actor::messages::{
 use anyhow::Result;
 use async_trait::async_trait;
 use coerce::actor::{context::ActorContext, message::Handler, Actor};
+use move_binary_format::errors::VMError;
 use move_resource_viewer::MoveValueAnnotator;
 use moveos::moveos::MoveOS;
 use moveos_store::transaction_store::TransactionStore;
 use moveos_store::MoveOSStore;
 use moveos_types::function_return_value::AnnotatedFunctionResult;
 use moveos_types::function_return_value::AnnotatedFunctionReturnValue;
+use moveos_types::function_return_value::FunctionResult;
 use moveos_types::moveos_std::event::EventHandle;
 use moveos_types::moveos_std::event::{AnnotatedEvent, Event};
 use moveos_types::state::{AnnotatedState, State};
 use moveos_types::state_resolver::{AnnotatedStateReader, StateReader};
-use moveos_types::transaction::TransactionExecutionInfo;
-use rooch_genesis::RoochGenesis;
+use moveos_types::transaction::{TransactionExecutionInfo, VerifiedMoveOSTransaction};
+use rooch_genesis::{error_mapping, RoochGenesis};
 use rooch_store::RoochStore;
+use rooch_types::error::{VMErrorInfo, VMErrorKind};
 use rooch_types::framework::{system_post_execute_functions, system_pre_execute_functions};
 
 pub struct ReaderExecutorActor {
@@ -45,7 +49,8 @@ impl ReaderExecutorActor {
             genesis.config.clone(),
             system_pre_execute_functions(),
             system_post_execute_functions(),
-        )?;
+        )?
+        .with_natives_builder(genesis.natives_builder());
 
         Ok(Self {
             moveos,
@@ -60,6 +65,36 @@ impl ReaderExecutorActor {
     pub fn moveos(&self) -> &MoveOS {
         &self.moveos
     }
+
+    /// Execute the transaction at the current state root without applying the state changes,
+    /// it runs on the reader so a long dry run does not block the transaction execution.
+    pub fn dry_run(
+        &self,
+        tx: VerifiedMoveOSTransaction,
+        profile_gas: bool,
+    ) -> Result<DryRunTransactionResult> {
+        let result = if profile_gas {
+            self.moveos
+                .execute_with_gas_profiler(tx)
+                .map(|(output, gas_profile)| (output, Some(gas_profile)))
+        } else {
+            self.moveos.execute(tx).map(|output| (output, None))
+        };
+        let (output, gas_profile) = result.map_err(|e| match e.downcast::<VMError>() {
+            Ok(vm_error) => VMErrorInfo::new(
+                VMErrorKind::Execution,
+                vm_error.into_vm_status(),
+                self.moveos.moveos_resolver(),
+                error_mapping(),
+            )
+            .into(),
+            Err(e) => e,
+        })?;
+        Ok(DryRunTransactionResult {
+            output,
+            gas_profile,
+        })
+    }
 }
 
 impl Actor for ReaderExecutorActor {}
@@ -73,7 +108,11 @@ impl Handler<ExecuteViewFunctionMessage> for ReaderExecutorActor {
     ) -> Result<AnnotatedFunctionResult, anyhow::Error> {
         let resoler = self.moveos().moveos_resolver();
 
-        let function_result = self.moveos().execute_view_function(msg.call);
+        let function_result = self.moveos().execute_view_function_with_options(
+            msg.call,
+            msg.sender,
+            msg.max_gas_amount,
+        );
         Ok(AnnotatedFunctionResult {
             vm_status: function_result.vm_status,
             return_values: match function_result.return_values {
@@ -91,10 +130,35 @@ impl Handler<ExecuteViewFunctionMessage> for ReaderExecutorActor {
                 ),
                 None => None,
             },
+            gas_used: function_result.gas_used,
         })
     }
 }
 
+#[async_trait]
+impl Handler<DryRunTransactionMessage> for ReaderExecutorActor {
+    async fn handle(
+        &mut self,
+        msg: DryRunTransactionMessage,
+        _ctx: &mut ActorContext,
+    ) -> Result<DryRunTransactionResult> {
+        self.dry_run(msg.tx, msg.profile_gas)
+    }
+}
+
+#[async_trait]
+impl Handler<ExecuteReadonlyFunctionMessage> for ReaderExecutorActor {
+    async fn handle(
+        &mut self,
+        msg: ExecuteReadonlyFunctionMessage,
+        _ctx: &mut ActorContext,
+    ) -> Result<FunctionResult, anyhow::Error> {
+        Ok(self
+            .moveos()
+            .execute_readonly_function(&msg.tx_context, msg.call))
+    }
+}
+
 #[async_trait]
 impl Handler<StatesMessage> for ReaderExecutorActor {
     async fn handle(
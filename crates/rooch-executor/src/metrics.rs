@@ -0,0 +1,53 @@
+// Copyright (c) RoochNetwork
+// SPDX-License-Identifier: Apache-2.0
+
+use metrics::{
+    register, HistogramOpts, HistogramVec, Opts, PrometheusError, Registry, UIntCounterVec,
+};
+
+#[derive(Clone)]
+pub struct ExecutorMetrics {
+    pub executor_execute_tx_latency_seconds: HistogramVec,
+    pub executor_validate_tx_latency_seconds: HistogramVec,
+    pub executor_gas_used: UIntCounterVec,
+}
+
+impl ExecutorMetrics {
+    pub fn register(registry: &Registry) -> Result<Self, PrometheusError> {
+        let executor_execute_tx_latency_seconds = register(
+            HistogramVec::new(
+                HistogramOpts::new(
+                    "executor_execute_tx_latency_seconds",
+                    "Histogram of the time used to execute transactions",
+                ),
+                &["fn_name"],
+            )?,
+            registry,
+        )?;
+        let executor_validate_tx_latency_seconds = register(
+            HistogramVec::new(
+                HistogramOpts::new(
+                    "executor_validate_tx_latency_seconds",
+                    "Histogram of the time used to validate transactions",
+                ),
+                &["fn_name"],
+            )?,
+            registry,
+        )?;
+        let executor_gas_used = register(
+            UIntCounterVec::new(
+                Opts::new(
+                    "executor_gas_used",
+                    "Counters of the gas used by the executed transactions",
+                ),
+                &["fn_name"],
+            )?,
+            registry,
+        )?;
+        Ok(Self {
+            executor_execute_tx_latency_seconds,
+            executor_validate_tx_latency_seconds,
+            executor_gas_used,
+        })
+    }
+}
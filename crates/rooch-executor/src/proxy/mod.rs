@@ -2,7 +2,8 @@
 // SPDX-License-Identifier: Apache-2.0
 
 use crate::actor::messages::{
-    GetAnnotatedStatesByStateMessage, GetEventsByEventHandleMessage, GetEventsByEventIDsMessage,
+    DryRunTransactionMessage, DryRunTransactionResult, GetAnnotatedStatesByStateMessage,
+    GetEventsByEventHandleMessage, GetEventsByEventIDsMessage, GetStartupInfoMessage,
     GetTxExecutionInfosByHashMessage, ListAnnotatedStatesMessage, ListStatesMessage,
     RefreshStateMessage,
 };
@@ -10,8 +11,9 @@ use crate::actor::reader_executor::ReaderExecutorActor;
 use crate::actor::{
     executor::ExecutorActor,
     messages::{
-        AnnotatedStatesMessage, ExecuteViewFunctionMessage, GetAnnotatedEventsByEventHandleMessage,
-        ResolveMessage, StatesMessage, ValidateTransactionMessage,
+        AnnotatedStatesMessage, ExecuteReadonlyFunctionMessage, ExecuteViewFunctionMessage,
+        GetAnnotatedEventsByEventHandleMessage, ResolveMessage, StatesMessage,
+        ValidateTransactionMessage,
     },
 };
 use anyhow::Result;
@@ -23,6 +25,7 @@ use moveos_types::h256::H256;
 use moveos_types::module_binding::MoveFunctionCaller;
 use moveos_types::moveos_std::event::{Event, EventID};
 use moveos_types::moveos_std::tx_context::TxContext;
+use moveos_types::startup_info::StartupInfo;
 use moveos_types::transaction::FunctionCall;
 use moveos_types::transaction::TransactionExecutionInfo;
 use moveos_types::transaction::TransactionOutput;
@@ -71,12 +74,63 @@ impl ExecutorProxy {
         Ok((result.output, result.transaction_info))
     }
 
+    /// Execute a transaction sequenced by the upstream node, the state is not changed and an error
+    /// is returned if the state root after execution is not the `expected_state_root`.
+    pub async fn execute_followed_transaction(
+        &self,
+        tx: VerifiedMoveOSTransaction,
+        expected_state_root: H256,
+    ) -> Result<(TransactionOutput, TransactionExecutionInfo)> {
+        let result = self
+            .actor
+            .send(crate::actor::messages::ExecuteFollowedTransactionMessage {
+                tx,
+                expected_state_root,
+            })
+            .await??;
+        Ok((result.output, result.transaction_info))
+    }
+
+    pub async fn dry_run_transaction(
+        &self,
+        tx: VerifiedMoveOSTransaction,
+        profile_gas: bool,
+    ) -> Result<DryRunTransactionResult> {
+        self.reader_actor
+            .send(DryRunTransactionMessage { tx, profile_gas })
+            .await?
+    }
+
     pub async fn execute_view_function(
         &self,
         call: FunctionCall,
     ) -> Result<AnnotatedFunctionResult> {
+        self.execute_view_function_with_options(call, None, None)
+            .await
+    }
+
+    pub async fn execute_view_function_with_options(
+        &self,
+        call: FunctionCall,
+        sender: Option<AccountAddress>,
+        max_gas_amount: Option<u64>,
+    ) -> Result<AnnotatedFunctionResult> {
+        self.reader_actor
+            .send(ExecuteViewFunctionMessage {
+                call,
+                sender,
+                max_gas_amount,
+            })
+            .await?
+    }
+
+    pub async fn execute_readonly_function(
+        &self,
+        call: FunctionCall,
+        tx_context: TxContext,
+    ) -> Result<FunctionResult> {
         self.reader_actor
-            .send(ExecuteViewFunctionMessage { call })
+            .send(ExecuteReadonlyFunctionMessage { call, tx_context })
             .await?
     }
 
@@ -194,19 +248,24 @@ impl ExecutorProxy {
             })
             .await?
     }
+
+    pub async fn get_startup_info(&self) -> Result<Option<StartupInfo>> {
+        self.actor.send(GetStartupInfoMessage {}).await?
+    }
 }
 
 impl MoveFunctionCaller for ExecutorProxy {
     fn call_function(
         &self,
-        _ctx: &TxContext,
+        ctx: &TxContext,
         function_call: FunctionCall,
     ) -> Result<FunctionResult> {
         let executor = self.clone();
-        let function_result = tokio::task::block_in_place(|| {
-            Handle::current()
-                .block_on(async move { executor.execute_view_function(function_call).await })
-        })?;
-        function_result.try_into()
+        let ctx = ctx.clone();
+        tokio::task::block_in_place(|| {
+            Handle::current().block_on(async move {
+                executor.execute_readonly_function(function_call, ctx).await
+            })
+        })
     }
 }
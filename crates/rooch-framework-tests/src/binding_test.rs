@@ -27,9 +27,13 @@ pub struct RustBindingTest {
 
 impl RustBindingTest {
     pub fn new() -> Result<Self> {
+        Self::new_with_sequencer(RoochAddress::random())
+    }
+
+    /// The genesis state depends on the sequencer, use the same sequencer to get the same genesis state.
+    pub fn new_with_sequencer(sequencer: RoochAddress) -> Result<Self> {
         let moveos_store = MoveOSStore::mock_moveos_store()?;
         let rooch_store = RoochStore::mock_rooch_store()?;
-        let sequencer = RoochAddress::random();
         let gas_schedule_blob = bcs::to_bytes(&default_gas_schedule())
             .expect("Failure serializing genesis gas schedule");
         let executor = ExecutorActor::new(
@@ -4,20 +4,25 @@
 use std::collections::HashMap;
 
 use crate::binding_test;
+use bitcoin::absolute::LockTime;
 use bitcoin::consensus::deserialize;
-use bitcoin::{Block, OutPoint, Transaction, TxOut};
+use bitcoin::transaction::Version;
+use bitcoin::{Block, OutPoint, ScriptBuf, Sequence, Transaction, TxIn, TxOut, Witness};
 use hex::FromHex;
 use moveos_types::access_path::AccessPath;
 use moveos_types::module_binding::MoveFunctionCaller;
 use moveos_types::moveos_std::object;
 use moveos_types::state::MoveStructType;
 use moveos_types::state_resolver::StateReader;
-use moveos_types::transaction::MoveAction;
+use moveos_types::transaction::{FunctionCall, MoveAction};
 use rooch_key::keystore::account_keystore::AccountKeystore;
 use rooch_key::keystore::memory_keystore::InMemKeystore;
+use rooch_types::address::RoochAddress;
+use rooch_types::bitcoin::light_client::BitcoinLightClientModule;
 use rooch_types::bitcoin::ord::{Inscription, InscriptionID};
 use rooch_types::bitcoin::types::Header;
 use rooch_types::bitcoin::utxo::{OutputID, UTXO};
+use rooch_types::framework::onchain_config::OnchainConfigModule;
 use rooch_types::into_address::IntoAddress;
 use rooch_types::transaction::rooch::RoochTransactionData;
 use tracing::debug;
@@ -25,10 +30,9 @@ use tracing::debug;
 #[test]
 fn test_submit_block() {
     let _ = tracing_subscriber::fmt::try_init();
-    let mut binding_test = binding_test::RustBindingTest::new().unwrap();
-
     let keystore = InMemKeystore::new_insecure_for_tests(1);
     let sender = keystore.addresses()[0];
+    let mut binding_test = binding_test::RustBindingTest::new_with_sequencer(sender).unwrap();
     let sequence_number = 0;
 
     // Mainnet block 00000000b0c5a240b2a61d2e75692224efd4cbecdf6eaf4cc2cf477ca7c270e7
@@ -105,6 +109,314 @@ fn test_submit_block() {
     assert_eq!(now_milliseconds, duration.as_millis() as u64);
 }
 
+fn new_tx(previous_output: OutPoint, script_sig: ScriptBuf, output: TxOut) -> Transaction {
+    Transaction {
+        version: Version::ONE,
+        lock_time: LockTime::ZERO,
+        input: vec![TxIn {
+            previous_output,
+            script_sig,
+            sequence: Sequence::MAX,
+            witness: Witness::new(),
+        }],
+        output: vec![output],
+    }
+}
+
+/// Build a block on top of the `prev` block, the nonce makes the blocks of different forks distinct.
+fn new_block(prev: &Block, txdata: Vec<Transaction>, nonce: u32) -> Block {
+    let mut header = prev.header;
+    header.prev_blockhash = prev.block_hash();
+    header.time = prev.header.time + 600;
+    header.nonce = nonce;
+    let mut block = Block { header, txdata };
+    block.header.merkle_root = block.compute_merkle_root().unwrap();
+    block
+}
+
+#[test]
+fn test_submit_block_by_relayer() {
+    let _ = tracing_subscriber::fmt::try_init();
+    let keystore = InMemKeystore::new_insecure_for_tests(2);
+    // The relayer is not the sequencer
+    let sequencer = keystore.addresses()[0];
+    let relayer = keystore.addresses()[1];
+    let mut binding_test = binding_test::RustBindingTest::new_with_sequencer(sequencer).unwrap();
+    let mut sequencer_sequence_number = 0;
+    let mut relayer_sequence_number = 0;
+
+    let execute_call = |binding_test: &mut binding_test::RustBindingTest,
+                        sender: RoochAddress,
+                        sequence_number: &mut u64,
+                        call: FunctionCall| {
+        let tx_data = RoochTransactionData::new_for_test(
+            sender,
+            *sequence_number,
+            MoveAction::Function(call),
+        );
+        *sequence_number += 1;
+        let tx = keystore.sign_transaction(&sender, tx_data, None).unwrap();
+        binding_test.execute(tx)
+    };
+
+    // Mainnet block 00000000b0c5a240b2a61d2e75692224efd4cbecdf6eaf4cc2cf477ca7c270e7
+    let block_hex = Vec::<u8>::from_hex("010000004ddccd549d28f385ab457e98d1b11ce80bfea2c5ab93015ade4973e400000000bf4473e53794beae34e64fccc471dace6ae544180816f89591894e0f417a914cd74d6e49ffff001d323b3a7b0201000000010000000000000000000000000000000000000000000000000000000000000000ffffffff0804ffff001d026e04ffffffff0100f2052a0100000043410446ef0102d1ec5240f0d061a4246c1bdef63fc3dbab7733052fbbf0ecd8f41fc26bf049ebb4f9527f374280259e7cfa99c48b0e3f39c51347a19a5819651503a5ac00000000010000000321f75f3139a013f50f315b23b0c9a2b6eac31e2bec98e5891c924664889942260000000049483045022100cb2c6b346a978ab8c61b18b5e9397755cbd17d6eb2fe0083ef32e067fa6c785a02206ce44e613f31d9a6b0517e46f3db1576e9812cc98d159bfdaf759a5014081b5c01ffffffff79cda0945903627c3da1f85fc95d0b8ee3e76ae0cfdc9a65d09744b1f8fc85430000000049483045022047957cdd957cfd0becd642f6b84d82f49b6cb4c51a91f49246908af7c3cfdf4a022100e96b46621f1bffcf5ea5982f88cef651e9354f5791602369bf5a82a6cd61a62501fffffffffe09f5fe3ffbf5ee97a54eb5e5069e9da6b4856ee86fc52938c2f979b0f38e82000000004847304402204165be9a4cbab8049e1af9723b96199bfd3e85f44c6b4c0177e3962686b26073022028f638da23fc003760861ad481ead4099312c60030d4cb57820ce4d33812a5ce01ffffffff01009d966b01000000434104ea1feff861b51fe3f5f8a3b12d0f4712db80e919548a80839fc47c6a21e66d957e9c5d8cd108c7a2d2324bad71f9904ac0ae7336507d785b17a2c115e427a32fac00000000").unwrap();
+    let height = 496u64;
+    let block: Block = deserialize(&block_hex).unwrap();
+
+    assert!(execute_call(
+        &mut binding_test,
+        relayer,
+        &mut relayer_sequence_number,
+        BitcoinLightClientModule::create_submit_new_block_call(height, block.clone()),
+    )
+    .is_err());
+
+    execute_call(
+        &mut binding_test,
+        sequencer,
+        &mut sequencer_sequence_number,
+        OnchainConfigModule::create_add_relayer_call(relayer.into()),
+    )
+    .unwrap();
+    execute_call(
+        &mut binding_test,
+        relayer,
+        &mut relayer_sequence_number,
+        BitcoinLightClientModule::create_submit_new_block_call(height, block.clone()),
+    )
+    .unwrap();
+    execute_call(
+        &mut binding_test,
+        relayer,
+        &mut relayer_sequence_number,
+        BitcoinLightClientModule::create_process_utxos_call(block.txdata.len() as u64),
+    )
+    .unwrap();
+    check_utxo(block.txdata.clone(), &binding_test);
+
+    // The whitelisted relayer can rollback the blocks too
+    let coinbase = new_tx(
+        OutPoint::null(),
+        ScriptBuf::from_bytes(b"orphaned".to_vec()),
+        block.txdata[0].output[0].clone(),
+    );
+    let orphaned_block = new_block(&block, vec![coinbase], 1);
+    execute_call(
+        &mut binding_test,
+        relayer,
+        &mut relayer_sequence_number,
+        BitcoinLightClientModule::create_submit_new_block_call(height + 1, orphaned_block),
+    )
+    .unwrap();
+    execute_call(
+        &mut binding_test,
+        relayer,
+        &mut relayer_sequence_number,
+        BitcoinLightClientModule::create_rollback_to_fork_point_call(
+            height,
+            block.block_hash(),
+            100,
+        ),
+    )
+    .unwrap();
+    assert_eq!(
+        binding_test
+            .as_module_binding::<BitcoinLightClientModule>()
+            .get_latest_block_height()
+            .unwrap(),
+        Some(height)
+    );
+}
+
+#[test]
+fn test_rollback_to_fork_point() {
+    let _ = tracing_subscriber::fmt::try_init();
+    let keystore = InMemKeystore::new_insecure_for_tests(2);
+    // Only the sequencer and the whitelisted relayers can rollback the blocks
+    let sender = keystore.addresses()[0];
+    let other = keystore.addresses()[1];
+    let mut binding_test = binding_test::RustBindingTest::new_with_sequencer(sender).unwrap();
+    let mut sequence_number = 0;
+
+    let mut execute_call = |binding_test: &mut binding_test::RustBindingTest,
+                            call: FunctionCall| {
+        let tx_data =
+            RoochTransactionData::new_for_test(sender, sequence_number, MoveAction::Function(call));
+        sequence_number += 1;
+        let tx = keystore.sign_transaction(&sender, tx_data, None).unwrap();
+        binding_test.execute(tx)
+    };
+
+    // Mainnet block 00000000b0c5a240b2a61d2e75692224efd4cbecdf6eaf4cc2cf477ca7c270e7
+    let block_hex = Vec::<u8>::from_hex("010000004ddccd549d28f385ab457e98d1b11ce80bfea2c5ab93015ade4973e400000000bf4473e53794beae34e64fccc471dace6ae544180816f89591894e0f417a914cd74d6e49ffff001d323b3a7b0201000000010000000000000000000000000000000000000000000000000000000000000000ffffffff0804ffff001d026e04ffffffff0100f2052a0100000043410446ef0102d1ec5240f0d061a4246c1bdef63fc3dbab7733052fbbf0ecd8f41fc26bf049ebb4f9527f374280259e7cfa99c48b0e3f39c51347a19a5819651503a5ac00000000010000000321f75f3139a013f50f315b23b0c9a2b6eac31e2bec98e5891c924664889942260000000049483045022100cb2c6b346a978ab8c61b18b5e9397755cbd17d6eb2fe0083ef32e067fa6c785a02206ce44e613f31d9a6b0517e46f3db1576e9812cc98d159bfdaf759a5014081b5c01ffffffff79cda0945903627c3da1f85fc95d0b8ee3e76ae0cfdc9a65d09744b1f8fc85430000000049483045022047957cdd957cfd0becd642f6b84d82f49b6cb4c51a91f49246908af7c3cfdf4a022100e96b46621f1bffcf5ea5982f88cef651e9354f5791602369bf5a82a6cd61a62501fffffffffe09f5fe3ffbf5ee97a54eb5e5069e9da6b4856ee86fc52938c2f979b0f38e82000000004847304402204165be9a4cbab8049e1af9723b96199bfd3e85f44c6b4c0177e3962686b26073022028f638da23fc003760861ad481ead4099312c60030d4cb57820ce4d33812a5ce01ffffffff01009d966b01000000434104ea1feff861b51fe3f5f8a3b12d0f4712db80e919548a80839fc47c6a21e66d957e9c5d8cd108c7a2d2324bad71f9904ac0ae7336507d785b17a2c115e427a32fac00000000").unwrap();
+    let height = 496u64;
+    let block: Block = deserialize(&block_hex).unwrap();
+    let block_hash = block.block_hash();
+    execute_call(
+        &mut binding_test,
+        BitcoinLightClientModule::create_submit_new_block_call(height, block.clone()),
+    )
+    .unwrap();
+    execute_call(
+        &mut binding_test,
+        BitcoinLightClientModule::create_process_utxos_call(100),
+    )
+    .unwrap();
+
+    // The orphaned block 497 spends the output of the second tx in block 496
+    let spent_outpoint = OutPoint::new(block.txdata[1].txid(), 0);
+    let tx_out = block.txdata[1].output[0].clone();
+    let coinbase = new_tx(
+        OutPoint::null(),
+        ScriptBuf::from_bytes(b"orphaned".to_vec()),
+        block.txdata[0].output[0].clone(),
+    );
+    let spend_tx = new_tx(spent_outpoint, ScriptBuf::new(), tx_out.clone());
+    let orphaned_block = new_block(&block, vec![coinbase, spend_tx.clone()], 1);
+    execute_call(
+        &mut binding_test,
+        BitcoinLightClientModule::create_submit_new_block_call(height + 1, orphaned_block.clone()),
+    )
+    .unwrap();
+    execute_call(
+        &mut binding_test,
+        BitcoinLightClientModule::create_process_utxos_call(100),
+    )
+    .unwrap();
+    {
+        let utxo_module =
+            binding_test.as_module_binding::<rooch_types::bitcoin::utxo::UTXOModule>();
+        assert!(!utxo_module
+            .exists_utxo(spent_outpoint.txid, spent_outpoint.vout)
+            .unwrap());
+        assert!(utxo_module.exists_utxo(spend_tx.txid(), 0).unwrap());
+    }
+
+    // A block of the new best chain can not be submitted before the rollback
+    let coinbase = new_tx(
+        OutPoint::null(),
+        ScriptBuf::from_bytes(b"best chain".to_vec()),
+        block.txdata[0].output[0].clone(),
+    );
+    let best_block = new_block(&block, vec![coinbase], 2);
+    assert!(execute_call(
+        &mut binding_test,
+        BitcoinLightClientModule::create_submit_new_block_call(height + 1, best_block.clone())
+    )
+    .is_err());
+
+    let tx_data = RoochTransactionData::new_for_test(
+        other,
+        0,
+        MoveAction::Function(
+            BitcoinLightClientModule::create_rollback_to_fork_point_call(height, block_hash, 100),
+        ),
+    );
+    let tx = keystore.sign_transaction(&other, tx_data, None).unwrap();
+    assert!(binding_test.execute(tx).is_err());
+    assert_eq!(
+        binding_test
+            .as_module_binding::<BitcoinLightClientModule>()
+            .get_latest_block_height()
+            .unwrap(),
+        Some(height + 1)
+    );
+
+    // Rollback one tx per call, the orphaned block has two txs
+    execute_call(
+        &mut binding_test,
+        BitcoinLightClientModule::create_rollback_to_fork_point_call(height, block_hash, 1),
+    )
+    .unwrap();
+    {
+        let bitcoin_light_client_module =
+            binding_test.as_module_binding::<BitcoinLightClientModule>();
+        assert_eq!(
+            bitcoin_light_client_module
+                .get_rollback_fork_height()
+                .unwrap(),
+            Some(height)
+        );
+        assert_eq!(
+            bitcoin_light_client_module
+                .get_latest_block_height()
+                .unwrap(),
+            Some(height + 1)
+        );
+        let utxo_module =
+            binding_test.as_module_binding::<rooch_types::bitcoin::utxo::UTXOModule>();
+        assert!(!utxo_module.exists_utxo(spend_tx.txid(), 0).unwrap());
+    }
+    // The blocks can not be submitted and the UTXOs can not be processed until the rollback is finished
+    assert!(execute_call(
+        &mut binding_test,
+        BitcoinLightClientModule::create_submit_new_block_call(height + 1, best_block.clone())
+    )
+    .is_err());
+    assert!(execute_call(
+        &mut binding_test,
+        BitcoinLightClientModule::create_process_utxos_call(100),
+    )
+    .is_err());
+    let mut rollback_call_count = 1;
+    while binding_test
+        .as_module_binding::<BitcoinLightClientModule>()
+        .get_rollback_fork_height()
+        .unwrap()
+        .is_some()
+    {
+        execute_call(
+            &mut binding_test,
+            BitcoinLightClientModule::create_rollback_to_fork_point_call(height, block_hash, 1),
+        )
+        .unwrap();
+        rollback_call_count += 1;
+    }
+    // Revert the UTXO changes of the two txs, then remove the two txs
+    assert_eq!(rollback_call_count, 4);
+    {
+        let bitcoin_light_client_module =
+            binding_test.as_module_binding::<BitcoinLightClientModule>();
+        assert_eq!(
+            bitcoin_light_client_module
+                .get_latest_block_height()
+                .unwrap()
+                .unwrap(),
+            height
+        );
+        assert!(bitcoin_light_client_module
+            .get_block(orphaned_block.block_hash())
+            .unwrap()
+            .is_none());
+        assert_eq!(
+            bitcoin_light_client_module
+                .get_block_hash_by_height(height)
+                .unwrap(),
+            Some(block_hash)
+        );
+        assert_eq!(bitcoin_light_client_module.remaining_tx_count().unwrap(), 0);
+        let utxo_module =
+            binding_test.as_module_binding::<rooch_types::bitcoin::utxo::UTXOModule>();
+        assert!(!utxo_module.exists_utxo(spend_tx.txid(), 0).unwrap());
+    }
+    // The spent UTXO is restored
+    check_utxo(block.txdata.clone(), &binding_test);
+
+    execute_call(
+        &mut binding_test,
+        BitcoinLightClientModule::create_submit_new_block_call(height + 1, best_block.clone()),
+    )
+    .unwrap();
+    let bitcoin_light_client_module = binding_test.as_module_binding::<BitcoinLightClientModule>();
+    assert_eq!(
+        bitcoin_light_client_module
+            .get_block_hash_by_height(height + 1)
+            .unwrap(),
+        Some(best_block.block_hash())
+    );
+}
+
 //we temporarily ignore this test because it takes too long time
 //to run this test, use command:
 //RUST_LOG=debug cargo test --release --package rooch-framework-tests --lib -- --include-ignored tests::bitcoin_light_client_test::test_utxo_progress
@@ -112,10 +424,9 @@ fn test_submit_block() {
 #[test]
 fn test_utxo_progress() {
     let _ = tracing_subscriber::fmt::try_init();
-    let mut binding_test = binding_test::RustBindingTest::new().unwrap();
-
     let keystore = InMemKeystore::new_insecure_for_tests(1);
     let sender = keystore.addresses()[0];
+    let mut binding_test = binding_test::RustBindingTest::new_with_sequencer(sender).unwrap();
     let mut sequence_number = 0;
 
     let btc_block_hex = include_str!("../blocks/818677.txt");
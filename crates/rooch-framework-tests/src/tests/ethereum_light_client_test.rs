@@ -4,21 +4,15 @@
 use crate::binding_test;
 use ethers::prelude::*;
 use moveos_types::module_binding::MoveFunctionCaller;
-use moveos_types::transaction::MoveAction;
+use moveos_types::transaction::{FunctionCall, MoveAction};
 use rooch_key::keystore::account_keystore::AccountKeystore;
 use rooch_key::keystore::memory_keystore::InMemKeystore;
-use rooch_types::framework::ethereum_light_client::BlockHeader;
+use rooch_types::address::RoochAddress;
+use rooch_types::framework::ethereum_light_client::{BlockHeader, EthereumLightClientModule};
+use rooch_types::framework::onchain_config::OnchainConfigModule;
 use rooch_types::transaction::rooch::RoochTransactionData;
 
-#[test]
-fn test_submit_block() {
-    let _ = tracing_subscriber::fmt::try_init();
-    let mut binding_test = binding_test::RustBindingTest::new().unwrap();
-
-    let keystore = InMemKeystore::new_insecure_for_tests(1);
-    let sender = keystore.addresses()[0];
-    let sequence_number = 0;
-
+fn block_header_1b4() -> BlockHeader {
     let json = serde_json::json!(
     {
         "baseFeePerGas": "0x7",
@@ -51,9 +45,45 @@ fn test_submit_block() {
     );
 
     let ethereum_block: Block<()> = serde_json::from_value(json).unwrap();
+    BlockHeader::try_from(&ethereum_block).unwrap()
+}
+
+/// Build a block header on top of the `parent`, the fork tag makes the blocks of different forks distinct.
+fn new_block_header(parent: &BlockHeader, fork_tag: u8) -> BlockHeader {
+    let mut block_header = parent.clone();
+    block_header.number = parent.number + 1;
+    block_header.parent_hash = parent.hash.clone();
+    block_header.hash = H256::from_low_u64_be((block_header.number << 8) | fork_tag as u64)
+        .as_bytes()
+        .to_vec();
+    block_header
+}
+
+#[test]
+fn test_submit_block() {
+    let _ = tracing_subscriber::fmt::try_init();
+    let keystore = InMemKeystore::new_insecure_for_tests(2);
+    // Only the sequencer and the whitelisted relayers can submit the blocks
+    let sender = keystore.addresses()[0];
+    let other = keystore.addresses()[1];
+    let mut binding_test = binding_test::RustBindingTest::new_with_sequencer(sender).unwrap();
+    let sequence_number = 0;
+
+    let block_header = block_header_1b4();
+    let action = MoveAction::Function(EthereumLightClientModule::create_submit_new_block_call(
+        &block_header,
+    ));
+    let tx_data = RoochTransactionData::new_for_test(other, 0, action.clone());
+    let tx = keystore.sign_transaction(&other, tx_data, None).unwrap();
+    assert!(binding_test.execute(tx).is_err());
+    assert_eq!(
+        binding_test
+            .as_module_binding::<EthereumLightClientModule>()
+            .get_latest_block_number()
+            .unwrap(),
+        None
+    );
 
-    let block_header = BlockHeader::try_from(&ethereum_block).unwrap();
-    let action = MoveAction::Function(rooch_types::framework::ethereum_light_client::EthereumLightClientModule::create_submit_new_block_call(&block_header));
     let tx_data = RoochTransactionData::new_for_test(sender, sequence_number, action);
     let tx = keystore.sign_transaction(&sender, tx_data, None).unwrap();
     binding_test.execute(tx).unwrap();
@@ -69,3 +99,223 @@ fn test_submit_block() {
     );
     assert_eq!(now_milliseconds, duration.as_millis() as u64);
 }
+
+#[test]
+fn test_submit_block_by_relayer() {
+    let _ = tracing_subscriber::fmt::try_init();
+    let keystore = InMemKeystore::new_insecure_for_tests(2);
+    // The relayer is not the sequencer
+    let sequencer = keystore.addresses()[0];
+    let relayer = keystore.addresses()[1];
+    let mut binding_test = binding_test::RustBindingTest::new_with_sequencer(sequencer).unwrap();
+    let mut sequencer_sequence_number = 0;
+    let mut relayer_sequence_number = 0;
+
+    let execute_call = |binding_test: &mut binding_test::RustBindingTest,
+                        sender: RoochAddress,
+                        sequence_number: &mut u64,
+                        call: FunctionCall| {
+        let tx_data = RoochTransactionData::new_for_test(
+            sender,
+            *sequence_number,
+            MoveAction::Function(call),
+        );
+        *sequence_number += 1;
+        let tx = keystore.sign_transaction(&sender, tx_data, None).unwrap();
+        binding_test.execute(tx)
+    };
+
+    let block = block_header_1b4();
+    assert!(execute_call(
+        &mut binding_test,
+        relayer,
+        &mut relayer_sequence_number,
+        EthereumLightClientModule::create_submit_new_block_call(&block),
+    )
+    .is_err());
+
+    // Only the sequencer can add the relayers
+    assert!(execute_call(
+        &mut binding_test,
+        relayer,
+        &mut relayer_sequence_number,
+        OnchainConfigModule::create_add_relayer_call(relayer.into()),
+    )
+    .is_err());
+    execute_call(
+        &mut binding_test,
+        sequencer,
+        &mut sequencer_sequence_number,
+        OnchainConfigModule::create_add_relayer_call(relayer.into()),
+    )
+    .unwrap();
+    assert!(binding_test
+        .as_module_binding::<OnchainConfigModule>()
+        .is_relayer(relayer.into())
+        .unwrap());
+
+    execute_call(
+        &mut binding_test,
+        relayer,
+        &mut relayer_sequence_number,
+        EthereumLightClientModule::create_submit_new_block_call(&block),
+    )
+    .unwrap();
+    assert_eq!(
+        binding_test
+            .as_module_binding::<EthereumLightClientModule>()
+            .get_latest_block_number()
+            .unwrap(),
+        Some(block.number)
+    );
+
+    // The removed relayer can not submit the blocks
+    execute_call(
+        &mut binding_test,
+        sequencer,
+        &mut sequencer_sequence_number,
+        OnchainConfigModule::create_remove_relayer_call(relayer.into()),
+    )
+    .unwrap();
+    assert!(execute_call(
+        &mut binding_test,
+        relayer,
+        &mut relayer_sequence_number,
+        EthereumLightClientModule::create_submit_new_block_call(&new_block_header(&block, 0)),
+    )
+    .is_err());
+}
+
+#[test]
+fn test_submit_block_reorg() {
+    let _ = tracing_subscriber::fmt::try_init();
+    let keystore = InMemKeystore::new_insecure_for_tests(1);
+    let sender = keystore.addresses()[0];
+    let mut binding_test = binding_test::RustBindingTest::new_with_sequencer(sender).unwrap();
+    let mut sequence_number = 0;
+
+    let mut submit_block = |binding_test: &mut binding_test::RustBindingTest,
+                            block_header: &BlockHeader| {
+        let action = MoveAction::Function(EthereumLightClientModule::create_submit_new_block_call(
+            block_header,
+        ));
+        let tx_data = RoochTransactionData::new_for_test(sender, sequence_number, action);
+        sequence_number += 1;
+        let tx = keystore.sign_transaction(&sender, tx_data, None).unwrap();
+        binding_test.execute(tx)
+    };
+
+    let block = block_header_1b4();
+    let block1 = new_block_header(&block, 0);
+    let block2 = new_block_header(&block1, 0);
+    for block_header in [&block, &block1, &block2] {
+        submit_block(&mut binding_test, block_header).unwrap();
+    }
+    assert_eq!(
+        binding_test
+            .as_module_binding::<EthereumLightClientModule>()
+            .get_latest_block_number()
+            .unwrap(),
+        Some(block2.number)
+    );
+
+    // The block of the new fork can not be submitted before its parent
+    let fork_block1 = new_block_header(&block, 1);
+    let fork_block2 = new_block_header(&fork_block1, 1);
+    assert!(submit_block(&mut binding_test, &fork_block2).is_err());
+
+    // The fork block replaces the orphaned block and the blocks above it
+    submit_block(&mut binding_test, &fork_block1).unwrap();
+    {
+        let ethereum_light_client = binding_test.as_module_binding::<EthereumLightClientModule>();
+        assert_eq!(
+            ethereum_light_client.get_latest_block_number().unwrap(),
+            Some(fork_block1.number)
+        );
+        assert_eq!(
+            ethereum_light_client
+                .get_block(fork_block1.number)
+                .unwrap()
+                .hash,
+            fork_block1.hash
+        );
+        assert!(!ethereum_light_client.exists_block(block2.number).unwrap());
+    }
+
+    submit_block(&mut binding_test, &fork_block2).unwrap();
+    // Submit a relayed block again is a no-op
+    submit_block(&mut binding_test, &fork_block1).unwrap();
+    let ethereum_light_client = binding_test.as_module_binding::<EthereumLightClientModule>();
+    assert_eq!(
+        ethereum_light_client.get_latest_block_number().unwrap(),
+        Some(fork_block2.number)
+    );
+    assert_eq!(
+        ethereum_light_client
+            .get_block(fork_block2.number)
+            .unwrap()
+            .hash,
+        fork_block2.hash
+    );
+}
+
+#[test]
+fn test_submit_block_reorg_too_deep() {
+    // Same as `MAX_REORG_DEPTH` in ethereum_light_client.move
+    const MAX_REORG_DEPTH: u64 = 128;
+
+    let _ = tracing_subscriber::fmt::try_init();
+    let keystore = InMemKeystore::new_insecure_for_tests(1);
+    let sender = keystore.addresses()[0];
+    let mut binding_test = binding_test::RustBindingTest::new_with_sequencer(sender).unwrap();
+    let mut sequence_number = 0;
+
+    let mut submit_block = |binding_test: &mut binding_test::RustBindingTest,
+                            block_header: &BlockHeader| {
+        let action = MoveAction::Function(EthereumLightClientModule::create_submit_new_block_call(
+            block_header,
+        ));
+        let tx_data = RoochTransactionData::new_for_test(sender, sequence_number, action);
+        sequence_number += 1;
+        let tx = keystore.sign_transaction(&sender, tx_data, None).unwrap();
+        binding_test.execute(tx)
+    };
+
+    // Relay the blocks from `block.number` to `block.number + MAX_REORG_DEPTH`, the blocks in the
+    // middle are skipped, a gap in the light client does not change the depth of a reorg.
+    let block = block_header_1b4();
+    let block1 = new_block_header(&block, 0);
+    let mut gap_block = block1.clone();
+    gap_block.number = block.number + MAX_REORG_DEPTH - 2;
+    let top_block1 = new_block_header(&gap_block, 0);
+    let top_block = new_block_header(&top_block1, 0);
+    for block_header in [&block, &block1, &top_block1, &top_block] {
+        submit_block(&mut binding_test, block_header).unwrap();
+    }
+
+    // The fork at `block.number` replaces `MAX_REORG_DEPTH + 1` blocks
+    let mut fork_parent = block.clone();
+    fork_parent.number = block.number - 1;
+    fork_parent.hash = block.parent_hash.clone();
+    let fork_block = new_block_header(&fork_parent, 1);
+    assert!(submit_block(&mut binding_test, &fork_block).is_err());
+    assert_eq!(
+        binding_test
+            .as_module_binding::<EthereumLightClientModule>()
+            .get_latest_block_number()
+            .unwrap(),
+        Some(top_block.number)
+    );
+
+    // The fork at `block1.number` replaces `MAX_REORG_DEPTH` blocks
+    let fork_block1 = new_block_header(&block, 1);
+    submit_block(&mut binding_test, &fork_block1).unwrap();
+    let ethereum_light_client = binding_test.as_module_binding::<EthereumLightClientModule>();
+    assert_eq!(
+        ethereum_light_client.get_latest_block_number().unwrap(),
+        Some(fork_block1.number)
+    );
+    assert!(!ethereum_light_client
+        .exists_block(top_block.number)
+        .unwrap());
+}
@@ -0,0 +1,29 @@
+// Copyright (c) RoochNetwork
+// SPDX-License-Identifier: Apache-2.0
+
+use crate::binding_test;
+use moveos::gas::table::{cost_table_from_gas_schedule, initial_cost_schedule};
+use moveos_types::moveos_std::gas_schedule::GasSchedule;
+use rooch_framework::natives::gas_parameter::gas_member::FromOnChainGasSchedule;
+use rooch_framework::natives::NativeGasParameters;
+
+#[test]
+fn test_genesis_gas_schedule() {
+    let binding_test = binding_test::RustBindingTest::new().unwrap();
+    let moveos = binding_test.reader_executor.moveos();
+
+    let gas_schedule = moveos
+        .state()
+        .get_as_object::<GasSchedule>(GasSchedule::object_id())
+        .unwrap()
+        .expect("The gas schedule should be initialized at genesis")
+        .value;
+    assert_eq!(gas_schedule.feature_version, 1);
+
+    let entries = gas_schedule.to_map();
+    assert_eq!(
+        cost_table_from_gas_schedule(&entries).unwrap(),
+        initial_cost_schedule()
+    );
+    assert!(NativeGasParameters::from_on_chain_gas_schedule(&entries).is_some());
+}
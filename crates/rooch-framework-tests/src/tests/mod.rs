@@ -6,6 +6,10 @@ mod brc20_test;
 mod empty_tests;
 mod ethereum_light_client_test;
 mod ethereum_validator_tests;
+mod gas_schedule_tests;
 mod native_validator_tests;
 mod ord_test;
+mod storage_gas_tests;
 mod transaction_validator_tests;
+mod view_function_tests;
+mod vm_error_tests;
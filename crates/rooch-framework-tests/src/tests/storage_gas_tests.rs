@@ -0,0 +1,68 @@
+// Copyright (c) RoochNetwork
+// SPDX-License-Identifier: Apache-2.0
+
+use crate::binding_test;
+use move_core_types::u256::U256;
+use moveos_types::transaction::MoveAction;
+use rooch_key::keystore::account_keystore::AccountKeystore;
+use rooch_key::keystore::memory_keystore::InMemKeystore;
+use rooch_types::framework::empty::Empty;
+use rooch_types::framework::gas_coin::{GasCoin, GasCoinModule};
+use rooch_types::transaction::rooch::RoochTransactionData;
+use rooch_types::transaction::AbstractTransaction;
+
+#[test]
+fn test_storage_gas_statement() {
+    let mut binding_test = binding_test::RustBindingTest::new().unwrap();
+
+    let keystore = InMemKeystore::new_insecure_for_tests(1);
+    let sender = keystore.addresses()[0];
+    let action = MoveAction::new_function_call(Empty::empty_function_id(), vec![], vec![]);
+    let tx_data = RoochTransactionData::new_for_test(sender, 0, action);
+    let tx = keystore.sign_transaction(&sender, tx_data, None).unwrap();
+    let tx_size = tx.tx_size();
+
+    let result = binding_test.execute_as_result(tx).unwrap();
+    let gas_statement = result.output.gas_statement;
+    // The initial storage fee per transaction byte is 6
+    assert_eq!(gas_statement.tx_size_gas_used, tx_size * 6);
+    assert!(gas_statement.new_state_gas_used + gas_statement.modify_state_gas_used > 0);
+    assert_eq!(
+        gas_statement.storage_gas_used,
+        gas_statement.tx_size_gas_used
+            + gas_statement.new_state_gas_used
+            + gas_statement.modify_state_gas_used
+            + gas_statement.delete_state_gas_used
+            + gas_statement.event_gas_used
+    );
+    assert_eq!(
+        result.output.gas_used,
+        gas_statement.execution_gas_used + gas_statement.storage_gas_used
+    );
+}
+
+#[test]
+fn test_storage_gas_paid_by_sender() {
+    let mut binding_test = binding_test::RustBindingTest::new().unwrap();
+
+    let keystore = InMemKeystore::new_insecure_for_tests(1);
+    let sender = keystore.addresses()[0];
+    // The sender account is created and gets 100 RGC from faucet in the transaction pre_execute,
+    // so the transaction writes new states.
+    let action = MoveAction::new_function_call(Empty::empty_function_id(), vec![], vec![]);
+    let tx_data = RoochTransactionData::new_for_test(sender, 0, action);
+    let tx = keystore.sign_transaction(&sender, tx_data, None).unwrap();
+
+    let result = binding_test.execute_as_result(tx).unwrap();
+    let gas_statement = result.output.gas_statement;
+    assert!(gas_statement.new_state_gas_used > 0);
+
+    // The gas factor is 1, the sender pays both the execution gas and the storage gas.
+    let gas_coin_module = binding_test.as_module_binding::<GasCoinModule>();
+    let balance = gas_coin_module.balance(sender.into()).unwrap();
+    assert_eq!(
+        balance,
+        GasCoin::scaling(100u64)
+            - U256::from(gas_statement.execution_gas_used + gas_statement.storage_gas_used)
+    );
+}
@@ -13,9 +13,12 @@ use moveos_types::move_types::FunctionId;
 use moveos_types::{module_binding::ModuleBinding, transaction::MoveAction};
 use rooch_key::keystore::account_keystore::AccountKeystore;
 use rooch_key::keystore::memory_keystore::InMemKeystore;
+use rooch_types::framework::gas_coin::{GasCoin, GasCoinModule};
 use rooch_types::framework::session_key::SessionKeyModule;
 use rooch_types::framework::timestamp::TimestampModule;
+use rooch_types::transaction::authenticator::Authenticator;
 use rooch_types::transaction::ethereum::EthereumTransaction;
+use rooch_types::transaction::rooch::SponsoredRoochTransaction;
 use rooch_types::{addresses::ROOCH_FRAMEWORK_ADDRESS, framework::empty::Empty};
 use rooch_types::{
     framework::session_key::SessionScope,
@@ -199,3 +202,61 @@ fn test_session_key_rooch() {
         }
     }
 }
+
+#[test]
+fn test_sponsored_transaction() {
+    let _ = tracing_subscriber::fmt::try_init();
+    let mut binding_test = binding_test::RustBindingTest::new().unwrap();
+
+    let keystore = InMemKeystore::new_insecure_for_tests(2);
+    let sender = keystore.addresses()[0];
+    let fee_payer = keystore.addresses()[1];
+
+    // init the fee payer account and get the gas coin from faucet
+    let action = MoveAction::new_function_call(Empty::empty_function_id(), vec![], vec![]);
+    let tx_data = RoochTransactionData::new_for_test(fee_payer, 0, action);
+    let tx = keystore
+        .sign_transaction(&fee_payer, tx_data, None)
+        .unwrap();
+    binding_test.execute(tx).unwrap();
+
+    let gas_coin_module = binding_test.as_module_binding::<GasCoinModule>();
+    let fee_payer_balance_before = gas_coin_module.balance(fee_payer.into()).unwrap();
+
+    let action = MoveAction::new_function_call(Empty::empty_function_id(), vec![], vec![]);
+    let tx_data = RoochTransactionData::new_for_test(sender, 0, action);
+    let sender_signature = keystore
+        .sign_hashed(&sender, tx_data.sponsored_hash(fee_payer).as_bytes(), None)
+        .unwrap();
+    let tx =
+        SponsoredRoochTransaction::new(tx_data, Authenticator::rooch(sender_signature), fee_payer);
+
+    // the transaction without the fee payer's signature should be rejected.
+    binding_test
+        .execute_as_result(tx.clone())
+        .expect_err("expect missing fee payer signature error");
+
+    // the fee payer's authenticator signed by other account should be rejected.
+    let wrong_signature = keystore
+        .sign_hashed(&sender, tx.tx_hash().as_bytes(), None)
+        .unwrap();
+    let wrong_tx = tx
+        .clone()
+        .with_fee_payer_authenticator(Authenticator::rooch(wrong_signature));
+    binding_test
+        .execute_as_result(wrong_tx)
+        .expect_err("expect invalid fee payer signature error");
+
+    let fee_payer_signature = keystore
+        .sign_hashed(&fee_payer, tx.tx_hash().as_bytes(), None)
+        .unwrap();
+    let tx = tx.with_fee_payer_authenticator(Authenticator::rooch(fee_payer_signature));
+    binding_test.execute(tx).unwrap();
+
+    // the gas is paid by the fee payer, the sender only keeps the coin from faucet.
+    let gas_coin_module = binding_test.as_module_binding::<GasCoinModule>();
+    let sender_balance = gas_coin_module.balance(sender.into()).unwrap();
+    assert_eq!(sender_balance, GasCoin::scaling(100u64));
+    let fee_payer_balance_after = gas_coin_module.balance(fee_payer.into()).unwrap();
+    assert!(fee_payer_balance_after < fee_payer_balance_before);
+}
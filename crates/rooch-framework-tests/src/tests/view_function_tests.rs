@@ -0,0 +1,137 @@
+// Copyright (c) RoochNetwork
+// SPDX-License-Identifier: Apache-2.0
+
+use crate::binding_test;
+use move_binary_format::CompiledModule;
+use move_core_types::account_address::AccountAddress;
+use move_core_types::language_storage::ModuleId;
+use move_core_types::value::MoveValue;
+use move_core_types::vm_status::{AbortLocation, StatusCode, VMStatus};
+use moveos_types::move_types::FunctionId;
+use moveos_types::moveos_std::move_module::MoveModule;
+use moveos_types::moveos_std::tx_context::TxContext;
+use moveos_types::state::MoveStructType;
+use moveos_types::transaction::FunctionCall;
+use moveos_verifier::build::{ROOCH_METADATA_KEY, ROOCH_VIEW_FUNCTIONS_KEY};
+use moveos_verifier::metadata::{
+    check_metadata_format, get_metadata_from_compiled_module,
+    get_view_functions_from_compiled_module,
+};
+use moveos_verifier::verifier::ERROR_NOT_VIEW_FUNCTION;
+use rooch_genesis::ROOCH_LOCAL_GENESIS;
+use rooch_types::addresses::ROOCH_FRAMEWORK_ADDRESS;
+use std::str::FromStr;
+
+fn not_view_function_status() -> VMStatus {
+    VMStatus::MoveAbort(
+        AbortLocation::Module(ModuleId::new(
+            MoveModule::module_address(),
+            MoveModule::module_identifier(),
+        )),
+        ERROR_NOT_VIEW_FUNCTION,
+    )
+}
+
+#[test]
+fn test_view_function_with_sender_and_gas() {
+    let binding_test = binding_test::RustBindingTest::new().unwrap();
+    let moveos = binding_test.reader_executor.moveos();
+
+    let sender = AccountAddress::random();
+    let sender_call = FunctionCall::new(
+        FunctionId::from_str("0x2::context::sender").unwrap(),
+        vec![],
+        vec![],
+    );
+    let result = moveos.execute_view_function_with_options(sender_call, Some(sender), None);
+    let return_values = result.into_result().unwrap();
+    let return_sender: AccountAddress = bcs::from_bytes(&return_values[0].value).unwrap();
+    assert_eq!(return_sender, sender);
+
+    let balance_call = FunctionCall::new(
+        FunctionId::from_str("0x3::gas_coin::balance").unwrap(),
+        vec![],
+        vec![MoveValue::Address(sender).simple_serialize().unwrap()],
+    );
+    let result = moveos.execute_view_function(balance_call.clone());
+    assert_eq!(result.vm_status, VMStatus::Executed);
+    assert!(result.gas_used > 0);
+
+    let result = moveos.execute_view_function_with_options(balance_call, None, Some(1));
+    assert_eq!(result.vm_status.status_code(), StatusCode::OUT_OF_GAS);
+}
+
+#[test]
+fn test_view_function_verify() {
+    let binding_test = binding_test::RustBindingTest::new().unwrap();
+    let moveos = binding_test.reader_executor.moveos();
+
+    // function without return values is not a view function
+    let empty_call = FunctionCall::new(
+        FunctionId::from_str("0x3::empty::empty").unwrap(),
+        vec![],
+        vec![],
+    );
+    let result = moveos.execute_view_function(empty_call);
+    assert_eq!(result.vm_status, not_view_function_status());
+}
+
+#[test]
+fn test_view_function_without_view_attribute() {
+    let binding_test = binding_test::RustBindingTest::new().unwrap();
+    let moveos = binding_test.reader_executor.moveos();
+
+    // `account::exists_at` returns values and takes no signer, but it is not annotated with #[view]
+    let exists_at_call = FunctionCall::new(
+        FunctionId::from_str("0x3::account::exists_at").unwrap(),
+        vec![],
+        vec![MoveValue::Address(AccountAddress::random())
+            .simple_serialize()
+            .unwrap()],
+    );
+    let result = moveos.execute_view_function(exists_at_call.clone());
+    assert_eq!(result.vm_status, not_view_function_status());
+
+    // the readonly function call used by the Rust bindings does not require #[view]
+    let result = moveos.execute_readonly_function(
+        &TxContext::new_readonly_ctx(AccountAddress::ZERO),
+        exists_at_call,
+    );
+    assert_eq!(result.vm_status, VMStatus::Executed);
+}
+
+#[test]
+fn test_view_functions_metadata() {
+    let modules = ROOCH_LOCAL_GENESIS.modules().unwrap();
+    for module in modules.iter() {
+        check_metadata_format(module).unwrap();
+        assert!(get_view_functions_from_compiled_module(module).is_some());
+
+        // The view functions are not in the metadata, so its BCS is the one of the published modules
+        if let Some(data) = module
+            .metadata
+            .iter()
+            .find(|md| md.key == ROOCH_METADATA_KEY)
+        {
+            let metadata = get_metadata_from_compiled_module(module).unwrap();
+            assert_eq!(bcs::to_bytes(&metadata).unwrap(), data.value);
+        }
+    }
+
+    let gas_coin = modules
+        .iter()
+        .find(|module| {
+            module.self_id() == ModuleId::new(ROOCH_FRAMEWORK_ADDRESS, "gas_coin".parse().unwrap())
+        })
+        .unwrap();
+    let view_functions = get_view_functions_from_compiled_module(gas_coin).unwrap();
+    assert!(view_functions.contains("balance"));
+
+    // The modules built before #[view] was introduced do not carry the view functions
+    let mut legacy_module: CompiledModule = gas_coin.clone();
+    legacy_module
+        .metadata
+        .retain(|md| md.key != ROOCH_VIEW_FUNCTIONS_KEY);
+    check_metadata_format(&legacy_module).unwrap();
+    assert!(get_view_functions_from_compiled_module(&legacy_module).is_none());
+}
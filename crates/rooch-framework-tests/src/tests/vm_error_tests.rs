@@ -0,0 +1,41 @@
+// Copyright (c) RoochNetwork
+// SPDX-License-Identifier: Apache-2.0
+
+use crate::binding_test;
+use moveos_types::transaction::MoveAction;
+use rooch_key::keystore::account_keystore::AccountKeystore;
+use rooch_key::keystore::memory_keystore::InMemKeystore;
+use rooch_types::error::{VMErrorInfo, VMErrorKind};
+use rooch_types::framework::empty::Empty;
+use rooch_types::transaction::rooch::RoochTransactionData;
+
+#[test]
+fn test_validate_error_info() {
+    let mut binding_test = binding_test::RustBindingTest::new().unwrap();
+
+    let keystore = InMemKeystore::new_insecure_for_tests(1);
+    let sender = keystore.addresses()[0];
+    let action = MoveAction::new_function_call(Empty::empty_function_id(), vec![], vec![]);
+    let tx_data = RoochTransactionData::new_for_test(sender, 0, action.clone());
+    let tx = keystore.sign_transaction(&sender, tx_data, None).unwrap();
+    binding_test.execute(tx).unwrap();
+
+    // Replay the sequence number 0
+    let tx_data = RoochTransactionData::new_for_test(sender, 0, action);
+    let tx = keystore.sign_transaction(&sender, tx_data, None).unwrap();
+    let error = binding_test.executor().validate(tx).unwrap_err();
+    let error_info = error.downcast_ref::<VMErrorInfo>().unwrap();
+
+    assert_eq!(error_info.kind, VMErrorKind::Validation);
+    assert_eq!(error_info.major_status, "ABORTED");
+    assert_eq!(
+        error_info.abort_location.as_deref(),
+        Some("0x3::transaction_validator")
+    );
+    assert_eq!(error_info.abort_code, Some(1001));
+    let abort_explain = error_info.abort_explain.as_ref().unwrap();
+    assert_eq!(
+        abort_explain.reason_name.as_deref(),
+        Some("ErrorValidateSequenceNuberTooOld")
+    );
+}
@@ -0,0 +1,146 @@
+//# init --addresses test=0x42
+
+//# publish
+module test::m {
+    public fun value(): u64 { helper() }
+    fun helper(): u64 { 1 }
+}
+
+// upgrade the module under the default compatible policy
+//# publish
+module test::m {
+    public fun value(): u64 { helper() + 1 }
+    fun helper(): u64 { 1 }
+}
+
+// set the upgrade policy of the package to additive
+//# run --signers test
+script {
+    use std::string;
+    use std::vector;
+    use moveos_std::context::{Self, Context};
+    use moveos_std::move_module;
+
+    fun main(ctx: &mut Context, sender: signer) {
+        let name = string::utf8(b"m");
+        assert!(context::upgrade_policy(ctx, @test, name) == move_module::upgrade_policy_compatible(), 1);
+        context::set_upgrade_policy(ctx, &sender, vector::singleton(name), move_module::upgrade_policy_additive());
+        assert!(context::upgrade_policy(ctx, @test, name) == move_module::upgrade_policy_additive(), 2);
+    }
+}
+
+// add a new function under the additive policy
+//# publish
+module test::m {
+    public fun value(): u64 { helper() + 1 }
+    public fun new_value(): u64 { helper() + 2 }
+    fun helper(): u64 { 1 }
+}
+
+// change the private function signature under the additive policy should fail
+//# publish
+module test::m {
+    public fun value(): u64 { helper(1) + 1 }
+    public fun new_value(): u64 { helper(1) + 2 }
+    fun helper(v: u64): u64 { v }
+}
+
+// change the function body under the additive policy should fail
+//# publish
+module test::m {
+    public fun value(): u64 { helper() + 2 }
+    public fun new_value(): u64 { helper() + 2 }
+    fun helper(): u64 { 1 }
+}
+
+// set the upgrade policy of the package to dep_only
+//# run --signers test
+script {
+    use std::string;
+    use std::vector;
+    use moveos_std::context::{Self, Context};
+    use moveos_std::move_module;
+
+    fun main(ctx: &mut Context, sender: signer) {
+        let name = string::utf8(b"m");
+        context::set_upgrade_policy(ctx, &sender, vector::singleton(name), move_module::upgrade_policy_dep_only());
+        assert!(context::upgrade_policy(ctx, @test, name) == move_module::upgrade_policy_dep_only(), 1);
+    }
+}
+
+// republish the same code under the dep_only policy
+//# publish
+module test::m {
+    public fun value(): u64 { helper() + 1 }
+    public fun new_value(): u64 { helper() + 2 }
+    fun helper(): u64 { 1 }
+}
+
+// add a new function under the dep_only policy should fail
+//# publish
+module test::m {
+    public fun value(): u64 { helper() + 1 }
+    public fun new_value(): u64 { helper() + 2 }
+    public fun another_value(): u64 { helper() + 3 }
+    fun helper(): u64 { 1 }
+}
+
+// change the function body under the dep_only policy should fail
+//# publish
+module test::m {
+    public fun value(): u64 { helper() + 1 }
+    public fun new_value(): u64 { helper() + 3 }
+    fun helper(): u64 { 1 }
+}
+
+// set the upgrade policy of the package to immutable
+//# run --signers test
+script {
+    use std::string;
+    use std::vector;
+    use moveos_std::context::{Self, Context};
+    use moveos_std::move_module;
+
+    fun main(ctx: &mut Context, sender: signer) {
+        context::set_upgrade_policy(ctx, &sender, vector::singleton(string::utf8(b"m")), move_module::upgrade_policy_immutable());
+    }
+}
+
+// upgrade the module under the immutable policy should fail
+//# publish
+module test::m {
+    public fun value(): u64 { helper() + 1 }
+    public fun new_value(): u64 { helper() + 2 }
+    fun helper(): u64 { 1 }
+}
+
+// a new module is a new package, it is published under the compatible policy
+//# publish
+module test::n {
+}
+
+// loosen the upgrade policy should fail
+//# run --signers test
+script {
+    use std::string;
+    use std::vector;
+    use moveos_std::context::{Self, Context};
+    use moveos_std::move_module;
+
+    fun main(ctx: &mut Context, sender: signer) {
+        context::set_upgrade_policy(ctx, &sender, vector::singleton(string::utf8(b"m")), move_module::upgrade_policy_compatible());
+    }
+}
+
+// set the upgrade policy of a module that does not exist should fail
+//# run --signers test
+script {
+    use std::string;
+    use std::vector;
+    use moveos_std::context::{Self, Context};
+    use moveos_std::move_module;
+
+    fun main(ctx: &mut Context, sender: signer) {
+        context::set_upgrade_policy(ctx, &sender, vector::singleton(string::utf8(b"o")), move_module::upgrade_policy_additive());
+    }
+}
@@ -0,0 +1,83 @@
+//# init --addresses test=0x42 A=0x43
+
+//# publish
+
+module test::m {
+    use moveos_std::context::{Self, Context};
+    use moveos_std::object::{Self, Object};
+
+    struct S has store, key { v: u8 }
+
+    public entry fun mint(ctx: &mut Context) {
+        let obj = context::new_object(ctx, S { v: 1 });
+        object::transfer(obj, context::sender(ctx));
+    }
+
+    public entry fun read(obj_s: &Object<S>) {
+        let s = object::borrow(obj_s);
+        assert!(s.v == 1, 1000);
+    }
+
+    public entry fun read_both(obj_s1: &Object<S>, obj_s2: &Object<S>) {
+        assert!(object::borrow(obj_s1).v == object::borrow(obj_s2).v, 1001);
+    }
+
+    public entry fun update_with(obj_s1: &mut Object<S>, obj_s2: &Object<S>) {
+        let v = object::borrow(obj_s2).v;
+        let s = object::borrow_mut(obj_s1);
+        s.v = v + 1;
+    }
+}
+
+// The object id is generated by the tx hash of the mint transaction, same as the object/basic.move
+//# run test::m::mint --signers A
+
+//# run test::m::read --signers A --args object:0x8f684aa792b9b1058aeccd3941849e9662132d81c974b826a9c6bddae8880bd6
+
+// The same object can be passed as immutable reference more than once
+//# run test::m::read_both --signers A --args object:0x8f684aa792b9b1058aeccd3941849e9662132d81c974b826a9c6bddae8880bd6 object:0x8f684aa792b9b1058aeccd3941849e9662132d81c974b826a9c6bddae8880bd6
+
+// The object does not exist
+//# run --signers A --args object:0x1234
+script {
+    use moveos_std::object::Object;
+    use test::m::{Self, S};
+
+    fun main(obj_s: &Object<S>) {
+        m::read(obj_s);
+    }
+}
+
+// The object type mismatch
+//# run --signers A --args object:rooch_framework::timestamp::Timestamp
+script {
+    use moveos_std::object::Object;
+    use test::m::{Self, S};
+
+    fun main(obj_s: &Object<S>) {
+        m::read(obj_s);
+    }
+}
+
+// Only the owner can pass the object as mutable reference
+//# run --signers test --args object:0x8f684aa792b9b1058aeccd3941849e9662132d81c974b826a9c6bddae8880bd6
+script {
+    use moveos_std::object::Object;
+    use test::m::S;
+
+    fun main(_obj_s: &mut Object<S>) {
+    }
+}
+
+// The object passed as mutable reference can not be passed again
+//# run --signers A --args object:0x8f684aa792b9b1058aeccd3941849e9662132d81c974b826a9c6bddae8880bd6 object:0x8f684aa792b9b1058aeccd3941849e9662132d81c974b826a9c6bddae8880bd6
+script {
+    use moveos_std::object::Object;
+    use test::m::{Self, S};
+
+    fun main(obj_s1: &mut Object<S>, obj_s2: &Object<S>) {
+        m::update_with(obj_s1, obj_s2);
+    }
+}
+
+//# view_object --object-id 0x8f684aa792b9b1058aeccd3941849e9662132d81c974b826a9c6bddae8880bd6
@@ -14,6 +14,7 @@ module rooch_framework::account {
    friend rooch_framework::transfer;
    friend rooch_framework::genesis;
    friend rooch_framework::upgrade;
+   friend rooch_framework::onchain_config;
 
    /// Resource representing an account.
    struct Account has key, store {
@@ -118,6 +119,7 @@ module rooch_framework::account {
 
 
    /// Return the current sequence number at `addr`
+   #[view]
    public fun sequence_number(ctx: &Context, addr: address): u64 {
       // if account does not exist, return 0 as sequence number
       // TODO: refactor this after we decide how to handle account create.
@@ -128,6 +130,7 @@ module rooch_framework::account {
       sequence_number_for_account(account)
    }
 
+   #[view]
    public fun sequence_number_for_sender(ctx: &Context): u64 {
       let sender = context::sender(ctx);
       sequence_number(ctx, sender)
@@ -61,6 +61,7 @@ module rooch_framework::account_coin_store {
     // Public functions
 
     /// Returns the balance of `addr` for provided `CoinType`.
+    #[view]
     public fun balance<CoinType: key>(ctx: &Context, addr: address): u256 {
         if (exist_account_coin_store<CoinType>(ctx, addr)) {
             let coin_store = borrow_account_coin_store<CoinType>(ctx, addr);
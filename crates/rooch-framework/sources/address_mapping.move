@@ -119,12 +119,14 @@ module rooch_framework::address_mapping{
     }
 
     /// Resolve a multi-chain address to a rooch address
+    #[view]
     public fun resolve(ctx: &Context, maddress: MultiChainAddress): Option<address> {
         let am = Self::borrow(ctx);
         Self::resolve_address(am, maddress)
     }
 
     /// Resolve a multi-chain address to a rooch address, if not exists, generate a new rooch address
+    #[view]
     public fun resolve_or_generate(ctx: &Context, maddress: MultiChainAddress): address {
         let am = Self::borrow(ctx);
         Self::resolve_or_generate_address(am, maddress)
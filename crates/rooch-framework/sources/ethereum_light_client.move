@@ -3,16 +3,24 @@
 
 module rooch_framework::ethereum_light_client{
 
+    use std::option::{Self, Option};
     use moveos_std::context::{Self, Context};
     use moveos_std::table::{Self, Table};
     use moveos_std::bcs;
     use moveos_std::signer;
     use rooch_framework::ethereum_address::ETHAddress;
     use rooch_framework::timestamp; 
+    use rooch_framework::onchain_config;
 
     friend rooch_framework::genesis;
 
     const ErrorBlockNotFound:u64 = 1;
+    const ErrorParentHashMismatch:u64 = 2;
+    const ErrorNotRelayer:u64 = 3;
+    const ErrorReorgTooDeep:u64 = 4;
+
+    /// The max number of the relayed blocks a reorg can replace, same as `MAX_REORG_DEPTH` of the relayer.
+    const MAX_REORG_DEPTH:u64 = 128;
 
     #[data_struct]
     struct BlockHeader has store, copy, drop {
@@ -48,11 +56,14 @@ module rooch_framework::ethereum_light_client{
 
     struct BlockStore has key{
         blocks: Table<u64, BlockHeader>,
+        /// The number of the latest relayed block, the relayer resumes from it
+        latest_block_number: Option<u64>,
     }
 
     public(friend) fun genesis_init(ctx: &mut Context, genesis_account: &signer){
         let block_store = BlockStore{
             blocks: context::new_table(ctx),
+            latest_block_number: option::none(),
         };
         context::move_resource_to(ctx, genesis_account, block_store);
     }
@@ -62,27 +73,70 @@ module rooch_framework::ethereum_light_client{
         //TODO validate the block hash
         //TODO validate the block via ethereum consensus(pos validators)
         let block_store = context::borrow_mut_resource<BlockStore>(ctx, @rooch_framework);
-        if(table::contains(&block_store.blocks, block_header.number)){
-            //repeat block number
-            //TODO check if it is a soft fork.
-            return
+        let number = block_header.number;
+        if(table::contains(&block_store.blocks, number)){
+            //repeat block
+            if(table::borrow(&block_store.blocks, number).hash == block_header.hash){
+                return
+            };
+            //reorg, the block and the blocks above it are orphaned
+            let latest_block_number = *option::borrow(&block_store.latest_block_number);
+            assert!(latest_block_number - number < MAX_REORG_DEPTH, ErrorReorgTooDeep);
+            remove_blocks_from(block_store, number);
+        };
+        if(number > 0 && table::contains(&block_store.blocks, number - 1)){
+            let parent = table::borrow(&block_store.blocks, number - 1);
+            assert!(parent.hash == block_header.parent_hash, ErrorParentHashMismatch);
+        };
+        table::add(&mut block_store.blocks, number, block_header);
+        if(option::is_none(&block_store.latest_block_number) || number > *option::borrow(&block_store.latest_block_number)){
+            block_store.latest_block_number = option::some(number);
         };
-        table::add(&mut block_store.blocks, block_header.number, block_header);
 
         let timestamp_seconds = (block_header.timestamp as u64);
         let module_signer = signer::module_signer<BlockStore>();
         timestamp::try_update_global_time(ctx, &module_signer, timestamp::seconds_to_milliseconds(timestamp_seconds));        
     }
 
-    /// The relay server submit a new Ethereum block to the light client.
+    /// Remove the blocks from the `number` to the latest block
+    fun remove_blocks_from(block_store: &mut BlockStore, number: u64){
+        let latest_block_number = option::destroy_with_default(block_store.latest_block_number, number);
+        while(latest_block_number >= number){
+            if(table::contains(&block_store.blocks, latest_block_number)){
+                table::remove(&mut block_store.blocks, latest_block_number);
+            };
+            if(latest_block_number == 0){
+                break
+            };
+            latest_block_number = latest_block_number - 1;
+        };
+        block_store.latest_block_number = if(number > 0){ option::some(number - 1) }else{ option::none() };
+    }
+
+    /// The relay server submit a new Ethereum block to the light client, only the relayers can submit the blocks.
     public entry fun submit_new_block(ctx: &mut Context, block_header_bytes: vector<u8>){
+        assert!(onchain_config::is_relayer(ctx, context::sender(ctx)), ErrorNotRelayer);
         process_block(ctx, block_header_bytes);
     }
 
     /// Get block via block_number
+    #[view]
     public fun get_block(ctx: &Context, block_number: u64): &BlockHeader{
         let block_store = context::borrow_resource<BlockStore>(ctx, @rooch_framework);
         assert!(table::contains(&block_store.blocks, block_number), ErrorBlockNotFound);
         table::borrow(&block_store.blocks, block_number)
     }
+
+    #[view]
+    public fun exists_block(ctx: &Context, block_number: u64): bool{
+        let block_store = context::borrow_resource<BlockStore>(ctx, @rooch_framework);
+        table::contains(&block_store.blocks, block_number)
+    }
+
+    /// Get the number of the latest relayed block
+    #[view]
+    public fun get_latest_block_number(ctx: &Context): Option<u64>{
+        let block_store = context::borrow_resource<BlockStore>(ctx, @rooch_framework);
+        block_store.latest_block_number
+    }
 }
@@ -17,6 +17,7 @@ module rooch_framework::gas_coin {
     //If not, we can remove `store` ability from GasCoin.
     struct GasCoin has key, store {}
 
+    #[view]
     public fun balance(ctx: &Context, addr: address): u256 {
         account_coin_store::balance<GasCoin>(ctx, addr)
     }
@@ -3,52 +3,107 @@
 
 module rooch_framework::onchain_config {
 
-    use std::option;
-    use std::option::Option;
-    use std::string::String;
-    use moveos_std::bcs;
     use moveos_std::context::{Self, Context};
+    use moveos_std::event;
+    use moveos_std::gas_schedule;
     use moveos_std::object;
+    use moveos_std::signer;
     use std::vector;
+    use rooch_framework::account::create_signer;
 
     friend rooch_framework::upgrade;
     friend rooch_framework::genesis;
 
-    #[data_struct]
-    struct GasEntry has store, copy, drop {
-        key: String,
-        val: u64,
-    }
-
-    #[data_struct]
-    struct GasSchedule has key, copy, drop, store {
-        feature_version: u64,
-        entries: vector<GasEntry>,
-    }
+    const ErrorNotSequencer: u64 = 1;
 
     /// OnchainConfig is framework configurations stored on chain.
     struct OnchainConfig has key {
         framework_version: u64,
         sequencer: address,
-        gas_schedule: Option<GasSchedule>,
     }
 
-    public(friend) fun genesis_init(ctx: &mut Context, _genesis_account: &signer, sequencer: address, gas_schedule_blob: vector<u8>){
-        let gas_schedule = option::none<GasSchedule>();
+    /// The accounts allowed to relay the blocks of the other chains to the light clients, besides the sequencer.
+    struct RelayerWhitelist has key {
+        relayers: vector<address>,
+    }
 
+    /// Event for gas schedule updates
+    struct GasScheduleUpdatedEvent has drop, store {
+        feature_version: u64,
+    }
+
+    public(friend) fun genesis_init(ctx: &mut Context, _genesis_account: &signer, sequencer: address, gas_schedule_blob: vector<u8>){
         if (vector::length(&gas_schedule_blob) > 0) {
-            gas_schedule = option::some(bcs::from_bytes<GasSchedule>(gas_schedule_blob));
+            let moveos_std_signer = create_signer(@moveos_std);
+            gas_schedule::initialize(ctx, &moveos_std_signer, gas_schedule_blob);
         };
 
         let config = OnchainConfig{
             framework_version: 0,
             sequencer,
-            gas_schedule,
         };
         let obj = context::new_named_object(ctx, config);
         object::transfer_extend(obj, @rooch_framework);
     }
 
+    /// Update the on-chain gas schedule with the bcs serialized gas schedule, only the sequencer can update it.
+    /// The feature version of the new gas schedule must be greater than the current one.
+    entry fun update_gas_schedule_entry(ctx: &mut Context, account: &signer, gas_schedule_blob: vector<u8>) {
+        let sender_address = signer::address_of(account);
+        assert!(sender_address == sequencer(ctx), ErrorNotSequencer);
+
+        let moveos_std_signer = create_signer(@moveos_std);
+        gas_schedule::update(ctx, &moveos_std_signer, gas_schedule_blob);
+        event::emit<GasScheduleUpdatedEvent>(GasScheduleUpdatedEvent { feature_version: gas_schedule::feature_version(ctx) });
+    }
+
+    /// Allow the `relayer` to relay the blocks to the light clients, only the sequencer can add the relayers.
+    entry fun add_relayer_entry(ctx: &mut Context, account: &signer, relayer: address) {
+        let sender_address = signer::address_of(account);
+        assert!(sender_address == sequencer(ctx), ErrorNotSequencer);
+
+        let object_id = object::named_object_id<RelayerWhitelist>();
+        if (!context::exists_object<RelayerWhitelist>(ctx, object_id)) {
+            let obj = context::new_named_object(ctx, RelayerWhitelist { relayers: vector::empty() });
+            object::transfer_extend(obj, @rooch_framework);
+        };
+        let obj = context::borrow_mut_object_extend<RelayerWhitelist>(ctx, object_id);
+        let whitelist = object::borrow_mut(obj);
+        if (!vector::contains(&whitelist.relayers, &relayer)) {
+            vector::push_back(&mut whitelist.relayers, relayer);
+        };
+    }
+
+    /// Disallow the `relayer` to relay the blocks to the light clients, only the sequencer can remove the relayers.
+    entry fun remove_relayer_entry(ctx: &mut Context, account: &signer, relayer: address) {
+        let sender_address = signer::address_of(account);
+        assert!(sender_address == sequencer(ctx), ErrorNotSequencer);
+
+        let object_id = object::named_object_id<RelayerWhitelist>();
+        if (!context::exists_object<RelayerWhitelist>(ctx, object_id)) {
+            return
+        };
+        let obj = context::borrow_mut_object_extend<RelayerWhitelist>(ctx, object_id);
+        let whitelist = object::borrow_mut(obj);
+        let (found, index) = vector::index_of(&whitelist.relayers, &relayer);
+        if (found) {
+            vector::remove(&mut whitelist.relayers, index);
+        };
+    }
+
+    /// The sequencer and the accounts in the relayer whitelist can relay the blocks to the light clients.
+    public fun is_relayer(ctx: &Context, account: address): bool {
+        if (account == sequencer(ctx)) {
+            return true
+        };
+        let object_id = object::named_object_id<RelayerWhitelist>();
+        if (!context::exists_object<RelayerWhitelist>(ctx, object_id)) {
+            return false
+        };
+        let whitelist = object::borrow(context::borrow_object<RelayerWhitelist>(ctx, object_id));
+        vector::contains(&whitelist.relayers, &account)
+    }
+
     public fun sequencer(ctx: &Context): address {
         onchain_config(ctx).sequencer
     }
@@ -73,8 +128,4 @@ module rooch_framework::onchain_config {
         let obj = context::borrow_object<OnchainConfig>(ctx, object_id);
         object::borrow(obj)
     }
-
-    public fun onchain_gas_schedule(ctx: &Context): &Option<GasSchedule> {
-        &onchain_config(ctx).gas_schedule
-    }
 }
@@ -6,6 +6,7 @@ module rooch_framework::session_key {
     use std::option::{Self, Option};
     use std::signer;
     use moveos_std::context::{Self, Context};
+    use moveos_std::object::ObjectID;
     use moveos_std::table::{Self, Table};
     use moveos_std::tx_meta::{Self, FunctionCallMeta};
     use rooch_framework::auth_validator;
@@ -72,6 +73,7 @@ module rooch_framework::session_key {
         return false
     }
 
+    #[view]
     public fun is_expired_session_key(ctx: &Context, account_address: address, authentication_key: vector<u8>) : bool {
         let session_key_option = get_session_key(ctx, account_address, authentication_key);
         if (option::is_none(&session_key_option)){
@@ -87,6 +89,7 @@ module rooch_framework::session_key {
     }
 
     /// Get the session key of the account_address by the authentication key
+    #[view]
     public fun get_session_key(ctx: &Context, account_address: address, authentication_key: vector<u8>) : Option<SessionKey> {
         if (!context::exists_resource<SessionKeys>(ctx, account_address)){
             return option::none()
@@ -99,6 +102,15 @@ module rooch_framework::session_key {
         }
     }
 
+    /// Return the SessionKeys table handle of the account_address
+    public fun session_keys_handle(ctx: &Context, account_address: address) : Option<ObjectID> {
+        if (!context::exists_resource<SessionKeys>(ctx, account_address)){
+            return option::none()
+        };
+        let session_keys = context::borrow_resource<SessionKeys>(ctx, account_address);
+        option::some(*table::handle(&session_keys.keys))
+    }
+
     public fun create_session_key(ctx: &mut Context, sender: &signer, authentication_key: vector<u8>, scopes: vector<SessionScope>, max_inactive_interval: u64) {
         //Can not create new session key by the other session key
         assert!(!auth_validator::is_validate_via_session_key(ctx), ErrorSessionKeyCreatePermissionDenied);
@@ -79,12 +79,14 @@ module rooch_framework::timestamp {
     }
 
     /// Gets the current time in milliseconds.
+    #[view]
     public fun now_milliseconds(ctx: &Context): u64 {
         let timestamp = timestamp(ctx);
         timestamp.milliseconds
     }
 
     /// Gets the current time in seconds.
+    #[view]
     public fun now_seconds(ctx: &Context): u64 {
         now_milliseconds(ctx) / MILLI_CONVERSION_FACTOR
     }
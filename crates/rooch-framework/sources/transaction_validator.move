@@ -9,7 +9,7 @@ module rooch_framework::transaction_validator {
     use rooch_framework::multichain_address::MultiChainAddress;
     use rooch_framework::address_mapping;
     use rooch_framework::account_authentication;
-    use rooch_framework::auth_validator::{Self, TxValidateResult};
+    use rooch_framework::auth_validator::{Self, AuthValidator, TxValidateResult};
     use rooch_framework::auth_validator_registry;
     use rooch_framework::session_key;
     use rooch_framework::chain_id;
@@ -82,9 +82,15 @@ module rooch_framework::transaction_validator {
         let max_gas_amount = context::max_gas_amount(ctx);
         let gas = transaction_fee::calculate_gas(ctx, max_gas_amount);
 
+        // The fee payer of a sponsored transaction is checked by `validate_fee_payer`,
+        // otherwise the sender needs the gas, even if the module account pays it.
+        let sponsored = context::has_gas_payment_account(ctx)
+            && !context::tx_gas_paid_by_module_account(ctx)
+            && context::tx_gas_payment_account(ctx) != sender;
+
         // We skip the gas check for the new account, for avoid break the current testcase
         // TODO remove the skip afater we provide the gas faucet and update all testcase
-        if(account::exists_at(ctx, sender)){
+        if(!sponsored && account::exists_at(ctx, sender)){
             let gas_balance = gas_coin::balance(ctx, sender);
             assert!(
                 gas_balance >= gas,
@@ -114,6 +120,35 @@ module rooch_framework::transaction_validator {
         }
     }
 
+    /// This function is for Rooch to validate the fee payer of a sponsored transaction.
+    /// The fee payer must exist and have enough gas, returns the auth validator to validate the fee payer's authenticator.
+    public fun validate_fee_payer(
+        ctx: &Context,
+        fee_payer: address,
+        auth_validator_id: u64,
+    ): AuthValidator {
+        assert!(account::exists_at(ctx, fee_payer), ErrorValidateAccountDoesNotExist);
+
+        let max_gas_amount = context::max_gas_amount(ctx);
+        let gas = transaction_fee::calculate_gas(ctx, max_gas_amount);
+        let gas_balance = gas_coin::balance(ctx, fee_payer);
+        assert!(
+            gas_balance >= gas,
+            ErrorValidateCantPayGasDeposit
+        );
+
+        let auth_validator = auth_validator_registry::borrow_validator(ctx, auth_validator_id);
+        let validator_id = auth_validator::validator_id(auth_validator);
+        // builtin auth validator id do not need to install
+        if (!rooch_framework::builtin_validators::is_builtin_auth_validator(auth_validator_id)) {
+            assert!(
+                account_authentication::is_auth_validator_installed(ctx, fee_payer, validator_id),
+                ErrorValidateNotInstalledAuthValidator
+            );
+        };
+        *auth_validator
+    }
+
     /// Transaction pre_execute function.
     /// Execute before the transaction is executed, automatically called by the MoveOS VM.
     /// This function is for Rooch to auto create account and address maping.
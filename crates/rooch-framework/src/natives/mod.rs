@@ -39,15 +39,14 @@ pub struct NativeGasParameters {
 impl FromOnChainGasSchedule for NativeGasParameters {
     fn from_on_chain_gas_schedule(gas_schedule: &BTreeMap<String, u64>) -> Option<Self> {
         Some(Self {
-            moveos_stdlib: FromOnChainGasSchedule::from_on_chain_gas_schedule(gas_schedule)
-                .unwrap(),
-            account: FromOnChainGasSchedule::from_on_chain_gas_schedule(gas_schedule).unwrap(),
-            hash: FromOnChainGasSchedule::from_on_chain_gas_schedule(gas_schedule).unwrap(),
-            ed25519: FromOnChainGasSchedule::from_on_chain_gas_schedule(gas_schedule).unwrap(),
-            ecdsa_k1: FromOnChainGasSchedule::from_on_chain_gas_schedule(gas_schedule).unwrap(),
-            encoding: FromOnChainGasSchedule::from_on_chain_gas_schedule(gas_schedule).unwrap(),
-            decoding: FromOnChainGasSchedule::from_on_chain_gas_schedule(gas_schedule).unwrap(),
-            bcs: FromOnChainGasSchedule::from_on_chain_gas_schedule(gas_schedule).unwrap(),
+            moveos_stdlib: FromOnChainGasSchedule::from_on_chain_gas_schedule(gas_schedule)?,
+            account: FromOnChainGasSchedule::from_on_chain_gas_schedule(gas_schedule)?,
+            hash: FromOnChainGasSchedule::from_on_chain_gas_schedule(gas_schedule)?,
+            ed25519: FromOnChainGasSchedule::from_on_chain_gas_schedule(gas_schedule)?,
+            ecdsa_k1: FromOnChainGasSchedule::from_on_chain_gas_schedule(gas_schedule)?,
+            encoding: FromOnChainGasSchedule::from_on_chain_gas_schedule(gas_schedule)?,
+            decoding: FromOnChainGasSchedule::from_on_chain_gas_schedule(gas_schedule)?,
+            bcs: FromOnChainGasSchedule::from_on_chain_gas_schedule(gas_schedule)?,
         })
     }
 }
@@ -84,19 +83,18 @@ impl InitialGasSchedule for NativeGasParameters {
 impl FromOnChainGasSchedule for MoveOSStdlibGasParameters {
     fn from_on_chain_gas_schedule(gas_schedule: &BTreeMap<String, u64>) -> Option<Self> {
         Some(Self {
-            move_stdlib: FromOnChainGasSchedule::from_on_chain_gas_schedule(gas_schedule).unwrap(),
-            move_nursery: FromOnChainGasSchedule::from_on_chain_gas_schedule(gas_schedule).unwrap(),
-            table_extension: FromOnChainGasSchedule::from_on_chain_gas_schedule(gas_schedule)
-                .unwrap(),
-            type_info: FromOnChainGasSchedule::from_on_chain_gas_schedule(gas_schedule).unwrap(),
-            rlp: FromOnChainGasSchedule::from_on_chain_gas_schedule(gas_schedule).unwrap(),
-            bcd: FromOnChainGasSchedule::from_on_chain_gas_schedule(gas_schedule).unwrap(),
-            events: FromOnChainGasSchedule::from_on_chain_gas_schedule(gas_schedule).unwrap(),
-            test_helper: FromOnChainGasSchedule::from_on_chain_gas_schedule(gas_schedule).unwrap(),
-            signer: FromOnChainGasSchedule::from_on_chain_gas_schedule(gas_schedule).unwrap(),
-            move_module: FromOnChainGasSchedule::from_on_chain_gas_schedule(gas_schedule).unwrap(),
-            object: FromOnChainGasSchedule::from_on_chain_gas_schedule(gas_schedule).unwrap(),
-            json: FromOnChainGasSchedule::from_on_chain_gas_schedule(gas_schedule).unwrap(),
+            move_stdlib: FromOnChainGasSchedule::from_on_chain_gas_schedule(gas_schedule)?,
+            move_nursery: FromOnChainGasSchedule::from_on_chain_gas_schedule(gas_schedule)?,
+            table_extension: FromOnChainGasSchedule::from_on_chain_gas_schedule(gas_schedule)?,
+            type_info: FromOnChainGasSchedule::from_on_chain_gas_schedule(gas_schedule)?,
+            rlp: FromOnChainGasSchedule::from_on_chain_gas_schedule(gas_schedule)?,
+            bcd: FromOnChainGasSchedule::from_on_chain_gas_schedule(gas_schedule)?,
+            events: FromOnChainGasSchedule::from_on_chain_gas_schedule(gas_schedule)?,
+            test_helper: FromOnChainGasSchedule::from_on_chain_gas_schedule(gas_schedule)?,
+            signer: FromOnChainGasSchedule::from_on_chain_gas_schedule(gas_schedule)?,
+            move_module: FromOnChainGasSchedule::from_on_chain_gas_schedule(gas_schedule)?,
+            object: FromOnChainGasSchedule::from_on_chain_gas_schedule(gas_schedule)?,
+            json: FromOnChainGasSchedule::from_on_chain_gas_schedule(gas_schedule)?,
         })
     }
 }
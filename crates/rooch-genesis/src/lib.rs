@@ -3,9 +3,11 @@
 
 use anyhow::Result;
 use move_binary_format::{errors::Location, CompiledModule};
-use move_core_types::{account_address::AccountAddress, identifier::Identifier};
+use move_core_types::{
+    account_address::AccountAddress, errmap::ErrorMapping, identifier::Identifier,
+};
 use move_vm_runtime::{config::VMConfig, native_functions::NativeFunction};
-use moveos::moveos::{MoveOS, MoveOSConfig};
+use moveos::moveos::{MoveOS, MoveOSConfig, NativesBuilder};
 use moveos_stdlib_builder::Stdlib;
 use moveos_store::{config_store::ConfigDBStore, MoveOSStore};
 use moveos_types::genesis_info::GenesisInfo;
@@ -14,7 +16,9 @@ use moveos_types::h256::H256;
 use moveos_types::transaction::MoveAction;
 use once_cell::sync::Lazy;
 use rooch_framework::natives::default_gas_schedule;
-use rooch_framework::natives::gas_parameter::gas_member::InitialGasSchedule;
+use rooch_framework::natives::gas_parameter::gas_member::{
+    FromOnChainGasSchedule, InitialGasSchedule,
+};
 use rooch_types::bitcoin::genesis::BitcoinGenesisContext;
 use rooch_types::bitcoin::network::Network;
 use rooch_types::error::GenesisError;
@@ -23,6 +27,7 @@ use rooch_types::transaction::rooch::RoochTransaction;
 use rooch_types::{address::RoochAddress, chain_id::RoochChainID};
 use serde::{Deserialize, Serialize};
 use std::str::FromStr;
+use std::sync::Arc;
 use std::{
     fs::File,
     io::Write,
@@ -131,6 +136,24 @@ impl RoochGenesis {
         rooch_framework_native_tables
     }
 
+    /// Build the natives from the gas schedule loaded from chain, so the native gas
+    /// parameters follow the on-chain config instead of the initial values.
+    pub fn natives_builder(&self) -> NativesBuilder {
+        let bitcoin_move_gas_params = self.bitcoin_move_gas_params.clone();
+        Arc::new(move |gas_schedule| {
+            let rooch_framework_gas_params =
+                rooch_framework::natives::NativeGasParameters::from_on_chain_gas_schedule(
+                    gas_schedule,
+                )?;
+            let mut rooch_framework_native_tables =
+                rooch_framework::natives::all_natives(rooch_framework_gas_params);
+            rooch_framework_native_tables.extend(bitcoin_move::natives::all_natives(
+                bitcoin_move_gas_params.clone(),
+            ));
+            Some(rooch_framework_native_tables)
+        })
+    }
+
     pub fn genesis_package_hash(&self) -> H256 {
         h256::sha3_256_of(
             bcs::to_bytes(&self.genesis_package)
@@ -298,6 +321,38 @@ pub fn rooch_framework_error_descriptions() -> &'static [u8] {
     ROOCH_FRAMEWORK_ERROR_DESCRIPTIONS
 }
 
+const BITCOIN_MOVE_ERROR_DESCRIPTIONS: &[u8] =
+    include_bytes!("../generated/bitcoin_move_error_description.errmap");
+
+pub fn bitcoin_move_error_descriptions() -> &'static [u8] {
+    BITCOIN_MOVE_ERROR_DESCRIPTIONS
+}
+
+static ERROR_MAPPING: Lazy<ErrorMapping> = Lazy::new(|| {
+    let mut error_mapping = ErrorMapping::default();
+    for error_descriptions in [
+        move_std_error_descriptions(),
+        moveos_std_error_descriptions(),
+        rooch_framework_error_descriptions(),
+        bitcoin_move_error_descriptions(),
+    ] {
+        let mapping: ErrorMapping =
+            bcs::from_bytes(error_descriptions).expect("Decode err map failed");
+        error_mapping
+            .error_categories
+            .extend(mapping.error_categories);
+        error_mapping
+            .module_error_maps
+            .extend(mapping.module_error_maps);
+    }
+    error_mapping
+});
+
+/// The error map of all the builtin framework modules.
+pub fn error_mapping() -> &'static ErrorMapping {
+    &ERROR_MAPPING
+}
+
 #[cfg(test)]
 mod tests {
     use moveos::moveos::MoveOS;
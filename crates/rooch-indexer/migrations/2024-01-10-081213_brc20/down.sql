@@ -0,0 +1,3 @@
+DROP TABLE IF EXISTS brc20_ticks;
+DROP TABLE IF EXISTS brc20_balances;
+DROP TABLE IF EXISTS brc20_operations;
\ No newline at end of file
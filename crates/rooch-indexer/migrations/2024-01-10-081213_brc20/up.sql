@@ -0,0 +1,44 @@
+CREATE TABLE brc20_ticks
+(
+    tick               VARCHAR        NOT NULL       PRIMARY KEY,
+    max                VARCHAR        NOT NULL,
+    lim                VARCHAR        NOT NULL,
+    dec                BIGINT         NOT NULL,
+    supply             VARCHAR        NOT NULL,
+    deployer           VARCHAR        NOT NULL,
+    tx_order           BIGINT         NOT NULL,
+    created_at         BIGINT         NOT NULL,
+    updated_at         BIGINT         NOT NULL
+);
+
+CREATE TABLE brc20_balances
+(
+    tick               VARCHAR        NOT NULL,
+    owner              VARCHAR        NOT NULL,
+    balance            VARCHAR        NOT NULL,
+    tx_order           BIGINT         NOT NULL,
+    updated_at         BIGINT         NOT NULL,
+    PRIMARY KEY (owner, tick)
+);
+
+CREATE INDEX idx_brc20_balances_tick ON brc20_balances (tick);
+
+CREATE TABLE brc20_operations
+(
+    tx_order           BIGINT         NOT NULL,
+    event_index        BIGINT         NOT NULL,
+    tx_hash            VARCHAR        NOT NULL,
+    op                 VARCHAR        NOT NULL,
+    tick               VARCHAR        NOT NULL,
+    from_address       VARCHAR        NOT NULL,
+    to_address         VARCHAR        NOT NULL,
+    amt                VARCHAR        NOT NULL,
+    success            BOOLEAN        NOT NULL,
+    created_at         BIGINT         NOT NULL,
+    PRIMARY KEY (tx_order, event_index)
+);
+
+CREATE INDEX idx_brc20_operations_tick ON brc20_operations (tick);
+CREATE INDEX idx_brc20_operations_from_address ON brc20_operations (from_address);
+CREATE INDEX idx_brc20_operations_to_address ON brc20_operations (to_address);
+CREATE INDEX idx_brc20_operations_tx_hash ON brc20_operations (tx_hash);
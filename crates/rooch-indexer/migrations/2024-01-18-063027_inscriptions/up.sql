@@ -0,0 +1,13 @@
+CREATE TABLE inscriptions
+(
+    object_id          VARCHAR        NOT NULL       PRIMARY KEY,
+    content_type       VARCHAR        NOT NULL,
+    media              VARCHAR        NOT NULL,
+    parent             VARCHAR        NOT NULL,
+    tx_order           BIGINT         NOT NULL,
+    created_at         BIGINT         NOT NULL
+);
+
+CREATE INDEX idx_inscriptions_content_type ON inscriptions (content_type);
+CREATE INDEX idx_inscriptions_media ON inscriptions (media);
+CREATE INDEX idx_inscriptions_parent ON inscriptions (parent);
@@ -4,9 +4,11 @@
 use crate::actor::messages::{
     IndexerEventsMessage, IndexerStatesMessage, IndexerTransactionMessage,
 };
+use crate::metrics::IndexerMetrics;
 use crate::store::traits::IndexerStoreTrait;
 use crate::types::{
-    IndexedEvent, IndexedGlobalState, IndexedTableChangeSet, IndexedTableState, IndexedTransaction,
+    IndexedBRC20Balance, IndexedBRC20Operation, IndexedBRC20Tick, IndexedEvent, IndexedGlobalState,
+    IndexedInscription, IndexedTableChangeSet, IndexedTableState, IndexedTransaction,
 };
 use crate::utils::format_struct_tag;
 use crate::IndexerStore;
@@ -19,13 +21,20 @@ use moveos_store::MoveOSStore;
 use moveos_types::moveos_std::context;
 use moveos_types::moveos_std::object::{ObjectEntity, ObjectID, RawObject};
 use moveos_types::moveos_std::raw_table::TableInfo;
-use moveos_types::state::{SplitStateChangeSet, State};
+use moveos_types::state::{MoveStructType, SplitStateChangeSet, State};
 use moveos_types::state_resolver::MoveOSResolverProxy;
 use rooch_rpc_api::jsonrpc_types::{AnnotatedMoveStructView, AnnotatedMoveValueView};
+use rooch_types::bitcoin::brc20::BRC20OpEvent;
+use std::collections::BTreeMap;
 
 pub struct IndexerActor {
     indexer_store: IndexerStore,
     moveos_store: MoveOSResolverProxy<MoveOSStore>,
+    /// The max tx order received by the indexer
+    latest_tx_order: Option<u64>,
+    /// The tx order of the last indexed transaction
+    indexed_tx_order: Option<u64>,
+    metrics: Option<IndexerMetrics>,
 }
 
 impl IndexerActor {
@@ -33,9 +42,31 @@ impl IndexerActor {
         Ok(Self {
             indexer_store,
             moveos_store: MoveOSResolverProxy(moveos_store),
+            latest_tx_order: None,
+            indexed_tx_order: None,
+            metrics: None,
         })
     }
 
+    pub fn with_metrics(mut self, metrics: IndexerMetrics) -> Self {
+        self.metrics = Some(metrics);
+        self
+    }
+
+    fn update_metrics(&self) {
+        if let Some(metrics) = &self.metrics {
+            if let Some(indexed_tx_order) = self.indexed_tx_order {
+                metrics.indexer_tx_order.set(indexed_tx_order);
+            }
+            let lag = match (self.latest_tx_order, self.indexed_tx_order) {
+                (Some(latest), Some(indexed)) => latest.saturating_sub(indexed),
+                (Some(latest), None) => latest + 1,
+                _ => 0,
+            };
+            metrics.indexer_lag.set(lag);
+        }
+    }
+
     pub fn resolve_raw_object_value_to_json(&self, raw_object: &RawObject) -> Result<String> {
         let obj_value = MoveValueAnnotator::new(&self.moveos_store)
             .view_resource(&raw_object.value.struct_tag, &raw_object.value.value)?;
@@ -126,12 +157,16 @@ impl Handler<IndexerStatesMessage> for IndexerActor {
             tx_order,
             state_change_set,
         } = msg;
+        self.latest_tx_order = self.latest_tx_order.max(Some(tx_order));
+        self.update_metrics();
 
         // indexer state index generator
         let mut state_index_generator = 0u64;
         let mut new_global_states = vec![];
         let mut update_global_states = vec![];
         let mut remove_global_states = vec![];
+        // The inscriptions are indexed when created, and removed with the global states
+        let mut new_inscriptions = vec![];
 
         let mut new_table_states = vec![];
         let mut update_table_states = vec![];
@@ -192,6 +227,14 @@ impl Handler<IndexerStatesMessage> for IndexerActor {
 
                                 new_global_states.push(state);
                             } else if value.is_object() {
+                                if let Some(inscription) =
+                                    IndexedInscription::try_new_from_raw_object(
+                                        &value.as_raw_object()?,
+                                        tx_order,
+                                    )?
+                                {
+                                    new_inscriptions.push(inscription);
+                                }
                                 let state = self.new_global_state_from_raw_object(
                                     value,
                                     tx_order,
@@ -249,6 +292,9 @@ impl Handler<IndexerStatesMessage> for IndexerActor {
         new_global_states.append(&mut update_global_states);
         self.indexer_store
             .persist_or_update_global_states(new_global_states)?;
+        self.indexer_store.persist_inscriptions(new_inscriptions)?;
+        self.indexer_store
+            .delete_inscriptions(remove_global_states.clone())?;
         self.indexer_store
             .delete_global_states(remove_global_states)?;
 
@@ -303,10 +349,14 @@ impl Handler<IndexerTransactionMessage> for IndexerActor {
             moveos_tx,
         } = msg;
 
+        let tx_order = sequence_info.tx_order;
+        self.latest_tx_order = self.latest_tx_order.max(Some(tx_order));
         let indexed_transaction =
             IndexedTransaction::new(transaction, sequence_info, execution_info, moveos_tx)?;
         let transactions = vec![indexed_transaction];
         self.indexer_store.persist_transactions(transactions)?;
+        self.indexed_tx_order = self.indexed_tx_order.max(Some(tx_order));
+        self.update_metrics();
         Ok(())
     }
 }
@@ -332,7 +382,38 @@ impl Handler<IndexerEventsMessage> for IndexerActor {
                 )
             })
             .collect();
+
+        // The BRC20 op event carries the states after the operation, so the ticks and balances
+        // are indexed from it directly, keep the last state if a key is changed more than once.
+        // The deploy info of a tick is merged, the `from` of a mint is the minter, not the deployer.
+        let brc20_op_event_type = BRC20OpEvent::struct_tag();
+        let mut brc20_operations = vec![];
+        let mut brc20_ticks = BTreeMap::new();
+        let mut brc20_balances = BTreeMap::new();
+        for event in events
+            .iter()
+            .filter(|event| event.event_type == brc20_op_event_type)
+        {
+            let op_event = bcs::from_bytes::<BRC20OpEvent>(&event.event_data)?;
+            if let Some(tick) = IndexedBRC20Tick::new_from_op_event(&op_event, event.tx_order) {
+                brc20_ticks
+                    .entry(tick.tick.clone())
+                    .and_modify(|indexed_tick: &mut IndexedBRC20Tick| indexed_tick.merge(&tick))
+                    .or_insert(tick);
+            }
+            for balance in IndexedBRC20Balance::new_from_op_event(&op_event, event.tx_order) {
+                brc20_balances.insert((balance.owner, balance.tick.clone()), balance);
+            }
+            brc20_operations.push(IndexedBRC20Operation::new(event, &op_event));
+        }
+
         self.indexer_store.persist_events(events)?;
+        self.indexer_store
+            .persist_brc20_operations(brc20_operations)?;
+        self.indexer_store
+            .persist_or_update_brc20_ticks(brc20_ticks.into_values().collect())?;
+        self.indexer_store
+            .persist_or_update_brc20_balances(brc20_balances.into_values().collect())?;
         Ok(())
     }
 }
@@ -3,10 +3,15 @@
 
 use anyhow::Result;
 use coerce::actor::message::Message;
+use move_core_types::account_address::AccountAddress;
 use moveos_types::moveos_std::event::Event;
 use moveos_types::state::StateChangeSet;
 use moveos_types::transaction::{TransactionExecutionInfo, VerifiedMoveOSTransaction};
+use rooch_types::indexer::brc20::{
+    BRC20OperationFilter, IndexerBRC20Balance, IndexerBRC20Operation, IndexerBRC20Tick,
+};
 use rooch_types::indexer::event_filter::{EventFilter, IndexerEvent, IndexerEventID};
+use rooch_types::indexer::inscription::InscriptionFilter;
 use rooch_types::indexer::state::{
     GlobalStateFilter, IndexerGlobalState, IndexerStateID, IndexerTableChangeSet,
     IndexerTableState, StateSyncFilter, TableStateFilter,
@@ -95,6 +100,20 @@ impl Message for QueryIndexerGlobalStatesMessage {
     type Result = Result<Vec<IndexerGlobalState>>;
 }
 
+/// Query Indexer Inscription States Message
+#[derive(Debug, Serialize, Deserialize)]
+pub struct QueryIndexerInscriptionStatesMessage {
+    pub filter: InscriptionFilter,
+    // exclusive cursor if `Some`, otherwise start from the beginning
+    pub cursor: Option<IndexerStateID>,
+    pub limit: usize,
+    pub descending_order: bool,
+}
+
+impl Message for QueryIndexerInscriptionStatesMessage {
+    type Result = Result<Vec<IndexerGlobalState>>;
+}
+
 /// Query Indexer Table States Message
 #[derive(Debug, Serialize, Deserialize)]
 pub struct QueryIndexerTableStatesMessage {
@@ -122,3 +141,43 @@ pub struct SyncIndexerStatesMessage {
 impl Message for SyncIndexerStatesMessage {
     type Result = Result<Vec<IndexerTableChangeSet>>;
 }
+
+/// Query Indexer BRC20 Ticks Message
+#[derive(Debug, Serialize, Deserialize)]
+pub struct QueryIndexerBRC20TicksMessage {
+    pub tick: Option<String>,
+    // exclusive cursor if `Some`, otherwise start from the beginning
+    pub cursor: Option<String>,
+    pub limit: usize,
+}
+
+impl Message for QueryIndexerBRC20TicksMessage {
+    type Result = Result<Vec<IndexerBRC20Tick>>;
+}
+
+/// Query Indexer BRC20 Balances Message
+#[derive(Debug, Serialize, Deserialize)]
+pub struct QueryIndexerBRC20BalancesMessage {
+    pub owner: AccountAddress,
+    // exclusive cursor if `Some`, otherwise start from the beginning
+    pub cursor: Option<String>,
+    pub limit: usize,
+}
+
+impl Message for QueryIndexerBRC20BalancesMessage {
+    type Result = Result<Vec<IndexerBRC20Balance>>;
+}
+
+/// Query Indexer BRC20 Operations Message
+#[derive(Debug, Serialize, Deserialize)]
+pub struct QueryIndexerBRC20OperationsMessage {
+    pub filter: BRC20OperationFilter,
+    // exclusive cursor if `Some`, otherwise start from the beginning
+    pub cursor: Option<IndexerEventID>,
+    pub limit: usize,
+    pub descending_order: bool,
+}
+
+impl Message for QueryIndexerBRC20OperationsMessage {
+    type Result = Result<Vec<IndexerBRC20Operation>>;
+}
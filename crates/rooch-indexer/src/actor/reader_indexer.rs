@@ -2,13 +2,16 @@
 // SPDX-License-Identifier: Apache-2.0
 
 use crate::actor::messages::{
-    QueryIndexerEventsMessage, QueryIndexerGlobalStatesMessage, QueryIndexerTableStatesMessage,
+    QueryIndexerBRC20BalancesMessage, QueryIndexerBRC20OperationsMessage,
+    QueryIndexerBRC20TicksMessage, QueryIndexerEventsMessage, QueryIndexerGlobalStatesMessage,
+    QueryIndexerInscriptionStatesMessage, QueryIndexerTableStatesMessage,
     QueryIndexerTransactionsMessage, SyncIndexerStatesMessage,
 };
 use crate::indexer_reader::IndexerReader;
 use anyhow::{anyhow, Result};
 use async_trait::async_trait;
 use coerce::actor::{context::ActorContext, message::Handler, Actor};
+use rooch_types::indexer::brc20::{IndexerBRC20Balance, IndexerBRC20Operation, IndexerBRC20Tick};
 use rooch_types::indexer::event_filter::IndexerEvent;
 use rooch_types::indexer::state::{IndexerGlobalState, IndexerTableChangeSet, IndexerTableState};
 use rooch_types::transaction::TransactionWithInfo;
@@ -82,6 +85,30 @@ impl Handler<QueryIndexerGlobalStatesMessage> for IndexerReaderActor {
     }
 }
 
+#[async_trait]
+impl Handler<QueryIndexerInscriptionStatesMessage> for IndexerReaderActor {
+    async fn handle(
+        &mut self,
+        msg: QueryIndexerInscriptionStatesMessage,
+        _ctx: &mut ActorContext,
+    ) -> Result<Vec<IndexerGlobalState>> {
+        let QueryIndexerInscriptionStatesMessage {
+            filter,
+            cursor,
+            limit,
+            descending_order,
+        } = msg;
+        self.indexer_reader
+            .query_inscription_states_with_filter(filter, cursor, limit, descending_order)
+            .map_err(|e| {
+                anyhow!(format!(
+                    "Failed to query indexer inscription states: {:?}",
+                    e
+                ))
+            })
+    }
+}
+
 #[async_trait]
 impl Handler<QueryIndexerTableStatesMessage> for IndexerReaderActor {
     async fn handle(
@@ -124,3 +151,58 @@ impl Handler<SyncIndexerStatesMessage> for IndexerReaderActor {
             })
     }
 }
+
+#[async_trait]
+impl Handler<QueryIndexerBRC20TicksMessage> for IndexerReaderActor {
+    async fn handle(
+        &mut self,
+        msg: QueryIndexerBRC20TicksMessage,
+        _ctx: &mut ActorContext,
+    ) -> Result<Vec<IndexerBRC20Tick>> {
+        let QueryIndexerBRC20TicksMessage {
+            tick,
+            cursor,
+            limit,
+        } = msg;
+        self.indexer_reader
+            .query_brc20_ticks(tick, cursor, limit)
+            .map_err(|e| anyhow!(format!("Failed to query indexer brc20 ticks: {:?}", e)))
+    }
+}
+
+#[async_trait]
+impl Handler<QueryIndexerBRC20BalancesMessage> for IndexerReaderActor {
+    async fn handle(
+        &mut self,
+        msg: QueryIndexerBRC20BalancesMessage,
+        _ctx: &mut ActorContext,
+    ) -> Result<Vec<IndexerBRC20Balance>> {
+        let QueryIndexerBRC20BalancesMessage {
+            owner,
+            cursor,
+            limit,
+        } = msg;
+        self.indexer_reader
+            .query_brc20_balances(owner, cursor, limit)
+            .map_err(|e| anyhow!(format!("Failed to query indexer brc20 balances: {:?}", e)))
+    }
+}
+
+#[async_trait]
+impl Handler<QueryIndexerBRC20OperationsMessage> for IndexerReaderActor {
+    async fn handle(
+        &mut self,
+        msg: QueryIndexerBRC20OperationsMessage,
+        _ctx: &mut ActorContext,
+    ) -> Result<Vec<IndexerBRC20Operation>> {
+        let QueryIndexerBRC20OperationsMessage {
+            filter,
+            cursor,
+            limit,
+            descending_order,
+        } = msg;
+        self.indexer_reader
+            .query_brc20_operations_with_filter(filter, cursor, limit, descending_order)
+            .map_err(|e| anyhow!(format!("Failed to query indexer brc20 operations: {:?}", e)))
+    }
+}
@@ -11,13 +11,19 @@ use diesel::{
 };
 use std::ops::DerefMut;
 
+use crate::models::brc20::{StoredBRC20Balance, StoredBRC20Operation, StoredBRC20Tick};
 use crate::models::events::StoredEvent;
 use crate::models::states::{StoredGlobalState, StoredTableChangeSet, StoredTableState};
 use crate::models::transactions::StoredTransaction;
 use crate::schema::global_states;
 use crate::schema::{events, table_change_sets, table_states, transactions};
-use crate::utils::format_struct_tag;
+use crate::utils::{escape_sql_string, format_struct_tag};
+use move_core_types::account_address::AccountAddress;
+use rooch_types::indexer::brc20::{
+    BRC20OperationFilter, IndexerBRC20Balance, IndexerBRC20Operation, IndexerBRC20Tick,
+};
 use rooch_types::indexer::event_filter::{EventFilter, IndexerEvent, IndexerEventID};
+use rooch_types::indexer::inscription::InscriptionFilter;
 use rooch_types::indexer::state::{
     GlobalStateFilter, IndexerGlobalState, IndexerStateID, IndexerTableChangeSet,
     IndexerTableState, StateSyncFilter, TableStateFilter,
@@ -43,6 +49,16 @@ pub const STATE_INDEX_STR: &str = "state_index";
 pub const STATE_OBJECT_TYPE_STR: &str = "object_type";
 pub const STATE_OWNER_STR: &str = "owner";
 
+pub const INSCRIPTION_CONTENT_TYPE_STR: &str = "content_type";
+pub const INSCRIPTION_MEDIA_STR: &str = "media";
+pub const INSCRIPTION_PARENT_STR: &str = "parent";
+
+pub const BRC20_TICK_STR: &str = "tick";
+pub const BRC20_OWNER_STR: &str = "owner";
+pub const BRC20_OP_STR: &str = "op";
+pub const BRC20_FROM_ADDRESS_STR: &str = "from_address";
+pub const BRC20_TO_ADDRESS_STR: &str = "to_address";
+
 #[derive(Clone)]
 pub(crate) struct InnerIndexerReader {
     pool: crate::SqliteConnectionPool,
@@ -404,6 +420,91 @@ impl IndexerReader {
         Ok(result)
     }
 
+    /// Query the global states of the inscriptions matched by the filter on the inscription content fields
+    pub fn query_inscription_states_with_filter(
+        &self,
+        filter: InscriptionFilter,
+        // exclusive cursor if `Some`, otherwise start from the beginning
+        cursor: Option<IndexerStateID>,
+        limit: usize,
+        descending_order: bool,
+    ) -> IndexerResult<Vec<IndexerGlobalState>> {
+        let (tx_order, state_index) = if let Some(cursor) = cursor {
+            let IndexerStateID {
+                tx_order,
+                state_index,
+            } = cursor;
+            (tx_order as i64, state_index as i64)
+        } else if descending_order {
+            (i64::MAX, 0)
+        } else {
+            (-1, 0)
+        };
+
+        let inscription_where_clause = match filter {
+            InscriptionFilter::ContentType(content_type) => {
+                format!(
+                    "{INSCRIPTION_CONTENT_TYPE_STR} = '{}'",
+                    escape_sql_string(content_type)
+                )
+            }
+            InscriptionFilter::MediaClass(media) => {
+                format!(
+                    "{INSCRIPTION_MEDIA_STR} = '{}'",
+                    escape_sql_string(media.to_lowercase())
+                )
+            }
+            InscriptionFilter::Parent(parent) => {
+                format!("{INSCRIPTION_PARENT_STR} = '{}'", parent)
+            }
+        };
+
+        let cursor_clause = if descending_order {
+            format!(
+                "AND ({TX_ORDER_STR} < {} OR ({TX_ORDER_STR} = {} AND {STATE_INDEX_STR} < {}))",
+                tx_order, tx_order, state_index
+            )
+        } else {
+            format!(
+                "AND ({TX_ORDER_STR} > {} OR ({TX_ORDER_STR} = {} AND {STATE_INDEX_STR} > {}))",
+                tx_order, tx_order, state_index
+            )
+        };
+        let order_clause = if descending_order {
+            format!("{TX_ORDER_STR} DESC, {STATE_INDEX_STR} DESC")
+        } else {
+            format!("{TX_ORDER_STR} ASC, {STATE_INDEX_STR} ASC")
+        };
+
+        let query = format!(
+            "
+                SELECT * FROM global_states \
+                WHERE {OBJECT_ID_STR} IN (SELECT {OBJECT_ID_STR} FROM inscriptions WHERE {}) {} \
+                ORDER BY {} \
+                LIMIT {}
+            ",
+            inscription_where_clause, cursor_clause, order_clause, limit,
+        );
+
+        tracing::debug!("query inscription states: {}", query);
+        let stored_states = self
+            .inner_indexer_reader
+            .run_query(|conn| diesel::sql_query(query).load::<StoredGlobalState>(conn))?;
+
+        let result = stored_states
+            .into_iter()
+            .map(|v| v.try_into_indexer_global_state())
+            .collect::<Result<Vec<_>>>()
+            .map_err(|e| {
+                IndexerError::SQLiteReadError(format!(
+                    "Cast indexer inscription states failed: {:?}",
+                    e
+                ))
+            })?;
+
+        Ok(result)
+    }
+
     pub fn query_table_states_with_filter(
         &self,
         filter: TableStateFilter,
@@ -567,4 +668,194 @@ impl IndexerReader {
 
         Ok(result)
     }
+
+    pub fn query_brc20_ticks(
+        &self,
+        tick: Option<String>,
+        // exclusive cursor of the tick if `Some`, otherwise start from the beginning
+        cursor: Option<String>,
+        limit: usize,
+    ) -> IndexerResult<Vec<IndexerBRC20Tick>> {
+        let mut clauses = vec![];
+        if let Some(tick) = tick {
+            clauses.push(format!(
+                "{BRC20_TICK_STR} = '{}'",
+                escape_sql_string(tick.to_lowercase())
+            ));
+        }
+        if let Some(cursor) = cursor {
+            clauses.push(format!(
+                "{BRC20_TICK_STR} > '{}'",
+                escape_sql_string(cursor.to_lowercase())
+            ));
+        }
+        let where_clause = if clauses.is_empty() {
+            "".to_string()
+        } else {
+            format!("WHERE {}", clauses.join(" AND "))
+        };
+
+        let query = format!(
+            "
+                SELECT * FROM brc20_ticks \
+                {} \
+                ORDER BY {BRC20_TICK_STR} ASC \
+                LIMIT {}
+            ",
+            where_clause, limit,
+        );
+
+        tracing::debug!("query brc20 ticks: {}", query);
+        let stored_ticks = self
+            .inner_indexer_reader
+            .run_query(|conn| diesel::sql_query(query).load::<StoredBRC20Tick>(conn))?;
+
+        let result = stored_ticks
+            .into_iter()
+            .map(|t| t.try_into_indexer_brc20_tick())
+            .collect::<Result<Vec<_>>>()
+            .map_err(|e| {
+                IndexerError::SQLiteReadError(format!("Cast indexer brc20 ticks failed: {:?}", e))
+            })?;
+
+        Ok(result)
+    }
+
+    pub fn query_brc20_balances(
+        &self,
+        owner: AccountAddress,
+        // exclusive cursor of the tick if `Some`, otherwise start from the beginning
+        cursor: Option<String>,
+        limit: usize,
+    ) -> IndexerResult<Vec<IndexerBRC20Balance>> {
+        let cursor_clause = match cursor {
+            Some(cursor) => format!(
+                "AND {BRC20_TICK_STR} > '{}'",
+                escape_sql_string(cursor.to_lowercase())
+            ),
+            None => "".to_string(),
+        };
+
+        let query = format!(
+            "
+                SELECT * FROM brc20_balances \
+                WHERE {BRC20_OWNER_STR} = '{}' {} \
+                ORDER BY {BRC20_TICK_STR} ASC \
+                LIMIT {}
+            ",
+            owner.to_hex_literal(),
+            cursor_clause,
+            limit,
+        );
+
+        tracing::debug!("query brc20 balances: {}", query);
+        let stored_balances = self
+            .inner_indexer_reader
+            .run_query(|conn| diesel::sql_query(query).load::<StoredBRC20Balance>(conn))?;
+
+        let result = stored_balances
+            .into_iter()
+            .map(|b| b.try_into_indexer_brc20_balance())
+            .collect::<Result<Vec<_>>>()
+            .map_err(|e| {
+                IndexerError::SQLiteReadError(format!(
+                    "Cast indexer brc20 balances failed: {:?}",
+                    e
+                ))
+            })?;
+
+        Ok(result)
+    }
+
+    pub fn query_brc20_operations_with_filter(
+        &self,
+        filter: BRC20OperationFilter,
+        // exclusive cursor if `Some`, otherwise start from the beginning
+        cursor: Option<IndexerEventID>,
+        limit: usize,
+        descending_order: bool,
+    ) -> IndexerResult<Vec<IndexerBRC20Operation>> {
+        let (tx_order, event_index) = if let Some(cursor) = cursor {
+            let IndexerEventID {
+                tx_order,
+                event_index,
+            } = cursor;
+            (tx_order as i64, event_index as i64)
+        } else if descending_order {
+            (i64::MAX, 0)
+        } else {
+            (-1, 0)
+        };
+
+        let main_where_clause = match filter {
+            BRC20OperationFilter::Tick(tick) => {
+                format!(
+                    "{BRC20_TICK_STR} = '{}'",
+                    escape_sql_string(tick.to_lowercase())
+                )
+            }
+            BRC20OperationFilter::Address(address) => {
+                let address_str = address.to_hex_literal();
+                format!(
+                    "({BRC20_FROM_ADDRESS_STR} = '{}' OR {BRC20_TO_ADDRESS_STR} = '{}')",
+                    address_str, address_str
+                )
+            }
+            BRC20OperationFilter::Op(op) => {
+                format!(
+                    "{BRC20_OP_STR} = '{}'",
+                    escape_sql_string(op.to_lowercase())
+                )
+            }
+            BRC20OperationFilter::TxHash(tx_hash) => {
+                let tx_hash_str = format!("{:?}", tx_hash);
+                format!("{TX_HASH_STR} = '{}'", tx_hash_str)
+            }
+        };
+
+        let cursor_clause = if descending_order {
+            format!(
+                "AND ({TX_ORDER_STR} < {} OR ({TX_ORDER_STR} = {} AND {EVENT_INDEX_STR} < {}))",
+                tx_order, tx_order, event_index
+            )
+        } else {
+            format!(
+                "AND ({TX_ORDER_STR} > {} OR ({TX_ORDER_STR} = {} AND {EVENT_INDEX_STR} > {}))",
+                tx_order, tx_order, event_index
+            )
+        };
+        let order_clause = if descending_order {
+            format!("{TX_ORDER_STR} DESC, {EVENT_INDEX_STR} DESC")
+        } else {
+            format!("{TX_ORDER_STR} ASC, {EVENT_INDEX_STR} ASC")
+        };
+
+        let query = format!(
+            "
+                SELECT * FROM brc20_operations \
+                WHERE {} {} \
+                ORDER BY {} \
+                LIMIT {}
+            ",
+            main_where_clause, cursor_clause, order_clause, limit,
+        );
+
+        tracing::debug!("query brc20 operations: {}", query);
+        let stored_operations = self
+            .inner_indexer_reader
+            .run_query(|conn| diesel::sql_query(query).load::<StoredBRC20Operation>(conn))?;
+
+        let result = stored_operations
+            .into_iter()
+            .map(|o| o.try_into_indexer_brc20_operation())
+            .collect::<Result<Vec<_>>>()
+            .map_err(|e| {
+                IndexerError::SQLiteReadError(format!(
+                    "Cast indexer brc20 operations failed: {:?}",
+                    e
+                ))
+            })?;
+
+        Ok(result)
+    }
 }
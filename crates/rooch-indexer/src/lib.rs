@@ -11,7 +11,8 @@ use diesel::sqlite::SqliteConnection;
 use crate::store::sqlite_store::SqliteIndexerStore;
 use crate::store::traits::IndexerStoreTrait;
 use crate::types::{
-    IndexedEvent, IndexedGlobalState, IndexedTableChangeSet, IndexedTableState, IndexedTransaction,
+    IndexedBRC20Balance, IndexedBRC20Operation, IndexedBRC20Tick, IndexedEvent, IndexedGlobalState,
+    IndexedInscription, IndexedTableChangeSet, IndexedTableState, IndexedTransaction,
 };
 use crate::utils::create_all_tables_if_not_exists;
 use errors::IndexerError;
@@ -20,6 +21,7 @@ use rooch_config::indexer_config::ROOCH_INDEXER_DB_FILENAME;
 pub mod actor;
 pub mod errors;
 pub mod indexer_reader;
+pub mod metrics;
 pub mod models;
 pub mod proxy;
 pub mod schema;
@@ -136,6 +138,38 @@ impl IndexerStoreTrait for IndexerStore {
     fn persist_events(&self, events: Vec<IndexedEvent>) -> Result<(), IndexerError> {
         self.sqlite_store.persist_events(events)
     }
+
+    fn persist_brc20_operations(
+        &self,
+        operations: Vec<IndexedBRC20Operation>,
+    ) -> Result<(), IndexerError> {
+        self.sqlite_store.persist_brc20_operations(operations)
+    }
+
+    fn persist_or_update_brc20_ticks(
+        &self,
+        ticks: Vec<IndexedBRC20Tick>,
+    ) -> Result<(), IndexerError> {
+        self.sqlite_store.persist_or_update_brc20_ticks(ticks)
+    }
+
+    fn persist_or_update_brc20_balances(
+        &self,
+        balances: Vec<IndexedBRC20Balance>,
+    ) -> Result<(), IndexerError> {
+        self.sqlite_store.persist_or_update_brc20_balances(balances)
+    }
+
+    fn persist_inscriptions(
+        &self,
+        inscriptions: Vec<IndexedInscription>,
+    ) -> Result<(), IndexerError> {
+        self.sqlite_store.persist_inscriptions(inscriptions)
+    }
+
+    fn delete_inscriptions(&self, object_ids: Vec<String>) -> Result<(), IndexerError> {
+        self.sqlite_store.delete_inscriptions(object_ids)
+    }
 }
 
 pub fn new_sqlite_connection_pool_impl(
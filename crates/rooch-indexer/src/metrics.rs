@@ -0,0 +1,33 @@
+// Copyright (c) RoochNetwork
+// SPDX-License-Identifier: Apache-2.0
+
+use metrics::{register, Opts, PrometheusError, Registry, UIntGauge};
+
+#[derive(Clone)]
+pub struct IndexerMetrics {
+    pub indexer_tx_order: UIntGauge,
+    pub indexer_lag: UIntGauge,
+}
+
+impl IndexerMetrics {
+    pub fn register(registry: &Registry) -> Result<Self, PrometheusError> {
+        let indexer_tx_order = register(
+            UIntGauge::with_opts(Opts::new(
+                "indexer_tx_order",
+                "The order of the last indexed transaction",
+            ))?,
+            registry,
+        )?;
+        let indexer_lag = register(
+            UIntGauge::with_opts(Opts::new(
+                "indexer_lag",
+                "The number of transactions received by the indexer but not indexed yet",
+            ))?,
+            registry,
+        )?;
+        Ok(Self {
+            indexer_tx_order,
+            indexer_lag,
+        })
+    }
+}
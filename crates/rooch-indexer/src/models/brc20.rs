@@ -0,0 +1,195 @@
+// Copyright (c) RoochNetwork
+// SPDX-License-Identifier: Apache-2.0
+
+use crate::schema::{brc20_balances, brc20_operations, brc20_ticks};
+use crate::types::{IndexedBRC20Balance, IndexedBRC20Operation, IndexedBRC20Tick};
+use diesel::prelude::*;
+use move_core_types::account_address::AccountAddress;
+use move_core_types::u256::U256;
+use moveos_types::h256::H256;
+use rooch_types::indexer::brc20::{IndexerBRC20Balance, IndexerBRC20Operation, IndexerBRC20Tick};
+use rooch_types::indexer::event_filter::IndexerEventID;
+use std::str::FromStr;
+
+#[derive(Queryable, QueryableByName, Insertable, Debug, Clone)]
+#[diesel(table_name = brc20_ticks)]
+pub struct StoredBRC20Tick {
+    /// the tick in lower case
+    #[diesel(sql_type = diesel::sql_types::Text)]
+    pub tick: String,
+    /// the max supply, u256 in decimal string
+    #[diesel(sql_type = diesel::sql_types::Text)]
+    pub max: String,
+    /// the mint limit per inscription, u256 in decimal string
+    #[diesel(sql_type = diesel::sql_types::Text)]
+    pub lim: String,
+    #[diesel(sql_type = diesel::sql_types::BigInt)]
+    pub dec: i64,
+    /// the minted supply, u256 in decimal string
+    #[diesel(sql_type = diesel::sql_types::Text)]
+    pub supply: String,
+    /// the rooch address of the deployer
+    #[diesel(sql_type = diesel::sql_types::Text)]
+    pub deployer: String,
+    /// the tx order of the last operation which changed the tick info
+    #[diesel(sql_type = diesel::sql_types::BigInt)]
+    pub tx_order: i64,
+
+    #[diesel(sql_type = diesel::sql_types::BigInt)]
+    pub created_at: i64,
+    #[diesel(sql_type = diesel::sql_types::BigInt)]
+    pub updated_at: i64,
+}
+
+impl From<IndexedBRC20Tick> for StoredBRC20Tick {
+    fn from(tick: IndexedBRC20Tick) -> Self {
+        Self {
+            tick: tick.tick,
+            max: tick.max.to_string(),
+            lim: tick.lim.to_string(),
+            dec: tick.dec as i64,
+            supply: tick.supply.to_string(),
+            deployer: tick.deployer.to_hex_literal(),
+            tx_order: tick.tx_order as i64,
+            created_at: tick.created_at as i64,
+            updated_at: tick.updated_at as i64,
+        }
+    }
+}
+
+impl StoredBRC20Tick {
+    pub fn try_into_indexer_brc20_tick(&self) -> Result<IndexerBRC20Tick, anyhow::Error> {
+        let deployer = AccountAddress::from_hex_literal(self.deployer.as_str())?;
+
+        let tick = IndexerBRC20Tick {
+            tick: self.tick.clone(),
+            max: U256::from_str(self.max.as_str())?,
+            lim: U256::from_str(self.lim.as_str())?,
+            dec: self.dec as u64,
+            supply: U256::from_str(self.supply.as_str())?,
+            deployer,
+            tx_order: self.tx_order as u64,
+            created_at: self.created_at as u64,
+            updated_at: self.updated_at as u64,
+        };
+        Ok(tick)
+    }
+}
+
+#[derive(Queryable, QueryableByName, Insertable, Debug, Clone)]
+#[diesel(table_name = brc20_balances)]
+pub struct StoredBRC20Balance {
+    /// the tick in lower case
+    #[diesel(sql_type = diesel::sql_types::Text)]
+    pub tick: String,
+    /// the rooch address of the owner
+    #[diesel(sql_type = diesel::sql_types::Text)]
+    pub owner: String,
+    /// the balance, u256 in decimal string
+    #[diesel(sql_type = diesel::sql_types::Text)]
+    pub balance: String,
+    /// the tx order of the last operation which changed the balance
+    #[diesel(sql_type = diesel::sql_types::BigInt)]
+    pub tx_order: i64,
+
+    #[diesel(sql_type = diesel::sql_types::BigInt)]
+    pub updated_at: i64,
+}
+
+impl From<IndexedBRC20Balance> for StoredBRC20Balance {
+    fn from(balance: IndexedBRC20Balance) -> Self {
+        Self {
+            tick: balance.tick,
+            owner: balance.owner.to_hex_literal(),
+            balance: balance.balance.to_string(),
+            tx_order: balance.tx_order as i64,
+            updated_at: balance.updated_at as i64,
+        }
+    }
+}
+
+impl StoredBRC20Balance {
+    pub fn try_into_indexer_brc20_balance(&self) -> Result<IndexerBRC20Balance, anyhow::Error> {
+        let owner = AccountAddress::from_hex_literal(self.owner.as_str())?;
+
+        let balance = IndexerBRC20Balance {
+            tick: self.tick.clone(),
+            owner,
+            balance: U256::from_str(self.balance.as_str())?,
+            tx_order: self.tx_order as u64,
+            updated_at: self.updated_at as u64,
+        };
+        Ok(balance)
+    }
+}
+
+#[derive(Queryable, QueryableByName, Insertable, Debug, Clone)]
+#[diesel(table_name = brc20_operations)]
+pub struct StoredBRC20Operation {
+    /// the tx order of the transaction which emitted the op event
+    #[diesel(sql_type = diesel::sql_types::BigInt)]
+    pub tx_order: i64,
+    /// the op event index in the transaction events
+    #[diesel(sql_type = diesel::sql_types::BigInt)]
+    pub event_index: i64,
+    /// the hash of the transaction
+    #[diesel(sql_type = diesel::sql_types::Text)]
+    pub tx_hash: String,
+    /// deploy, mint or transfer
+    #[diesel(sql_type = diesel::sql_types::Text)]
+    pub op: String,
+    #[diesel(sql_type = diesel::sql_types::Text)]
+    pub tick: String,
+    /// the rooch address of the operation sender
+    #[diesel(sql_type = diesel::sql_types::Text)]
+    pub from_address: String,
+    /// the rooch address of the operation receiver
+    #[diesel(sql_type = diesel::sql_types::Text)]
+    pub to_address: String,
+    /// the inscribed amount
+    #[diesel(sql_type = diesel::sql_types::Text)]
+    pub amt: String,
+    #[diesel(sql_type = diesel::sql_types::Bool)]
+    pub success: bool,
+
+    #[diesel(sql_type = diesel::sql_types::BigInt)]
+    pub created_at: i64,
+}
+
+impl From<IndexedBRC20Operation> for StoredBRC20Operation {
+    fn from(operation: IndexedBRC20Operation) -> Self {
+        Self {
+            tx_order: operation.tx_order as i64,
+            event_index: operation.event_index as i64,
+            tx_hash: format!("{:?}", operation.tx_hash),
+            op: operation.op,
+            tick: operation.tick,
+            from_address: operation.from.to_hex_literal(),
+            to_address: operation.to.to_hex_literal(),
+            amt: operation.amt,
+            success: operation.success,
+            created_at: operation.created_at as i64,
+        }
+    }
+}
+
+impl StoredBRC20Operation {
+    pub fn try_into_indexer_brc20_operation(&self) -> Result<IndexerBRC20Operation, anyhow::Error> {
+        let tx_hash = H256::from_str(self.tx_hash.as_str())?;
+        let from = AccountAddress::from_hex_literal(self.from_address.as_str())?;
+        let to = AccountAddress::from_hex_literal(self.to_address.as_str())?;
+
+        let operation = IndexerBRC20Operation {
+            indexer_event_id: IndexerEventID::new(self.tx_order as u64, self.event_index as u64),
+            tx_hash,
+            op: self.op.clone(),
+            tick: self.tick.clone(),
+            from,
+            to,
+            amt: self.amt.clone(),
+            success: self.success,
+            created_at: self.created_at as u64,
+        };
+        Ok(operation)
+    }
+}
@@ -0,0 +1,45 @@
+// Copyright (c) RoochNetwork
+// SPDX-License-Identifier: Apache-2.0
+
+use crate::schema::inscriptions;
+use crate::types::IndexedInscription;
+use diesel::prelude::*;
+
+#[derive(Queryable, QueryableByName, Insertable, Debug, Clone)]
+#[diesel(table_name = inscriptions)]
+pub struct StoredInscription {
+    /// the object id of the inscription
+    #[diesel(sql_type = diesel::sql_types::Text)]
+    pub object_id: String,
+    /// the content type of the inscription, empty if the inscription has no content type
+    #[diesel(sql_type = diesel::sql_types::Text)]
+    pub content_type: String,
+    /// the media class of the content type
+    #[diesel(sql_type = diesel::sql_types::Text)]
+    pub media: String,
+    /// the object id of the parent inscription, empty if the inscription has no parent
+    #[diesel(sql_type = diesel::sql_types::Text)]
+    pub parent: String,
+    /// the tx order of the transaction which created the inscription
+    #[diesel(sql_type = diesel::sql_types::BigInt)]
+    pub tx_order: i64,
+
+    #[diesel(sql_type = diesel::sql_types::BigInt)]
+    pub created_at: i64,
+}
+
+impl From<IndexedInscription> for StoredInscription {
+    fn from(inscription: IndexedInscription) -> Self {
+        Self {
+            object_id: inscription.object_id.to_string(),
+            content_type: inscription.content_type.unwrap_or_default(),
+            media: inscription.media,
+            parent: inscription
+                .parent
+                .map(|parent| parent.to_string())
+                .unwrap_or_default(),
+            tx_order: inscription.tx_order as i64,
+            created_at: inscription.created_at as i64,
+        }
+    }
+}
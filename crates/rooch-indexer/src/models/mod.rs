@@ -1,6 +1,8 @@
 // Copyright (c) RoochNetwork
 // SPDX-License-Identifier: Apache-2.0
 
+pub mod brc20;
 pub mod events;
+pub mod inscriptions;
 pub mod states;
 pub mod transactions;
@@ -4,16 +4,23 @@
 use crate::actor::indexer::IndexerActor;
 use crate::actor::messages::{
     IndexerEventsMessage, IndexerStatesMessage, IndexerTransactionMessage,
-    QueryIndexerEventsMessage, QueryIndexerGlobalStatesMessage, QueryIndexerTableStatesMessage,
+    QueryIndexerBRC20BalancesMessage, QueryIndexerBRC20OperationsMessage,
+    QueryIndexerBRC20TicksMessage, QueryIndexerEventsMessage, QueryIndexerGlobalStatesMessage,
+    QueryIndexerInscriptionStatesMessage, QueryIndexerTableStatesMessage,
     QueryIndexerTransactionsMessage, SyncIndexerStatesMessage,
 };
 use crate::actor::reader_indexer::IndexerReaderActor;
 use anyhow::Result;
 use coerce::actor::ActorRef;
+use move_core_types::account_address::AccountAddress;
 use moveos_types::moveos_std::event::Event;
 use moveos_types::state::StateChangeSet;
 use moveos_types::transaction::{TransactionExecutionInfo, VerifiedMoveOSTransaction};
+use rooch_types::indexer::brc20::{
+    BRC20OperationFilter, IndexerBRC20Balance, IndexerBRC20Operation, IndexerBRC20Tick,
+};
 use rooch_types::indexer::event_filter::{EventFilter, IndexerEvent, IndexerEventID};
+use rooch_types::indexer::inscription::InscriptionFilter;
 use rooch_types::indexer::state::{
     GlobalStateFilter, IndexerGlobalState, IndexerStateID, IndexerTableChangeSet,
     IndexerTableState, StateSyncFilter, TableStateFilter,
@@ -136,6 +143,24 @@ impl IndexerProxy {
             .await?
     }
 
+    pub async fn query_inscription_states(
+        &self,
+        filter: InscriptionFilter,
+        // exclusive cursor if `Some`, otherwise start from the beginning
+        cursor: Option<IndexerStateID>,
+        limit: usize,
+        descending_order: bool,
+    ) -> Result<Vec<IndexerGlobalState>> {
+        self.reader_actor
+            .send(QueryIndexerInscriptionStatesMessage {
+                filter,
+                cursor,
+                limit,
+                descending_order,
+            })
+            .await?
+    }
+
     pub async fn query_table_states(
         &self,
         filter: TableStateFilter,
@@ -171,4 +196,54 @@ impl IndexerProxy {
             })
             .await?
     }
+
+    pub async fn query_brc20_ticks(
+        &self,
+        tick: Option<String>,
+        // exclusive cursor if `Some`, otherwise start from the beginning
+        cursor: Option<String>,
+        limit: usize,
+    ) -> Result<Vec<IndexerBRC20Tick>> {
+        self.reader_actor
+            .send(QueryIndexerBRC20TicksMessage {
+                tick,
+                cursor,
+                limit,
+            })
+            .await?
+    }
+
+    pub async fn query_brc20_balances(
+        &self,
+        owner: AccountAddress,
+        // exclusive cursor if `Some`, otherwise start from the beginning
+        cursor: Option<String>,
+        limit: usize,
+    ) -> Result<Vec<IndexerBRC20Balance>> {
+        self.reader_actor
+            .send(QueryIndexerBRC20BalancesMessage {
+                owner,
+                cursor,
+                limit,
+            })
+            .await?
+    }
+
+    pub async fn query_brc20_operations(
+        &self,
+        filter: BRC20OperationFilter,
+        // exclusive cursor if `Some`, otherwise start from the beginning
+        cursor: Option<IndexerEventID>,
+        limit: usize,
+        descending_order: bool,
+    ) -> Result<Vec<IndexerBRC20Operation>> {
+        self.reader_actor
+            .send(QueryIndexerBRC20OperationsMessage {
+                filter,
+                cursor,
+                limit,
+                descending_order,
+            })
+            .await?
+    }
 }
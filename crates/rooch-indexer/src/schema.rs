@@ -3,6 +3,45 @@
 
 // @generated automatically by Diesel CLI.
 
+diesel::table! {
+    brc20_balances (owner, tick) {
+        tick -> Text,
+        owner -> Text,
+        balance -> Text,
+        tx_order -> BigInt,
+        updated_at -> BigInt,
+    }
+}
+
+diesel::table! {
+    brc20_operations (tx_order, event_index) {
+        tx_order -> BigInt,
+        event_index -> BigInt,
+        tx_hash -> Text,
+        op -> Text,
+        tick -> Text,
+        from_address -> Text,
+        to_address -> Text,
+        amt -> Text,
+        success -> Bool,
+        created_at -> BigInt,
+    }
+}
+
+diesel::table! {
+    brc20_ticks (tick) {
+        tick -> Text,
+        max -> Text,
+        lim -> Text,
+        dec -> BigInt,
+        supply -> Text,
+        deployer -> Text,
+        tx_order -> BigInt,
+        created_at -> BigInt,
+        updated_at -> BigInt,
+    }
+}
+
 diesel::table! {
     events (event_index, tx_order) {
         event_handle_id -> Text,
@@ -33,6 +72,17 @@ diesel::table! {
     }
 }
 
+diesel::table! {
+    inscriptions (object_id) {
+        object_id -> Text,
+        content_type -> Text,
+        media -> Text,
+        parent -> Text,
+        tx_order -> BigInt,
+        created_at -> BigInt,
+    }
+}
+
 diesel::table! {
     table_change_sets (tx_order, state_index) {
         tx_order -> BigInt,
@@ -84,8 +134,12 @@ diesel::table! {
 }
 
 diesel::allow_tables_to_appear_in_same_query!(
+    brc20_balances,
+    brc20_operations,
+    brc20_ticks,
     events,
     global_states,
+    inscriptions,
     table_change_sets,
     table_states,
     transactions,
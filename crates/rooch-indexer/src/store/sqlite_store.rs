@@ -7,12 +7,18 @@ use diesel::{ExpressionMethods, RunQueryDsl};
 use tracing::log;
 
 use crate::errors::{Context, IndexerError};
+use crate::models::brc20::{StoredBRC20Balance, StoredBRC20Operation, StoredBRC20Tick};
 use crate::models::events::StoredEvent;
+use crate::models::inscriptions::StoredInscription;
 use crate::models::states::{StoredGlobalState, StoredTableChangeSet, StoredTableState};
 use crate::models::transactions::StoredTransaction;
-use crate::schema::{events, global_states, table_change_sets, table_states, transactions};
+use crate::schema::{
+    brc20_operations, events, global_states, inscriptions, table_change_sets, table_states,
+    transactions,
+};
 use crate::types::{
-    IndexedEvent, IndexedGlobalState, IndexedTableChangeSet, IndexedTableState, IndexedTransaction,
+    IndexedBRC20Balance, IndexedBRC20Operation, IndexedBRC20Tick, IndexedEvent, IndexedGlobalState,
+    IndexedInscription, IndexedTableChangeSet, IndexedTableState, IndexedTransaction,
 };
 use crate::utils::escape_sql_string;
 use crate::{get_sqlite_pool_connection, SqliteConnectionPool};
@@ -307,4 +313,204 @@ impl SqliteIndexerStore {
 
         Ok(())
     }
+
+    pub fn persist_brc20_operations(
+        &self,
+        operations: Vec<IndexedBRC20Operation>,
+    ) -> Result<(), IndexerError> {
+        if operations.is_empty() {
+            return Ok(());
+        }
+
+        let mut connection = get_sqlite_pool_connection(&self.connection_pool)?;
+        let operations = operations
+            .into_iter()
+            .map(StoredBRC20Operation::from)
+            .collect::<Vec<_>>();
+
+        diesel::insert_into(brc20_operations::table)
+            .values(operations.as_slice())
+            .execute(&mut connection)
+            .map_err(|e| IndexerError::SQLiteWriteError(e.to_string()))
+            .context("Failed to write brc20 operations to SQLiteDB")?;
+
+        Ok(())
+    }
+
+    pub fn persist_or_update_brc20_ticks(
+        &self,
+        ticks: Vec<IndexedBRC20Tick>,
+    ) -> Result<(), IndexerError> {
+        if ticks.is_empty() {
+            return Ok(());
+        }
+
+        let mut connection = get_sqlite_pool_connection(&self.connection_pool)?;
+        let ticks = ticks
+            .into_iter()
+            .map(StoredBRC20Tick::from)
+            .collect::<Vec<_>>();
+
+        // The deploy info of a tick never changes, only the supply is updated by the mint operations
+        let values_clause = ticks
+            .into_iter()
+            .map(|tick| {
+                format!(
+                    "('{}', '{}', '{}', {}, '{}', '{}', {}, {}, {})",
+                    escape_sql_string(tick.tick),
+                    escape_sql_string(tick.max),
+                    escape_sql_string(tick.lim),
+                    tick.dec,
+                    escape_sql_string(tick.supply),
+                    escape_sql_string(tick.deployer),
+                    tick.tx_order,
+                    tick.created_at,
+                    tick.updated_at,
+                )
+            })
+            .collect::<Vec<_>>()
+            .join(",");
+        let query = format!(
+            "
+                INSERT INTO brc20_ticks (tick, max, lim, dec, supply, deployer, tx_order, created_at, updated_at) \
+                VALUES {} \
+                ON CONFLICT (tick) DO UPDATE SET \
+                supply = excluded.supply, \
+                tx_order = excluded.tx_order, \
+                updated_at = excluded.updated_at
+            ",
+            values_clause
+        );
+
+        // Execute the raw SQL query
+        diesel::sql_query(query.clone())
+            .execute(&mut connection)
+            .map_err(|e| {
+                log::error!("Upsert brc20 ticks Executing Query error: {}", query);
+                IndexerError::SQLiteWriteError(e.to_string())
+            })
+            .context("Failed to write or update brc20 ticks to SQLiteDB")?;
+
+        Ok(())
+    }
+
+    pub fn persist_or_update_brc20_balances(
+        &self,
+        balances: Vec<IndexedBRC20Balance>,
+    ) -> Result<(), IndexerError> {
+        if balances.is_empty() {
+            return Ok(());
+        }
+
+        let mut connection = get_sqlite_pool_connection(&self.connection_pool)?;
+        let balances = balances
+            .into_iter()
+            .map(StoredBRC20Balance::from)
+            .collect::<Vec<_>>();
+
+        let values_clause = balances
+            .into_iter()
+            .map(|balance| {
+                format!(
+                    "('{}', '{}', '{}', {}, {})",
+                    escape_sql_string(balance.tick),
+                    escape_sql_string(balance.owner),
+                    escape_sql_string(balance.balance),
+                    balance.tx_order,
+                    balance.updated_at,
+                )
+            })
+            .collect::<Vec<_>>()
+            .join(",");
+        let query = format!(
+            "
+                INSERT INTO brc20_balances (tick, owner, balance, tx_order, updated_at) \
+                VALUES {} \
+                ON CONFLICT (owner, tick) DO UPDATE SET \
+                balance = excluded.balance, \
+                tx_order = excluded.tx_order, \
+                updated_at = excluded.updated_at
+            ",
+            values_clause
+        );
+
+        // Execute the raw SQL query
+        diesel::sql_query(query.clone())
+            .execute(&mut connection)
+            .map_err(|e| {
+                log::error!("Upsert brc20 balances Executing Query error: {}", query);
+                IndexerError::SQLiteWriteError(e.to_string())
+            })
+            .context("Failed to write or update brc20 balances to SQLiteDB")?;
+
+        Ok(())
+    }
+
+    pub fn persist_inscriptions(
+        &self,
+        inscriptions: Vec<IndexedInscription>,
+    ) -> Result<(), IndexerError> {
+        if inscriptions.is_empty() {
+            return Ok(());
+        }
+
+        let mut connection = get_sqlite_pool_connection(&self.connection_pool)?;
+        let inscriptions = inscriptions
+            .into_iter()
+            .map(StoredInscription::from)
+            .collect::<Vec<_>>();
+
+        // The content of an inscription never changes, keep the first indexed one
+        let values_clause = inscriptions
+            .into_iter()
+            .map(|inscription| {
+                format!(
+                    "('{}', '{}', '{}', '{}', {}, {})",
+                    escape_sql_string(inscription.object_id),
+                    escape_sql_string(inscription.content_type),
+                    escape_sql_string(inscription.media),
+                    escape_sql_string(inscription.parent),
+                    inscription.tx_order,
+                    inscription.created_at,
+                )
+            })
+            .collect::<Vec<_>>()
+            .join(",");
+        let query = format!(
+            "
+                INSERT INTO inscriptions (object_id, content_type, media, parent, tx_order, created_at) \
+                VALUES {} \
+                ON CONFLICT (object_id) DO NOTHING
+            ",
+            values_clause
+        );
+
+        // Execute the raw SQL query
+        diesel::sql_query(query.clone())
+            .execute(&mut connection)
+            .map_err(|e| {
+                log::error!("Insert inscriptions Executing Query error: {}", query);
+                IndexerError::SQLiteWriteError(e.to_string())
+            })
+            .context("Failed to write inscriptions to SQLiteDB")?;
+
+        Ok(())
+    }
+
+    pub fn delete_inscriptions(&self, object_ids: Vec<String>) -> Result<(), IndexerError> {
+        if object_ids.is_empty() {
+            return Ok(());
+        }
+
+        let mut connection = get_sqlite_pool_connection(&self.connection_pool)?;
+
+        diesel::delete(
+            inscriptions::table.filter(inscriptions::object_id.eq_any(object_ids.as_slice())),
+        )
+        .execute(&mut connection)
+        .map_err(|e| IndexerError::SQLiteWriteError(e.to_string()))
+        .context("Failed to delete inscriptions to SQLiteDB")?;
+
+        Ok(())
+    }
 }
@@ -3,7 +3,8 @@
 
 use crate::errors::IndexerError;
 use crate::types::{
-    IndexedEvent, IndexedGlobalState, IndexedTableChangeSet, IndexedTableState, IndexedTransaction,
+    IndexedBRC20Balance, IndexedBRC20Operation, IndexedBRC20Tick, IndexedEvent, IndexedGlobalState,
+    IndexedInscription, IndexedTableChangeSet, IndexedTableState, IndexedTransaction,
 };
 
 pub trait IndexerStoreTrait: Send + Sync {
@@ -37,4 +38,26 @@ pub trait IndexerStoreTrait: Send + Sync {
     ) -> Result<(), IndexerError>;
 
     fn persist_events(&self, events: Vec<IndexedEvent>) -> Result<(), IndexerError>;
+
+    fn persist_brc20_operations(
+        &self,
+        operations: Vec<IndexedBRC20Operation>,
+    ) -> Result<(), IndexerError>;
+
+    fn persist_or_update_brc20_ticks(
+        &self,
+        ticks: Vec<IndexedBRC20Tick>,
+    ) -> Result<(), IndexerError>;
+
+    fn persist_or_update_brc20_balances(
+        &self,
+        balances: Vec<IndexedBRC20Balance>,
+    ) -> Result<(), IndexerError>;
+
+    fn persist_inscriptions(
+        &self,
+        inscriptions: Vec<IndexedInscription>,
+    ) -> Result<(), IndexerError>;
+
+    fn delete_inscriptions(&self, object_ids: Vec<String>) -> Result<(), IndexerError>;
 }
@@ -4,13 +4,15 @@
 use crate::indexer_reader::IndexerReader;
 use crate::store::traits::IndexerStoreTrait;
 use crate::types::{
-    IndexedEvent, IndexedGlobalState, IndexedTableChangeSet, IndexedTableState, IndexedTransaction,
+    IndexedBRC20Balance, IndexedBRC20Operation, IndexedBRC20Tick, IndexedEvent, IndexedGlobalState,
+    IndexedInscription, IndexedTableChangeSet, IndexedTableState, IndexedTransaction,
 };
 use crate::utils::format_struct_tag;
 use crate::IndexerStore;
 use anyhow::Result;
 use move_core_types::account_address::AccountAddress;
 use move_core_types::language_storage::StructTag;
+use move_core_types::u256::U256;
 use move_core_types::vm_status::KeptVMStatus;
 use moveos_types::h256::H256;
 use moveos_types::move_types::{random_struct_tag, random_type_tag};
@@ -20,9 +22,12 @@ use moveos_types::state::{MoveStructType, SplitStateChangeSet};
 use moveos_types::transaction::{TransactionExecutionInfo, VerifiedMoveOSTransaction};
 use rand::{thread_rng, Rng};
 use rooch_config::indexer_config::ROOCH_INDEXER_DB_FILENAME;
+use rooch_types::bitcoin::brc20::BRC20OpEvent;
 use rooch_types::framework::coin::CoinInfo;
 use rooch_types::framework::gas_coin::GasCoin;
+use rooch_types::indexer::brc20::BRC20OperationFilter;
 use rooch_types::indexer::event_filter::EventFilter;
+use rooch_types::indexer::inscription::InscriptionFilter;
 use rooch_types::indexer::state::{GlobalStateFilter, TableStateFilter};
 use rooch_types::indexer::transaction_filter::TransactionFilter;
 use rooch_types::test_utils::{
@@ -31,6 +36,7 @@ use rooch_types::test_utils::{
 };
 use rooch_types::transaction::authenticator::Authenticator;
 use rooch_types::transaction::TransactionSequenceInfo;
+use std::collections::BTreeMap;
 use std::str::FromStr;
 
 fn random_update_global_states(states: Vec<IndexedGlobalState>) -> Vec<IndexedGlobalState> {
@@ -311,3 +317,215 @@ fn test_state_store() -> Result<()> {
 
     Ok(())
 }
+
+fn new_brc20_op_event(
+    op: &str,
+    from: AccountAddress,
+    to: AccountAddress,
+    amt: u64,
+    supply: u64,
+    from_balance: u64,
+    to_balance: u64,
+) -> BRC20OpEvent {
+    BRC20OpEvent {
+        op: op.to_string().into(),
+        tick: "ordi".to_string().into(),
+        from,
+        to,
+        amt: amt.to_string().into(),
+        success: true,
+        max: U256::from(21000000u64),
+        lim: U256::from(1000u64),
+        dec: 18,
+        supply: U256::from(supply),
+        from_balance: U256::from(from_balance),
+        to_balance: U256::from(to_balance),
+    }
+}
+
+#[test]
+fn test_brc20_store() -> Result<()> {
+    let tmpdir = moveos_config::temp_dir();
+    let indexer_db = tmpdir.path().join(ROOCH_INDEXER_DB_FILENAME);
+    if !indexer_db.exists() {
+        std::fs::File::create(indexer_db.clone())?;
+    }
+    let indexer_db_url = indexer_db
+        .as_path()
+        .to_str()
+        .ok_or(anyhow::anyhow!("Invalid mock indexer db dir"))?;
+    let indexer_store = IndexerStore::new(indexer_db_url)?;
+    indexer_store.create_all_tables_if_not_exists()?;
+    let indexer_reader = IndexerReader::new(indexer_db_url)?;
+
+    let alice = AccountAddress::random();
+    let bob = AccountAddress::random();
+    let op_events = vec![
+        new_brc20_op_event(BRC20OpEvent::OP_DEPLOY, alice, alice, 21000000, 0, 0, 0),
+        new_brc20_op_event(BRC20OpEvent::OP_MINT, alice, alice, 1000, 1000, 1000, 1000),
+        new_brc20_op_event(BRC20OpEvent::OP_TRANSFER, alice, bob, 400, 1000, 600, 400),
+    ];
+
+    for (tx_order, op_event) in op_events.into_iter().enumerate() {
+        let tx_order = tx_order as u64;
+        let operation = IndexedBRC20Operation {
+            tx_order,
+            event_index: 0,
+            tx_hash: H256::random(),
+            op: op_event.op.to_string(),
+            tick: op_event.tick.to_string(),
+            from: op_event.from,
+            to: op_event.to,
+            amt: op_event.amt.to_string(),
+            success: op_event.success,
+            created_at: 0,
+        };
+        indexer_store.persist_brc20_operations(vec![operation])?;
+        indexer_store.persist_or_update_brc20_ticks(
+            IndexedBRC20Tick::new_from_op_event(&op_event, tx_order)
+                .into_iter()
+                .collect(),
+        )?;
+        indexer_store.persist_or_update_brc20_balances(IndexedBRC20Balance::new_from_op_event(
+            &op_event, tx_order,
+        ))?;
+    }
+
+    let ticks = indexer_reader.query_brc20_ticks(Some("ORDI".to_string()), None, 10)?;
+    assert_eq!(ticks.len(), 1);
+    assert_eq!(ticks[0].supply, U256::from(1000u64));
+    assert_eq!(ticks[0].deployer, alice);
+
+    let alice_balances = indexer_reader.query_brc20_balances(alice, None, 10)?;
+    assert_eq!(alice_balances.len(), 1);
+    assert_eq!(alice_balances[0].balance, U256::from(600u64));
+    let bob_balances = indexer_reader.query_brc20_balances(bob, None, 10)?;
+    assert_eq!(bob_balances.len(), 1);
+    assert_eq!(bob_balances[0].balance, U256::from(400u64));
+
+    let filter = BRC20OperationFilter::Address(bob);
+    let operations = indexer_reader.query_brc20_operations_with_filter(filter, None, 10, true)?;
+    assert_eq!(operations.len(), 1);
+    assert_eq!(operations[0].op, BRC20OpEvent::OP_TRANSFER);
+
+    let filter = BRC20OperationFilter::Tick("ordi".to_string());
+    let operations = indexer_reader.query_brc20_operations_with_filter(filter, None, 10, false)?;
+    assert_eq!(operations.len(), 3);
+    assert_eq!(operations[0].op, BRC20OpEvent::OP_DEPLOY);
+    Ok(())
+}
+
+#[test]
+fn test_brc20_tick_deployer() -> Result<()> {
+    let tmpdir = moveos_config::temp_dir();
+    let indexer_db = tmpdir.path().join(ROOCH_INDEXER_DB_FILENAME);
+    if !indexer_db.exists() {
+        std::fs::File::create(indexer_db.clone())?;
+    }
+    let indexer_db_url = indexer_db
+        .as_path()
+        .to_str()
+        .ok_or(anyhow::anyhow!("Invalid mock indexer db dir"))?;
+    let indexer_store = IndexerStore::new(indexer_db_url)?;
+    indexer_store.create_all_tables_if_not_exists()?;
+    let indexer_reader = IndexerReader::new(indexer_db_url)?;
+
+    // The deploy and a mint by another address are in the same batch
+    let alice = AccountAddress::random();
+    let bob = AccountAddress::random();
+    let op_events = vec![
+        new_brc20_op_event(BRC20OpEvent::OP_DEPLOY, alice, alice, 21000000, 0, 0, 0),
+        new_brc20_op_event(BRC20OpEvent::OP_MINT, bob, bob, 1000, 1000, 1000, 1000),
+    ];
+    let mut ticks = BTreeMap::new();
+    for op_event in op_events.iter() {
+        if let Some(tick) = IndexedBRC20Tick::new_from_op_event(op_event, 1) {
+            ticks
+                .entry(tick.tick.clone())
+                .and_modify(|indexed_tick: &mut IndexedBRC20Tick| indexed_tick.merge(&tick))
+                .or_insert(tick);
+        }
+    }
+    indexer_store.persist_or_update_brc20_ticks(ticks.into_values().collect())?;
+
+    let ticks = indexer_reader.query_brc20_ticks(Some("ORDI".to_string()), None, 10)?;
+    assert_eq!(ticks.len(), 1);
+    assert_eq!(ticks[0].supply, U256::from(1000u64));
+    assert_eq!(ticks[0].deployer, alice);
+
+    // A later mint only changes the supply
+    let op_event = new_brc20_op_event(BRC20OpEvent::OP_MINT, bob, bob, 1000, 2000, 2000, 2000);
+    indexer_store.persist_or_update_brc20_ticks(
+        IndexedBRC20Tick::new_from_op_event(&op_event, 2)
+            .into_iter()
+            .collect(),
+    )?;
+    let ticks = indexer_reader.query_brc20_ticks(Some("ORDI".to_string()), None, 10)?;
+    assert_eq!(ticks[0].supply, U256::from(2000u64));
+    assert_eq!(ticks[0].deployer, alice);
+    Ok(())
+}
+
+#[test]
+fn test_inscription_store() -> Result<()> {
+    let tmpdir = moveos_config::temp_dir();
+    let indexer_db = tmpdir.path().join(ROOCH_INDEXER_DB_FILENAME);
+    if !indexer_db.exists() {
+        std::fs::File::create(indexer_db.clone())?;
+    }
+    let indexer_db_url = indexer_db
+        .as_path()
+        .to_str()
+        .ok_or(anyhow::anyhow!("Invalid mock indexer db dir"))?;
+    let indexer_store = IndexerStore::new(indexer_db_url)?;
+    indexer_store.create_all_tables_if_not_exists()?;
+    let indexer_reader = IndexerReader::new(indexer_db_url)?;
+
+    let new_global_states = random_new_global_states()?;
+    indexer_store.persist_or_update_global_states(new_global_states.clone())?;
+
+    let parent = new_global_states[0].object_id;
+    let inscriptions = new_global_states
+        .iter()
+        .enumerate()
+        .map(|(n, state)| {
+            let (content_type, media, parent) = if n == 0 {
+                (Some("image/png".to_string()), "image".to_string(), None)
+            } else {
+                (
+                    Some("text/plain;charset=utf-8".to_string()),
+                    "text".to_string(),
+                    Some(parent),
+                )
+            };
+            IndexedInscription {
+                object_id: state.object_id,
+                content_type,
+                media,
+                parent,
+                tx_order: state.tx_order,
+                created_at: 0,
+            }
+        })
+        .collect::<Vec<_>>();
+    indexer_store.persist_inscriptions(inscriptions)?;
+
+    let filter = InscriptionFilter::MediaClass("IMAGE".to_string());
+    let states = indexer_reader.query_inscription_states_with_filter(filter, None, 10, true)?;
+    assert_eq!(states.len(), 1);
+    assert_eq!(states[0].object_id, parent);
+
+    let filter = InscriptionFilter::ContentType("text/plain;charset=utf-8".to_string());
+    let states = indexer_reader.query_inscription_states_with_filter(filter, None, 10, false)?;
+    assert_eq!(states.len(), new_global_states.len() - 1);
+
+    let filter = InscriptionFilter::Parent(parent);
+    let states = indexer_reader.query_inscription_states_with_filter(filter, None, 10, false)?;
+    assert_eq!(states.len(), new_global_states.len() - 1);
+
+    indexer_store.delete_inscriptions(vec![parent.to_string()])?;
+    let filter = InscriptionFilter::MediaClass("image".to_string());
+    let states = indexer_reader.query_inscription_states_with_filter(filter, None, 10, true)?;
+    assert_eq!(states.len(), 0);
+    Ok(())
+}
@@ -4,14 +4,19 @@
 use anyhow::Result;
 use move_core_types::account_address::AccountAddress;
 use move_core_types::language_storage::{StructTag, TypeTag};
+use move_core_types::u256::U256;
 
+use bitcoin_move::natives::ord::media::Media;
 use moveos_types::h256::H256;
+use moveos_types::move_std::string::MoveString;
 use moveos_types::moveos_std::event::Event;
 use moveos_types::moveos_std::object::{ObjectEntity, ObjectID, RawObject};
 use moveos_types::moveos_std::raw_table::TableInfo;
-use moveos_types::state::TableChangeSet;
+use moveos_types::state::{MoveStructType, TableChangeSet};
 use moveos_types::transaction::{MoveAction, TransactionExecutionInfo, VerifiedMoveOSTransaction};
 use rooch_rpc_api::jsonrpc_types::TableChangeSetView;
+use rooch_types::bitcoin::brc20::BRC20OpEvent;
+use rooch_types::bitcoin::ord::Inscription;
 use rooch_types::multichain_id::MultiChainID;
 use rooch_types::transaction::{
     AbstractTransaction, TransactionSequenceInfo, TransactionType, TypedTransaction,
@@ -156,6 +161,121 @@ impl IndexedEvent {
     }
 }
 
+#[derive(Debug, Clone)]
+pub struct IndexedBRC20Operation {
+    // the tx order of the transaction which emits the op event
+    pub tx_order: u64,
+    // the op event index in the transaction events
+    pub event_index: u64,
+    pub tx_hash: H256,
+    // deploy, mint or transfer
+    pub op: String,
+    pub tick: String,
+    pub from: AccountAddress,
+    pub to: AccountAddress,
+    // the inscribed amount of mint and transfer, or the max supply of deploy
+    pub amt: String,
+    pub success: bool,
+    pub created_at: u64,
+}
+
+impl IndexedBRC20Operation {
+    pub fn new(event: &IndexedEvent, op_event: &BRC20OpEvent) -> Self {
+        IndexedBRC20Operation {
+            tx_order: event.tx_order,
+            event_index: event.event_index,
+            tx_hash: event.tx_hash,
+            op: op_event.op.to_string(),
+            tick: op_event.tick.to_string(),
+            from: op_event.from,
+            to: op_event.to,
+            amt: op_event.amt.to_string(),
+            success: op_event.success,
+            created_at: event.created_at,
+        }
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct IndexedBRC20Tick {
+    pub tick: String,
+    pub max: U256,
+    pub lim: U256,
+    pub dec: u64,
+    pub supply: U256,
+    pub deployer: AccountAddress,
+    // the tx order of the last operation which changes the tick info
+    pub tx_order: u64,
+    pub created_at: u64,
+    pub updated_at: u64,
+}
+
+impl IndexedBRC20Tick {
+    /// Only the successful deploy and mint operations change the tick info
+    pub fn new_from_op_event(op_event: &BRC20OpEvent, tx_order: u64) -> Option<Self> {
+        let op = op_event.op.to_string();
+        if !op_event.success || (op != BRC20OpEvent::OP_DEPLOY && op != BRC20OpEvent::OP_MINT) {
+            return None;
+        }
+        Some(IndexedBRC20Tick {
+            tick: op_event.tick.to_string(),
+            max: op_event.max,
+            lim: op_event.lim,
+            dec: op_event.dec,
+            supply: op_event.supply,
+            deployer: op_event.from,
+            tx_order,
+
+            //TODO record transaction timestamp
+            created_at: 0,
+            updated_at: 0,
+        })
+    }
+
+    /// Merge a later state of the tick, the deploy info is kept and the supply is updated.
+    pub fn merge(&mut self, later: &IndexedBRC20Tick) {
+        self.supply = later.supply;
+        self.tx_order = later.tx_order;
+        self.updated_at = later.updated_at;
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct IndexedBRC20Balance {
+    pub tick: String,
+    pub owner: AccountAddress,
+    pub balance: U256,
+    // the tx order of the last operation which changes the balance
+    pub tx_order: u64,
+    pub updated_at: u64,
+}
+
+impl IndexedBRC20Balance {
+    /// The successful mint and transfer operations change the balances of `from` and `to`
+    pub fn new_from_op_event(op_event: &BRC20OpEvent, tx_order: u64) -> Vec<Self> {
+        if !op_event.success || op_event.op.to_string() == BRC20OpEvent::OP_DEPLOY {
+            return vec![];
+        }
+        let new_balance = |owner: AccountAddress, balance: U256| IndexedBRC20Balance {
+            tick: op_event.tick.to_string(),
+            owner,
+            balance,
+            tx_order,
+
+            //TODO record transaction timestamp
+            updated_at: 0,
+        };
+        if op_event.from == op_event.to {
+            vec![new_balance(op_event.to, op_event.to_balance)]
+        } else {
+            vec![
+                new_balance(op_event.from, op_event.from_balance),
+                new_balance(op_event.to, op_event.to_balance),
+            ]
+        }
+    }
+}
+
 #[derive(Debug, Clone)]
 pub struct IndexedGlobalState {
     // The global state key
@@ -239,6 +359,43 @@ impl IndexedGlobalState {
     }
 }
 
+#[derive(Debug, Clone)]
+pub struct IndexedInscription {
+    // The object id of the inscription
+    pub object_id: ObjectID,
+    pub content_type: Option<String>,
+    // The media class of the content type
+    pub media: String,
+    // The object id of the parent inscription
+    pub parent: Option<ObjectID>,
+    // The tx order of the transaction which creates the inscription
+    pub tx_order: u64,
+    pub created_at: u64,
+}
+
+impl IndexedInscription {
+    /// Index the content fields of the inscription object, return None if the object is not an inscription
+    pub fn try_new_from_raw_object(raw_object: &RawObject, tx_order: u64) -> Result<Option<Self>> {
+        if raw_object.value.struct_tag != Inscription::struct_tag() {
+            return Ok(None);
+        }
+        let inscription = bcs::from_bytes::<Inscription>(&raw_object.value.value)?;
+        let content_type =
+            Option::<MoveString>::from(inscription.content_type).map(|v| v.to_string());
+        let media = Media::from_content_type(content_type.as_deref()).to_string();
+        Ok(Some(IndexedInscription {
+            object_id: raw_object.id,
+            content_type,
+            media,
+            parent: inscription.parent.into(),
+            tx_order,
+
+            //TODO record transaction timestamp
+            created_at: 0,
+        }))
+    }
+}
+
 #[derive(Debug, Clone)]
 pub struct IndexedTableState {
     // The state table handle
@@ -25,10 +25,11 @@ use moveos::moveos_test_runner::{CompiledState, MoveOSTestAdapter, TaskInput};
 use moveos_store::MoveOSStore;
 use moveos_types::{
     addresses::MOVEOS_STD_ADDRESS,
+    gas_profile::GasProfile,
     move_types::FunctionId,
     moveos_std::object::ObjectID,
     state_resolver::AnnotatedStateReader,
-    transaction::{MoveAction, MoveOSTransaction, TransactionOutput},
+    transaction::{MoveAction, MoveOSTransaction, TransactionOutput, VerifiedMoveOSTransaction},
 };
 use moveos_verifier::build::build_model;
 use moveos_verifier::metadata::run_extended_checks;
@@ -52,7 +53,12 @@ pub struct MoveOSTestRunner<'a> {
 pub struct MoveOSPublishArgs {}
 
 #[derive(Parser, Debug)]
-pub struct MoveOSRunArgs {}
+pub struct MoveOSRunArgs {
+    /// Profile the gas usage of the transaction, and print the gas used by each call stack
+    /// in the folded stack format.
+    #[clap(long = "profile-gas")]
+    pub profile_gas: bool,
+}
 
 #[derive(Parser, Debug)]
 pub struct MoveOSExtraInitArgs {}
@@ -174,7 +180,7 @@ impl<'a> MoveOSTestAdapter<'a> for MoveOSTestRunner<'a> {
         let tx = MoveOSTransaction::new_for_test(sender, action);
         let verified_tx = self.moveos.verify(tx)?;
         let (_state_root, output) = self.moveos.execute_and_apply(verified_tx)?;
-        Ok((Some(tx_output_to_str(output)), module))
+        Ok((Some(tx_output_to_str(output, None)), module))
     }
 
     fn execute_script(
@@ -186,7 +192,7 @@ impl<'a> MoveOSTestAdapter<'a> for MoveOSTestRunner<'a> {
             <<Self as MoveOSTestAdapter<'a>>::ExtraValueArgs as ParsableValue>::ConcreteValue,
         >,
         _gas_budget: Option<u64>,
-        _extra: Self::ExtraRunArgs,
+        extra: Self::ExtraRunArgs,
     ) -> anyhow::Result<(
         Option<String>,
         move_vm_runtime::session::SerializedReturnValues,
@@ -209,13 +215,13 @@ impl<'a> MoveOSTestAdapter<'a> for MoveOSTestRunner<'a> {
             MoveAction::new_script_call(script_bytes, type_args, args),
         );
         let verified_tx = self.moveos.verify(tx)?;
-        let (_state_root, output) = self.moveos.execute_and_apply(verified_tx)?;
+        let (output, gas_profile) = self.execute_and_apply(verified_tx, extra.profile_gas)?;
         //TODO return values
         let value = SerializedReturnValues {
             mutable_reference_outputs: vec![],
             return_values: vec![],
         };
-        Ok((Some(tx_output_to_str(output)), value))
+        Ok((Some(tx_output_to_str(output, gas_profile)), value))
     }
 
     fn call_function(
@@ -228,7 +234,7 @@ impl<'a> MoveOSTestAdapter<'a> for MoveOSTestRunner<'a> {
             <<Self as MoveOSTestAdapter<'a>>::ExtraValueArgs as ParsableValue>::ConcreteValue,
         >,
         _gas_budget: Option<u64>,
-        _extra: Self::ExtraRunArgs,
+        extra: Self::ExtraRunArgs,
     ) -> anyhow::Result<(
         Option<String>,
         move_vm_runtime::session::SerializedReturnValues,
@@ -248,7 +254,7 @@ impl<'a> MoveOSTestAdapter<'a> for MoveOSTestRunner<'a> {
             MoveAction::new_function_call(function_id, type_args, args),
         );
         let verified_tx = self.moveos.verify(tx)?;
-        let (_state_root, output) = self.moveos.execute_and_apply(verified_tx)?;
+        let (output, gas_profile) = self.execute_and_apply(verified_tx, extra.profile_gas)?;
         debug_assert!(
             output.status == move_core_types::vm_status::KeptVMStatus::Executed,
             "{:?}",
@@ -260,7 +266,7 @@ impl<'a> MoveOSTestAdapter<'a> for MoveOSTestRunner<'a> {
             return_values: vec![],
         };
 
-        Ok((Some(tx_output_to_str(output)), value))
+        Ok((Some(tx_output_to_str(output, gas_profile)), value))
     }
 
     fn view_data(
@@ -371,9 +377,34 @@ pub fn run_integration_test_with_extended_check(
     )
 }
 
-fn tx_output_to_str(output: TransactionOutput) -> String {
+impl<'a> MoveOSTestRunner<'a> {
+    fn execute_and_apply(
+        &mut self,
+        verified_tx: VerifiedMoveOSTransaction,
+        profile_gas: bool,
+    ) -> anyhow::Result<(TransactionOutput, Option<GasProfile>)> {
+        if profile_gas {
+            let (_state_root, output, gas_profile) = self
+                .moveos
+                .execute_and_apply_with_gas_profiler(verified_tx)?;
+            Ok((output, Some(gas_profile)))
+        } else {
+            let (_state_root, output) = self.moveos.execute_and_apply(verified_tx)?;
+            Ok((output, None))
+        }
+    }
+}
+
+fn tx_output_to_str(output: TransactionOutput, gas_profile: Option<GasProfile>) -> String {
     //TODO introduce output view, and print json output
-    output.status.to_string()
+    match gas_profile {
+        Some(gas_profile) => format!(
+            "{}\n{}",
+            output.status,
+            gas_profile.to_folded_stacks().trim_end()
+        ),
+        None => output.status.to_string(),
+    }
 }
 
 pub fn resolve_package_named_addresses(root_path: PathBuf) -> BTreeMap<String, NumericalAddress> {
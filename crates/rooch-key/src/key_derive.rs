@@ -8,12 +8,12 @@ use bip32::DerivationPath;
 use bip39::{Language, Mnemonic, MnemonicType, Seed};
 use chacha20poly1305::aead::Aead;
 use chacha20poly1305::{AeadCore, ChaCha20Poly1305, KeyInit};
-use fastcrypto::ed25519::{Ed25519KeyPair, Ed25519PrivateKey};
+use fastcrypto::ed25519::{Ed25519KeyPair, Ed25519PrivateKey, ED25519_PRIVATE_KEY_LENGTH};
 use fastcrypto::encoding::{Base64, Encoding};
 use fastcrypto::traits::{KeyPair, ToFromBytes};
 use rand::rngs::OsRng;
 use rooch_types::address::RoochAddress;
-use rooch_types::crypto::RoochKeyPair;
+use rooch_types::crypto::{EncodeDecodeBase64, RoochKeyPair};
 use rooch_types::error::RoochError;
 use rooch_types::key_struct::{EncryptionData, GenerateNewKeyPair, GeneratedKeyPair};
 use rooch_types::multichain_id::RoochMultiChainID;
@@ -140,10 +140,11 @@ pub fn derive_address_from_private_key(private_key: Vec<u8>) -> Result<RoochAddr
     Ok(address)
 }
 
-pub fn retrieve_key_pair(
+/// Decrypt the raw bytes of the encryption data, the bytes can be a private key or a mnemonic phrase
+pub fn decrypt_encryption_data(
     encryption: &EncryptionData,
     password: Option<String>,
-) -> Result<RoochKeyPair, RoochError> {
+) -> Result<Vec<u8>, RoochError> {
     let nonce = Base64::decode(&encryption.nonce)
         .map_err(|e| RoochError::KeyConversionError(e.to_string()))?;
     let ciphertext = Base64::decode(&encryption.ciphertext)
@@ -151,7 +152,14 @@ pub fn retrieve_key_pair(
     let tag = Base64::decode(&encryption.tag)
         .map_err(|e| RoochError::KeyConversionError(e.to_string()))?;
 
-    let private_key = decrypt_key(&nonce, &ciphertext, &tag, password)?;
+    decrypt_key(&nonce, &ciphertext, &tag, password)
+}
+
+pub fn retrieve_key_pair(
+    encryption: &EncryptionData,
+    password: Option<String>,
+) -> Result<RoochKeyPair, RoochError> {
+    let private_key = decrypt_encryption_data(encryption, password)?;
 
     let kp = Ed25519KeyPair::from(
         Ed25519PrivateKey::from_bytes(&private_key)
@@ -258,6 +266,30 @@ fn parse_word_length(s: Option<String>) -> Result<MnemonicType, anyhow::Error> {
     }
 }
 
+/// Parse a private key from a hex encoded raw ed25519 private key (with or without `0x` prefix),
+/// or from a Base64 encoded `flag || privkey` which is exported by `rooch account export`
+pub fn parse_private_key(private_key: &str) -> Result<RoochKeyPair, RoochError> {
+    let private_key = private_key.trim();
+    let hex_key = private_key.strip_prefix("0x").unwrap_or(private_key);
+    // The Base64 `flag || privkey` is never a valid 32 bytes hex string
+    match hex::decode(hex_key) {
+        Ok(bytes) if bytes.len() == ED25519_PRIVATE_KEY_LENGTH => {
+            let sk = Ed25519PrivateKey::from_bytes(&bytes)
+                .map_err(|e| RoochError::KeyConversionError(e.to_string()))?;
+            Ok(Ed25519KeyPair::from(sk).into())
+        }
+        _ => <RoochKeyPair as EncodeDecodeBase64>::decode_base64(private_key)
+            .map_err(|e| RoochError::KeyConversionError(e.to_string())),
+    }
+}
+
+/// Get the raw private key bytes of the keypair
+pub fn private_key_bytes(key_pair: &RoochKeyPair) -> Vec<u8> {
+    match key_pair {
+        RoochKeyPair::Ed25519(kp) => kp.as_bytes().to_vec(),
+    }
+}
+
 /// Get a keypair from a random encryption data
 pub fn get_key_pair_from_red() -> (RoochAddress, EncryptionData) {
     let random_encryption_data = EncryptionData::new_for_test();
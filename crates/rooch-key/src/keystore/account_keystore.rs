@@ -1,10 +1,10 @@
 // Copyright (c) RoochNetwork
 // SPDX-License-Identifier: Apache-2.0
 
-use super::types::LocalAccount;
+use super::types::{LocalAccount, LocalExternalSigner};
 use crate::key_derive::{
     derive_address_from_private_key, derive_private_key_from_path, encrypt_key,
-    generate_derivation_path, generate_new_key_pair, hash_password,
+    generate_derivation_path, generate_new_key_pair, hash_password, private_key_bytes,
 };
 use crate::keystore::ImportedMnemonic;
 use bip32::DerivationPath;
@@ -30,6 +30,13 @@ pub trait AccountKeystore {
         address: RoochAddress,
         encryption: EncryptionData,
     ) -> Result<(), anyhow::Error>;
+    /// Register an address whose private key is held by an external signer
+    fn add_external_signer(
+        &mut self,
+        address: RoochAddress,
+        external_signer: LocalExternalSigner,
+    ) -> Result<(), anyhow::Error>;
+
     fn get_address_public_keys(
         &self,
         password: Option<String>,
@@ -190,6 +197,31 @@ pub trait AccountKeystore {
         Ok(encryption_data)
     }
 
+    /// Import a raw private key, the key is encrypted with the keystore password
+    fn import_private_key(
+        &mut self,
+        key_pair: &RoochKeyPair,
+        password: Option<String>,
+    ) -> Result<RoochAddress, anyhow::Error> {
+        let address: RoochAddress = (&key_pair.public()).into();
+        if self.addresses().contains(&address) {
+            return Err(anyhow::Error::new(RoochError::ImportAccountError(format!(
+                "Address [{address}] already exists in the keystore"
+            ))));
+        }
+        let encryption = encrypt_key(&private_key_bytes(key_pair), password)?;
+        self.add_address_encryption_data(address, encryption)?;
+        Ok(address)
+    }
+
+    /// Re-encrypt all the keys, session keys and mnemonics with the new password.
+    /// The keystore is left unchanged if any of the keys can not be decrypted by the old password.
+    fn change_password(
+        &mut self,
+        old_password: Option<String>,
+        new_password: Option<String>,
+    ) -> Result<(), anyhow::Error>;
+
     fn nullify_address(&mut self, address: &RoochAddress) -> Result<(), anyhow::Error> {
         self.nullify(address)?;
         Ok(())
@@ -208,6 +240,13 @@ pub trait AccountKeystore {
         session_key: SessionKey,
     ) -> Result<(), anyhow::Error>;
 
+    /// Remove the LocalSessionKey of the address by the authentication key
+    fn remove_session_key(
+        &mut self,
+        address: &RoochAddress,
+        authentication_key: &AuthenticationKey,
+    ) -> Result<(), anyhow::Error>;
+
     fn sign_transaction_via_session_key(
         &self,
         address: &RoochAddress,
@@ -3,17 +3,23 @@
 
 use std::collections::BTreeMap;
 
-use super::types::{AddressMapping, LocalAccount, LocalSessionKey};
-use crate::key_derive::{decrypt_key, generate_new_key_pair, retrieve_key_pair};
+use super::types::{AddressMapping, LocalAccount, LocalExternalSigner, LocalSessionKey};
+use crate::key_derive::{
+    decrypt_encryption_data, decrypt_key, encrypt_key, generate_new_key_pair, hash_password,
+    retrieve_key_pair, verify_password,
+};
 use crate::keystore::account_keystore::AccountKeystore;
 use anyhow::anyhow;
+use chacha20poly1305::{AeadCore, ChaCha20Poly1305};
 use fastcrypto::encoding::{Base64, Encoding};
+use fastcrypto::hash::HashFunction;
+use rand::rngs::OsRng;
 use rooch_types::framework::session_key::SessionKey;
 use rooch_types::key_struct::{MnemonicData, MnemonicResult};
 use rooch_types::{
     address::RoochAddress,
     authentication_key::AuthenticationKey,
-    crypto::{PublicKey, RoochKeyPair, Signature},
+    crypto::{DefaultHash, PublicKey, RoochKeyPair, Signature},
     error::RoochError,
     key_struct::EncryptionData,
     transaction::{
@@ -40,6 +46,8 @@ pub(crate) struct BaseKeyStore {
     pub(crate) is_password_empty: bool,
     #[serde(default)]
     pub(crate) address_mapping: AddressMapping,
+    #[serde(default)]
+    pub(crate) external_signers: BTreeMap<RoochAddress, LocalExternalSigner>,
 }
 
 impl BaseKeyStore {
@@ -51,6 +59,7 @@ impl BaseKeyStore {
             password_hash: None,
             is_password_empty: true,
             address_mapping: AddressMapping::default(),
+            external_signers: BTreeMap::new(),
         }
     }
 }
@@ -75,6 +84,21 @@ impl AccountKeystore for BaseKeyStore {
             };
             accounts.insert(*address, local_account);
         }
+        for (address, external_signer) in &self.external_signers {
+            let multichain_address = self
+                .address_mapping
+                .rooch_to_multichain
+                .get(address)
+                .cloned();
+            let has_session_key = self.session_keys.get(address).is_some();
+            let local_account = LocalAccount {
+                address: *address,
+                multichain_address,
+                public_key: Some(external_signer.public_key.clone()),
+                has_session_key,
+            };
+            accounts.insert(*address, local_account);
+        }
         for address in self.session_keys.keys() {
             if accounts.contains_key(address) {
                 continue;
@@ -118,6 +142,9 @@ impl AccountKeystore for BaseKeyStore {
         msg: &[u8],
         password: Option<String>,
     ) -> Result<Signature, anyhow::Error> {
+        if let Some(external_signer) = self.external_signers.get(address) {
+            return external_signer.sign_hashed(msg);
+        }
         Ok(Signature::new_hashed(
             msg,
             &self.get_key_pair_with_password(address, password)?,
@@ -133,6 +160,11 @@ impl AccountKeystore for BaseKeyStore {
     where
         T: Serialize,
     {
+        if let Some(external_signer) = self.external_signers.get(address) {
+            let mut hasher = DefaultHash::default();
+            hasher.update(&bcs::to_bytes(msg)?);
+            return external_signer.sign_hashed(&hasher.finalize().digest);
+        }
         Ok(Signature::new_secure(
             msg,
             &self.get_key_pair_with_password(address, password)?,
@@ -145,6 +177,12 @@ impl AccountKeystore for BaseKeyStore {
         msg: RoochTransactionData,
         password: Option<String>,
     ) -> Result<RoochTransaction, anyhow::Error> {
+        if let Some(external_signer) = self.external_signers.get(address) {
+            let signature = external_signer.sign_hashed(msg.hash().as_bytes())?;
+            let auth = authenticator::Authenticator::rooch(signature);
+            return Ok(RoochTransaction::new(msg, auth));
+        }
+
         let kp = self
             .get_key_pair_with_password(address, password)
             .ok()
@@ -168,6 +206,15 @@ impl AccountKeystore for BaseKeyStore {
         Ok(())
     }
 
+    fn add_external_signer(
+        &mut self,
+        address: RoochAddress,
+        external_signer: LocalExternalSigner,
+    ) -> Result<(), anyhow::Error> {
+        self.external_signers.insert(address, external_signer);
+        Ok(())
+    }
+
     fn get_public_key(&self, password: Option<String>) -> Result<PublicKey, anyhow::Error> {
         self.keys
             .values()
@@ -210,12 +257,77 @@ impl AccountKeystore for BaseKeyStore {
         address: &RoochAddress,
         encryption: EncryptionData,
     ) -> Result<(), anyhow::Error> {
-        self.keys.entry(*address).or_insert(encryption);
+        self.keys.insert(*address, encryption);
+        Ok(())
+    }
+
+    fn change_password(
+        &mut self,
+        old_password: Option<String>,
+        new_password: Option<String>,
+    ) -> Result<(), anyhow::Error> {
+        if let Some(password_hash) = &self.password_hash {
+            if !verify_password(old_password.clone(), password_hash.clone())? {
+                return Err(anyhow::Error::new(RoochError::InvalidPasswordError(
+                    "Password is invalid".to_owned(),
+                )));
+            }
+        }
+
+        // Re-encrypt everything before touching the keystore, so the change is all or nothing
+        let reencrypt = |encryption: &EncryptionData| -> Result<EncryptionData, RoochError> {
+            let data = decrypt_encryption_data(encryption, old_password.clone())?;
+            encrypt_key(&data, new_password.clone())
+        };
+        let keys = self
+            .keys
+            .iter()
+            .map(|(address, encryption)| Ok((*address, reencrypt(encryption)?)))
+            .collect::<Result<BTreeMap<_, _>, RoochError>>()?;
+        let mnemonics = self
+            .mnemonics
+            .iter()
+            .map(|(mnemonic_phrase_key, mnemonic_data)| {
+                let mnemonic_data = MnemonicData {
+                    addresses: mnemonic_data.addresses.clone(),
+                    mnemonic_phrase_encryption: reencrypt(
+                        &mnemonic_data.mnemonic_phrase_encryption,
+                    )?,
+                };
+                Ok((mnemonic_phrase_key.clone(), mnemonic_data))
+            })
+            .collect::<Result<BTreeMap<_, _>, RoochError>>()?;
+        let session_keys = self
+            .session_keys
+            .iter()
+            .map(|(address, inner_map)| {
+                let inner_map = inner_map
+                    .iter()
+                    .map(|(authentication_key, local_session_key)| {
+                        let local_session_key = LocalSessionKey {
+                            session_key: local_session_key.session_key.clone(),
+                            private_key: reencrypt(&local_session_key.private_key)?,
+                        };
+                        Ok((authentication_key.clone(), local_session_key))
+                    })
+                    .collect::<Result<BTreeMap<_, _>, RoochError>>()?;
+                Ok((*address, inner_map))
+            })
+            .collect::<Result<BTreeMap<_, _>, RoochError>>()?;
+        let nonce = ChaCha20Poly1305::generate_nonce(&mut OsRng);
+        let password_hash = hash_password(&nonce, new_password.clone())?;
+
+        self.keys = keys;
+        self.mnemonics = mnemonics;
+        self.session_keys = session_keys;
+        self.password_hash = Some(password_hash);
+        self.is_password_empty = new_password.is_none();
         Ok(())
     }
 
     fn nullify(&mut self, address: &RoochAddress) -> Result<(), anyhow::Error> {
         self.keys.remove(address);
+        self.external_signers.remove(address);
         Ok(())
     }
 
@@ -253,6 +365,20 @@ impl AccountKeystore for BaseKeyStore {
         Ok(())
     }
 
+    fn remove_session_key(
+        &mut self,
+        address: &RoochAddress,
+        authentication_key: &AuthenticationKey,
+    ) -> Result<(), anyhow::Error> {
+        if let Some(inner_map) = self.session_keys.get_mut(address) {
+            inner_map.remove(authentication_key);
+            if inner_map.is_empty() {
+                self.session_keys.remove(address);
+            }
+        }
+        Ok(())
+    }
+
     fn sign_transaction_via_session_key(
         &self,
         address: &RoochAddress,
@@ -301,6 +427,10 @@ impl AccountKeystore for BaseKeyStore {
             addresses.push(*key);
         }
 
+        for key in self.external_signers.keys() {
+            addresses.push(*key);
+        }
+
         for key in self.session_keys.keys() {
             addresses.push(*key);
         }
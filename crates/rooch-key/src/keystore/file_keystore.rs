@@ -1,7 +1,7 @@
 // Copyright (c) RoochNetwork
 // SPDX-License-Identifier: Apache-2.0
 
-use super::types::LocalAccount;
+use super::types::{LocalAccount, LocalExternalSigner};
 use crate::key_derive::retrieve_key_pair;
 use crate::keystore::account_keystore::AccountKeystore;
 use crate::keystore::base_keystore::BaseKeyStore;
@@ -43,6 +43,17 @@ impl AccountKeystore for FileBasedKeystore {
         Ok(())
     }
 
+    fn add_external_signer(
+        &mut self,
+        address: RoochAddress,
+        external_signer: LocalExternalSigner,
+    ) -> Result<(), anyhow::Error> {
+        self.keystore
+            .add_external_signer(address, external_signer)?;
+        self.save()?;
+        Ok(())
+    }
+
     fn get_address_public_keys(
         &self,
         password: Option<String>,
@@ -81,6 +92,16 @@ impl AccountKeystore for FileBasedKeystore {
         Ok(())
     }
 
+    fn change_password(
+        &mut self,
+        old_password: Option<String>,
+        new_password: Option<String>,
+    ) -> Result<(), anyhow::Error> {
+        self.keystore.change_password(old_password, new_password)?;
+        self.save()?;
+        Ok(())
+    }
+
     fn nullify(&mut self, address: &RoochAddress) -> Result<(), anyhow::Error> {
         self.keystore.nullify(address)?;
         self.save()?;
@@ -126,6 +147,10 @@ impl AccountKeystore for FileBasedKeystore {
             addresses.push(*key);
         }
 
+        for key in self.keystore.external_signers.keys() {
+            addresses.push(*key);
+        }
+
         for key in self.keystore.session_keys.keys() {
             addresses.push(*key);
         }
@@ -153,6 +178,17 @@ impl AccountKeystore for FileBasedKeystore {
         Ok(())
     }
 
+    fn remove_session_key(
+        &mut self,
+        address: &RoochAddress,
+        authentication_key: &AuthenticationKey,
+    ) -> Result<(), anyhow::Error> {
+        self.keystore
+            .remove_session_key(address, authentication_key)?;
+        self.save()?;
+        Ok(())
+    }
+
     fn sign_transaction_via_session_key(
         &self,
         address: &RoochAddress,
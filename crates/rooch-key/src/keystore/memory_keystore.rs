@@ -1,7 +1,7 @@
 // Copyright (c) RoochNetwork
 // SPDX-License-Identifier: Apache-2.0
 
-use super::types::LocalAccount;
+use super::types::{LocalAccount, LocalExternalSigner};
 use crate::key_derive::get_key_pair_from_red;
 use crate::keystore::account_keystore::AccountKeystore;
 use crate::keystore::base_keystore::BaseKeyStore;
@@ -35,6 +35,14 @@ impl AccountKeystore for InMemKeystore {
             .add_address_encryption_data(address, encryption)
     }
 
+    fn add_external_signer(
+        &mut self,
+        address: RoochAddress,
+        external_signer: LocalExternalSigner,
+    ) -> Result<(), anyhow::Error> {
+        self.keystore.add_external_signer(address, external_signer)
+    }
+
     fn get_address_public_keys(
         &self,
         password: Option<String>,
@@ -71,6 +79,14 @@ impl AccountKeystore for InMemKeystore {
             .update_address_encryption_data(address, encryption)
     }
 
+    fn change_password(
+        &mut self,
+        old_password: Option<String>,
+        new_password: Option<String>,
+    ) -> Result<(), anyhow::Error> {
+        self.keystore.change_password(old_password, new_password)
+    }
+
     fn nullify(&mut self, address: &RoochAddress) -> Result<(), anyhow::Error> {
         self.keystore.nullify(address)
     }
@@ -114,6 +130,10 @@ impl AccountKeystore for InMemKeystore {
             addresses.push(*key);
         }
 
+        for key in self.keystore.external_signers.keys() {
+            addresses.push(*key);
+        }
+
         for key in self.keystore.session_keys.keys() {
             addresses.push(*key);
         }
@@ -137,6 +157,15 @@ impl AccountKeystore for InMemKeystore {
         self.keystore.binding_session_key(address, session_key)
     }
 
+    fn remove_session_key(
+        &mut self,
+        address: &RoochAddress,
+        authentication_key: &AuthenticationKey,
+    ) -> Result<(), anyhow::Error> {
+        self.keystore
+            .remove_session_key(address, authentication_key)
+    }
+
     fn sign_transaction_via_session_key(
         &self,
         address: &RoochAddress,
@@ -204,3 +233,114 @@ impl InMemKeystore {
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::key_derive::{
+        generate_new_key_pair, hash_password, parse_private_key, private_key_bytes,
+        retrieve_key_pair, verify_password,
+    };
+    use fastcrypto::encoding::{Base64, Encoding};
+    use rooch_types::crypto::EncodeDecodeBase64;
+
+    fn random_key_pair() -> RoochKeyPair {
+        let result = generate_new_key_pair(None, None, None, None).unwrap();
+        retrieve_key_pair(&result.key_pair_data.private_key_encryption, None).unwrap()
+    }
+
+    fn keystore_with_password(password: &str) -> (InMemKeystore, RoochAddress) {
+        let mut keystore = InMemKeystore::new_insecure_for_tests(0);
+        let password = Some(password.to_owned());
+        let result = keystore
+            .generate_and_add_new_key(None, None, None, password.clone())
+            .unwrap();
+        let password_hash = hash_password(
+            &Base64::decode(&result.key_pair_data.private_key_encryption.nonce).unwrap(),
+            password.clone(),
+        )
+        .unwrap();
+        keystore
+            .set_password_hash_with_indicator(password_hash, false)
+            .unwrap();
+        keystore
+            .generate_session_key(&result.address, password)
+            .unwrap();
+        (keystore, result.address)
+    }
+
+    #[test]
+    fn test_export_and_import_private_key() {
+        let key_pair = random_key_pair();
+        let hex_key = hex::encode(private_key_bytes(&key_pair));
+        let base64_key = key_pair.encode_base64();
+        for exported in [
+            hex_key.clone(),
+            format!("0x{}", hex_key),
+            base64_key.clone(),
+        ] {
+            assert_eq!(
+                parse_private_key(&exported).unwrap().encode_base64(),
+                base64_key
+            );
+        }
+        assert!(parse_private_key("0x1234").is_err());
+
+        let mut keystore = InMemKeystore::new_insecure_for_tests(0);
+        let password = Some("password".to_owned());
+        let address = keystore
+            .import_private_key(&key_pair, password.clone())
+            .unwrap();
+        assert_eq!(address, (&key_pair.public()).into());
+        let imported = keystore
+            .get_key_pair_with_password(&address, password.clone())
+            .unwrap();
+        assert_eq!(imported.encode_base64(), base64_key);
+        assert!(keystore
+            .get_key_pair_with_password(&address, Some("wrong".to_owned()))
+            .is_err());
+        // import the same key twice is rejected
+        assert!(keystore.import_private_key(&key_pair, password).is_err());
+    }
+
+    #[test]
+    fn test_change_password() {
+        let old_password = Some("old".to_owned());
+        let new_password = Some("new".to_owned());
+        let (mut keystore, address) = keystore_with_password("old");
+
+        // a wrong old password leaves the keystore unchanged
+        assert!(keystore
+            .change_password(Some("wrong".to_owned()), new_password.clone())
+            .is_err());
+        assert!(keystore
+            .get_key_pair_with_password(&address, old_password.clone())
+            .is_ok());
+
+        keystore
+            .change_password(old_password.clone(), new_password.clone())
+            .unwrap();
+        assert!(!keystore.get_if_password_is_empty());
+        assert!(verify_password(new_password.clone(), keystore.get_password_hash()).unwrap());
+        assert!(!verify_password(old_password.clone(), keystore.get_password_hash()).unwrap());
+        assert!(keystore
+            .get_key_pair_with_password(&address, old_password.clone())
+            .is_err());
+        assert!(keystore
+            .get_key_pair_with_password(&address, new_password.clone())
+            .is_ok());
+        assert!(keystore.get_mnemonics(old_password).is_err());
+        let mnemonics = keystore.get_mnemonics(new_password.clone()).unwrap();
+        assert_eq!(mnemonics[0].mnemonic_data.addresses, vec![address]);
+        for local_session_key in keystore.keystore.session_keys[&address].values() {
+            assert!(
+                retrieve_key_pair(&local_session_key.private_key, new_password.clone()).is_ok()
+            );
+        }
+
+        // change to an empty password
+        keystore.change_password(new_password, None).unwrap();
+        assert!(keystore.get_if_password_is_empty());
+        assert!(keystore.get_key_pair_with_password(&address, None).is_ok());
+    }
+}
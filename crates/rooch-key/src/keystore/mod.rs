@@ -86,6 +86,21 @@ impl AccountKeystore for Keystore {
         }
     }
 
+    fn add_external_signer(
+        &mut self,
+        address: RoochAddress,
+        external_signer: types::LocalExternalSigner,
+    ) -> Result<(), anyhow::Error> {
+        match self {
+            Keystore::File(file_keystore) => {
+                file_keystore.add_external_signer(address, external_signer)
+            }
+            Keystore::InMem(inmem_keystore) => {
+                inmem_keystore.add_external_signer(address, external_signer)
+            }
+        }
+    }
+
     fn get_address_public_keys(
         &self,
         password: Option<String>,
@@ -149,6 +164,21 @@ impl AccountKeystore for Keystore {
         }
     }
 
+    fn change_password(
+        &mut self,
+        old_password: Option<String>,
+        new_password: Option<String>,
+    ) -> Result<(), anyhow::Error> {
+        match self {
+            Keystore::File(file_keystore) => {
+                file_keystore.change_password(old_password, new_password)
+            }
+            Keystore::InMem(inmem_keystore) => {
+                inmem_keystore.change_password(old_password, new_password)
+            }
+        }
+    }
+
     fn nullify(&mut self, address: &RoochAddress) -> Result<(), anyhow::Error> {
         // Implement this method to nullify the key pair by coin ID for the appropriate variant (File or InMem)
         match self {
@@ -230,6 +260,21 @@ impl AccountKeystore for Keystore {
         }
     }
 
+    fn remove_session_key(
+        &mut self,
+        address: &RoochAddress,
+        authentication_key: &AuthenticationKey,
+    ) -> Result<(), anyhow::Error> {
+        match self {
+            Keystore::File(file_keystore) => {
+                file_keystore.remove_session_key(address, authentication_key)
+            }
+            Keystore::InMem(inmem_keystore) => {
+                inmem_keystore.remove_session_key(address, authentication_key)
+            }
+        }
+    }
+
     fn addresses(&self) -> Vec<RoochAddress> {
         match self {
             Keystore::File(file_keystore) => file_keystore.addresses(),
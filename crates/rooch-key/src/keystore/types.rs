@@ -1,9 +1,10 @@
 // Copyright (c) RoochNetwork
 // SPDX-License-Identifier: Apache-2.0
 
+use crate::signer::{sign_hashed_with_signer, ExternalSignerConfig};
 use rooch_types::{
     address::{MultiChainAddress, RoochAddress},
-    crypto::PublicKey,
+    crypto::{PublicKey, Signature},
     framework::session_key::SessionKey,
     key_struct::EncryptionData,
 };
@@ -16,6 +17,20 @@ pub struct LocalSessionKey {
     pub private_key: EncryptionData,
 }
 
+/// An address whose private key is held by an external signer
+#[derive(Clone, Serialize, Deserialize, Debug)]
+pub struct LocalExternalSigner {
+    pub public_key: PublicKey,
+    pub config: ExternalSignerConfig,
+}
+
+impl LocalExternalSigner {
+    pub fn sign_hashed(&self, msg: &[u8]) -> Result<Signature, anyhow::Error> {
+        let signer = self.config.build()?;
+        sign_hashed_with_signer(signer.as_ref(), &self.public_key, msg)
+    }
+}
+
 #[derive(Clone, Serialize, Deserialize, Debug)]
 pub struct LocalAccount {
     pub address: RoochAddress,
@@ -0,0 +1,278 @@
+// Copyright (c) RoochNetwork
+// SPDX-License-Identifier: Apache-2.0
+
+use crate::key_derive::retrieve_key_pair;
+use anyhow::{anyhow, ensure};
+use rooch_types::{
+    crypto::{PublicKey, RoochKeyPair, Signature},
+    key_struct::EncryptionData,
+};
+use serde::{Deserialize, Serialize};
+use serde_with::hex::Hex;
+use serde_with::serde_as;
+use std::fmt::Debug;
+use std::io::{BufRead, BufReader, Write};
+use std::path::PathBuf;
+
+/// A signer backend which holds the private key of an address outside of the keystore.
+/// The keystore delegates `sign_hashed`/`sign_transaction` of the address to the signer,
+/// so the private key never needs to be decryptable on the local machine.
+pub trait Signer: Send + Sync + Debug {
+    /// The public key of the address managed by the signer
+    fn public_key(&self) -> Result<PublicKey, anyhow::Error>;
+
+    /// Sign the message which is already hashed
+    fn sign_hashed(&self, msg: &[u8]) -> Result<Signature, anyhow::Error>;
+}
+
+/// The persisted config of an external signer in the keystore
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(tag = "type", rename_all = "snake_case")]
+pub enum ExternalSignerConfig {
+    /// A remote signer process listening on a local Unix socket
+    UnixSocket { path: PathBuf },
+}
+
+impl ExternalSignerConfig {
+    pub fn build(&self) -> Result<Box<dyn Signer>, anyhow::Error> {
+        match self {
+            ExternalSignerConfig::UnixSocket { path } => {
+                Ok(Box::new(UnixSocketSigner::new(path.clone())))
+            }
+        }
+    }
+}
+
+/// The request of the remote signer protocol, one json object per line
+#[serde_as]
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(tag = "method", rename_all = "snake_case")]
+pub enum SignerRequest {
+    PublicKey,
+    SignHashed {
+        #[serde_as(as = "Hex")]
+        msg: Vec<u8>,
+    },
+}
+
+/// The response of the remote signer protocol, one json object per line
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum SignerResponse {
+    PublicKey(PublicKey),
+    Signature(Signature),
+    Error(String),
+}
+
+/// Handle a signer request with the given signer, it is used to implement the remote side of the protocol
+pub fn handle_signer_request(signer: &dyn Signer, request: SignerRequest) -> SignerResponse {
+    let result = match request {
+        SignerRequest::PublicKey => signer.public_key().map(SignerResponse::PublicKey),
+        SignerRequest::SignHashed { msg } => {
+            signer.sign_hashed(&msg).map(SignerResponse::Signature)
+        }
+    };
+    result.unwrap_or_else(|e| SignerResponse::Error(e.to_string()))
+}
+
+/// A signer which talks to a remote signer process over a local Unix socket.
+/// The remote process can be a PKCS#11 bridge or any program which speaks the line based json protocol,
+/// see `SignerRequest` and `SignerResponse`.
+#[derive(Clone, Debug)]
+pub struct UnixSocketSigner {
+    path: PathBuf,
+}
+
+impl UnixSocketSigner {
+    pub fn new(path: PathBuf) -> Self {
+        Self { path }
+    }
+
+    #[cfg(unix)]
+    fn request(&self, request: &SignerRequest) -> Result<SignerResponse, anyhow::Error> {
+        let mut stream = std::os::unix::net::UnixStream::connect(&self.path).map_err(|e| {
+            anyhow!(
+                "Can't connect to the external signer at {:?}: {}",
+                self.path,
+                e
+            )
+        })?;
+        let mut line = serde_json::to_string(request)?;
+        line.push('\n');
+        stream.write_all(line.as_bytes())?;
+        stream.flush()?;
+
+        let mut response = String::new();
+        BufReader::new(stream).read_line(&mut response)?;
+        match serde_json::from_str::<SignerResponse>(&response)? {
+            SignerResponse::Error(e) => Err(anyhow!("External signer error: {}", e)),
+            response => Ok(response),
+        }
+    }
+
+    #[cfg(not(unix))]
+    fn request(&self, _request: &SignerRequest) -> Result<SignerResponse, anyhow::Error> {
+        Err(anyhow!("Unix socket signer is only supported on unix"))
+    }
+}
+
+impl Signer for UnixSocketSigner {
+    fn public_key(&self) -> Result<PublicKey, anyhow::Error> {
+        match self.request(&SignerRequest::PublicKey)? {
+            SignerResponse::PublicKey(public_key) => Ok(public_key),
+            response => Err(anyhow!(
+                "Unexpected external signer response: {:?}",
+                response
+            )),
+        }
+    }
+
+    fn sign_hashed(&self, msg: &[u8]) -> Result<Signature, anyhow::Error> {
+        match self.request(&SignerRequest::SignHashed { msg: msg.to_vec() })? {
+            SignerResponse::Signature(signature) => Ok(signature),
+            response => Err(anyhow!(
+                "Unexpected external signer response: {:?}",
+                response
+            )),
+        }
+    }
+}
+
+/// Serve the remote signer protocol on the Unix socket listener with the given signer
+#[cfg(unix)]
+pub fn serve_unix_socket(
+    listener: std::os::unix::net::UnixListener,
+    signer: &dyn Signer,
+) -> Result<(), anyhow::Error> {
+    for stream in listener.incoming() {
+        let mut stream = stream?;
+        let mut reader = BufReader::new(stream.try_clone()?);
+        let mut line = String::new();
+        while reader.read_line(&mut line)? > 0 {
+            let response = match serde_json::from_str::<SignerRequest>(&line) {
+                Ok(request) => handle_signer_request(signer, request),
+                Err(e) => SignerResponse::Error(e.to_string()),
+            };
+            let mut output = serde_json::to_string(&response)?;
+            output.push('\n');
+            stream.write_all(output.as_bytes())?;
+            line.clear();
+        }
+    }
+    Ok(())
+}
+
+/// A signer which holds the key pair in memory, it is only used for tests
+#[derive(Debug)]
+pub struct MockSigner {
+    key_pair: RoochKeyPair,
+}
+
+impl MockSigner {
+    pub fn new(key_pair: RoochKeyPair) -> Self {
+        Self { key_pair }
+    }
+
+    pub fn new_random() -> Self {
+        let key_pair = retrieve_key_pair(&EncryptionData::new_for_test(), None)
+            .expect("retrieve key pair from random encryption data should success");
+        Self::new(key_pair)
+    }
+}
+
+impl Signer for MockSigner {
+    fn public_key(&self) -> Result<PublicKey, anyhow::Error> {
+        Ok(self.key_pair.public())
+    }
+
+    fn sign_hashed(&self, msg: &[u8]) -> Result<Signature, anyhow::Error> {
+        Ok(Signature::new_hashed(msg, &self.key_pair))
+    }
+}
+
+/// Sign the hashed message via the signer, and check the signature is signed by the expected public key
+pub fn sign_hashed_with_signer(
+    signer: &dyn Signer,
+    public_key: &PublicKey,
+    msg: &[u8],
+) -> Result<Signature, anyhow::Error> {
+    let signature = signer.sign_hashed(msg)?;
+    ensure!(
+        &signature.to_public_key()? == public_key,
+        "The signature of the external signer does not match the public key {:?}",
+        public_key
+    );
+    Ok(signature)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::keystore::account_keystore::AccountKeystore;
+    use crate::keystore::memory_keystore::InMemKeystore;
+    use crate::keystore::types::LocalExternalSigner;
+
+    #[test]
+    fn test_signer_protocol_roundtrip() {
+        let request = SignerRequest::SignHashed { msg: vec![1, 2, 3] };
+        let json = serde_json::to_string(&request).unwrap();
+        assert_eq!(json, r#"{"method":"sign_hashed","msg":"010203"}"#);
+        assert_eq!(
+            serde_json::from_str::<SignerRequest>(&json).unwrap(),
+            request
+        );
+
+        let signer = MockSigner::new_random();
+        let response = handle_signer_request(&signer, request);
+        let json = serde_json::to_string(&response).unwrap();
+        assert_eq!(
+            serde_json::from_str::<SignerResponse>(&json).unwrap(),
+            response
+        );
+    }
+
+    #[test]
+    fn test_sign_hashed_with_signer_check_public_key() {
+        let signer = MockSigner::new_random();
+        let public_key = signer.public_key().unwrap();
+        let signature = sign_hashed_with_signer(&signer, &public_key, b"hashed").unwrap();
+        assert_eq!(signature.to_public_key().unwrap(), public_key);
+
+        let other_public_key = MockSigner::new_random().public_key().unwrap();
+        assert!(sign_hashed_with_signer(&signer, &other_public_key, b"hashed").is_err());
+    }
+
+    #[cfg(unix)]
+    #[test]
+    fn test_unix_socket_signer() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("signer.sock");
+        let listener = std::os::unix::net::UnixListener::bind(&path).unwrap();
+        let mock_signer = MockSigner::new_random();
+        let expect_public_key = mock_signer.public_key().unwrap();
+        let expect_signature = mock_signer.sign_hashed(b"hashed").unwrap();
+        std::thread::spawn(move || serve_unix_socket(listener, &mock_signer));
+
+        let signer = UnixSocketSigner::new(path.clone());
+        assert_eq!(signer.public_key().unwrap(), expect_public_key);
+        assert_eq!(signer.sign_hashed(b"hashed").unwrap(), expect_signature);
+
+        // The keystore delegates the signing of the external address to the signer
+        let mut keystore = InMemKeystore::new_insecure_for_tests(0);
+        let address = expect_public_key.address();
+        keystore
+            .add_external_signer(
+                address,
+                LocalExternalSigner {
+                    public_key: expect_public_key,
+                    config: ExternalSignerConfig::UnixSocket { path },
+                },
+            )
+            .unwrap();
+        assert!(keystore.addresses().contains(&address));
+        assert_eq!(
+            keystore.sign_hashed(&address, b"hashed", None).unwrap(),
+            expect_signature
+        );
+    }
+}
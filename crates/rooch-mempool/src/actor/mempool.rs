@@ -0,0 +1,109 @@
+// Copyright (c) RoochNetwork
+// SPDX-License-Identifier: Apache-2.0
+
+use crate::errors::MempoolError;
+use crate::messages::{
+    AddTransactionMessage, EvictExpiredMessage, GetMempoolStatusMessage,
+    GetPendingTransactionsMessage, PopReadyTransactionMessage, ReadyTransaction, SyncSenderMessage,
+};
+use crate::pool::TransactionPool;
+use async_trait::async_trait;
+use coerce::actor::{context::ActorContext, message::Handler, Actor};
+use moveos_types::h256::H256;
+use rooch_config::mempool_config::MempoolConfig;
+use rooch_types::mempool::{MempoolStatus, PendingTransaction};
+use std::time::Instant;
+use tracing::debug;
+
+pub struct MempoolActor {
+    pool: TransactionPool,
+}
+
+impl MempoolActor {
+    pub fn new(config: MempoolConfig) -> Self {
+        Self {
+            pool: TransactionPool::new(config),
+        }
+    }
+}
+
+impl Actor for MempoolActor {}
+
+#[async_trait]
+impl Handler<AddTransactionMessage> for MempoolActor {
+    async fn handle(
+        &mut self,
+        msg: AddTransactionMessage,
+        _ctx: &mut ActorContext,
+    ) -> Result<Vec<H256>, MempoolError> {
+        let removed = self.pool.add(
+            msg.tx,
+            msg.tx_hash,
+            msg.sender,
+            msg.sequence_number,
+            msg.tx_size,
+            msg.account_sequence_number,
+            Instant::now(),
+        )?;
+        debug!(
+            "Mempool add tx {:?}, sender: {}, sequence_number: {}, removed: {:?}",
+            msg.tx_hash, msg.sender, msg.sequence_number, removed
+        );
+        Ok(removed)
+    }
+}
+
+#[async_trait]
+impl Handler<PopReadyTransactionMessage> for MempoolActor {
+    async fn handle(
+        &mut self,
+        _msg: PopReadyTransactionMessage,
+        _ctx: &mut ActorContext,
+    ) -> Option<ReadyTransaction> {
+        self.pool
+            .pop_ready(Instant::now())
+            .map(|tx| ReadyTransaction {
+                tx: tx.tx,
+                tx_hash: tx.tx_hash,
+                sender: tx.sender,
+                sequence_number: tx.sequence_number,
+            })
+    }
+}
+
+#[async_trait]
+impl Handler<SyncSenderMessage> for MempoolActor {
+    async fn handle(&mut self, msg: SyncSenderMessage, _ctx: &mut ActorContext) -> Vec<H256> {
+        self.pool
+            .sync_sender(msg.sender, msg.account_sequence_number)
+    }
+}
+
+#[async_trait]
+impl Handler<EvictExpiredMessage> for MempoolActor {
+    async fn handle(&mut self, _msg: EvictExpiredMessage, _ctx: &mut ActorContext) -> Vec<H256> {
+        self.pool.evict_expired(Instant::now())
+    }
+}
+
+#[async_trait]
+impl Handler<GetPendingTransactionsMessage> for MempoolActor {
+    async fn handle(
+        &mut self,
+        msg: GetPendingTransactionsMessage,
+        _ctx: &mut ActorContext,
+    ) -> Vec<PendingTransaction> {
+        self.pool.pending(msg.sender, Instant::now())
+    }
+}
+
+#[async_trait]
+impl Handler<GetMempoolStatusMessage> for MempoolActor {
+    async fn handle(
+        &mut self,
+        _msg: GetMempoolStatusMessage,
+        _ctx: &mut ActorContext,
+    ) -> MempoolStatus {
+        self.pool.status(Instant::now())
+    }
+}
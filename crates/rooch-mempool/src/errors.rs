@@ -0,0 +1,27 @@
+// Copyright (c) RoochNetwork
+// SPDX-License-Identifier: Apache-2.0
+
+use thiserror::Error;
+
+#[derive(Debug, Clone, Eq, PartialEq, Error)]
+pub enum MempoolError {
+    #[error("Transaction already exists in mempool")]
+    AlreadyExists,
+
+    #[error("Transaction sequence number is too old, expected at least {expected}, got {actual}")]
+    SequenceNumberTooOld { expected: u64, actual: u64 },
+
+    #[error("Transaction sequence number is too new, expected at most {max}, got {actual}")]
+    SequenceNumberTooNew { max: u64, actual: u64 },
+
+    #[error(
+        "Replacement transaction underpriced, gas price must be at least {required}, got {actual}"
+    )]
+    ReplacementUnderpriced { required: u64, actual: u64 },
+
+    #[error("Sender has too many pending transactions in mempool, limit: {0}")]
+    SenderQueueFull(usize),
+
+    #[error("Mempool is full and the transaction gas price is too low to evict others")]
+    MempoolFull,
+}
@@ -0,0 +1,8 @@
+// Copyright (c) RoochNetwork
+// SPDX-License-Identifier: Apache-2.0
+
+pub mod actor;
+pub mod errors;
+pub mod messages;
+pub mod pool;
+pub mod proxy;
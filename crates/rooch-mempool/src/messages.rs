@@ -0,0 +1,81 @@
+// Copyright (c) RoochNetwork
+// SPDX-License-Identifier: Apache-2.0
+
+use crate::errors::MempoolError;
+use coerce::actor::message::Message;
+use move_core_types::account_address::AccountAddress;
+use moveos_types::h256::H256;
+use rooch_types::mempool::{MempoolStatus, PendingTransaction};
+use rooch_types::transaction::TypedTransaction;
+use serde::{Deserialize, Serialize};
+
+/// Add a validated transaction to the mempool
+#[derive(Debug, Serialize, Deserialize)]
+pub struct AddTransactionMessage {
+    pub tx: TypedTransaction,
+    pub tx_hash: H256,
+    /// The resolved sender of the transaction
+    pub sender: AccountAddress,
+    pub sequence_number: u64,
+    pub tx_size: u64,
+    /// The current on-chain sequence number of the sender
+    pub account_sequence_number: u64,
+}
+
+impl Message for AddTransactionMessage {
+    /// The hashes of the transactions removed from the mempool to make room for the new one
+    type Result = Result<Vec<H256>, MempoolError>;
+}
+
+/// A ready transaction popped from the mempool
+#[derive(Debug, Serialize, Deserialize)]
+pub struct ReadyTransaction {
+    pub tx: TypedTransaction,
+    pub tx_hash: H256,
+    pub sender: AccountAddress,
+    pub sequence_number: u64,
+}
+
+/// Pop the next ready transaction from the mempool
+#[derive(Debug, Serialize, Deserialize)]
+pub struct PopReadyTransactionMessage {}
+
+impl Message for PopReadyTransactionMessage {
+    type Result = Option<ReadyTransaction>;
+}
+
+/// Sync the sender's sequence number after its transaction is executed or rejected
+#[derive(Debug, Serialize, Deserialize)]
+pub struct SyncSenderMessage {
+    pub sender: AccountAddress,
+    pub account_sequence_number: u64,
+}
+
+impl Message for SyncSenderMessage {
+    /// The hashes of the stale transactions dropped from the mempool
+    type Result = Vec<H256>;
+}
+
+/// Evict the expired transactions
+#[derive(Debug, Serialize, Deserialize)]
+pub struct EvictExpiredMessage {}
+
+impl Message for EvictExpiredMessage {
+    type Result = Vec<H256>;
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct GetPendingTransactionsMessage {
+    pub sender: Option<AccountAddress>,
+}
+
+impl Message for GetPendingTransactionsMessage {
+    type Result = Vec<PendingTransaction>;
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct GetMempoolStatusMessage {}
+
+impl Message for GetMempoolStatusMessage {
+    type Result = MempoolStatus;
+}
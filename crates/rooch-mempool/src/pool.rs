@@ -0,0 +1,589 @@
+// Copyright (c) RoochNetwork
+// SPDX-License-Identifier: Apache-2.0
+
+use crate::errors::MempoolError;
+use ethers::types::U256;
+use move_core_types::account_address::AccountAddress;
+use moveos_types::h256::H256;
+use rooch_config::mempool_config::MempoolConfig;
+use rooch_types::mempool::{MempoolStatus, PendingTransaction};
+use rooch_types::transaction::TypedTransaction;
+use std::collections::{BTreeMap, HashMap};
+use std::time::{Duration, Instant};
+
+/// Rooch transactions do not carry a gas price yet, they all share the default one.
+pub const DEFAULT_GAS_PRICE: u64 = 1;
+
+#[derive(Debug, Clone)]
+pub struct PoolTransaction {
+    pub tx: TypedTransaction,
+    pub tx_hash: H256,
+    pub sender: AccountAddress,
+    pub sequence_number: u64,
+    pub gas_price: u64,
+    pub tx_size: u64,
+    pub inserted_at: Instant,
+    /// A monotonic counter to keep the arrival order between transactions with the same gas price.
+    insertion_order: u64,
+}
+
+#[derive(Debug, Default)]
+struct SenderQueue {
+    /// The next sequence number the sender is expected to send.
+    next_sequence_number: u64,
+    txs: BTreeMap<u64, PoolTransaction>,
+    last_active: Option<Instant>,
+}
+
+impl SenderQueue {
+    fn first_ready(&self, now: Instant, gap_timeout: Duration) -> Option<&PoolTransaction> {
+        self.txs.values().next().filter(|tx| {
+            tx.sequence_number == self.next_sequence_number
+                || now.saturating_duration_since(tx.inserted_at) >= gap_timeout
+        })
+    }
+}
+
+/// TransactionPool keeps the validated transactions before they are sequenced.
+/// The transactions of a sender are released in sequence number order,
+/// and the ready transactions of different senders are released by gas price.
+#[derive(Debug)]
+pub struct TransactionPool {
+    config: MempoolConfig,
+    senders: HashMap<AccountAddress, SenderQueue>,
+    hashes: HashMap<H256, (AccountAddress, u64)>,
+    total_bytes: u64,
+    next_insertion_order: u64,
+}
+
+impl TransactionPool {
+    pub fn new(config: MempoolConfig) -> Self {
+        Self {
+            config,
+            senders: HashMap::new(),
+            hashes: HashMap::new(),
+            total_bytes: 0,
+            next_insertion_order: 0,
+        }
+    }
+
+    pub fn config(&self) -> &MempoolConfig {
+        &self.config
+    }
+
+    pub fn len(&self) -> usize {
+        self.hashes.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.hashes.is_empty()
+    }
+
+    pub fn contains(&self, tx_hash: &H256) -> bool {
+        self.hashes.contains_key(tx_hash)
+    }
+
+    /// Add a validated transaction to the pool.
+    /// `account_sequence_number` is the current on-chain sequence number of the sender.
+    /// Returns the hashes of the transactions removed from the pool to make room for it,
+    /// including the transaction it replaces.
+    #[allow(clippy::too_many_arguments)]
+    pub fn add(
+        &mut self,
+        tx: TypedTransaction,
+        tx_hash: H256,
+        sender: AccountAddress,
+        sequence_number: u64,
+        tx_size: u64,
+        account_sequence_number: u64,
+        now: Instant,
+    ) -> Result<Vec<H256>, MempoolError> {
+        if self.hashes.contains_key(&tx_hash) {
+            return Err(MempoolError::AlreadyExists);
+        }
+        let gas_price = gas_price_of(&tx);
+        let max_count_per_sender = self.config.max_count_per_sender;
+        let price_bump_percent = self.config.price_bump_percent;
+
+        let queue = self.senders.entry(sender).or_default();
+        if account_sequence_number > queue.next_sequence_number {
+            queue.next_sequence_number = account_sequence_number;
+        }
+        if sequence_number < queue.next_sequence_number {
+            return Err(MempoolError::SequenceNumberTooOld {
+                expected: queue.next_sequence_number,
+                actual: sequence_number,
+            });
+        }
+        let max_sequence_number = queue.next_sequence_number + max_count_per_sender as u64 - 1;
+        if sequence_number > max_sequence_number {
+            return Err(MempoolError::SequenceNumberTooNew {
+                max: max_sequence_number,
+                actual: sequence_number,
+            });
+        }
+
+        let replaced = match queue.txs.get(&sequence_number) {
+            Some(existing) => {
+                let required = existing
+                    .gas_price
+                    .saturating_mul(100 + price_bump_percent)
+                    .saturating_add(99)
+                    / 100;
+                let required = required.max(existing.gas_price.saturating_add(1));
+                if gas_price < required {
+                    return Err(MempoolError::ReplacementUnderpriced {
+                        required,
+                        actual: gas_price,
+                    });
+                }
+                Some(existing.tx_size)
+            }
+            None => {
+                if queue.txs.len() >= max_count_per_sender {
+                    return Err(MempoolError::SenderQueueFull(max_count_per_sender));
+                }
+                None
+            }
+        };
+
+        // The replaced transaction makes room for the new one, evict others only for the rest.
+        let (replaced_count, replaced_bytes) = replaced.map_or((0, 0), |size| (1, size));
+        let mut count = self.hashes.len() + 1 - replaced_count;
+        let mut bytes = self.total_bytes + tx_size - replaced_bytes;
+        let mut evicted = vec![];
+        let mut evicted_per_sender = HashMap::new();
+        while count > self.config.max_count || bytes > self.config.max_bytes {
+            let candidate = self
+                .eviction_candidate(&sender, gas_price, &evicted_per_sender)
+                .ok_or(MempoolError::MempoolFull)?;
+            *evicted_per_sender.entry(candidate.sender).or_insert(0) += 1;
+            count -= 1;
+            bytes -= candidate.tx_size;
+            evicted.push((candidate.sender, candidate.sequence_number));
+        }
+        let mut removed = vec![];
+        if replaced.is_some() {
+            removed.extend(self.remove(&sender, sequence_number).map(|tx| tx.tx_hash));
+        }
+        for (evict_sender, evict_sequence_number) in evicted {
+            removed.extend(
+                self.remove(&evict_sender, evict_sequence_number)
+                    .map(|tx| tx.tx_hash),
+            );
+        }
+
+        let insertion_order = self.next_insertion_order;
+        self.next_insertion_order += 1;
+        let queue = self.senders.entry(sender).or_default();
+        queue.last_active = Some(now);
+        queue.txs.insert(
+            sequence_number,
+            PoolTransaction {
+                tx,
+                tx_hash,
+                sender,
+                sequence_number,
+                gas_price,
+                tx_size,
+                inserted_at: now,
+                insertion_order,
+            },
+        );
+        self.hashes.insert(tx_hash, (sender, sequence_number));
+        self.total_bytes += tx_size;
+        Ok(removed)
+    }
+
+    /// Pop the ready transaction with the highest gas price, the earliest one wins on a tie.
+    /// A transaction is ready when it is the next expected one of its sender,
+    /// or when it has waited longer than `gap_timeout` for the missing ones.
+    pub fn pop_ready(&mut self, now: Instant) -> Option<PoolTransaction> {
+        let gap_timeout = self.config.gap_timeout();
+        let (sender, sequence_number) = self
+            .senders
+            .values()
+            .filter_map(|queue| queue.first_ready(now, gap_timeout))
+            .max_by(|a, b| {
+                a.gas_price
+                    .cmp(&b.gas_price)
+                    .then(b.insertion_order.cmp(&a.insertion_order))
+            })
+            .map(|tx| (tx.sender, tx.sequence_number))?;
+        let tx = self.remove(&sender, sequence_number)?;
+        if let Some(queue) = self.senders.get_mut(&sender) {
+            queue.next_sequence_number = sequence_number + 1;
+            queue.last_active = Some(now);
+        }
+        Some(tx)
+    }
+
+    /// Sync the sender's expected sequence number with the on-chain one after a transaction is
+    /// executed or rejected, and drop the pending transactions that can never be executed.
+    pub fn sync_sender(
+        &mut self,
+        sender: AccountAddress,
+        account_sequence_number: u64,
+    ) -> Vec<H256> {
+        let stale = match self.senders.get_mut(&sender) {
+            Some(queue) => {
+                queue.next_sequence_number = account_sequence_number;
+                queue
+                    .txs
+                    .range(..account_sequence_number)
+                    .map(|(sequence_number, _)| *sequence_number)
+                    .collect::<Vec<_>>()
+            }
+            None => return vec![],
+        };
+        stale
+            .into_iter()
+            .filter_map(|sequence_number| self.remove(&sender, sequence_number))
+            .map(|tx| tx.tx_hash)
+            .collect()
+    }
+
+    /// Evict the transactions that have stayed in the pool longer than the ttl,
+    /// and forget the idle senders.
+    pub fn evict_expired(&mut self, now: Instant) -> Vec<H256> {
+        let ttl = self.config.ttl();
+        let expired = self
+            .senders
+            .values()
+            .flat_map(|queue| queue.txs.values())
+            .filter(|tx| now.saturating_duration_since(tx.inserted_at) >= ttl)
+            .map(|tx| (tx.sender, tx.sequence_number))
+            .collect::<Vec<_>>();
+        let evicted = expired
+            .into_iter()
+            .filter_map(|(sender, sequence_number)| self.remove(&sender, sequence_number))
+            .map(|tx| tx.tx_hash)
+            .collect();
+        self.senders.retain(|_, queue| {
+            !queue.txs.is_empty()
+                || queue
+                    .last_active
+                    .map(|last_active| now.saturating_duration_since(last_active) < ttl)
+                    .unwrap_or(false)
+        });
+        evicted
+    }
+
+    /// List the pending transactions, ordered by sender and sequence number.
+    pub fn pending(&self, sender: Option<AccountAddress>, now: Instant) -> Vec<PendingTransaction> {
+        let gap_timeout = self.config.gap_timeout();
+        let mut senders = self
+            .senders
+            .iter()
+            .filter(|(address, _)| sender.map(|s| s == **address).unwrap_or(true))
+            .collect::<Vec<_>>();
+        senders.sort_by_key(|(address, _)| **address);
+        senders
+            .into_iter()
+            .flat_map(|(_, queue)| {
+                let ready_hash = queue.first_ready(now, gap_timeout).map(|tx| tx.tx_hash);
+                queue.txs.values().map(move |tx| PendingTransaction {
+                    tx_hash: tx.tx_hash,
+                    sender: tx.sender,
+                    sequence_number: tx.sequence_number,
+                    gas_price: tx.gas_price,
+                    tx_size: tx.tx_size,
+                    ready: ready_hash == Some(tx.tx_hash),
+                    waiting_millis: now.saturating_duration_since(tx.inserted_at).as_millis()
+                        as u64,
+                })
+            })
+            .collect()
+    }
+
+    pub fn status(&self, now: Instant) -> MempoolStatus {
+        let gap_timeout = self.config.gap_timeout();
+        let ready_count = self
+            .senders
+            .values()
+            .filter(|queue| queue.first_ready(now, gap_timeout).is_some())
+            .count();
+        MempoolStatus {
+            pending_count: self.hashes.len() as u64,
+            ready_count: ready_count as u64,
+            sender_count: self
+                .senders
+                .values()
+                .filter(|queue| !queue.txs.is_empty())
+                .count() as u64,
+            total_bytes: self.total_bytes,
+            max_count: self.config.max_count as u64,
+            max_bytes: self.config.max_bytes,
+        }
+    }
+
+    /// Pick the lowest priced tail transaction of the other senders that is cheaper than `gas_price`.
+    /// Only the tail of a sender queue is evicted, so the remaining transactions keep no gap.
+    /// `evicted_per_sender` counts the tail transactions already picked from each sender.
+    fn eviction_candidate(
+        &self,
+        incoming_sender: &AccountAddress,
+        gas_price: u64,
+        evicted_per_sender: &HashMap<AccountAddress, usize>,
+    ) -> Option<&PoolTransaction> {
+        self.senders
+            .iter()
+            .filter(|(sender, _)| *sender != incoming_sender)
+            .filter_map(|(sender, queue)| {
+                let skip = evicted_per_sender.get(sender).copied().unwrap_or(0);
+                queue.txs.values().rev().nth(skip)
+            })
+            .filter(|tx| tx.gas_price < gas_price)
+            .min_by(|a, b| {
+                a.gas_price
+                    .cmp(&b.gas_price)
+                    .then(b.insertion_order.cmp(&a.insertion_order))
+            })
+    }
+
+    fn remove(&mut self, sender: &AccountAddress, sequence_number: u64) -> Option<PoolTransaction> {
+        let tx = self.senders.get_mut(sender)?.txs.remove(&sequence_number)?;
+        self.hashes.remove(&tx.tx_hash);
+        self.total_bytes -= tx.tx_size;
+        Some(tx)
+    }
+}
+
+/// Derive the gas price of a transaction for ordering in the mempool.
+pub fn gas_price_of(tx: &TypedTransaction) -> u64 {
+    match tx {
+        TypedTransaction::Rooch(_) | TypedTransaction::RoochSponsored(_) => DEFAULT_GAS_PRICE,
+        TypedTransaction::Ethereum(tx) => {
+            tx.0.gas_price
+                .or(tx.0.max_fee_per_gas)
+                .map(|price| price.min(U256::from(u64::MAX)).as_u64())
+                .unwrap_or(DEFAULT_GAS_PRICE)
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rooch_types::address::RoochAddress;
+    use rooch_types::transaction::ethereum::EthereumTransaction;
+    use rooch_types::transaction::rooch::RoochTransaction;
+    use rooch_types::transaction::AbstractTransaction;
+
+    fn eth_tx(sender: AccountAddress, sequence_number: u64, gas_price: u64) -> TypedTransaction {
+        let mut tx = EthereumTransaction::new_for_test(
+            RoochAddress::from(sender),
+            sequence_number.into(),
+            Default::default(),
+        );
+        tx.0.gas_price = Some(gas_price.into());
+        TypedTransaction::Ethereum(tx)
+    }
+
+    fn rooch_tx(sender: AccountAddress, sequence_number: u64) -> TypedTransaction {
+        TypedTransaction::Rooch(RoochTransaction::new_for_test(
+            RoochAddress::from(sender),
+            sequence_number,
+            RoochTransaction::mock().action().clone(),
+        ))
+    }
+
+    fn add(
+        pool: &mut TransactionPool,
+        sender: AccountAddress,
+        tx: TypedTransaction,
+        account_sequence_number: u64,
+        now: Instant,
+    ) -> Result<Vec<H256>, MempoolError> {
+        let sequence_number = match &tx {
+            TypedTransaction::Rooch(tx) => tx.sequence_number(),
+            TypedTransaction::RoochSponsored(tx) => tx.sequence_number(),
+            TypedTransaction::Ethereum(tx) => tx.0.nonce.as_u64(),
+        };
+        let (hash, size) = (tx.tx_hash(), tx.tx_size());
+        pool.add(
+            tx,
+            hash,
+            sender,
+            sequence_number,
+            size,
+            account_sequence_number,
+            now,
+        )
+    }
+
+    #[test]
+    fn test_per_sender_order() {
+        let mut pool = TransactionPool::new(MempoolConfig::default());
+        let now = Instant::now();
+        let sender = AccountAddress::random();
+        add(&mut pool, sender, rooch_tx(sender, 1), 0, now).unwrap();
+        // seq 1 waits for seq 0
+        assert!(pool.pop_ready(now).is_none());
+        add(&mut pool, sender, rooch_tx(sender, 0), 0, now).unwrap();
+        assert_eq!(pool.pop_ready(now).unwrap().sequence_number, 0);
+        assert_eq!(pool.pop_ready(now).unwrap().sequence_number, 1);
+        assert!(pool.pop_ready(now).is_none());
+        assert!(pool.is_empty());
+    }
+
+    #[test]
+    fn test_gap_timeout() {
+        let config = MempoolConfig::default();
+        let gap_timeout = config.gap_timeout();
+        let mut pool = TransactionPool::new(config);
+        let now = Instant::now();
+        let sender = AccountAddress::random();
+        add(&mut pool, sender, rooch_tx(sender, 2), 0, now).unwrap();
+        assert!(pool.pop_ready(now).is_none());
+        assert_eq!(pool.status(now).ready_count, 0);
+        let later = now + gap_timeout;
+        assert_eq!(pool.status(later).ready_count, 1);
+        assert_eq!(pool.pop_ready(later).unwrap().sequence_number, 2);
+    }
+
+    #[test]
+    fn test_sequence_number_window() {
+        let config = MempoolConfig {
+            max_count_per_sender: 2,
+            ..Default::default()
+        };
+        let mut pool = TransactionPool::new(config);
+        let now = Instant::now();
+        let sender = AccountAddress::random();
+        assert_eq!(
+            add(&mut pool, sender, rooch_tx(sender, 4), 5, now),
+            Err(MempoolError::SequenceNumberTooOld {
+                expected: 5,
+                actual: 4
+            })
+        );
+        assert_eq!(
+            add(&mut pool, sender, rooch_tx(sender, 7), 5, now),
+            Err(MempoolError::SequenceNumberTooNew { max: 6, actual: 7 })
+        );
+        add(&mut pool, sender, rooch_tx(sender, 5), 5, now).unwrap();
+        add(&mut pool, sender, rooch_tx(sender, 6), 5, now).unwrap();
+        assert_eq!(pool.len(), 2);
+    }
+
+    #[test]
+    fn test_gas_price_ordering() {
+        let mut pool = TransactionPool::new(MempoolConfig::default());
+        let now = Instant::now();
+        let (alice, bob, carol) = (
+            AccountAddress::random(),
+            AccountAddress::random(),
+            AccountAddress::random(),
+        );
+        add(&mut pool, alice, eth_tx(alice, 0, 10), 0, now).unwrap();
+        add(&mut pool, bob, eth_tx(bob, 0, 30), 0, now).unwrap();
+        add(&mut pool, carol, eth_tx(carol, 0, 10), 0, now).unwrap();
+        assert_eq!(pool.pop_ready(now).unwrap().sender, bob);
+        // same gas price, the earlier one first
+        assert_eq!(pool.pop_ready(now).unwrap().sender, alice);
+        assert_eq!(pool.pop_ready(now).unwrap().sender, carol);
+    }
+
+    #[test]
+    fn test_replacement() {
+        let mut pool = TransactionPool::new(MempoolConfig::default());
+        let now = Instant::now();
+        let sender = AccountAddress::random();
+        let tx = eth_tx(sender, 0, 100);
+        let hash = tx.tx_hash();
+        add(&mut pool, sender, tx.clone(), 0, now).unwrap();
+        assert_eq!(
+            add(&mut pool, sender, tx, 0, now),
+            Err(MempoolError::AlreadyExists)
+        );
+        assert_eq!(
+            add(&mut pool, sender, eth_tx(sender, 0, 105), 0, now),
+            Err(MempoolError::ReplacementUnderpriced {
+                required: 110,
+                actual: 105
+            })
+        );
+        let removed = add(&mut pool, sender, eth_tx(sender, 0, 110), 0, now).unwrap();
+        assert_eq!(removed, vec![hash]);
+        assert_eq!(pool.len(), 1);
+        assert_eq!(pool.pop_ready(now).unwrap().gas_price, 110);
+    }
+
+    #[test]
+    fn test_count_limit_eviction() {
+        let config = MempoolConfig {
+            max_count: 2,
+            ..Default::default()
+        };
+        let mut pool = TransactionPool::new(config);
+        let now = Instant::now();
+        let (alice, bob, carol) = (
+            AccountAddress::random(),
+            AccountAddress::random(),
+            AccountAddress::random(),
+        );
+        let cheap = eth_tx(alice, 0, 1);
+        let cheap_hash = cheap.tx_hash();
+        add(&mut pool, alice, cheap, 0, now).unwrap();
+        add(&mut pool, bob, eth_tx(bob, 0, 5), 0, now).unwrap();
+        assert_eq!(
+            add(&mut pool, carol, eth_tx(carol, 0, 1), 0, now),
+            Err(MempoolError::MempoolFull)
+        );
+        let removed = add(&mut pool, carol, eth_tx(carol, 0, 2), 0, now).unwrap();
+        assert_eq!(removed, vec![cheap_hash]);
+        assert!(!pool.contains(&cheap_hash));
+        assert_eq!(pool.len(), 2);
+    }
+
+    #[test]
+    fn test_bytes_limit() {
+        let sender = AccountAddress::random();
+        let tx = rooch_tx(sender, 0);
+        let config = MempoolConfig {
+            max_bytes: tx.tx_size(),
+            ..Default::default()
+        };
+        let mut pool = TransactionPool::new(config);
+        let now = Instant::now();
+        add(&mut pool, sender, tx.clone(), 0, now).unwrap();
+        assert_eq!(pool.status(now).total_bytes, tx.tx_size());
+        let other = AccountAddress::random();
+        assert_eq!(
+            add(&mut pool, other, rooch_tx(other, 0), 0, now),
+            Err(MempoolError::MempoolFull)
+        );
+    }
+
+    #[test]
+    fn test_ttl_eviction() {
+        let config = MempoolConfig::default();
+        let ttl = config.ttl();
+        let mut pool = TransactionPool::new(config);
+        let now = Instant::now();
+        let sender = AccountAddress::random();
+        let tx = rooch_tx(sender, 3);
+        let hash = tx.tx_hash();
+        add(&mut pool, sender, tx, 0, now).unwrap();
+        assert!(pool.evict_expired(now).is_empty());
+        assert_eq!(pool.evict_expired(now + ttl), vec![hash]);
+        assert!(pool.is_empty());
+        assert_eq!(pool.status(now + ttl).sender_count, 0);
+    }
+
+    #[test]
+    fn test_sync_sender() {
+        let mut pool = TransactionPool::new(MempoolConfig::default());
+        let now = Instant::now();
+        let sender = AccountAddress::random();
+        add(&mut pool, sender, rooch_tx(sender, 0), 0, now).unwrap();
+        add(&mut pool, sender, rooch_tx(sender, 1), 0, now).unwrap();
+        add(&mut pool, sender, rooch_tx(sender, 2), 0, now).unwrap();
+        assert_eq!(pool.sync_sender(sender, 2).len(), 2);
+        let pending = pool.pending(Some(sender), now);
+        assert_eq!(pending.len(), 1);
+        assert_eq!(pending[0].sequence_number, 2);
+        assert!(pending[0].ready);
+    }
+}
@@ -0,0 +1,84 @@
+// Copyright (c) RoochNetwork
+// SPDX-License-Identifier: Apache-2.0
+
+use crate::actor::mempool::MempoolActor;
+use crate::messages::{
+    AddTransactionMessage, EvictExpiredMessage, GetMempoolStatusMessage,
+    GetPendingTransactionsMessage, PopReadyTransactionMessage, ReadyTransaction, SyncSenderMessage,
+};
+use anyhow::Result;
+use coerce::actor::ActorRef;
+use move_core_types::account_address::AccountAddress;
+use moveos_types::h256::H256;
+use rooch_types::mempool::{MempoolStatus, PendingTransaction};
+use rooch_types::transaction::TypedTransaction;
+
+#[derive(Clone)]
+pub struct MempoolProxy {
+    pub actor: ActorRef<MempoolActor>,
+}
+
+impl MempoolProxy {
+    pub fn new(actor: ActorRef<MempoolActor>) -> Self {
+        Self { actor }
+    }
+
+    #[allow(clippy::too_many_arguments)]
+    pub async fn add_transaction(
+        &self,
+        tx: TypedTransaction,
+        tx_hash: H256,
+        sender: AccountAddress,
+        sequence_number: u64,
+        tx_size: u64,
+        account_sequence_number: u64,
+    ) -> Result<Vec<H256>> {
+        Ok(self
+            .actor
+            .send(AddTransactionMessage {
+                tx,
+                tx_hash,
+                sender,
+                sequence_number,
+                tx_size,
+                account_sequence_number,
+            })
+            .await??)
+    }
+
+    pub async fn pop_ready_transaction(&self) -> Result<Option<ReadyTransaction>> {
+        Ok(self.actor.send(PopReadyTransactionMessage {}).await?)
+    }
+
+    pub async fn sync_sender(
+        &self,
+        sender: AccountAddress,
+        account_sequence_number: u64,
+    ) -> Result<Vec<H256>> {
+        Ok(self
+            .actor
+            .send(SyncSenderMessage {
+                sender,
+                account_sequence_number,
+            })
+            .await?)
+    }
+
+    pub async fn evict_expired(&self) -> Result<Vec<H256>> {
+        Ok(self.actor.send(EvictExpiredMessage {}).await?)
+    }
+
+    pub async fn get_pending_transactions(
+        &self,
+        sender: Option<AccountAddress>,
+    ) -> Result<Vec<PendingTransaction>> {
+        Ok(self
+            .actor
+            .send(GetPendingTransactionsMessage { sender })
+            .await?)
+    }
+
+    pub async fn get_mempool_status(&self) -> Result<MempoolStatus> {
+        Ok(self.actor.send(GetMempoolStatusMessage {}).await?)
+    }
+}
@@ -37,3 +37,19 @@ impl Message for ProposeBlock {
 }
 
 impl TimerTick for ProposeBlock {}
+
+/// Get Proposer Status Message
+#[derive(Debug)]
+pub struct GetProposerStatusMessage {}
+
+#[derive(Debug, Clone)]
+pub struct ProposerStatus {
+    /// The last proposed block number, `None` if no block is proposed
+    pub last_block_number: Option<u128>,
+    /// The number of transactions waiting to be proposed in the next block
+    pub pending_tx_count: u64,
+}
+
+impl Message for GetProposerStatusMessage {
+    type Result = Result<ProposerStatus>;
+}
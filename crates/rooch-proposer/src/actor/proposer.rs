@@ -10,7 +10,10 @@ use rooch_types::crypto::RoochKeyPair;
 
 use crate::scc::StateCommitmentChain;
 
-use super::messages::{ProposeBlock, TransactionProposeMessage, TransactionProposeResult};
+use super::messages::{
+    GetProposerStatusMessage, ProposeBlock, ProposerStatus, TransactionProposeMessage,
+    TransactionProposeResult,
+};
 
 pub struct ProposerActor {
     proposer_key: RoochKeyPair,
@@ -60,3 +63,17 @@ impl Handler<ProposeBlock> for ProposerActor {
         let _proposer_key = &self.proposer_key;
     }
 }
+
+#[async_trait]
+impl Handler<GetProposerStatusMessage> for ProposerActor {
+    async fn handle(
+        &mut self,
+        _message: GetProposerStatusMessage,
+        _ctx: &mut ActorContext,
+    ) -> Result<ProposerStatus> {
+        Ok(ProposerStatus {
+            last_block_number: self.scc.last_block_number(),
+            pending_tx_count: self.scc.pending_tx_count(),
+        })
+    }
+}
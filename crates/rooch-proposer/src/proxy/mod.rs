@@ -8,7 +8,10 @@ use moveos_types::transaction::TransactionExecutionInfo;
 use rooch_types::transaction::{TransactionSequenceInfo, TypedTransaction};
 
 use crate::actor::{
-    messages::{TransactionProposeMessage, TransactionProposeResult},
+    messages::{
+        GetProposerStatusMessage, ProposeBlock, ProposerStatus, TransactionProposeMessage,
+        TransactionProposeResult,
+    },
     proposer::ProposerActor,
 };
 
@@ -36,4 +39,13 @@ impl ProposerProxy {
             })
             .await?
     }
+
+    /// Propose a block with the pending transactions immediately, without waiting for the timer
+    pub async fn propose_block(&self) -> Result<()> {
+        Ok(self.actor.send(ProposeBlock {}).await?)
+    }
+
+    pub async fn get_status(&self) -> Result<ProposerStatus> {
+        self.actor.send(GetProposerStatusMessage {}).await?
+    }
 }
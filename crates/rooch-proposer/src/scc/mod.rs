@@ -50,6 +50,11 @@ impl StateCommitmentChain {
         self.blocks.keys().last().copied()
     }
 
+    /// Get the number of transactions waiting to be proposed
+    pub fn pending_tx_count(&self) -> u64 {
+        self.buffer.len() as u64
+    }
+
     /// Trigger the proposer to propose a new block
     pub async fn propose_block(&mut self) -> Option<&Block> {
         if self.buffer.is_empty() {
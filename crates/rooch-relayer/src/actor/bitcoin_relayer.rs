@@ -2,27 +2,35 @@
 // SPDX-License-Identifier: Apache-2.0
 
 use crate::Relayer;
-use anyhow::Result;
+use anyhow::{bail, Result};
 use async_trait::async_trait;
-use bitcoin::Block;
+use bitcoin::{Block, BlockHash};
 use bitcoincore_rpc::{bitcoincore_rpc_json::GetBlockHeaderResult, Auth, Client, RpcApi};
 use moveos_types::{module_binding::MoveFunctionCaller, transaction::FunctionCall};
 use rooch_config::BitcoinRelayerConfig;
-use rooch_executor::proxy::ExecutorProxy;
 use rooch_types::bitcoin::light_client::BitcoinLightClientModule;
-use std::cmp::max;
-use tracing::{debug, info};
+use std::cmp::{max, min};
+use tracing::{debug, info, warn};
 
-pub struct BitcoinRelayer {
+/// The max depth of a reorg that the light client can roll back, same as `MAX_REORG_DEPTH` in light_client.move.
+pub const MAX_REORG_DEPTH: u64 = 100;
+
+/// Relay the Bitcoin blocks to the light client, the light client states are read via the `move_caller`,
+/// the `ExecutorProxy` in the Rooch server or the Rooch RPC client in the standalone relayer.
+pub struct BitcoinRelayer<C> {
     start_block_height: Option<u64>,
     rpc_client: Client,
-    //TODO if we want make the relayer to an independent process, we need to replace the executor proxy with a rooch rpc client
-    move_caller: ExecutorProxy,
+    move_caller: C,
     buffer: Vec<BlockResult>,
     tx_batch_size: u64,
     sync_block_interval: u64,
     latest_sync_timestamp: u64,
     sync_to_latest: bool,
+    lag: Option<u64>,
+    /// The number of confirmations a block needs before it is relayed
+    confirmations: u64,
+    /// The rollback call to submit when the Bitcoin best chain is reorganized
+    rollback: Option<FunctionCall>,
 }
 
 #[derive(Debug, Clone)]
@@ -31,8 +39,8 @@ pub struct BlockResult {
     pub block: Block,
 }
 
-impl BitcoinRelayer {
-    pub fn new(config: BitcoinRelayerConfig, executor: ExecutorProxy) -> Result<Self> {
+impl<C: MoveFunctionCaller> BitcoinRelayer<C> {
+    pub fn new(config: BitcoinRelayerConfig, move_caller: C) -> Result<Self> {
         let rpc = Client::new(
             config.btc_rpc_url.as_str(),
             Auth::UserPass(config.btc_rpc_user_name, config.btc_rpc_password),
@@ -40,12 +48,15 @@ impl BitcoinRelayer {
         Ok(Self {
             start_block_height: config.btc_start_block_height,
             rpc_client: rpc,
-            move_caller: executor,
+            move_caller,
             buffer: vec![],
             tx_batch_size: 1000u64,
             sync_block_interval: 60u64,
             latest_sync_timestamp: 0u64,
             sync_to_latest: false,
+            lag: None,
+            confirmations: config.btc_confirmations,
+            rollback: None,
         })
     }
 
@@ -69,6 +80,35 @@ impl BitcoinRelayer {
             .rpc_client
             .get_block_header_info(&latest_block_hash_in_bitcoin)?;
         let latest_block_height_in_bitcoin = latest_block_header_info.height as u64;
+        self.lag = latest_block_height_in_rooch
+            .map(|height| latest_block_height_in_bitcoin.saturating_sub(height));
+
+        if let Some(latest_block_height_in_rooch) = latest_block_height_in_rooch {
+            let fork_point = find_fork_point(
+                latest_block_height_in_rooch,
+                latest_block_height_in_bitcoin,
+                |height| bitcoin_light_client.get_block_hash_by_height(height),
+                |height| Ok(self.rpc_client.get_block_hash(height)?),
+            )?;
+            if let Some((fork_height, fork_block_hash)) = fork_point {
+                warn!(
+                    "BitcoinRelayer detected reorg, rollback from height: {} to fork point height: {}, hash: {}",
+                    latest_block_height_in_rooch, fork_height, fork_block_hash
+                );
+                self.rollback = Some(
+                    BitcoinLightClientModule::create_rollback_to_fork_point_call(
+                        fork_height,
+                        fork_block_hash,
+                        self.tx_batch_size,
+                    ),
+                );
+                self.sync_to_latest = false;
+                return Ok(());
+            }
+        }
+
+        //Only relay the blocks with enough confirmations
+        let relay_block_height = latest_block_height_in_bitcoin.saturating_sub(self.confirmations);
         let start_block_height: u64 = match (self.start_block_height, latest_block_height_in_rooch)
         {
             (Some(start_block_height), Some(latest_block_height_in_rooch)) => {
@@ -78,12 +118,12 @@ impl BitcoinRelayer {
             (None, Some(latest_block_height_in_rooch)) => latest_block_height_in_rooch + 1,
             (None, None) => {
                 //if the start_block_height is None, and the latest_block_height_in_rooch is None
-                //we sync from the latest block
-                latest_block_height_in_bitcoin
+                //we sync from the latest confirmed block
+                relay_block_height
             }
         };
 
-        if start_block_height > latest_block_height_in_bitcoin {
+        if start_block_height > relay_block_height {
             self.sync_to_latest = true;
             return Ok(());
         }
@@ -104,6 +144,9 @@ impl BitcoinRelayer {
             block: start_block,
         });
         while let Some(next_hash) = next_block_hash {
+            if self.buffer.len() as u64 + start_block_height > relay_block_height {
+                break;
+            }
             let header_info = self.rpc_client.get_block_header_info(&next_hash)?;
             let block = self.rpc_client.get_block(&next_hash)?;
             next_block_hash = header_info.next_block_hash;
@@ -122,6 +165,16 @@ impl BitcoinRelayer {
             let block_result = self.buffer.remove(0);
             let block_height = block_result.header_info.height;
             let block_hash = block_result.header_info.hash;
+            //The buffered blocks may be orphaned by a reorg after they are fetched
+            if self.rpc_client.get_block_hash(block_height as u64)? != block_hash {
+                warn!(
+                    "BitcoinRelayer buffered block is not on the best chain, height: {}, hash: {}",
+                    block_height, block_hash
+                );
+                self.buffer.clear();
+                self.sync_to_latest = false;
+                return Ok(None);
+            }
             let time = block_result.block.header.time;
             info!(
                 "BitcoinRelayer process block, height: {}, hash: {}, time: {}",
@@ -133,6 +186,35 @@ impl BitcoinRelayer {
         }
     }
 
+    /// Continue the unfinished rollback, the light client reverts at most `tx_batch_size` txs in a call
+    fn check_rollback_progress(&self) -> Result<Option<FunctionCall>> {
+        let bitcoin_light_client = self
+            .move_caller
+            .as_module_binding::<BitcoinLightClientModule>();
+        let fork_height = match bitcoin_light_client.get_rollback_fork_height()? {
+            Some(fork_height) => fork_height,
+            None => return Ok(None),
+        };
+        let fork_block_hash = match bitcoin_light_client.get_block_hash_by_height(fork_height)? {
+            Some(fork_block_hash) => fork_block_hash,
+            None => bail!(
+                "BitcoinRelayer can not find the fork block at height: {}",
+                fork_height
+            ),
+        };
+        info!(
+            "BitcoinRelayer continue rollback to fork point height: {}, hash: {}",
+            fork_height, fork_block_hash
+        );
+        Ok(Some(
+            BitcoinLightClientModule::create_rollback_to_fork_point_call(
+                fork_height,
+                fork_block_hash,
+                self.tx_batch_size,
+            ),
+        ))
+    }
+
     fn check_utxo_progress(&self) -> Result<Option<FunctionCall>> {
         let bitcoin_light_client = self
             .move_caller
@@ -152,17 +234,31 @@ impl BitcoinRelayer {
 }
 
 #[async_trait]
-impl Relayer for BitcoinRelayer {
+impl<C: MoveFunctionCaller> Relayer for BitcoinRelayer<C> {
+    fn name(&self) -> &'static str {
+        "bitcoin"
+    }
+
     async fn relay(&mut self) -> Result<Option<FunctionCall>> {
+        if let Some(call) = self.check_rollback_progress()? {
+            return Ok(Some(call));
+        }
         if let Some(call) = self.check_utxo_progress()? {
             return Ok(Some(call));
         }
         self.sync_block().await?;
+        if let Some(call) = self.rollback.take() {
+            return Ok(Some(call));
+        }
         if let Some(call) = self.pop_buffer()? {
             return Ok(Some(call));
         }
         Ok(None)
     }
+
+    fn lag(&self) -> Option<u64> {
+        self.lag
+    }
 }
 
 fn block_result_to_call(block_result: BlockResult) -> Result<FunctionCall> {
@@ -173,3 +269,156 @@ fn block_result_to_call(block_result: BlockResult) -> Result<FunctionCall> {
     );
     Ok(call)
 }
+
+/// Find the fork point between the blocks stored in the light client and the Bitcoin best chain.
+/// Returns the highest stored block that is still on the best chain, or None if there is no reorg.
+pub fn find_fork_point<R, B>(
+    latest_block_height_in_rooch: u64,
+    latest_block_height_in_bitcoin: u64,
+    block_hash_in_rooch: R,
+    block_hash_in_bitcoin: B,
+) -> Result<Option<(u64, BlockHash)>>
+where
+    R: Fn(u64) -> Result<Option<BlockHash>>,
+    B: Fn(u64) -> Result<BlockHash>,
+{
+    //The stored blocks above the tip of a shorter best chain are orphaned
+    let mut reorged = latest_block_height_in_rooch > latest_block_height_in_bitcoin;
+    let mut height = min(latest_block_height_in_rooch, latest_block_height_in_bitcoin);
+    let lowest_height = latest_block_height_in_rooch.saturating_sub(MAX_REORG_DEPTH);
+    loop {
+        let block_hash = match block_hash_in_rooch(height)? {
+            Some(block_hash) => block_hash,
+            None => bail!(
+                "Can not find the fork point, the block at height {} is not in the light client",
+                height
+            ),
+        };
+        if block_hash_in_bitcoin(height)? == block_hash {
+            return Ok(reorged.then_some((height, block_hash)));
+        }
+        reorged = true;
+        if height <= lowest_height {
+            bail!(
+                "Can not find the fork point, the reorg is deeper than {} blocks",
+                MAX_REORG_DEPTH
+            );
+        }
+        height -= 1;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use bitcoin::blockdata::constants::genesis_block;
+    use bitcoin::Network;
+
+    /// A stand-in of a bitcoind regtest node, it only mines the block headers of the best chain.
+    #[derive(Clone)]
+    struct RegtestChain {
+        blocks: Vec<Block>,
+    }
+
+    impl RegtestChain {
+        fn new(height: u64) -> Self {
+            let mut chain = Self {
+                blocks: vec![genesis_block(Network::Regtest)],
+            };
+            chain.mine(height, 0);
+            chain
+        }
+
+        /// Mine blocks on the tip, the miner tag makes the blocks of different forks distinct.
+        fn mine(&mut self, count: u64, miner_tag: u32) {
+            for _ in 0..count {
+                let tip = self.blocks.last().expect("genesis block should exist");
+                let mut header = tip.header;
+                header.prev_blockhash = tip.block_hash();
+                header.time = tip.header.time + 600;
+                header.nonce = miner_tag;
+                self.blocks.push(Block {
+                    header,
+                    txdata: vec![],
+                });
+            }
+        }
+
+        /// Replace the blocks above the fork height with a new fork of `count` blocks.
+        fn reorg(&mut self, fork_height: u64, count: u64) {
+            self.blocks.truncate(fork_height as usize + 1);
+            self.mine(count, 1);
+        }
+
+        fn tip_height(&self) -> u64 {
+            self.blocks.len() as u64 - 1
+        }
+
+        fn block_hash(&self, height: u64) -> Option<BlockHash> {
+            self.blocks
+                .get(height as usize)
+                .map(|block| block.block_hash())
+        }
+
+        fn find_fork_point(&self, light_client: &RegtestChain) -> Result<Option<(u64, BlockHash)>> {
+            find_fork_point(
+                light_client.tip_height(),
+                self.tip_height(),
+                |height| Ok(light_client.block_hash(height)),
+                |height| {
+                    Ok(self
+                        .block_hash(height)
+                        .expect("height should be on the chain"))
+                },
+            )
+        }
+    }
+
+    #[test]
+    fn test_no_reorg() {
+        let mut bitcoind = RegtestChain::new(10);
+        let light_client = bitcoind.clone();
+        assert_eq!(bitcoind.find_fork_point(&light_client).unwrap(), None);
+
+        bitcoind.mine(5, 0);
+        assert_eq!(bitcoind.find_fork_point(&light_client).unwrap(), None);
+    }
+
+    #[test]
+    fn test_find_fork_point() {
+        let mut bitcoind = RegtestChain::new(10);
+        let light_client = bitcoind.clone();
+        bitcoind.reorg(7, 5);
+        assert_eq!(
+            bitcoind.find_fork_point(&light_client).unwrap(),
+            Some((7, light_client.block_hash(7).unwrap()))
+        );
+    }
+
+    #[test]
+    fn test_find_fork_point_with_shorter_best_chain() {
+        let mut bitcoind = RegtestChain::new(10);
+        let light_client = bitcoind.clone();
+        bitcoind.reorg(5, 2);
+        assert_eq!(
+            bitcoind.find_fork_point(&light_client).unwrap(),
+            Some((5, light_client.block_hash(5).unwrap()))
+        );
+
+        // The best chain drops the tip blocks without a competing fork
+        let mut bitcoind = light_client.clone();
+        bitcoind.blocks.truncate(9);
+        assert_eq!(
+            bitcoind.find_fork_point(&light_client).unwrap(),
+            Some((8, light_client.block_hash(8).unwrap()))
+        );
+    }
+
+    #[test]
+    fn test_reorg_too_deep() {
+        let mut bitcoind = RegtestChain::new(MAX_REORG_DEPTH + 10);
+        let light_client = bitcoind.clone();
+        bitcoind.reorg(5, MAX_REORG_DEPTH + 10);
+        assert!(bitcoind.find_fork_point(&light_client).is_err());
+    }
+}
@@ -2,65 +2,246 @@
 // SPDX-License-Identifier: Apache-2.0
 
 use crate::Relayer;
-use anyhow::Result;
+use anyhow::{bail, Result};
 use async_trait::async_trait;
 use ethers::prelude::*;
-use moveos_types::transaction::FunctionCall;
+use moveos_types::{module_binding::MoveFunctionCaller, transaction::FunctionCall};
 use rooch_config::EthereumRelayerConfig;
 use rooch_types::framework::ethereum_light_client::{BlockHeader, EthereumLightClientModule};
-use std::collections::HashSet;
-use tracing::info;
+use std::cmp::min;
+use std::collections::VecDeque;
+use tracing::{info, warn};
 
-pub struct EthereumRelayer {
+/// The max depth of a reorg that the relayer walks back to find the fork point, same as
+/// `MAX_REORG_DEPTH` in ethereum_light_client.move.
+pub const MAX_REORG_DEPTH: u64 = 128;
+
+/// Relay the Ethereum blocks to the light client sequentially, the relay progress is read from the
+/// light client states via the `move_caller`, so the relayer catches up from the last relayed block.
+pub struct EthereumRelayer<C> {
     rpc_client: Provider<Http>,
-    processed_blocks: HashSet<H256>,
+    move_caller: C,
+    /// The fetched blocks waiting to be relayed, at most `batch_size` blocks
+    buffer: VecDeque<BlockHeader>,
+    batch_size: u64,
+    /// The number of confirmations a block needs before it is relayed
+    confirmations: u64,
+    lag: Option<u64>,
 }
 
-impl EthereumRelayer {
-    pub fn new(config: EthereumRelayerConfig) -> Result<Self> {
+impl<C: MoveFunctionCaller> EthereumRelayer<C> {
+    pub fn new(config: EthereumRelayerConfig, move_caller: C) -> Result<Self> {
         let rpc_client = Provider::<Http>::try_from(config.eth_rpc_url)?;
         Ok(Self {
             rpc_client,
-            //TODO load processed block from Move state
-            processed_blocks: HashSet::new(),
+            move_caller,
+            buffer: VecDeque::new(),
+            batch_size: config.eth_batch_size.max(1),
+            confirmations: config.eth_confirmations,
+            lag: None,
         })
     }
 
-    async fn relay_ethereum(&mut self) -> Result<Option<FunctionCall>> {
+    async fn get_block_header(&self, block_number: u64) -> Result<BlockHeader> {
         let block = self
             .rpc_client
-            .get_block(BlockId::Number(BlockNumber::Latest))
-            .await?;
-        match block {
-            Some(block) => {
-                let block_hash = block
-                    .hash
-                    .ok_or_else(|| anyhow::format_err!("The block is a pending block"))?;
-                if self.processed_blocks.contains(&block_hash) {
-                    info!("The block {} has already been processed", block_hash);
-                    return Ok(None);
+            .get_block(BlockId::Number(BlockNumber::Number(block_number.into())))
+            .await?
+            .ok_or_else(|| anyhow::format_err!("The RPC returned no block {}", block_number))?;
+        BlockHeader::try_from(&block)
+    }
+
+    async fn sync_block(&mut self) -> Result<()> {
+        let ethereum_light_client = self
+            .move_caller
+            .as_module_binding::<EthereumLightClientModule>();
+        let latest_block_number_in_rooch = ethereum_light_client.get_latest_block_number()?;
+        let next_block_number = latest_block_number_in_rooch.map(|number| number + 1);
+        if let Some(block_header) = self.buffer.front() {
+            if Some(block_header.number) == next_block_number {
+                return Ok(());
+            }
+            //The relay tx of the previous block failed, fetch the blocks again
+            self.buffer.clear();
+        }
+
+        let latest_block_number_in_ethereum = self.rpc_client.get_block_number().await?.as_u64();
+        self.lag = latest_block_number_in_rooch
+            .map(|number| latest_block_number_in_ethereum.saturating_sub(number));
+        //Only relay the blocks with enough confirmations
+        let relay_block_number = latest_block_number_in_ethereum.saturating_sub(self.confirmations);
+
+        let start_block_number = match latest_block_number_in_rooch {
+            Some(latest_block_number_in_rooch) => {
+                let mut block_hashes = vec![];
+                let top_block_number = min(latest_block_number_in_rooch, relay_block_number);
+                let lowest_block_number = top_block_number.saturating_sub(MAX_REORG_DEPTH);
+                let mut block_number = top_block_number;
+                //Collect the hashes of the relayed blocks top down, stop at the first match
+                loop {
+                    if !ethereum_light_client.exists_block(block_number)? {
+                        break;
+                    }
+                    let hash_in_rooch = ethereum_light_client.get_block(block_number)?.hash;
+                    let hash_in_ethereum = self.get_block_header(block_number).await?.hash;
+                    let matched = hash_in_rooch == hash_in_ethereum;
+                    block_hashes.push((block_number, hash_in_rooch, hash_in_ethereum));
+                    if matched || block_number <= lowest_block_number {
+                        break;
+                    }
+                    block_number -= 1;
                 }
-                let block_header = BlockHeader::try_from(&block)?;
-                let call = EthereumLightClientModule::create_submit_new_block_call(&block_header);
-                info!(
-                    "EthereumRelayer process block, hash: {}, number: {}, timestamp: {}",
-                    block_hash, block_header.number, block_header.timestamp
-                );
-                self.processed_blocks.insert(block_hash);
-                Ok(Some(call))
+                let start_block_number = find_start_block_number(
+                    latest_block_number_in_rooch,
+                    relay_block_number,
+                    &block_hashes,
+                )?;
+                if start_block_number <= top_block_number {
+                    warn!(
+                        "EthereumRelayer detected reorg, relay from block {} to replace the orphaned blocks",
+                        start_block_number
+                    );
+                }
+                start_block_number
             }
-            None => {
-                info!("The RPC returned no block");
-                Ok(None)
+            //No block is relayed yet, relay from the latest confirmed block
+            None => relay_block_number,
+        };
+        if start_block_number > relay_block_number {
+            return Ok(());
+        }
+        let end_block_number = min(start_block_number + self.batch_size - 1, relay_block_number);
+        for block_number in start_block_number..=end_block_number {
+            let block_header = self.get_block_header(block_number).await?;
+            //The chain may be reorganized while fetching, relay the blocks on the same chain only
+            if let Some(parent) = self.buffer.back() {
+                if parent.hash != block_header.parent_hash {
+                    warn!(
+                        "EthereumRelayer block {} does not follow the fetched parent, wait for the next round",
+                        block_number
+                    );
+                    break;
+                }
             }
+            self.buffer.push_back(block_header);
         }
-        //TODO clean up processed block
+        Ok(())
+    }
+
+    fn pop_buffer(&mut self) -> Option<FunctionCall> {
+        let block_header = self.buffer.pop_front()?;
+        info!(
+            "EthereumRelayer process block, hash: {}, number: {}, timestamp: {}",
+            H256::from_slice(&block_header.hash),
+            block_header.number,
+            block_header.timestamp
+        );
+        let call = EthereumLightClientModule::create_submit_new_block_call(&block_header);
+        Some(call)
     }
 }
 
 #[async_trait]
-impl Relayer for EthereumRelayer {
+impl<C: MoveFunctionCaller> Relayer for EthereumRelayer<C> {
+    fn name(&self) -> &'static str {
+        "ethereum"
+    }
+
     async fn relay(&mut self) -> Result<Option<FunctionCall>> {
-        self.relay_ethereum().await
+        self.sync_block().await?;
+        Ok(self.pop_buffer())
+    }
+
+    fn lag(&self) -> Option<u64> {
+        self.lag
+    }
+}
+
+/// Find the number of the next block to relay. The `block_hashes` are the `(number, hash in the light
+/// client, hash in Ethereum)` of the relayed blocks, collected top down and ended by the first matched
+/// block or a gap in the light client. The relayed blocks above the fork point are orphaned and relayed
+/// again, the light client replaces them.
+pub fn find_start_block_number(
+    latest_block_number_in_rooch: u64,
+    relay_block_number: u64,
+    block_hashes: &[(u64, Vec<u8>, Vec<u8>)],
+) -> Result<u64> {
+    let (lowest_block_number, hash_in_rooch, hash_in_ethereum) = match block_hashes.last() {
+        Some(block_hash) => block_hash,
+        //There is a gap in the light client, the parent of the next block is unknown
+        None => return Ok(min(latest_block_number_in_rooch, relay_block_number) + 1),
+    };
+    if hash_in_rooch == hash_in_ethereum {
+        return Ok(lowest_block_number + 1);
+    }
+    if block_hashes.len() as u64 > MAX_REORG_DEPTH {
+        bail!(
+            "Can not find the fork point, the reorg is deeper than {} blocks",
+            MAX_REORG_DEPTH
+        );
+    }
+    //The fork point is below the relayed blocks, relay from the lowest orphaned block
+    Ok(*lowest_block_number)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn collect_block_hashes(
+        light_client: &[Vec<u8>],
+        ethereum: &[Vec<u8>],
+        relay_block_number: u64,
+    ) -> Vec<(u64, Vec<u8>, Vec<u8>)> {
+        let mut block_hashes = vec![];
+        let mut block_number = min(light_client.len() as u64 - 1, relay_block_number);
+        loop {
+            let hash_in_rooch = light_client[block_number as usize].clone();
+            let hash_in_ethereum = ethereum[block_number as usize].clone();
+            let matched = hash_in_rooch == hash_in_ethereum;
+            block_hashes.push((block_number, hash_in_rooch, hash_in_ethereum));
+            if matched || block_number == 0 {
+                break;
+            }
+            block_number -= 1;
+        }
+        block_hashes
+    }
+
+    fn chain(len: u8, fork_tag: u8) -> Vec<Vec<u8>> {
+        (0..len).map(|number| vec![number, fork_tag]).collect()
+    }
+
+    #[test]
+    fn test_catch_up_without_reorg() {
+        let light_client = chain(10, 0);
+        let ethereum = chain(20, 0);
+        let block_hashes = collect_block_hashes(&light_client, &ethereum, 19);
+        assert_eq!(find_start_block_number(9, 19, &block_hashes).unwrap(), 10);
+    }
+
+    #[test]
+    fn test_relay_from_fork_point() {
+        let light_client = chain(10, 0);
+        let mut ethereum = chain(20, 1);
+        ethereum[..6].clone_from_slice(&light_client[..6]);
+        let block_hashes = collect_block_hashes(&light_client, &ethereum, 19);
+        assert_eq!(find_start_block_number(9, 19, &block_hashes).unwrap(), 6);
+    }
+
+    #[test]
+    fn test_gap_in_light_client() {
+        assert_eq!(find_start_block_number(9, 19, &[]).unwrap(), 10);
+        assert_eq!(find_start_block_number(9, 5, &[]).unwrap(), 6);
+    }
+
+    #[test]
+    fn test_reorg_too_deep() {
+        let len = MAX_REORG_DEPTH as u8 + 10;
+        let light_client = chain(len, 0);
+        let mut ethereum = chain(len, 1);
+        ethereum[0] = light_client[0].clone();
+        let block_hashes = collect_block_hashes(&light_client, &ethereum, len as u64 - 1);
+        assert!(find_start_block_number(len as u64 - 1, len as u64 - 1, &block_hashes).is_err());
     }
 }
@@ -1,6 +1,7 @@
 // Copyright (c) RoochNetwork
 // SPDX-License-Identifier: Apache-2.0
 
+use anyhow::Result;
 use coerce::actor::{message::Message, scheduler::timer::TimerTick};
 
 #[derive(Clone)]
@@ -11,3 +12,28 @@ impl Message for RelayTick {
 }
 
 impl TimerTick for RelayTick {}
+
+/// Pause or resume the relayer, the paused relayer skips the relay ticks
+#[derive(Debug)]
+pub struct SetRelayerPausedMessage {
+    pub paused: bool,
+}
+
+impl Message for SetRelayerPausedMessage {
+    type Result = Result<()>;
+}
+
+/// Get Relayer Status Message
+#[derive(Debug)]
+pub struct GetRelayerStatusMessage {}
+
+#[derive(Debug, Clone)]
+pub struct RelayerStatus {
+    pub paused: bool,
+    /// The names of the relayed chains
+    pub relayers: Vec<String>,
+}
+
+impl Message for GetRelayerStatusMessage {
+    type Result = Result<RelayerStatus>;
+}
@@ -3,19 +3,23 @@
 
 use super::bitcoin_relayer::BitcoinRelayer;
 use super::ethereum_relayer::EthereumRelayer;
-use super::messages::RelayTick;
+use super::messages::{GetRelayerStatusMessage, RelayTick, RelayerStatus, SetRelayerPausedMessage};
+use crate::metrics::RelayerMetrics;
 use crate::{Relayer, TxSubmiter};
 use anyhow::Result;
 use async_trait::async_trait;
 use coerce::actor::{context::ActorContext, message::Handler, Actor};
-use moveos_types::{gas_config::GasConfig, transaction::MoveAction};
+use moveos_types::{
+    gas_config::GasConfig, module_binding::MoveFunctionCaller, transaction::MoveAction,
+};
 use rooch_config::{BitcoinRelayerConfig, EthereumRelayerConfig};
-use rooch_executor::proxy::ExecutorProxy;
 use rooch_rpc_api::jsonrpc_types::KeptVMStatusView;
 use rooch_rpc_client::ClientBuilder;
 use rooch_types::{
     address::RoochAddress,
+    addresses::ROOCH_FRAMEWORK_ADDRESS,
     crypto::RoochKeyPair,
+    framework::onchain_config::{self, OnchainConfigModule},
     transaction::{rooch::RoochTransactionData, AbstractTransaction},
 };
 use tracing::{info, warn};
@@ -27,12 +31,14 @@ pub struct RelayerActor {
     relayer_key: RoochKeyPair,
     tx_submiter: Box<dyn TxSubmiter>,
     relayers: Vec<Box<dyn Relayer>>,
+    paused: bool,
+    metrics: Option<RelayerMetrics>,
 }
 
 impl RelayerActor {
-    /// Create a new RelayerActor, use rooch_rpc_client::Client as TxSubmiter
+    /// Create a new RelayerActor for the standalone relayer, use rooch_rpc_client::Client to read
+    /// the light client states and as TxSubmiter
     pub async fn new_for_client(
-        executor: ExecutorProxy,
         relayer_key: RoochKeyPair,
         ethereum_config: Option<EthereumRelayerConfig>,
         bitcoin_config: Option<BitcoinRelayerConfig>,
@@ -40,7 +46,7 @@ impl RelayerActor {
     ) -> Result<Self> {
         let rooch_rpc_client = ClientBuilder::default().build(rooch_rpc_url).await?;
         Self::new(
-            executor,
+            rooch_rpc_client.clone(),
             relayer_key,
             ethereum_config,
             bitcoin_config,
@@ -49,8 +55,8 @@ impl RelayerActor {
         .await
     }
 
-    pub async fn new<T: TxSubmiter + 'static>(
-        executor: ExecutorProxy,
+    pub async fn new<C: MoveFunctionCaller + Clone + 'static, T: TxSubmiter + 'static>(
+        move_caller: C,
         relayer_key: RoochKeyPair,
         ethereum_config: Option<EthereumRelayerConfig>,
         bitcoin_config: Option<BitcoinRelayerConfig>,
@@ -58,14 +64,29 @@ impl RelayerActor {
     ) -> Result<Self> {
         let chain_id = tx_submiter.get_chain_id().await?;
         let relayer_address = relayer_key.public().address();
+        // The light clients only accept the blocks from the sequencer and the whitelisted relayers
+        match move_caller
+            .as_module_binding::<OnchainConfigModule>()
+            .is_relayer(relayer_address.into())
+        {
+            Ok(true) => {}
+            Ok(false) => warn!(
+                "The relayer account {} is not in the relayer whitelist, the sequencer should add it by `{}::{}::{}`",
+                relayer_address,
+                ROOCH_FRAMEWORK_ADDRESS.to_hex_literal(),
+                onchain_config::MODULE_NAME,
+                OnchainConfigModule::ADD_RELAYER_ENTRY_FUNCTION_NAME,
+            ),
+            Err(e) => warn!("Failed to check the relayer whitelist: {:?}", e),
+        }
         let mut relayers: Vec<Box<dyn Relayer>> = vec![];
         if let Some(ethereum_config) = ethereum_config {
-            let eth_relayer = EthereumRelayer::new(ethereum_config)?;
+            let eth_relayer = EthereumRelayer::new(ethereum_config, move_caller.clone())?;
             relayers.push(Box::new(eth_relayer));
         }
 
         if let Some(bitcoin_config) = bitcoin_config {
-            let bitcoin_relayer = BitcoinRelayer::new(bitcoin_config, executor)?;
+            let bitcoin_relayer = BitcoinRelayer::new(bitcoin_config, move_caller)?;
             relayers.push(Box::new(bitcoin_relayer));
         }
 
@@ -76,9 +97,16 @@ impl RelayerActor {
             relayer_key,
             relayers,
             tx_submiter: Box::new(tx_submiter),
+            paused: false,
+            metrics: None,
         })
     }
 
+    pub fn with_metrics(mut self, metrics: RelayerMetrics) -> Self {
+        self.metrics = Some(metrics);
+        self
+    }
+
     async fn sync(&mut self) -> Result<()> {
         for relayer in &mut self.relayers {
             let relayer_name = relayer.name();
@@ -100,6 +128,16 @@ impl RelayerActor {
                         let tx = tx_data.clone().sign(&self.relayer_key);
                         let tx_hash = tx.tx_hash();
                         let result = self.tx_submiter.submit_tx(tx).await?;
+                        if let Some(metrics) = &self.metrics {
+                            let result_label = match result.execution_info.status {
+                                KeptVMStatusView::Executed => "executed",
+                                _ => "failed",
+                            };
+                            metrics
+                                .relayer_relay_tx_total
+                                .with_label_values(&[relayer_name, result_label])
+                                .inc();
+                        }
                         match result.execution_info.status {
                             KeptVMStatusView::Executed => {
                                 info!("Relayer execute relay tx({:?}) success", tx_hash);
@@ -123,6 +161,12 @@ impl RelayerActor {
                     }
                 }
             }
+            if let (Some(metrics), Some(lag)) = (&self.metrics, relayer.lag()) {
+                metrics
+                    .relayer_lag
+                    .with_label_values(&[relayer_name])
+                    .set(lag);
+            }
         }
 
         Ok(())
@@ -137,8 +181,44 @@ impl Actor for RelayerActor {
 #[async_trait]
 impl Handler<RelayTick> for RelayerActor {
     async fn handle(&mut self, _message: RelayTick, _ctx: &mut ActorContext) {
+        if self.paused {
+            return;
+        }
         if let Err(err) = self.sync().await {
             warn!("Relayer tick task error: {:?}", err);
         }
     }
 }
+
+#[async_trait]
+impl Handler<SetRelayerPausedMessage> for RelayerActor {
+    async fn handle(
+        &mut self,
+        message: SetRelayerPausedMessage,
+        _ctx: &mut ActorContext,
+    ) -> Result<()> {
+        if self.paused != message.paused {
+            info!("Relayer paused: {}", message.paused);
+        }
+        self.paused = message.paused;
+        Ok(())
+    }
+}
+
+#[async_trait]
+impl Handler<GetRelayerStatusMessage> for RelayerActor {
+    async fn handle(
+        &mut self,
+        _message: GetRelayerStatusMessage,
+        _ctx: &mut ActorContext,
+    ) -> Result<RelayerStatus> {
+        Ok(RelayerStatus {
+            paused: self.paused,
+            relayers: self
+                .relayers
+                .iter()
+                .map(|relayer| relayer.name().to_string())
+                .collect(),
+        })
+    }
+}
@@ -10,6 +10,8 @@ use rooch_rpc_client::Client;
 use rooch_types::{address::RoochAddress, transaction::rooch::RoochTransaction};
 
 pub mod actor;
+pub mod metrics;
+pub mod proxy;
 
 #[async_trait]
 pub trait Relayer: Send + Sync {
@@ -18,6 +20,11 @@ pub trait Relayer: Send + Sync {
     }
 
     async fn relay(&mut self) -> Result<Option<FunctionCall>>;
+
+    /// How many blocks the relayer is behind the relayed chain, `None` if it is unknown.
+    fn lag(&self) -> Option<u64> {
+        None
+    }
 }
 
 #[async_trait]
@@ -0,0 +1,39 @@
+// Copyright (c) RoochNetwork
+// SPDX-License-Identifier: Apache-2.0
+
+use metrics::{register, Opts, PrometheusError, Registry, UIntCounterVec, UIntGaugeVec};
+
+#[derive(Clone)]
+pub struct RelayerMetrics {
+    pub relayer_lag: UIntGaugeVec,
+    pub relayer_relay_tx_total: UIntCounterVec,
+}
+
+impl RelayerMetrics {
+    pub fn register(registry: &Registry) -> Result<Self, PrometheusError> {
+        let relayer_lag = register(
+            UIntGaugeVec::new(
+                Opts::new(
+                    "relayer_lag",
+                    "How many blocks the relayer is behind the relayed chain",
+                ),
+                &["chain"],
+            )?,
+            registry,
+        )?;
+        let relayer_relay_tx_total = register(
+            UIntCounterVec::new(
+                Opts::new(
+                    "relayer_relay_tx_total",
+                    "Counters of the relay transactions submitted by the relayer",
+                ),
+                &["chain", "result"],
+            )?,
+            registry,
+        )?;
+        Ok(Self {
+            relayer_lag,
+            relayer_relay_tx_total,
+        })
+    }
+}
@@ -0,0 +1,26 @@
+// Copyright (c) RoochNetwork
+// SPDX-License-Identifier: Apache-2.0
+
+use crate::actor::messages::{GetRelayerStatusMessage, RelayerStatus, SetRelayerPausedMessage};
+use crate::actor::relayer::RelayerActor;
+use anyhow::Result;
+use coerce::actor::ActorRef;
+
+#[derive(Clone)]
+pub struct RelayerProxy {
+    pub actor: ActorRef<RelayerActor>,
+}
+
+impl RelayerProxy {
+    pub fn new(actor: ActorRef<RelayerActor>) -> Self {
+        Self { actor }
+    }
+
+    pub async fn set_paused(&self, paused: bool) -> Result<()> {
+        self.actor.send(SetRelayerPausedMessage { paused }).await?
+    }
+
+    pub async fn get_status(&self) -> Result<RelayerStatus> {
+        self.actor.send(GetRelayerStatusMessage {}).await?
+    }
+}
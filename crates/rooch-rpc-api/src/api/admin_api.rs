@@ -0,0 +1,34 @@
+// Copyright (c) RoochNetwork
+// SPDX-License-Identifier: Apache-2.0
+
+use crate::jsonrpc_types::admin_view::{ActorHealthView, NodeStatusView, RelayerStatusView};
+use jsonrpsee::core::RpcResult;
+use jsonrpsee::proc_macros::rpc;
+
+/// Operator API, served on the admin port only and never exposed publicly.
+#[rpc(server, client, namespace = "admin")]
+#[async_trait]
+pub trait AdminAPI {
+    /// Get the sequencer order, startup info and the status of the proposer, DA and relayer
+    #[method(name = "getNodeStatus")]
+    async fn get_node_status(&self) -> RpcResult<NodeStatusView>;
+
+    /// Ping every actor of the node and report whether it answered in time
+    #[method(name = "healthCheck")]
+    async fn health_check(&self) -> RpcResult<Vec<ActorHealthView>>;
+
+    /// Stop relaying L1 blocks until `resumeRelayer` is called
+    #[method(name = "pauseRelayer")]
+    async fn pause_relayer(&self) -> RpcResult<RelayerStatusView>;
+
+    #[method(name = "resumeRelayer")]
+    async fn resume_relayer(&self) -> RpcResult<RelayerStatusView>;
+
+    /// Propose a block with the pending transactions now, without waiting for the timer
+    #[method(name = "proposeBlock")]
+    async fn propose_block(&self) -> RpcResult<()>;
+
+    /// Flush the memtables of all RocksDB instances to disk
+    #[method(name = "flushStore")]
+    async fn flush_store(&self) -> RpcResult<()>;
+}
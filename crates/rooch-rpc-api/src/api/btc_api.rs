@@ -1,12 +1,18 @@
 // Copyright (c) RoochNetwork
 // SPDX-License-Identifier: Apache-2.0
 
-use crate::jsonrpc_types::btc::ord::InscriptionFilterView;
+use crate::jsonrpc_types::address::BitcoinAddressView;
+use crate::jsonrpc_types::btc::brc20::{BRC20OperationFilterView, BRC20TickView};
+use crate::jsonrpc_types::btc::ord::{InscriptionContentView, InscriptionFilterView};
 use crate::jsonrpc_types::btc::utxo::UTXOFilterView;
-use crate::jsonrpc_types::{InscriptionPageView, StrView, UTXOPageView};
+use crate::jsonrpc_types::{
+    BRC20BalancePageView, BRC20OperationPageView, BRC20TickPageView, InscriptionPageView, StrView,
+    UTXOPageView,
+};
 use jsonrpsee::core::RpcResult;
 use jsonrpsee::proc_macros::rpc;
 use rooch_open_rpc_macros::open_rpc;
+use rooch_types::indexer::event_filter::IndexerEventID;
 use rooch_types::indexer::state::IndexerStateID;
 
 #[open_rpc(namespace = "btc")]
@@ -34,4 +40,45 @@ pub trait BtcAPI {
         limit: Option<StrView<usize>>,
         descending_order: Option<bool>,
     ) -> RpcResult<InscriptionPageView>;
+
+    /// Get the raw content of the Inscription with the content type, the inscription id is in the `<txid>i<index>` format
+    #[method(name = "getInscriptionContent")]
+    async fn get_inscription_content(
+        &self,
+        inscription_id: String,
+    ) -> RpcResult<Option<InscriptionContentView>>;
+
+    /// Get the BRC20 tick info via global index by tick
+    #[method(name = "getBRC20Tick")]
+    async fn get_brc20_tick(&self, tick: String) -> RpcResult<Option<BRC20TickView>>;
+
+    /// List the BRC20 ticks via global index, ordered by tick
+    #[method(name = "listBRC20Ticks")]
+    async fn list_brc20_ticks(
+        &self,
+        // exclusive cursor if `Some`, otherwise start from the beginning
+        cursor: Option<String>,
+        limit: Option<StrView<usize>>,
+    ) -> RpcResult<BRC20TickPageView>;
+
+    /// Get the BRC20 balances of the bitcoin address via global index, ordered by tick
+    #[method(name = "getBRC20Balances")]
+    async fn get_brc20_balances(
+        &self,
+        address: BitcoinAddressView,
+        // exclusive cursor if `Some`, otherwise start from the beginning
+        cursor: Option<String>,
+        limit: Option<StrView<usize>>,
+    ) -> RpcResult<BRC20BalancePageView>;
+
+    /// Query the BRC20 operations via global index by BRC20 operation filter
+    #[method(name = "queryBRC20Operations")]
+    async fn query_brc20_operations(
+        &self,
+        filter: BRC20OperationFilterView,
+        // exclusive cursor if `Some`, otherwise start from the beginning
+        cursor: Option<IndexerEventID>,
+        limit: Option<StrView<usize>>,
+        descending_order: Option<bool>,
+    ) -> RpcResult<BRC20OperationPageView>;
 }
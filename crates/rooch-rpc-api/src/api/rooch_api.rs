@@ -3,14 +3,15 @@
 
 use crate::jsonrpc_types::account_view::BalanceInfoView;
 use crate::jsonrpc_types::event_view::EventFilterView;
+use crate::jsonrpc_types::mempool_view::{MempoolStatusView, PendingTransactionView};
 use crate::jsonrpc_types::transaction_view::{TransactionFilterView, TransactionWithInfoView};
 use crate::jsonrpc_types::{
     AccessPathView, AccountAddressView, AnnotatedFunctionResultView, BalanceInfoPageView,
-    BytesView, EventOptions, EventPageView, ExecuteTransactionResponseView, FunctionCallView,
-    GlobalStateFilterView, H256View, IndexerEventPageView, IndexerGlobalStatePageView,
-    IndexerTableChangeSetPageView, IndexerTableStatePageView, StateOptions, StateSyncFilterView,
-    StateView, StatesPageView, StrView, StructTagView, TableStateFilterView,
-    TransactionWithInfoPageView,
+    BytesView, DryRunTransactionResponseView, EventOptions, EventPageView,
+    ExecuteTransactionResponseView, FunctionCallView, GlobalStateFilterView, H256View,
+    IndexerEventPageView, IndexerGlobalStatePageView, IndexerTableChangeSetPageView,
+    IndexerTableStatePageView, SessionKeyPageView, StateOptions, StateSyncFilterView, StateView,
+    StatesPageView, StrView, StructTagView, TableStateFilterView, TransactionWithInfoPageView,
 };
 use jsonrpsee::core::RpcResult;
 use jsonrpsee::proc_macros::rpc;
@@ -38,6 +39,28 @@ pub trait RoochAPI {
         tx_bcs_hex: BytesView,
     ) -> RpcResult<ExecuteTransactionResponseView>;
 
+    /// Send the sponsored transaction signed by both the sender and the fee payer in bcs hex format
+    /// This method does not block waiting for the transaction to be executed.
+    #[method(name = "sendRawSponsoredTransaction")]
+    async fn send_raw_sponsored_transaction(&self, tx_bcs_hex: BytesView) -> RpcResult<H256View>;
+
+    /// Send the sponsored transaction signed by both the sender and the fee payer in bcs hex format
+    /// This method blocks waiting for the transaction to be executed.
+    #[method(name = "executeRawSponsoredTransaction")]
+    async fn execute_raw_sponsored_transaction(
+        &self,
+        tx_bcs_hex: BytesView,
+    ) -> RpcResult<ExecuteTransactionResponseView>;
+
+    /// Execute the signed transaction in bcs hex format without applying the state changes.
+    /// If `profile_gas` is true, the gas profile of the execution is returned.
+    #[method(name = "dryRunRawTransaction")]
+    async fn dry_run_raw_transaction(
+        &self,
+        tx_bcs_hex: BytesView,
+        profile_gas: Option<bool>,
+    ) -> RpcResult<DryRunTransactionResponseView>;
+
     /// Execute a read-only function call
     /// The function do not change the state of Application
     #[method(name = "executeViewFunction")]
@@ -106,6 +129,15 @@ pub trait RoochAPI {
         limit: Option<StrView<usize>>,
     ) -> RpcResult<BalanceInfoPageView>;
 
+    /// get account session keys by AccountAddress
+    #[method(name = "getSessionKeys")]
+    async fn get_session_keys(
+        &self,
+        account_addr: AccountAddressView,
+        cursor: Option<BytesView>,
+        limit: Option<StrView<usize>>,
+    ) -> RpcResult<SessionKeyPageView>;
+
     /// Query the transactions indexer by transaction filter
     #[method(name = "queryTransactions")]
     async fn query_transactions(
@@ -160,4 +192,16 @@ pub trait RoochAPI {
         limit: Option<StrView<usize>>,
         descending_order: Option<bool>,
     ) -> RpcResult<IndexerTableChangeSetPageView>;
+
+    /// Get the pending transactions in the mempool, optionally filtered by sender
+    #[method(name = "getPendingTransactions")]
+    async fn get_pending_transactions(
+        &self,
+        sender: Option<AccountAddressView>,
+        limit: Option<StrView<usize>>,
+    ) -> RpcResult<Vec<PendingTransactionView>>;
+
+    /// Get the status of the mempool
+    #[method(name = "getMempoolStatus")]
+    async fn get_mempool_status(&self) -> RpcResult<MempoolStatusView>;
 }
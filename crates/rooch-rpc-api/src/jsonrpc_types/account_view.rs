@@ -2,8 +2,9 @@
 // SPDX-License-Identifier: Apache-2.0
 
 use super::CoinInfoView;
-use crate::jsonrpc_types::StrView;
+use crate::jsonrpc_types::{BytesView, StrView};
 use move_core_types::u256::U256;
+use rooch_types::framework::session_key::SessionKey;
 use schemars::JsonSchema;
 use serde::{Deserialize, Serialize};
 use std::ops::Div;
@@ -32,3 +33,33 @@ impl BalanceInfoView {
         balance.to_string()
     }
 }
+
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
+pub struct SessionKeyView {
+    pub authentication_key: BytesView,
+    pub scopes: Vec<String>,
+    pub create_time: u64,
+    pub last_active_time: u64,
+    pub max_inactive_interval: u64,
+    /// Whether the session key is expired at the on-chain timestamp
+    pub is_expired: bool,
+}
+
+impl SessionKeyView {
+    /// Create the view of the session key, `now_seconds` is the on-chain timestamp in seconds
+    pub fn new(session_key: SessionKey, now_seconds: u64) -> Self {
+        let is_expired = session_key.is_expired_at(now_seconds);
+        Self {
+            authentication_key: StrView(session_key.authentication_key),
+            scopes: session_key
+                .scopes
+                .iter()
+                .map(|scope| scope.to_string())
+                .collect(),
+            create_time: session_key.create_time,
+            last_active_time: session_key.last_active_time,
+            max_inactive_interval: session_key.max_inactive_interval,
+            is_expired,
+        }
+    }
+}
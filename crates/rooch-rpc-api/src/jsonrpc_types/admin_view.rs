@@ -0,0 +1,58 @@
+// Copyright (c) RoochNetwork
+// SPDX-License-Identifier: Apache-2.0
+
+use crate::jsonrpc_types::{H256View, StrView};
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
+pub struct ProposerStatusView {
+    pub last_block_number: Option<StrView<u128>>,
+    pub pending_tx_count: StrView<u64>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
+pub struct DAStatusView {
+    pub servers: Vec<String>,
+    pub submit_threshold: StrView<usize>,
+    pub last_submitted_block_number: Option<StrView<u128>>,
+    /// The number of batches at least one DA server failed to accept
+    pub failed_batch_count: StrView<u64>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
+pub struct RelayerStatusView {
+    pub paused: bool,
+    pub relayers: Vec<String>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
+pub struct NodeStatusView {
+    /// The order of the last sequenced transaction
+    pub sequencer_order: StrView<u64>,
+    /// The state root recorded in the startup info, None before genesis is initialized
+    pub state_root: Option<H256View>,
+    /// Whether the node is a read-only follower of an upstream node
+    pub follower: bool,
+    pub proposer: ProposerStatusView,
+    pub da: DAStatusView,
+    /// None if the relayer is not running
+    pub relayer: Option<RelayerStatusView>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
+pub struct ActorHealthView {
+    pub name: String,
+    pub healthy: bool,
+    pub error: Option<String>,
+}
+
+impl ActorHealthView {
+    pub fn new(name: &str, result: anyhow::Result<()>) -> Self {
+        Self {
+            name: name.to_owned(),
+            healthy: result.is_ok(),
+            error: result.err().map(|e| e.to_string()),
+        }
+    }
+}
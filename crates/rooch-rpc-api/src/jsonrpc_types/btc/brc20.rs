@@ -0,0 +1,155 @@
+// Copyright (c) RoochNetwork
+// SPDX-License-Identifier: Apache-2.0
+
+use crate::jsonrpc_types::address::BitcoinAddressView;
+use crate::jsonrpc_types::{AccountAddressView, H256View, StrView};
+use move_core_types::account_address::AccountAddress;
+use move_core_types::u256::U256;
+use rooch_types::address::BitcoinAddress;
+use rooch_types::indexer::brc20::{
+    BRC20OperationFilter, IndexerBRC20Balance, IndexerBRC20Operation, IndexerBRC20Tick,
+};
+use rooch_types::indexer::event_filter::IndexerEventID;
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+
+#[derive(Debug, Clone, Deserialize, Serialize, JsonSchema)]
+#[serde(rename_all = "snake_case")]
+pub enum BRC20OperationFilterView {
+    /// Query by tick
+    Tick(String),
+    /// Query by the sender or receiver of the operation, represent by bitcoin address
+    Address(BitcoinAddressView),
+    /// Query by the operation type, deploy, mint or transfer
+    Op(String),
+    /// Return the operations in the given transaction
+    TxHash(H256View),
+}
+
+impl BRC20OperationFilterView {
+    pub fn into_brc20_operation_filter(
+        filter: BRC20OperationFilterView,
+        resolve_address: AccountAddress,
+    ) -> BRC20OperationFilter {
+        match filter {
+            BRC20OperationFilterView::Tick(tick) => BRC20OperationFilter::Tick(tick),
+            BRC20OperationFilterView::Address(_address) => {
+                BRC20OperationFilter::Address(resolve_address)
+            }
+            BRC20OperationFilterView::Op(op) => BRC20OperationFilter::Op(op),
+            BRC20OperationFilterView::TxHash(tx_hash) => {
+                BRC20OperationFilter::TxHash(tx_hash.into())
+            }
+        }
+    }
+}
+
+fn format_bitcoin_address(
+    bitcoin_address: Option<BitcoinAddress>,
+    network: u8,
+) -> Result<Option<String>, anyhow::Error> {
+    match bitcoin_address {
+        Some(baddress) => Ok(Some(baddress.format(network)?)),
+        None => Ok(None),
+    }
+}
+
+#[derive(Clone, Debug, Serialize, Deserialize, JsonSchema)]
+pub struct BRC20TickView {
+    pub tick: String,
+    pub max: StrView<U256>,
+    pub lim: StrView<U256>,
+    pub dec: u64,
+    pub supply: StrView<U256>,
+    pub deployer: AccountAddressView,
+    pub deployer_bitcoin_address: Option<String>,
+    pub tx_order: u64,
+    pub created_at: u64,
+    pub updated_at: u64,
+}
+
+impl BRC20TickView {
+    pub fn try_new_from_indexer_tick(
+        tick: IndexerBRC20Tick,
+        deployer_bitcoin_address: Option<BitcoinAddress>,
+        network: u8,
+    ) -> Result<BRC20TickView, anyhow::Error> {
+        Ok(BRC20TickView {
+            tick: tick.tick,
+            max: tick.max.into(),
+            lim: tick.lim.into(),
+            dec: tick.dec,
+            supply: tick.supply.into(),
+            deployer: tick.deployer.into(),
+            deployer_bitcoin_address: format_bitcoin_address(deployer_bitcoin_address, network)?,
+            tx_order: tick.tx_order,
+            created_at: tick.created_at,
+            updated_at: tick.updated_at,
+        })
+    }
+}
+
+#[derive(Clone, Debug, Serialize, Deserialize, JsonSchema)]
+pub struct BRC20BalanceView {
+    pub tick: String,
+    pub owner: AccountAddressView,
+    pub owner_bitcoin_address: Option<String>,
+    pub balance: StrView<U256>,
+    pub tx_order: u64,
+    pub updated_at: u64,
+}
+
+impl BRC20BalanceView {
+    pub fn try_new_from_indexer_balance(
+        balance: IndexerBRC20Balance,
+        owner_bitcoin_address: Option<BitcoinAddress>,
+        network: u8,
+    ) -> Result<BRC20BalanceView, anyhow::Error> {
+        Ok(BRC20BalanceView {
+            tick: balance.tick,
+            owner: balance.owner.into(),
+            owner_bitcoin_address: format_bitcoin_address(owner_bitcoin_address, network)?,
+            balance: balance.balance.into(),
+            tx_order: balance.tx_order,
+            updated_at: balance.updated_at,
+        })
+    }
+}
+
+#[derive(Clone, Debug, Serialize, Deserialize, JsonSchema)]
+pub struct BRC20OperationView {
+    pub indexer_event_id: IndexerEventID,
+    pub tx_hash: H256View,
+    pub op: String,
+    pub tick: String,
+    pub from: AccountAddressView,
+    pub from_bitcoin_address: Option<String>,
+    pub to: AccountAddressView,
+    pub to_bitcoin_address: Option<String>,
+    pub amt: String,
+    pub success: bool,
+    pub created_at: u64,
+}
+
+impl BRC20OperationView {
+    pub fn try_new_from_indexer_operation(
+        operation: IndexerBRC20Operation,
+        from_bitcoin_address: Option<BitcoinAddress>,
+        to_bitcoin_address: Option<BitcoinAddress>,
+        network: u8,
+    ) -> Result<BRC20OperationView, anyhow::Error> {
+        Ok(BRC20OperationView {
+            indexer_event_id: operation.indexer_event_id,
+            tx_hash: operation.tx_hash.into(),
+            op: operation.op,
+            tick: operation.tick,
+            from: operation.from.into(),
+            from_bitcoin_address: format_bitcoin_address(from_bitcoin_address, network)?,
+            to: operation.to.into(),
+            to_bitcoin_address: format_bitcoin_address(to_bitcoin_address, network)?,
+            amt: operation.amt,
+            success: operation.success,
+            created_at: operation.created_at,
+        })
+    }
+}
@@ -10,6 +10,7 @@ use move_core_types::account_address::AccountAddress;
 use moveos_types::move_std::string::MoveString;
 use moveos_types::{moveos_std::object::ObjectID, state::MoveStructType};
 use rooch_types::bitcoin::ord::{Inscription, InscriptionState};
+use rooch_types::indexer::inscription::InscriptionFilter;
 use rooch_types::indexer::state::GlobalStateFilter;
 use schemars::JsonSchema;
 use serde::{Deserialize, Serialize};
@@ -25,6 +26,15 @@ pub enum InscriptionFilterView {
     // Txid(TxidView),
     /// Query by object id.
     ObjectId(ObjectID),
+    /// Query by content type, such as `image/png`
+    ContentType(String),
+    /// Query by media class of the content type, such as `image`, `text` or `video`
+    MediaClass(String),
+    /// Query the children of the parent inscription
+    Parent(ObjectID),
+    // TODO The sat ranges of the UTXOs are not tracked, an inscription is moved to the first output of
+    // the spending tx instead of following its sat, so there is no sat-range owner filter. The `Owner`
+    // filter matches the owner of the output which holds the inscription.
 }
 
 impl InscriptionFilterView {
@@ -41,10 +51,31 @@ impl InscriptionFilterView {
                 InscriptionFilterView::ObjectId(object_id) => {
                     GlobalStateFilter::ObjectId(object_id)
                 }
+                InscriptionFilterView::ContentType(_)
+                | InscriptionFilterView::MediaClass(_)
+                | InscriptionFilterView::Parent(_) => {
+                    GlobalStateFilter::ObjectType(Inscription::struct_tag())
+                }
             },
             None => GlobalStateFilter::ObjectType(Inscription::struct_tag()),
         }
     }
+
+    /// The filters backed by the inscriptions index, return None if the filter is a global state filter
+    pub fn into_inscription_filter(
+        filter_opt: Option<InscriptionFilterView>,
+    ) -> Option<InscriptionFilter> {
+        match filter_opt {
+            Some(InscriptionFilterView::ContentType(content_type)) => {
+                Some(InscriptionFilter::ContentType(content_type))
+            }
+            Some(InscriptionFilterView::MediaClass(media_class)) => {
+                Some(InscriptionFilter::MediaClass(media_class))
+            }
+            Some(InscriptionFilterView::Parent(parent)) => Some(InscriptionFilter::Parent(parent)),
+            _ => None,
+        }
+    }
 }
 
 #[derive(Debug, Clone, Deserialize, Serialize, JsonSchema)]
@@ -84,6 +115,23 @@ impl From<Inscription> for InscriptionView {
     }
 }
 
+#[derive(Debug, Clone, Deserialize, Serialize, JsonSchema)]
+pub struct InscriptionContentView {
+    pub content_type: Option<MoveStringView>,
+    pub content_encoding: Option<MoveStringView>,
+    pub body: BytesView,
+}
+
+impl From<Inscription> for InscriptionContentView {
+    fn from(inscription: Inscription) -> Self {
+        InscriptionContentView {
+            content_type: Option::<MoveString>::from(inscription.content_type).map(StrView),
+            content_encoding: Option::<MoveString>::from(inscription.content_encoding).map(StrView),
+            body: StrView(inscription.body),
+        }
+    }
+}
+
 #[derive(Clone, Debug, Serialize, Deserialize, JsonSchema)]
 pub struct InscriptionStateView {
     pub object_id: ObjectID,
@@ -3,14 +3,16 @@
 
 use super::BytesView;
 use super::{ModuleIdView, StateChangeSetView, StrView};
-use crate::jsonrpc_types::event_view::EventView;
+use crate::jsonrpc_types::event_view::{EventView, TransactionEventView};
 use crate::jsonrpc_types::H256View;
 use move_core_types::vm_status::{AbortLocation, KeptVMStatus};
-use moveos_types::transaction::TransactionExecutionInfo;
-use moveos_types::transaction::TransactionOutput;
+use moveos_types::gas_profile::{FunctionGasProfile, GasProfile};
+use moveos_types::transaction::{GasStatement, TransactionExecutionInfo};
+use moveos_types::transaction::{RawTransactionOutput, TransactionOutput};
 use rooch_types::transaction::{authenticator::Authenticator, TransactionSequenceInfo};
 use schemars::JsonSchema;
 use serde::{Deserialize, Serialize};
+use std::collections::BTreeMap;
 use std::str::FromStr;
 
 pub type AbortLocationView = StrView<AbortLocation>;
@@ -94,6 +96,15 @@ impl From<Authenticator> for AuthenticatorView {
         }
     }
 }
+
+impl From<AuthenticatorView> for Authenticator {
+    fn from(authenticator: AuthenticatorView) -> Self {
+        Self {
+            auth_validator_id: authenticator.auth_validator_id.0,
+            payload: authenticator.payload.0,
+        }
+    }
+}
 #[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
 pub struct TransactionSequenceInfoView {
     pub tx_order: StrView<u64>,
@@ -113,6 +124,16 @@ impl From<TransactionSequenceInfo> for TransactionSequenceInfoView {
     }
 }
 
+impl From<TransactionSequenceInfoView> for TransactionSequenceInfo {
+    fn from(transaction_sequence_info: TransactionSequenceInfoView) -> Self {
+        Self {
+            tx_order: transaction_sequence_info.tx_order.0,
+            tx_order_signature: transaction_sequence_info.tx_order_signature.into(),
+            tx_accumulator_root: transaction_sequence_info.tx_accumulator_root.into(),
+        }
+    }
+}
+
 #[derive(Clone, Debug, Serialize, Deserialize, JsonSchema)]
 pub struct TransactionExecutionInfoView {
     pub tx_hash: H256View,
@@ -134,6 +155,31 @@ impl From<TransactionExecutionInfo> for TransactionExecutionInfoView {
     }
 }
 
+#[derive(Clone, Debug, Serialize, Deserialize, JsonSchema)]
+pub struct GasStatementView {
+    pub execution_gas_used: u64,
+    pub storage_gas_used: u64,
+    pub tx_size_gas_used: u64,
+    pub new_state_gas_used: u64,
+    pub modify_state_gas_used: u64,
+    pub delete_state_gas_used: u64,
+    pub event_gas_used: u64,
+}
+
+impl From<GasStatement> for GasStatementView {
+    fn from(gas_statement: GasStatement) -> Self {
+        Self {
+            execution_gas_used: gas_statement.execution_gas_used,
+            storage_gas_used: gas_statement.storage_gas_used,
+            tx_size_gas_used: gas_statement.tx_size_gas_used,
+            new_state_gas_used: gas_statement.new_state_gas_used,
+            modify_state_gas_used: gas_statement.modify_state_gas_used,
+            delete_state_gas_used: gas_statement.delete_state_gas_used,
+            event_gas_used: gas_statement.event_gas_used,
+        }
+    }
+}
+
 #[derive(Clone, Debug, Serialize, Deserialize, JsonSchema)]
 pub struct TransactionOutputView {
     pub status: KeptVMStatusView,
@@ -142,6 +188,7 @@ pub struct TransactionOutputView {
     pub table_changeset: StateChangeSetView,
     pub events: Vec<EventView>,
     pub gas_used: u64,
+    pub gas_statement: GasStatementView,
     pub is_upgrade: bool,
 }
 
@@ -156,6 +203,7 @@ impl From<TransactionOutput> for TransactionOutputView {
                 .map(|event| event.into())
                 .collect(),
             gas_used: tx_output.gas_used,
+            gas_statement: tx_output.gas_statement.into(),
             is_upgrade: tx_output.is_upgrade,
         }
     }
@@ -184,3 +232,98 @@ impl From<ExecuteTransactionResponse> for ExecuteTransactionResponseView {
         }
     }
 }
+
+#[derive(Clone, Debug, Serialize, Deserialize, JsonSchema)]
+pub struct FunctionGasProfileView {
+    pub calls: u64,
+    pub instructions: u64,
+    pub gas_used: u64,
+}
+
+impl From<FunctionGasProfile> for FunctionGasProfileView {
+    fn from(profile: FunctionGasProfile) -> Self {
+        Self {
+            calls: profile.calls,
+            instructions: profile.instructions,
+            gas_used: profile.gas_used,
+        }
+    }
+}
+
+#[derive(Clone, Debug, Serialize, Deserialize, JsonSchema)]
+pub struct GasProfileView {
+    pub execution_gas_used: u64,
+    pub storage_gas_used: u64,
+    pub instructions: u64,
+    pub functions: BTreeMap<String, FunctionGasProfileView>,
+    pub natives: BTreeMap<String, FunctionGasProfileView>,
+    /// The gas used by each call stack, the frames are separated by `;`
+    pub folded_stacks: BTreeMap<String, u64>,
+}
+
+impl From<GasProfile> for GasProfileView {
+    fn from(profile: GasProfile) -> Self {
+        Self {
+            execution_gas_used: profile.execution_gas_used,
+            storage_gas_used: profile.storage_gas_used,
+            instructions: profile.instructions,
+            functions: profile
+                .functions
+                .into_iter()
+                .map(|(name, profile)| (name, profile.into()))
+                .collect(),
+            natives: profile
+                .natives
+                .into_iter()
+                .map(|(name, profile)| (name, profile.into()))
+                .collect(),
+            folded_stacks: profile.folded_stacks,
+        }
+    }
+}
+
+impl GasProfileView {
+    /// Render the call stacks in the folded stack format, which can be read by flamegraph tools.
+    pub fn to_folded_stacks(&self) -> String {
+        self.folded_stacks
+            .iter()
+            .map(|(stack, gas)| format!("{} {}\n", stack, gas))
+            .collect()
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct DryRunTransactionResponse {
+    pub output: RawTransactionOutput,
+    pub gas_profile: Option<GasProfile>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
+pub struct DryRunTransactionResponseView {
+    pub status: KeptVMStatusView,
+    pub table_changeset: StateChangeSetView,
+    pub events: Vec<TransactionEventView>,
+    pub gas_used: u64,
+    pub gas_statement: GasStatementView,
+    pub is_upgrade: bool,
+    pub gas_profile: Option<GasProfileView>,
+}
+
+impl From<DryRunTransactionResponse> for DryRunTransactionResponseView {
+    fn from(response: DryRunTransactionResponse) -> Self {
+        let output = response.output;
+        Self {
+            status: output.status.into(),
+            table_changeset: output.state_changeset.into(),
+            events: output
+                .events
+                .into_iter()
+                .map(|event| event.into())
+                .collect(),
+            gas_used: output.gas_used,
+            gas_statement: output.gas_statement.into(),
+            is_upgrade: output.is_upgrade,
+            gas_profile: response.gas_profile.map(Into::into),
+        }
+    }
+}
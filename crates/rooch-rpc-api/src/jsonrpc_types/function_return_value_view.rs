@@ -92,6 +92,7 @@ impl TryFrom<VMStatusView> for VMStatus {
 pub struct AnnotatedFunctionResultView {
     pub vm_status: VMStatusView,
     pub return_values: Option<Vec<AnnotatedFunctionReturnValueView>>,
+    pub gas_used: StrView<u64>,
 }
 
 impl From<AnnotatedFunctionResult> for AnnotatedFunctionResultView {
@@ -101,6 +102,7 @@ impl From<AnnotatedFunctionResult> for AnnotatedFunctionResultView {
             return_values: value
                 .return_values
                 .map(|v| v.into_iter().map(|v| v.into()).collect()),
+            gas_used: StrView(value.gas_used),
         }
     }
 }
@@ -116,6 +118,7 @@ impl TryFrom<AnnotatedFunctionResultView> for FunctionResult {
                     .map(|v| v.value.into())
                     .collect::<Vec<FunctionReturnValue>>()
             }),
+            gas_used: value.gas_used.0,
         })
     }
 }
@@ -0,0 +1,57 @@
+// Copyright (c) RoochNetwork
+// SPDX-License-Identifier: Apache-2.0
+
+use crate::jsonrpc_types::{AccountAddressView, H256View, StrView};
+use rooch_types::mempool::{MempoolStatus, PendingTransaction};
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
+pub struct PendingTransactionView {
+    pub tx_hash: H256View,
+    pub sender: AccountAddressView,
+    pub sequence_number: StrView<u64>,
+    pub gas_price: StrView<u64>,
+    pub tx_size: StrView<u64>,
+    /// Whether the transaction can be sequenced now
+    pub ready: bool,
+    /// The time in milliseconds the transaction has been waiting in the mempool
+    pub waiting_millis: StrView<u64>,
+}
+
+impl From<PendingTransaction> for PendingTransactionView {
+    fn from(tx: PendingTransaction) -> Self {
+        Self {
+            tx_hash: tx.tx_hash.into(),
+            sender: tx.sender.into(),
+            sequence_number: tx.sequence_number.into(),
+            gas_price: tx.gas_price.into(),
+            tx_size: tx.tx_size.into(),
+            ready: tx.ready,
+            waiting_millis: tx.waiting_millis.into(),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
+pub struct MempoolStatusView {
+    pub pending_count: StrView<u64>,
+    pub ready_count: StrView<u64>,
+    pub sender_count: StrView<u64>,
+    pub total_bytes: StrView<u64>,
+    pub max_count: StrView<u64>,
+    pub max_bytes: StrView<u64>,
+}
+
+impl From<MempoolStatus> for MempoolStatusView {
+    fn from(status: MempoolStatus) -> Self {
+        Self {
+            pending_count: status.pending_count.into(),
+            ready_count: status.ready_count.into(),
+            sender_count: status.sender_count.into(),
+            total_bytes: status.total_bytes.into(),
+            max_count: status.max_count.into(),
+            max_bytes: status.max_bytes.into(),
+        }
+    }
+}
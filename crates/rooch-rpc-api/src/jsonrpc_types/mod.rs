@@ -15,8 +15,10 @@ mod tests;
 mod transaction_argument_view;
 
 pub mod account_view;
+pub mod admin_view;
 pub mod eth;
 pub mod event_view;
+pub mod mempool_view;
 pub mod transaction_view;
 
 pub mod address;
@@ -238,6 +238,12 @@ pub struct FunctionCallView {
     pub function_id: FunctionIdView,
     pub ty_args: Vec<TypeTagView>,
     pub args: Vec<BytesView>,
+    /// The sender of the view function call, only used when executing view function, default is 0x0
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub sender: Option<AccountAddressView>,
+    /// The max gas amount of the view function call, only used when executing view function
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub max_gas_amount: Option<StrView<u64>>,
 }
 
 impl From<FunctionCall> for FunctionCallView {
@@ -246,6 +252,8 @@ impl From<FunctionCall> for FunctionCallView {
             function_id: StrView(origin.function_id),
             ty_args: origin.ty_args.into_iter().map(StrView).collect(),
             args: origin.args.into_iter().map(StrView).collect(),
+            sender: None,
+            max_gas_amount: None,
         }
     }
 }
@@ -1,7 +1,8 @@
 // Copyright (c) RoochNetwork
 // SPDX-License-Identifier: Apache-2.0
 
-use crate::jsonrpc_types::account_view::BalanceInfoView;
+use crate::jsonrpc_types::account_view::{BalanceInfoView, SessionKeyView};
+use crate::jsonrpc_types::btc::brc20::{BRC20BalanceView, BRC20OperationView, BRC20TickView};
 use crate::jsonrpc_types::btc::ord::InscriptionStateView;
 use crate::jsonrpc_types::btc::utxo::UTXOStateView;
 use crate::jsonrpc_types::event_view::{EventView, IndexerEventView};
@@ -15,7 +16,9 @@ use move_core_types::u256::U256;
 use rooch_types::framework::coin::CoinInfo;
 use rooch_types::indexer::event_filter::IndexerEventID;
 use rooch_types::indexer::state::IndexerStateID;
-use rooch_types::transaction::{AbstractTransaction, TransactionType, TypedTransaction};
+use rooch_types::transaction::{
+    AbstractTransaction, RawTransaction, TransactionType, TypedTransaction,
+};
 use schemars::JsonSchema;
 use serde::{Deserialize, Serialize};
 use std::string::String;
@@ -24,6 +27,7 @@ pub type EventPageView = PageView<EventView, u64>;
 pub type TransactionWithInfoPageView = PageView<TransactionWithInfoView, u64>;
 pub type StatesPageView = PageView<KeyStateKVView, BytesView>;
 pub type BalanceInfoPageView = PageView<BalanceInfoView, BytesView>;
+pub type SessionKeyPageView = PageView<SessionKeyView, BytesView>;
 pub type IndexerEventPageView = PageView<IndexerEventView, IndexerEventID>;
 pub type IndexerTableChangeSetPageView = PageView<IndexerTableChangeSetView, IndexerStateID>;
 
@@ -32,6 +36,9 @@ pub type IndexerTableStatePageView = PageView<IndexerTableStateView, IndexerStat
 
 pub type UTXOPageView = PageView<UTXOStateView, IndexerStateID>;
 pub type InscriptionPageView = PageView<InscriptionStateView, IndexerStateID>;
+pub type BRC20TickPageView = PageView<BRC20TickView, String>;
+pub type BRC20BalancePageView = PageView<BRC20BalanceView, String>;
+pub type BRC20OperationPageView = PageView<BRC20OperationView, IndexerEventID>;
 
 /// `next_cursor` points to the last item in the page;
 /// Reading with `next_cursor` will start from the next item after `next_cursor` if
@@ -48,6 +55,7 @@ pub struct PageView<T, C> {
 pub enum TransactionTypeView {
     Rooch,
     Ethereum,
+    RoochSponsored,
 }
 
 impl From<TransactionType> for TransactionTypeView {
@@ -55,6 +63,17 @@ impl From<TransactionType> for TransactionTypeView {
         match tt {
             TransactionType::Rooch => Self::Rooch,
             TransactionType::Ethereum => Self::Ethereum,
+            TransactionType::RoochSponsored => Self::RoochSponsored,
+        }
+    }
+}
+
+impl From<TransactionTypeView> for TransactionType {
+    fn from(tt: TransactionTypeView) -> Self {
+        match tt {
+            TransactionTypeView::Rooch => Self::Rooch,
+            TransactionTypeView::Ethereum => Self::Ethereum,
+            TransactionTypeView::RoochSponsored => Self::RoochSponsored,
         }
     }
 }
@@ -70,6 +89,17 @@ pub struct TransactionView {
     pub raw: BytesView,
 }
 
+impl TryFrom<TransactionView> for TypedTransaction {
+    type Error = anyhow::Error;
+
+    fn try_from(transaction: TransactionView) -> Result<Self, Self::Error> {
+        TypedTransaction::try_from(RawTransaction {
+            transaction_type: transaction.transaction_type.into(),
+            raw: transaction.raw.0,
+        })
+    }
+}
+
 impl From<TypedTransaction> for TransactionView {
     fn from(transaction: TypedTransaction) -> Self {
         let transaction_type = transaction.transaction_type();
@@ -90,6 +120,14 @@ impl From<TypedTransaction> for TransactionView {
                 action_type: eth.decode_calldata_to_action().unwrap().into(),
                 raw: eth.encode().into(),
             },
+            TypedTransaction::RoochSponsored(rooch) => Self {
+                transaction_type: transaction_type.into(),
+                sequence_number: rooch.sequence_number(),
+                sender: rooch.sender().to_string(),
+                action: rooch.action().clone().into(),
+                action_type: rooch.action().clone().into(),
+                raw: rooch.encode().into(),
+            },
         }
     }
 }
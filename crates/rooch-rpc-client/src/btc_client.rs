@@ -0,0 +1,184 @@
+// Copyright (c) RoochNetwork
+// SPDX-License-Identifier: Apache-2.0
+
+use crate::pagination::paginate;
+use crate::retry::{retry, RetryConfig};
+use anyhow::Result;
+use futures::Stream;
+use jsonrpsee::http_client::HttpClient;
+use rooch_rpc_api::api::btc_api::BtcAPIClient;
+use rooch_rpc_api::jsonrpc_types::address::BitcoinAddressView;
+use rooch_rpc_api::jsonrpc_types::btc::brc20::{
+    BRC20OperationFilterView, BRC20OperationView, BRC20TickView,
+};
+use rooch_rpc_api::jsonrpc_types::btc::ord::{
+    InscriptionContentView, InscriptionFilterView, InscriptionStateView,
+};
+use rooch_rpc_api::jsonrpc_types::btc::utxo::{UTXOFilterView, UTXOStateView};
+use rooch_rpc_api::jsonrpc_types::{
+    BRC20BalancePageView, BRC20OperationPageView, BRC20TickPageView, InscriptionPageView,
+    UTXOPageView,
+};
+use rooch_types::indexer::event_filter::IndexerEventID;
+use rooch_types::indexer::state::IndexerStateID;
+use std::sync::Arc;
+
+#[derive(Clone, Debug)]
+pub struct BtcRpcClient {
+    http: Arc<HttpClient>,
+    retry_config: RetryConfig,
+}
+
+impl BtcRpcClient {
+    pub fn new(http: Arc<HttpClient>) -> Self {
+        Self {
+            http,
+            retry_config: RetryConfig::default(),
+        }
+    }
+
+    pub fn with_retry_config(mut self, retry_config: RetryConfig) -> Self {
+        self.retry_config = retry_config;
+        self
+    }
+
+    pub async fn query_utxos(
+        &self,
+        filter: Option<UTXOFilterView>,
+        cursor: Option<IndexerStateID>,
+        limit: Option<usize>,
+        descending_order: Option<bool>,
+    ) -> Result<UTXOPageView> {
+        Ok(retry(&self.retry_config, || {
+            self.http.query_utxos(
+                filter.clone(),
+                cursor,
+                limit.map(Into::into),
+                descending_order,
+            )
+        })
+        .await?)
+    }
+
+    pub async fn query_inscriptions(
+        &self,
+        filter: Option<InscriptionFilterView>,
+        cursor: Option<IndexerStateID>,
+        limit: Option<usize>,
+        descending_order: Option<bool>,
+    ) -> Result<InscriptionPageView> {
+        Ok(retry(&self.retry_config, || {
+            self.http.query_inscriptions(
+                filter.clone(),
+                cursor,
+                limit.map(Into::into),
+                descending_order,
+            )
+        })
+        .await?)
+    }
+
+    pub async fn get_inscription_content(
+        &self,
+        inscription_id: String,
+    ) -> Result<Option<InscriptionContentView>> {
+        Ok(retry(&self.retry_config, || {
+            self.http.get_inscription_content(inscription_id.clone())
+        })
+        .await?)
+    }
+
+    pub async fn get_brc20_tick(&self, tick: String) -> Result<Option<BRC20TickView>> {
+        Ok(retry(&self.retry_config, || {
+            self.http.get_brc20_tick(tick.clone())
+        })
+        .await?)
+    }
+
+    pub async fn list_brc20_ticks(
+        &self,
+        cursor: Option<String>,
+        limit: Option<usize>,
+    ) -> Result<BRC20TickPageView> {
+        Ok(retry(&self.retry_config, || {
+            self.http
+                .list_brc20_ticks(cursor.clone(), limit.map(Into::into))
+        })
+        .await?)
+    }
+
+    pub async fn get_brc20_balances(
+        &self,
+        address: BitcoinAddressView,
+        cursor: Option<String>,
+        limit: Option<usize>,
+    ) -> Result<BRC20BalancePageView> {
+        Ok(retry(&self.retry_config, || {
+            self.http
+                .get_brc20_balances(address.clone(), cursor.clone(), limit.map(Into::into))
+        })
+        .await?)
+    }
+
+    pub async fn query_brc20_operations(
+        &self,
+        filter: BRC20OperationFilterView,
+        cursor: Option<IndexerEventID>,
+        limit: Option<usize>,
+        descending_order: Option<bool>,
+    ) -> Result<BRC20OperationPageView> {
+        Ok(retry(&self.retry_config, || {
+            self.http.query_brc20_operations(
+                filter.clone(),
+                cursor,
+                limit.map(Into::into),
+                descending_order,
+            )
+        })
+        .await?)
+    }
+
+    /// Iterate all the UTXOs matched by the filter, `page_size` is the limit of every page request
+    pub fn query_utxos_stream(
+        &self,
+        filter: Option<UTXOFilterView>,
+        page_size: Option<usize>,
+        descending_order: Option<bool>,
+    ) -> impl Stream<Item = Result<UTXOStateView>> + '_ {
+        paginate(move |cursor| {
+            self.query_utxos(filter.clone(), cursor, page_size, descending_order)
+        })
+    }
+
+    /// Iterate all the inscriptions matched by the filter, `page_size` is the limit of every page request
+    pub fn query_inscriptions_stream(
+        &self,
+        filter: Option<InscriptionFilterView>,
+        page_size: Option<usize>,
+        descending_order: Option<bool>,
+    ) -> impl Stream<Item = Result<InscriptionStateView>> + '_ {
+        paginate(move |cursor| {
+            self.query_inscriptions(filter.clone(), cursor, page_size, descending_order)
+        })
+    }
+
+    /// Iterate all the BRC20 ticks, `page_size` is the limit of every page request
+    pub fn list_brc20_ticks_stream(
+        &self,
+        page_size: Option<usize>,
+    ) -> impl Stream<Item = Result<BRC20TickView>> + '_ {
+        paginate(move |cursor| self.list_brc20_ticks(cursor, page_size))
+    }
+
+    /// Iterate all the BRC20 operations matched by the filter, `page_size` is the limit of every page request
+    pub fn query_brc20_operations_stream(
+        &self,
+        filter: BRC20OperationFilterView,
+        page_size: Option<usize>,
+        descending_order: Option<bool>,
+    ) -> impl Stream<Item = Result<BRC20OperationView>> + '_ {
+        paginate(move |cursor| {
+            self.query_brc20_operations(filter.clone(), cursor, page_size, descending_order)
+        })
+    }
+}
@@ -2,6 +2,7 @@
 // SPDX-License-Identifier: Apache-2.0
 
 use anyhow::Result;
+use btc_client::BtcRpcClient;
 use eth_client::EthRpcClient;
 use jsonrpsee::core::client::ClientT;
 use jsonrpsee::http_client::{HttpClient, HttpClientBuilder};
@@ -15,12 +16,17 @@ use moveos_types::{
     function_return_value::FunctionResult, module_binding::MoveFunctionCaller,
     moveos_std::tx_context::TxContext, transaction::FunctionCall,
 };
+use retry::RetryConfig;
 use rooch_client::RoochRpcClient;
 use std::sync::Arc;
 use std::time::Duration;
+use tokio::runtime::{Handle, RuntimeFlavor};
 
+pub mod btc_client;
 pub mod client_config;
 pub mod eth_client;
+pub mod pagination;
+pub mod retry;
 pub mod rooch_client;
 pub mod wallet_context;
 
@@ -28,6 +34,7 @@ pub struct ClientBuilder {
     request_timeout: Duration,
     max_concurrent_requests: usize,
     ws_url: Option<String>,
+    retry_config: RetryConfig,
 }
 
 impl ClientBuilder {
@@ -46,6 +53,12 @@ impl ClientBuilder {
         self
     }
 
+    /// The retry policy of the read requests, see `RetryConfig`
+    pub fn retry_config(mut self, retry_config: RetryConfig) -> Self {
+        self.retry_config = retry_config;
+        self
+    }
+
     pub async fn build(self, http: impl AsRef<str>) -> Result<Client> {
         // TODO: add verison info
 
@@ -59,7 +72,8 @@ impl ClientBuilder {
 
         Ok(Client {
             http: http_client.clone(),
-            rooch: RoochRpcClient::new(http_client.clone()),
+            rooch: RoochRpcClient::new(http_client.clone()).with_retry_config(self.retry_config),
+            btc: BtcRpcClient::new(http_client.clone()).with_retry_config(self.retry_config),
             eth: EthRpcClient::new(http_client),
         })
     }
@@ -71,6 +85,7 @@ impl Default for ClientBuilder {
             request_timeout: Duration::from_secs(60),
             max_concurrent_requests: 256,
             ws_url: None,
+            retry_config: RetryConfig::default(),
         }
     }
 }
@@ -79,6 +94,7 @@ impl Default for ClientBuilder {
 pub struct Client {
     http: Arc<HttpClient>,
     pub rooch: RoochRpcClient,
+    pub btc: BtcRpcClient,
     pub eth: EthRpcClient,
 }
 
@@ -104,8 +120,15 @@ impl MoveFunctionCaller for Client {
         _ctx: &TxContext,
         function_call: FunctionCall,
     ) -> Result<FunctionResult> {
-        let function_result =
-            futures::executor::block_on(self.rooch.execute_view_function(function_call))?;
+        let view_function = self.rooch.execute_view_function(function_call);
+        // On the multi-thread runtime, e.g. in the standalone relayer, block in place so the other
+        // tasks of this worker keep running while waiting for the response.
+        let function_result = match Handle::try_current() {
+            Ok(handle) if handle.runtime_flavor() == RuntimeFlavor::MultiThread => {
+                tokio::task::block_in_place(|| handle.block_on(view_function))
+            }
+            _ => futures::executor::block_on(view_function),
+        }?;
         function_result.try_into()
     }
 }
@@ -0,0 +1,72 @@
+// Copyright (c) RoochNetwork
+// SPDX-License-Identifier: Apache-2.0
+
+use anyhow::Result;
+use futures::stream::{self, Stream, TryStreamExt};
+use rooch_rpc_api::jsonrpc_types::PageView;
+use std::future::Future;
+
+/// Turn a cursor based page API into a stream of items.
+/// The `fetch` is called with the cursor of the next page, starting from `None`,
+/// until the page reports there is no next page.
+pub fn paginate<T, C, F, Fut>(fetch: F) -> impl Stream<Item = Result<T>>
+where
+    F: FnMut(Option<C>) -> Fut,
+    Fut: Future<Output = Result<PageView<T, C>>>,
+{
+    stream::try_unfold(
+        (fetch, None, false),
+        |(mut fetch, cursor, finished)| async move {
+            if finished {
+                return Ok(None);
+            }
+            let page = fetch(cursor).await?;
+            // Stop if the server does not return the cursor, avoid fetching the first page again
+            let finished = !page.has_next_page || page.next_cursor.is_none();
+            Ok(Some((page.data, (fetch, page.next_cursor, finished))))
+        },
+    )
+    .map_ok(|data| stream::iter(data.into_iter().map(Ok)))
+    .try_flatten()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use futures::StreamExt;
+
+    fn page(data: Vec<u64>, next_cursor: Option<u64>, has_next_page: bool) -> PageView<u64, u64> {
+        PageView {
+            data,
+            next_cursor,
+            has_next_page,
+        }
+    }
+
+    #[tokio::test]
+    async fn test_paginate() {
+        let items: Vec<u64> = paginate(|cursor: Option<u64>| async move {
+            Ok(match cursor {
+                None => page(vec![1, 2], Some(2), true),
+                Some(2) => page(vec![3, 4], Some(4), true),
+                Some(4) => page(vec![5], Some(5), false),
+                Some(c) => panic!("unexpected cursor {}", c),
+            })
+        })
+        .try_collect()
+        .await
+        .unwrap();
+        assert_eq!(items, vec![1, 2, 3, 4, 5]);
+
+        // stop at the first error
+        let mut stream = Box::pin(paginate(|cursor: Option<u64>| async move {
+            match cursor {
+                None => Ok(page(vec![1], Some(1), true)),
+                Some(_) => Err(anyhow::anyhow!("server error")),
+            }
+        }));
+        assert_eq!(stream.next().await.unwrap().unwrap(), 1);
+        assert!(stream.next().await.unwrap().is_err());
+        assert!(stream.next().await.is_none());
+    }
+}
@@ -0,0 +1,116 @@
+// Copyright (c) RoochNetwork
+// SPDX-License-Identifier: Apache-2.0
+
+use jsonrpsee::core::Error as RpcError;
+use std::future::Future;
+use std::time::Duration;
+
+/// The retry policy of the read requests.
+/// Only the transport errors are retried, the errors returned by the server are returned immediately.
+/// The requests which change the state, such as `execute_tx`, are never retried.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct RetryConfig {
+    /// The max retry times, 0 means no retry
+    pub max_retries: usize,
+    /// The interval between two retries, it is doubled after every retry
+    pub retry_interval: Duration,
+}
+
+impl RetryConfig {
+    pub const DEFAULT_MAX_RETRIES: usize = 3;
+    pub const DEFAULT_RETRY_INTERVAL: Duration = Duration::from_millis(500);
+
+    pub fn no_retry() -> Self {
+        Self {
+            max_retries: 0,
+            retry_interval: Duration::ZERO,
+        }
+    }
+}
+
+impl Default for RetryConfig {
+    fn default() -> Self {
+        Self {
+            max_retries: Self::DEFAULT_MAX_RETRIES,
+            retry_interval: Self::DEFAULT_RETRY_INTERVAL,
+        }
+    }
+}
+
+fn is_retryable(error: &RpcError) -> bool {
+    matches!(error, RpcError::Transport(_) | RpcError::RestartNeeded(_))
+}
+
+/// Run the request, and retry it with the config if it fails with a transport error
+pub(crate) async fn retry<T, F, Fut>(config: &RetryConfig, mut request: F) -> Result<T, RpcError>
+where
+    F: FnMut() -> Fut,
+    Fut: Future<Output = Result<T, RpcError>>,
+{
+    let mut retries = 0;
+    let mut interval = config.retry_interval;
+    loop {
+        match request().await {
+            Err(e) if retries < config.max_retries && is_retryable(&e) => {
+                log::debug!(
+                    "RPC request failed with transport error: {}, retry {}/{} after {:?}",
+                    e,
+                    retries + 1,
+                    config.max_retries,
+                    interval
+                );
+                tokio::time::sleep(interval).await;
+                retries += 1;
+                interval *= 2;
+            }
+            result => return result,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::sync::atomic::{AtomicUsize, Ordering};
+
+    #[tokio::test]
+    async fn test_retry_transport_error() {
+        let config = RetryConfig {
+            max_retries: 2,
+            retry_interval: Duration::from_millis(1),
+        };
+        let calls = AtomicUsize::new(0);
+        let result = retry(&config, || async {
+            if calls.fetch_add(1, Ordering::SeqCst) < 2 {
+                Err(RpcError::Transport(anyhow::anyhow!("connection refused")))
+            } else {
+                Ok(1)
+            }
+        })
+        .await;
+        assert_eq!(result.unwrap(), 1);
+        assert_eq!(calls.load(Ordering::SeqCst), 3);
+
+        // give up after the max retries
+        let calls = AtomicUsize::new(0);
+        let result: Result<(), _> = retry(&config, || async {
+            calls.fetch_add(1, Ordering::SeqCst);
+            Err(RpcError::Transport(anyhow::anyhow!("connection refused")))
+        })
+        .await;
+        assert!(result.is_err());
+        assert_eq!(calls.load(Ordering::SeqCst), 3);
+    }
+
+    #[tokio::test]
+    async fn test_not_retry_server_error() {
+        let calls = AtomicUsize::new(0);
+        let result: Result<(), _> = retry(&RetryConfig::default(), || async {
+            calls.fetch_add(1, Ordering::SeqCst);
+            Err(RpcError::Custom("invalid params".to_owned()))
+        })
+        .await;
+        assert!(result.is_err());
+        assert_eq!(calls.load(Ordering::SeqCst), 1);
+    }
+}
@@ -1,30 +1,55 @@
 // Copyright (c) RoochNetwork
 // SPDX-License-Identifier: Apache-2.0
 
+use crate::pagination::paginate;
+use crate::retry::{retry, RetryConfig};
 use anyhow::Result;
+use futures::Stream;
 use jsonrpsee::http_client::HttpClient;
+use move_core_types::account_address::AccountAddress;
+use move_core_types::language_storage::StructTag;
+use move_core_types::u256::U256;
+use moveos_types::function_return_value::FunctionResult;
 use moveos_types::h256::H256;
+use moveos_types::move_std::option::MoveOption;
 use moveos_types::{
     access_path::AccessPath,
-    state::{MoveStructType, State},
-    transaction::FunctionCall,
+    state::{MoveState, MoveStructType, State},
+    transaction::{FunctionCall, MoveAction},
 };
 use rooch_rpc_api::api::rooch_api::RoochAPIClient;
+use rooch_rpc_api::jsonrpc_types::event_view::{EventFilterView, IndexerEventView};
+use rooch_rpc_api::jsonrpc_types::mempool_view::{MempoolStatusView, PendingTransactionView};
+use rooch_rpc_api::jsonrpc_types::transaction_view::TransactionFilterView;
 use rooch_rpc_api::jsonrpc_types::{
     account_view::BalanceInfoView, transaction_view::TransactionWithInfoView,
 };
 use rooch_rpc_api::jsonrpc_types::{
     AccessPathView, AccountAddressView, AnnotatedFunctionResultView, BalanceInfoPageView,
-    EventOptions, EventPageView, StateOptions, StatesPageView, StructTagView,
+    EventOptions, EventPageView, FunctionCallView, GlobalStateFilterView, H256View,
+    IndexerEventPageView, IndexerGlobalStatePageView, IndexerGlobalStateView,
+    IndexerTableChangeSetPageView, IndexerTableChangeSetView, IndexerTableStatePageView,
+    IndexerTableStateView, SessionKeyPageView, StateOptions, StateSyncFilterView, StatesPageView,
+    StrView, StructTagView, TableStateFilterView,
 };
 use rooch_rpc_api::jsonrpc_types::{BytesView, TransactionWithInfoPageView};
-use rooch_rpc_api::jsonrpc_types::{ExecuteTransactionResponseView, StateView};
-use rooch_types::{account::Account, address::RoochAddress, transaction::rooch::RoochTransaction};
+use rooch_rpc_api::jsonrpc_types::{
+    DryRunTransactionResponseView, ExecuteTransactionResponseView, StateView,
+};
+use rooch_types::framework::{address_mapping::AddressMapping, transfer::TransferModule};
+use rooch_types::indexer::event_filter::IndexerEventID;
+use rooch_types::indexer::state::IndexerStateID;
+use rooch_types::{
+    account::Account,
+    address::{MultiChainAddress, RoochAddress},
+    transaction::rooch::{RoochTransaction, SponsoredRoochTransaction},
+};
 use std::sync::Arc;
 
 #[derive(Clone, Debug)]
 pub struct RoochRpcClient {
     http: Arc<HttpClient>,
+    retry_config: RetryConfig,
 }
 
 // TODO: call args are uniformly defined in jsonrpc types?
@@ -32,11 +57,27 @@ pub struct RoochRpcClient {
 
 impl RoochRpcClient {
     pub fn new(http: Arc<HttpClient>) -> Self {
-        Self { http }
+        Self {
+            http,
+            retry_config: RetryConfig::default(),
+        }
+    }
+
+    pub fn with_retry_config(mut self, retry_config: RetryConfig) -> Self {
+        self.retry_config = retry_config;
+        self
     }
 
     pub async fn get_chain_id(&self) -> Result<u64> {
-        Ok(self.http.get_chain_id().await?.0)
+        Ok(retry(&self.retry_config, || self.http.get_chain_id())
+            .await?
+            .0)
+    }
+
+    /// Send the transaction without waiting for the execution, return the transaction hash
+    pub async fn send_tx(&self, tx: RoochTransaction) -> Result<H256> {
+        let tx_payload = bcs::to_bytes(&tx)?;
+        Ok(self.http.send_raw_transaction(tx_payload.into()).await?.0)
     }
 
     pub async fn execute_tx(&self, tx: RoochTransaction) -> Result<ExecuteTransactionResponseView> {
@@ -47,31 +88,84 @@ impl RoochRpcClient {
             .map_err(|e| anyhow::anyhow!(e))
     }
 
+    /// Send the sponsored transaction without waiting for the execution, return the transaction hash
+    pub async fn send_sponsored_tx(&self, tx: SponsoredRoochTransaction) -> Result<H256> {
+        let tx_payload = bcs::to_bytes(&tx)?;
+        Ok(self
+            .http
+            .send_raw_sponsored_transaction(tx_payload.into())
+            .await?
+            .0)
+    }
+
+    pub async fn execute_sponsored_tx(
+        &self,
+        tx: SponsoredRoochTransaction,
+    ) -> Result<ExecuteTransactionResponseView> {
+        let tx_payload = bcs::to_bytes(&tx)?;
+        self.http
+            .execute_raw_sponsored_transaction(tx_payload.into())
+            .await
+            .map_err(|e| anyhow::anyhow!(e))
+    }
+
+    pub async fn dry_run_tx(
+        &self,
+        tx: RoochTransaction,
+        profile_gas: bool,
+    ) -> Result<DryRunTransactionResponseView> {
+        let tx_payload: BytesView = bcs::to_bytes(&tx)?.into();
+        retry(&self.retry_config, || {
+            self.http
+                .dry_run_raw_transaction(tx_payload.clone(), Some(profile_gas))
+        })
+        .await
+        .map_err(|e| anyhow::anyhow!(e))
+    }
+
     pub async fn execute_view_function(
         &self,
         function_call: FunctionCall,
     ) -> Result<AnnotatedFunctionResultView> {
-        self.http
-            .execute_view_function(function_call.into())
+        self.execute_view_function_with_options(function_call, None, None)
             .await
-            .map_err(|e| anyhow::anyhow!(e))
+    }
+
+    /// Execute the view function as the `sender`, and limit the gas usage by `max_gas_amount`.
+    pub async fn execute_view_function_with_options(
+        &self,
+        function_call: FunctionCall,
+        sender: Option<AccountAddress>,
+        max_gas_amount: Option<u64>,
+    ) -> Result<AnnotatedFunctionResultView> {
+        let mut function_call_view: FunctionCallView = function_call.into();
+        function_call_view.sender = sender.map(Into::into);
+        function_call_view.max_gas_amount = max_gas_amount.map(StrView);
+        retry(&self.retry_config, || {
+            self.http.execute_view_function(function_call_view.clone())
+        })
+        .await
+        .map_err(|e| anyhow::anyhow!(e))
     }
 
     pub async fn get_states(&self, access_path: AccessPath) -> Result<Vec<Option<StateView>>> {
-        Ok(self.http.get_states(access_path.into(), None).await?)
+        Ok(retry(&self.retry_config, || {
+            self.http.get_states(access_path.clone().into(), None)
+        })
+        .await?)
     }
 
     pub async fn get_decoded_states(
         &self,
         access_path: AccessPath,
     ) -> Result<Vec<Option<StateView>>> {
-        Ok(self
-            .http
-            .get_states(
-                access_path.into(),
+        Ok(retry(&self.retry_config, || {
+            self.http.get_states(
+                access_path.clone().into(),
                 Some(StateOptions::default().decode(true)),
             )
-            .await?)
+        })
+        .await?)
     }
 
     pub async fn get_transactions_by_order(
@@ -79,20 +173,22 @@ impl RoochRpcClient {
         cursor: Option<u64>,
         limit: Option<u64>,
     ) -> Result<TransactionWithInfoPageView> {
-        Ok(self
-            .http
-            .get_transactions_by_order(cursor.map(Into::into), limit.map(Into::into))
-            .await?)
+        Ok(retry(&self.retry_config, || {
+            self.http
+                .get_transactions_by_order(cursor.map(Into::into), limit.map(Into::into))
+        })
+        .await?)
     }
 
     pub async fn get_transactions_by_hash(
         &self,
         tx_hashes: Vec<H256>,
     ) -> Result<Vec<Option<TransactionWithInfoView>>> {
-        Ok(self
-            .http
-            .get_transactions_by_hash(tx_hashes.iter().map(|hash| (*hash).into()).collect())
-            .await?)
+        let tx_hashes: Vec<H256View> = tx_hashes.iter().map(|hash| (*hash).into()).collect();
+        Ok(retry(&self.retry_config, || {
+            self.http.get_transactions_by_hash(tx_hashes.clone())
+        })
+        .await?)
     }
 
     pub async fn get_sequence_number(&self, sender: RoochAddress) -> Result<u64> {
@@ -116,15 +212,15 @@ impl RoochRpcClient {
         limit: Option<u64>,
         event_options: Option<EventOptions>,
     ) -> Result<EventPageView> {
-        let s = self
-            .http
-            .get_events_by_event_handle(
-                event_handle_type,
+        let s = retry(&self.retry_config, || {
+            self.http.get_events_by_event_handle(
+                event_handle_type.clone(),
                 cursor.map(Into::into),
                 limit.map(Into::into),
-                event_options,
+                event_options.clone(),
             )
-            .await?;
+        })
+        .await?;
         Ok(s)
     }
 
@@ -134,10 +230,15 @@ impl RoochRpcClient {
         cursor: Option<BytesView>,
         limit: Option<usize>,
     ) -> Result<StatesPageView> {
-        Ok(self
-            .http
-            .list_states(access_path, cursor, limit.map(Into::into), None)
-            .await?)
+        Ok(retry(&self.retry_config, || {
+            self.http.list_states(
+                access_path.clone(),
+                cursor.clone(),
+                limit.map(Into::into),
+                None,
+            )
+        })
+        .await?)
     }
 
     pub async fn list_decoded_states(
@@ -146,15 +247,15 @@ impl RoochRpcClient {
         cursor: Option<BytesView>,
         limit: Option<usize>,
     ) -> Result<StatesPageView> {
-        Ok(self
-            .http
-            .list_states(
-                access_path,
-                cursor,
+        Ok(retry(&self.retry_config, || {
+            self.http.list_states(
+                access_path.clone(),
+                cursor.clone(),
                 limit.map(Into::into),
                 Some(StateOptions::default().decode(true)),
             )
-            .await?)
+        })
+        .await?)
     }
 
     pub async fn get_balance(
@@ -162,7 +263,11 @@ impl RoochRpcClient {
         account_addr: AccountAddressView,
         coin_type: StructTagView,
     ) -> Result<BalanceInfoView> {
-        Ok(self.http.get_balance(account_addr, coin_type).await?)
+        Ok(retry(&self.retry_config, || {
+            self.http
+                .get_balance(account_addr.clone(), coin_type.clone())
+        })
+        .await?)
     }
 
     pub async fn get_balances(
@@ -171,9 +276,236 @@ impl RoochRpcClient {
         cursor: Option<BytesView>,
         limit: Option<usize>,
     ) -> Result<BalanceInfoPageView> {
-        Ok(self
-            .http
-            .get_balances(account_addr, cursor, limit.map(Into::into))
-            .await?)
+        Ok(retry(&self.retry_config, || {
+            self.http
+                .get_balances(account_addr.clone(), cursor.clone(), limit.map(Into::into))
+        })
+        .await?)
+    }
+
+    pub async fn get_session_keys(
+        &self,
+        account_addr: AccountAddressView,
+        cursor: Option<BytesView>,
+        limit: Option<usize>,
+    ) -> Result<SessionKeyPageView> {
+        Ok(retry(&self.retry_config, || {
+            self.http
+                .get_session_keys(account_addr.clone(), cursor.clone(), limit.map(Into::into))
+        })
+        .await?)
+    }
+
+    pub async fn get_pending_transactions(
+        &self,
+        sender: Option<AccountAddressView>,
+        limit: Option<usize>,
+    ) -> Result<Vec<PendingTransactionView>> {
+        Ok(retry(&self.retry_config, || {
+            self.http
+                .get_pending_transactions(sender.clone(), limit.map(Into::into))
+        })
+        .await?)
+    }
+
+    pub async fn get_mempool_status(&self) -> Result<MempoolStatusView> {
+        Ok(retry(&self.retry_config, || self.http.get_mempool_status()).await?)
+    }
+
+    pub async fn query_transactions(
+        &self,
+        filter: TransactionFilterView,
+        cursor: Option<u64>,
+        limit: Option<usize>,
+        descending_order: Option<bool>,
+    ) -> Result<TransactionWithInfoPageView> {
+        Ok(retry(&self.retry_config, || {
+            self.http.query_transactions(
+                filter.clone(),
+                cursor.map(Into::into),
+                limit.map(Into::into),
+                descending_order,
+            )
+        })
+        .await?)
+    }
+
+    pub async fn query_events(
+        &self,
+        filter: EventFilterView,
+        cursor: Option<IndexerEventID>,
+        limit: Option<usize>,
+        descending_order: Option<bool>,
+    ) -> Result<IndexerEventPageView> {
+        Ok(retry(&self.retry_config, || {
+            self.http.query_events(
+                filter.clone(),
+                cursor,
+                limit.map(Into::into),
+                descending_order,
+            )
+        })
+        .await?)
+    }
+
+    pub async fn query_global_states(
+        &self,
+        filter: GlobalStateFilterView,
+        cursor: Option<IndexerStateID>,
+        limit: Option<usize>,
+        descending_order: Option<bool>,
+    ) -> Result<IndexerGlobalStatePageView> {
+        Ok(retry(&self.retry_config, || {
+            self.http.query_global_states(
+                filter.clone(),
+                cursor,
+                limit.map(Into::into),
+                descending_order,
+            )
+        })
+        .await?)
+    }
+
+    pub async fn query_table_states(
+        &self,
+        filter: TableStateFilterView,
+        cursor: Option<IndexerStateID>,
+        limit: Option<usize>,
+        descending_order: Option<bool>,
+    ) -> Result<IndexerTableStatePageView> {
+        Ok(retry(&self.retry_config, || {
+            self.http.query_table_states(
+                filter.clone(),
+                cursor,
+                limit.map(Into::into),
+                descending_order,
+            )
+        })
+        .await?)
+    }
+
+    pub async fn sync_states(
+        &self,
+        filter: Option<StateSyncFilterView>,
+        cursor: Option<IndexerStateID>,
+        limit: Option<usize>,
+        descending_order: Option<bool>,
+    ) -> Result<IndexerTableChangeSetPageView> {
+        Ok(retry(&self.retry_config, || {
+            self.http.sync_states(
+                filter.clone(),
+                cursor,
+                limit.map(Into::into),
+                descending_order,
+            )
+        })
+        .await?)
+    }
+
+    /// Iterate all the transactions matched by the filter, `page_size` is the limit of every page request
+    pub fn query_transactions_stream(
+        &self,
+        filter: TransactionFilterView,
+        page_size: Option<usize>,
+        descending_order: Option<bool>,
+    ) -> impl Stream<Item = Result<TransactionWithInfoView>> + '_ {
+        paginate(move |cursor| {
+            self.query_transactions(filter.clone(), cursor, page_size, descending_order)
+        })
+    }
+
+    /// Iterate all the events matched by the filter, `page_size` is the limit of every page request
+    pub fn query_events_stream(
+        &self,
+        filter: EventFilterView,
+        page_size: Option<usize>,
+        descending_order: Option<bool>,
+    ) -> impl Stream<Item = Result<IndexerEventView>> + '_ {
+        paginate(move |cursor| {
+            self.query_events(filter.clone(), cursor, page_size, descending_order)
+        })
+    }
+
+    /// Iterate all the global states matched by the filter, `page_size` is the limit of every page request
+    pub fn query_global_states_stream(
+        &self,
+        filter: GlobalStateFilterView,
+        page_size: Option<usize>,
+        descending_order: Option<bool>,
+    ) -> impl Stream<Item = Result<IndexerGlobalStateView>> + '_ {
+        paginate(move |cursor| {
+            self.query_global_states(filter.clone(), cursor, page_size, descending_order)
+        })
+    }
+
+    /// Iterate all the table states matched by the filter, `page_size` is the limit of every page request
+    pub fn query_table_states_stream(
+        &self,
+        filter: TableStateFilterView,
+        page_size: Option<usize>,
+        descending_order: Option<bool>,
+    ) -> impl Stream<Item = Result<IndexerTableStateView>> + '_ {
+        paginate(move |cursor| {
+            self.query_table_states(filter.clone(), cursor, page_size, descending_order)
+        })
+    }
+
+    /// Iterate all the state change sets matched by the filter, `page_size` is the limit of every page request
+    pub fn sync_states_stream(
+        &self,
+        filter: Option<StateSyncFilterView>,
+        page_size: Option<usize>,
+        descending_order: Option<bool>,
+    ) -> impl Stream<Item = Result<IndexerTableChangeSetView>> + '_ {
+        paginate(move |cursor| {
+            self.sync_states(filter.clone(), cursor, page_size, descending_order)
+        })
+    }
+
+    /// Iterate all the balances of the account
+    pub fn get_balances_stream(
+        &self,
+        account_addr: AccountAddressView,
+        page_size: Option<usize>,
+    ) -> impl Stream<Item = Result<BalanceInfoView>> + '_ {
+        paginate(move |cursor| self.get_balances(account_addr.clone(), cursor, page_size))
+    }
+
+    /// Resolve the multichain address to the Rooch account address via the on-chain address mapping.
+    /// Return None if the multichain address is not mapped yet.
+    pub async fn resolve_address(
+        &self,
+        multichain_address: MultiChainAddress,
+    ) -> Result<Option<AccountAddress>> {
+        if multichain_address.is_rooch_address() {
+            let rooch_address: RoochAddress = multichain_address.try_into()?;
+            return Ok(Some(rooch_address.into()));
+        }
+        let function_result: FunctionResult = self
+            .execute_view_function(AddressMapping::create_resolve_call(&multichain_address))
+            .await?
+            .try_into()?;
+        let values = function_result.into_result()?;
+        let value = values
+            .first()
+            .ok_or_else(|| anyhow::anyhow!("Expected return value of address resolve"))?;
+        Ok(MoveOption::<AccountAddress>::from_bytes(&value.value)?.into())
+    }
+
+    /// Build the action to transfer `amount` of `coin_type` to the multichain address.
+    /// If the multichain address is not mapped to a Rooch address yet,
+    /// the coin is transferred via the multichain address, and the mapping is created on-chain.
+    pub async fn build_transfer_action(
+        &self,
+        to: MultiChainAddress,
+        amount: U256,
+        coin_type: StructTag,
+    ) -> Result<MoveAction> {
+        Ok(match self.resolve_address(to.clone()).await? {
+            Some(to) => TransferModule::create_transfer_coin_action(coin_type, to, amount),
+            None => TransferModule::create_transfer_coin_to_multichain_address_action(
+                coin_type, to, amount,
+            ),
+        })
     }
 }
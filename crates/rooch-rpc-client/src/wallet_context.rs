@@ -17,12 +17,12 @@ use rooch_key::keystore::Keystore;
 use rooch_rpc_api::jsonrpc_types::{ExecuteTransactionResponseView, KeptVMStatusView};
 use rooch_types::address::RoochAddress;
 use rooch_types::addresses;
-use rooch_types::crypto::Signature;
 use rooch_types::error::{RoochError, RoochResult};
-use rooch_types::transaction::{
-    authenticator::Authenticator,
-    rooch::{RoochTransaction, RoochTransactionData},
+use rooch_types::transaction::authenticator::Authenticator;
+use rooch_types::transaction::rooch::{
+    RoochTransaction, RoochTransactionData, SponsoredRoochTransaction,
 };
+use rooch_types::transaction::AbstractTransaction;
 use std::collections::BTreeMap;
 use std::path::PathBuf;
 use std::sync::Arc;
@@ -168,24 +168,52 @@ impl WalletContext {
         action: MoveAction,
         password: Option<String>,
     ) -> RoochResult<RoochTransaction> {
-        let kp = self
-            .keystore
-            .get_key_pair_with_password(&sender, password)
-            .ok()
-            .ok_or_else(|| {
-                RoochError::SignMessageError(format!(
-                    "Cannot find encryption data for address: [{sender}]"
-                ))
-            })?;
+        let tx_data = self.build_tx_data(sender, action).await?;
+        // The keystore signs the transaction with the local key or delegates it to the external signer
+        self.keystore
+            .sign_transaction(&sender, tx_data, password)
+            .map_err(|e| RoochError::SignMessageError(e.to_string()))
+    }
 
+    /// Sign a sponsored transaction as the sender, the gas fee is paid by the `fee_payer`.
+    /// The returned transaction should be co-signed by the fee payer via `sign_as_fee_payer`.
+    pub async fn sign_sponsored(
+        &self,
+        sender: RoochAddress,
+        fee_payer: RoochAddress,
+        action: MoveAction,
+        password: Option<String>,
+    ) -> RoochResult<SponsoredRoochTransaction> {
         let tx_data = self.build_tx_data(sender, action).await?;
-        let signature = Signature::new_hashed(tx_data.hash().as_bytes(), &kp);
-        Ok(RoochTransaction::new(
+        let signature = self
+            .keystore
+            .sign_hashed(
+                &sender,
+                tx_data.sponsored_hash(fee_payer).as_bytes(),
+                password,
+            )
+            .map_err(|e| RoochError::SignMessageError(e.to_string()))?;
+        Ok(SponsoredRoochTransaction::new(
             tx_data,
             Authenticator::rooch(signature),
+            fee_payer,
         ))
     }
 
+    /// Co-sign a sponsored transaction as the fee payer.
+    pub fn sign_as_fee_payer(
+        &self,
+        tx: SponsoredRoochTransaction,
+        password: Option<String>,
+    ) -> RoochResult<SponsoredRoochTransaction> {
+        let fee_payer = tx.fee_payer().address;
+        let signature = self
+            .keystore
+            .sign_hashed(&fee_payer, tx.tx_hash().as_bytes(), password)
+            .map_err(|e| RoochError::SignMessageError(e.to_string()))?;
+        Ok(tx.with_fee_payer_authenticator(Authenticator::rooch(signature)))
+    }
+
     pub async fn execute(
         &self,
         tx: RoochTransaction,
@@ -198,6 +226,18 @@ impl WalletContext {
             .map_err(|e| RoochError::TransactionError(e.to_string()))
     }
 
+    pub async fn execute_sponsored(
+        &self,
+        tx: SponsoredRoochTransaction,
+    ) -> RoochResult<ExecuteTransactionResponseView> {
+        let client = self.get_client().await?;
+        client
+            .rooch
+            .execute_sponsored_tx(tx)
+            .await
+            .map_err(|e| RoochError::TransactionError(e.to_string()))
+    }
+
     pub async fn sign_and_execute(
         &self,
         sender: RoochAddress,
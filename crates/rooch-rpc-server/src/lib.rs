@@ -1,9 +1,11 @@
 // Copyright (c) RoochNetwork
 // SPDX-License-Identifier: Apache-2.0
 
+use std::convert::Infallible;
 use std::env;
 use std::fmt::Debug;
 use std::net::SocketAddr;
+use std::str::FromStr;
 use std::sync::Arc;
 use std::time::Duration;
 
@@ -11,6 +13,8 @@ use anyhow::{Error, Result};
 use coerce::actor::scheduler::timer::Timer;
 use coerce::actor::{system::ActorSystem, IntoActor};
 use hyper::header::HeaderValue;
+use hyper::server::conn::AddrStream;
+use hyper::service::make_service_fn;
 use hyper::Method;
 use jsonrpsee::server::ServerBuilder;
 use jsonrpsee::RpcModule;
@@ -19,34 +23,45 @@ use tower_http::cors::{AllowOrigin, CorsLayer};
 use tower_http::trace::TraceLayer;
 use tracing::info;
 
+use ::metrics::Registry;
 use moveos_store::{MoveOSDB, MoveOSStore};
 use raw_store::errors::RawStoreError;
+use raw_store::metrics::StoreMetrics;
 use raw_store::rocks::RocksDB;
 use raw_store::StoreInstance;
 use rooch_config::da_config::DAConfig;
 use rooch_config::indexer_config::IndexerConfig;
-use rooch_config::server_config::ServerConfig;
+use rooch_config::server_config::{ServerConfig, DEFAULT_METRICS_HOST, DEFAULT_METRICS_PORT};
 use rooch_config::store_config::StoreConfig;
 use rooch_config::{BaseConfig, RoochOpt, ServerOpt};
 use rooch_da::actor::da::DAActor;
+use rooch_da::metrics::DAMetrics;
 use rooch_da::proxy::DAProxy;
 use rooch_executor::actor::executor::ExecutorActor;
 use rooch_executor::actor::reader_executor::ReaderExecutorActor;
+use rooch_executor::metrics::ExecutorMetrics;
 use rooch_executor::proxy::ExecutorProxy;
 use rooch_framework::natives::default_gas_schedule;
 use rooch_indexer::actor::indexer::IndexerActor;
 use rooch_indexer::actor::reader_indexer::IndexerReaderActor;
 use rooch_indexer::indexer_reader::IndexerReader;
+use rooch_indexer::metrics::IndexerMetrics;
 use rooch_indexer::proxy::IndexerProxy;
 use rooch_indexer::IndexerStore;
 use rooch_key::key_derive::{generate_new_key_pair, retrieve_key_pair};
+use rooch_mempool::actor::mempool::MempoolActor;
+use rooch_mempool::proxy::MempoolProxy;
 use rooch_proposer::actor::messages::ProposeBlock;
 use rooch_proposer::actor::proposer::ProposerActor;
 use rooch_proposer::proxy::ProposerProxy;
 use rooch_relayer::actor::messages::RelayTick;
 use rooch_relayer::actor::relayer::RelayerActor;
+use rooch_relayer::metrics::RelayerMetrics;
+use rooch_relayer::proxy::RelayerProxy;
 use rooch_rpc_api::api::RoochRpcModule;
+use rooch_rpc_client::ClientBuilder;
 use rooch_sequencer::actor::sequencer::SequencerActor;
+use rooch_sequencer::metrics::SequencerMetrics;
 use rooch_sequencer::proxy::SequencerProxy;
 use rooch_store::RoochStore;
 use rooch_types::address::RoochAddress;
@@ -55,13 +70,21 @@ use rooch_types::bitcoin::network::Network;
 use rooch_types::crypto::RoochKeyPair;
 use rooch_types::error::{GenesisError, RoochError};
 
+use crate::metrics::RpcMetrics;
+use crate::server::admin_server::AdminServer;
 use crate::server::btc_server::BtcServer;
 use crate::server::eth_server::{EthNetServer, EthServer};
 use crate::server::rooch_server::RoochServer;
+use crate::service::admin_auth::AdminAuthLayer;
 use crate::service::aggregate_service::AggregateService;
+use crate::service::follower::{Follower, FollowerStatusLayer};
+use crate::service::inscription_content::InscriptionContentLayer;
+use crate::service::rate_limit::{MethodClass, RateLimitLayer, RateLimiter};
+use crate::service::rpc_forward::{RemoteAddr, RpcForward};
 use crate::service::rpc_logger::RpcLogger;
 use crate::service::rpc_service::RpcService;
 
+pub mod metrics;
 pub mod server;
 pub mod service;
 
@@ -70,6 +93,9 @@ static R_EXIT_CODE_NEED_HELP: i32 = 120;
 
 pub struct ServerHandle {
     handle: jsonrpsee::server::ServerHandle,
+    /// The shutdown sender of the public hyper server, only set with the rate limit
+    public_shutdown: Option<tokio::sync::oneshot::Sender<()>>,
+    admin_handle: Option<jsonrpsee::server::ServerHandle>,
     timers: Vec<Timer>,
     _store_config: StoreConfig,
     _index_config: IndexerConfig,
@@ -78,6 +104,12 @@ pub struct ServerHandle {
 impl ServerHandle {
     fn stop(self) -> Result<()> {
         self.handle.stop()?;
+        if let Some(public_shutdown) = self.public_shutdown {
+            let _ = public_shutdown.send(());
+        }
+        if let Some(admin_handle) = self.admin_handle {
+            admin_handle.stop()?;
+        }
         for timer in self.timers {
             timer.stop();
         }
@@ -183,26 +215,42 @@ pub async fn run_start_server(opt: &RoochOpt, mut server_opt: ServerOpt) -> Resu
     let chain_id_opt = opt.chain_id.clone().unwrap_or_default();
 
     let addr: SocketAddr = format!("{}:{}", config.host, config.port).parse()?;
+    // Check the admin config before starting the services
+    let admin_config = opt.admin_config()?;
     let actor_system = ActorSystem::global_system();
 
+    // Init metrics, every server has its own registry, so the server can be started multiple times in testing.
+    // The metrics server listens on localhost unless the host is set explicitly.
+    let metrics_addr: SocketAddr = format!(
+        "{}:{}",
+        opt.metrics_host.as_deref().unwrap_or(DEFAULT_METRICS_HOST),
+        opt.metrics_port.unwrap_or(DEFAULT_METRICS_PORT)
+    )
+    .parse()?;
+    let registry = Registry::new();
+
     //Init store
     let base_config = BaseConfig::load_with_opt(opt)?;
     let mut store_config = StoreConfig::default();
     store_config.merge_with_opt_with_init(opt, Arc::new(base_config.clone()), true)?;
-    let (moveos_store, rooch_store) = init_storage(&store_config)?;
+    let (moveos_store, rooch_store, store_instances) =
+        init_storage(&store_config, StoreMetrics::register(&registry)?)?;
 
     //Init indexer store
     let mut indexer_config = IndexerConfig::default();
     indexer_config.merge_with_opt_with_init(opt, Arc::new(base_config), true)?;
     let (indexer_store, indexer_reader) = init_indexer(&indexer_config)?;
 
+    // A follower node syncs the transactions from the upstream node, it does not need the key pairs
+    let follower_config = opt.follower_config();
+
     // Check for key pairs
     if server_opt.sequencer_keypair.is_none()
         || server_opt.proposer_keypair.is_none()
         || server_opt.relayer_keypair.is_none()
     {
-        // only for integration test, generate test key pairs
-        if chain_id_opt.is_test_or_dev_or_local() {
+        // only for integration test and follower node, generate test key pairs
+        if chain_id_opt.is_test_or_dev_or_local() || follower_config.is_some() {
             let result = generate_new_key_pair(None, None, None, None)?;
             let kp: RoochKeyPair =
                 retrieve_key_pair(&result.key_pair_data.private_key_encryption, None)?;
@@ -217,12 +265,22 @@ pub async fn run_start_server(opt: &RoochOpt, mut server_opt: ServerOpt) -> Resu
     }
 
     let sequencer_keypair = server_opt.sequencer_keypair.unwrap();
-    let sequencer_account: RoochAddress = (&sequencer_keypair.public()).into();
+    let sequencer_account: RoochAddress = match &follower_config {
+        Some(follower_config) => RoochAddress::from_str(&follower_config.sequencer_account)
+            .map_err(|e| {
+                anyhow::anyhow!(
+                    "Invalid sequencer account {:?} of the follower node: {}",
+                    follower_config.sequencer_account,
+                    e
+                )
+            })?,
+        None => (&sequencer_keypair.public()).into(),
+    };
 
     // Init executor
     let is_genesis = moveos_store.statedb.is_genesis();
 
-    // #TODO: If not launched in the Genesis way, the latest onchain GasSchedule needs to be obtained.
+    // The gas schedule blob is only written at genesis, after that MoveOS loads the on-chain GasSchedule.
     let gas_schedule_blob =
         bcs::to_bytes(&default_gas_schedule()).expect("Failure serializing genesis gas schedule");
 
@@ -232,7 +290,8 @@ pub async fn run_start_server(opt: &RoochOpt, mut server_opt: ServerOpt) -> Resu
         BitcoinGenesisContext::new(btc_network),
         moveos_store.clone(),
         rooch_store.clone(),
-    )?;
+    )?
+    .with_metrics(ExecutorMetrics::register(&registry)?);
     let reader_executor = ReaderExecutorActor::new(
         executor_actor.genesis().clone(),
         moveos_store.clone(),
@@ -247,7 +306,12 @@ pub async fn run_start_server(opt: &RoochOpt, mut server_opt: ServerOpt) -> Resu
 
     // Init sequencer
     info!("RPC Server sequencer address: {:?}", sequencer_account);
-    let sequencer = SequencerActor::new(sequencer_keypair, rooch_store, is_genesis)?
+    let sequencer_actor = match &follower_config {
+        Some(_) => SequencerActor::new_follower(sequencer_account, rooch_store)?,
+        None => SequencerActor::new(sequencer_keypair, rooch_store, is_genesis)?,
+    };
+    let sequencer = sequencer_actor
+        .with_metrics(SequencerMetrics::register(&registry)?)
         .into_actor(Some("Sequencer"), &actor_system)
         .await?;
     let sequencer_proxy = SequencerProxy::new(sequencer.into());
@@ -259,6 +323,7 @@ pub async fn run_start_server(opt: &RoochOpt, mut server_opt: ServerOpt) -> Resu
     let da_proxy = DAProxy::new(
         DAActor::new(da_config, &actor_system)
             .await?
+            .with_metrics(DAMetrics::register(&registry)?)
             .into_actor(Some("DAProxy"), &actor_system)
             .await?
             .into(),
@@ -268,22 +333,26 @@ pub async fn run_start_server(opt: &RoochOpt, mut server_opt: ServerOpt) -> Resu
     let proposer_keypair = server_opt.proposer_keypair.unwrap();
     let proposer_account: RoochAddress = (&proposer_keypair.public()).into();
     info!("RPC Server proposer address: {:?}", proposer_account);
-    let proposer = ProposerActor::new(proposer_keypair, da_proxy)
+    let proposer = ProposerActor::new(proposer_keypair, da_proxy.clone())
         .into_actor(Some("Proposer"), &actor_system)
         .await?;
     let proposer_proxy = ProposerProxy::new(proposer.clone().into());
     //TODO load from config
     let block_propose_duration_in_seconds: u64 = 5;
     let mut timers = vec![];
-    let proposer_timer = Timer::start(
-        proposer,
-        Duration::from_secs(block_propose_duration_in_seconds),
-        ProposeBlock {},
-    );
-    timers.push(proposer_timer);
+    // The follower node does not propose blocks, the upstream sequencer does
+    if follower_config.is_none() {
+        let proposer_timer = Timer::start(
+            proposer,
+            Duration::from_secs(block_propose_duration_in_seconds),
+            ProposeBlock {},
+        );
+        timers.push(proposer_timer);
+    }
 
     // Init indexer
     let indexer_executor = IndexerActor::new(indexer_store, moveos_store)?
+        .with_metrics(IndexerMetrics::register(&registry)?)
         .into_actor(Some("Indexer"), &actor_system)
         .await?;
     let indexer_reader_executor = IndexerReaderActor::new(indexer_reader)?
@@ -291,19 +360,51 @@ pub async fn run_start_server(opt: &RoochOpt, mut server_opt: ServerOpt) -> Resu
         .await?;
     let indexer_proxy = IndexerProxy::new(indexer_executor.into(), indexer_reader_executor.into());
 
-    let rpc_service = RpcService::new(
+    // Init mempool
+    let mempool = MempoolActor::new(opt.mempool.clone().unwrap_or_default())
+        .into_actor(Some("Mempool"), &actor_system)
+        .await?;
+    let mempool_proxy = MempoolProxy::new(mempool.into());
+
+    let mut rpc_service = RpcService::new(
         chain_id_opt.chain_id().id(),
         executor_proxy.clone(),
         sequencer_proxy,
         proposer_proxy,
         indexer_proxy,
+        mempool_proxy,
     );
+    let rate_limit_config = opt.rate_limit.clone();
+    if let Some(rate_limit_config) = &rate_limit_config {
+        rpc_service = rpc_service.with_max_page_size(rate_limit_config.max_page_size);
+    }
+    match &follower_config {
+        Some(follower_config) => {
+            info!(
+                "RPC Server run as a follower of {:?}",
+                follower_config.upstream_url
+            );
+            let upstream = ClientBuilder::default()
+                .build(&follower_config.upstream_url)
+                .await?;
+            rpc_service = rpc_service.with_upstream(upstream.clone());
+            tokio::spawn(Follower::new(rpc_service.clone(), upstream).run());
+        }
+        None => {
+            tokio::spawn(rpc_service.clone().run_mempool_dispatcher());
+        }
+    }
     let aggregate_service = AggregateService::new(rpc_service.clone());
 
     let ethereum_relayer_config = opt.ethereum_relayer_config();
     let bitcoin_relayer_config = opt.bitcoin_relayer_config();
 
-    if ethereum_relayer_config.is_some() || bitcoin_relayer_config.is_some() {
+    let mut relayer_proxy = None;
+    if follower_config.is_some()
+        && (ethereum_relayer_config.is_some() || bitcoin_relayer_config.is_some())
+    {
+        log::warn!("The relayer is disabled on the follower node, the L1 blocks are relayed by the upstream node");
+    } else if ethereum_relayer_config.is_some() || bitcoin_relayer_config.is_some() {
         let relayer_keypair = server_opt.relayer_keypair.unwrap();
         let relayer_account: RoochAddress = (&relayer_keypair.public()).into();
         info!("RPC Server relayer address: {:?}", relayer_account);
@@ -315,8 +416,10 @@ pub async fn run_start_server(opt: &RoochOpt, mut server_opt: ServerOpt) -> Resu
             rpc_service.clone(),
         )
         .await?
+        .with_metrics(RelayerMetrics::register(&registry)?)
         .into_actor(Some("Relayer"), &actor_system)
         .await?;
+        relayer_proxy = Some(RelayerProxy::new(relayer.clone().into()));
         let relay_tick_in_seconds: u64 = 1;
         let relayer_timer = Timer::start(
             relayer,
@@ -339,22 +442,32 @@ pub async fn run_start_server(opt: &RoochOpt, mut server_opt: ServerOpt) -> Resu
     info!(?acl);
 
     let cors: CorsLayer = CorsLayer::new()
-        // Allow `POST` when accessing the resource
-        .allow_methods([Method::POST])
+        // Allow `POST` when accessing the resource, and `GET` for the inscription content
+        .allow_methods([Method::POST, Method::GET])
         // Allow requests from any origin
         .allow_origin(acl)
         .allow_headers([hyper::header::CONTENT_TYPE]);
 
+    let is_rate_limited = rate_limit_config.is_some();
+    let rate_limit = rate_limit_config.map(|rate_limit_config| {
+        info!(
+            "RPC Server rate limit per IP: {:?}, API keys: {}",
+            rate_limit_config.ip,
+            rate_limit_config.api_keys.len()
+        );
+        RateLimitLayer::new(Arc::new(RateLimiter::new(rate_limit_config)))
+    });
     let middleware = tower::ServiceBuilder::new()
         .layer(TraceLayer::new_for_http())
-        .layer(cors);
-
-    // Build server
-    let server = ServerBuilder::default()
-        .set_logger(RpcLogger)
-        .set_middleware(middleware)
-        .build(&addr)
-        .await?;
+        .layer(cors)
+        .option_layer(rate_limit)
+        .option_layer(
+            rpc_service
+                .follower_status()
+                .cloned()
+                .map(FollowerStatusLayer::new),
+        )
+        .layer(InscriptionContentLayer::new(aggregate_service.clone()));
 
     let mut rpc_module_builder = RpcModuleBuilder::new();
     rpc_module_builder.register_module(RoochServer::new(
@@ -375,13 +488,108 @@ pub async fn run_start_server(opt: &RoochOpt, mut server_opt: ServerOpt) -> Resu
 
     // let rpc_api = build_rpc_api(rpc_api);
     let methods_names = rpc_module_builder.module.method_names().collect::<Vec<_>>();
-    let handle = server.start(rpc_module_builder.module)?;
+    // The rate limit and the follower status depend on the class of every method
+    if let Some(method) = methods_names
+        .iter()
+        .find(|method| MethodClass::try_of(method).is_none())
+    {
+        return Err(anyhow::anyhow!(
+            "The JSON-RPC method {} is not classified in the rate limit method classes",
+            method
+        ));
+    }
+
+    // Build server
+    let rpc_logger = RpcLogger::default().with_metrics(RpcMetrics::register(&registry)?);
+    let (handle, public_shutdown) = if is_rate_limited {
+        // The rate limit needs the remote address of the connection, which the jsonrpsee server
+        // does not pass to its middleware. So the JSON-RPC server listens on localhost, and the
+        // public address is served by a hyper server, which runs the middleware with the remote
+        // address and forwards the requests to the JSON-RPC server.
+        let server = ServerBuilder::default()
+            .set_logger(rpc_logger)
+            .build("127.0.0.1:0")
+            .await?;
+        let rpc_forward = RpcForward::new(server.local_addr()?);
+        let handle = server.start(rpc_module_builder.module)?;
+
+        let make_service = make_service_fn(move |conn: &AddrStream| {
+            let remote_addr = RemoteAddr(conn.remote_addr());
+            let service = tower::ServiceBuilder::new()
+                .map_request(move |mut request: hyper::Request<hyper::Body>| {
+                    request.extensions_mut().insert(remote_addr);
+                    request
+                })
+                .layer(middleware.clone())
+                .service(rpc_forward.clone());
+            async move { Ok::<_, Infallible>(service) }
+        });
+        let (public_shutdown, public_shutdown_receiver) = tokio::sync::oneshot::channel::<()>();
+        let public_server = hyper::Server::try_bind(&addr)?
+            .serve(make_service)
+            .with_graceful_shutdown(async {
+                public_shutdown_receiver.await.ok();
+            });
+        tokio::spawn(async move {
+            if let Err(e) = public_server.await {
+                log::error!("JSON-RPC HTTP Server error: {:?}", e);
+            }
+        });
+        (handle, Some(public_shutdown))
+    } else {
+        let server = ServerBuilder::default()
+            .set_logger(rpc_logger)
+            .set_middleware(middleware)
+            .build(&addr)
+            .await?;
+        (server.start(rpc_module_builder.module)?, None)
+    };
 
     info!("JSON-RPC HTTP Server start listening {:?}", addr);
     info!("Available JSON-RPC methods : {:?}", methods_names);
 
+    // The admin server listens on localhost only, and every request must carry the admin token
+    let admin_handle = match admin_config {
+        Some(admin_config) => {
+            let admin_addr: SocketAddr = format!("127.0.0.1:{}", admin_config.port).parse()?;
+            let admin_middleware = tower::ServiceBuilder::new()
+                .layer(TraceLayer::new_for_http())
+                .layer(AdminAuthLayer::new(admin_config.token));
+            let admin_server = ServerBuilder::default()
+                .set_middleware(admin_middleware)
+                .build(&admin_addr)
+                .await?;
+            let mut admin_module_builder = RpcModuleBuilder::new();
+            admin_module_builder.register_module(AdminServer::new(
+                rpc_service.clone(),
+                da_proxy,
+                relayer_proxy,
+                store_instances,
+            ))?;
+            let admin_handle = admin_server.start(admin_module_builder.module)?;
+            info!(
+                "Admin JSON-RPC HTTP Server start listening {:?}",
+                admin_addr
+            );
+            Some(admin_handle)
+        }
+        None => None,
+    };
+
+    info!(
+        "Prometheus metrics server start listening {:?}",
+        metrics_addr
+    );
+    tokio::spawn(async move {
+        if let Err(e) = ::metrics::metric_server::start_server(metrics_addr, registry).await {
+            log::error!("Prometheus metrics server error: {:?}", e);
+        }
+    });
+
     Ok(ServerHandle {
         handle,
+        public_shutdown,
+        admin_handle,
         timers,
         _store_config: store_config,
         _index_config: indexer_config,
@@ -403,19 +611,23 @@ fn _build_rpc_api<M: Send + Sync + 'static>(mut rpc_module: RpcModule<M>) -> Rpc
     rpc_module
 }
 
-fn init_storage(store_config: &StoreConfig) -> Result<(MoveOSStore, RoochStore)> {
+fn init_storage(
+    store_config: &StoreConfig,
+    store_metrics: StoreMetrics,
+) -> Result<(MoveOSStore, RoochStore, Vec<(String, StoreInstance)>)> {
     let (rooch_db_path, moveos_db_path) = (
         store_config.get_rooch_store_dir(),
         store_config.get_moveos_store_dir(),
     );
 
     //Init store
-    let moveosdb = MoveOSDB::new(StoreInstance::new_db_instance(RocksDB::new(
+    let moveos_instance = StoreInstance::new_db_instance(RocksDB::new(
         moveos_db_path,
         moveos_store::StoreMeta::get_column_family_names().to_vec(),
         store_config.rocksdb_config(),
-        None,
-    )?))?;
+        Some(store_metrics.clone()),
+    )?);
+    let moveosdb = MoveOSDB::new(moveos_instance.clone())?;
     let lastest_state_root = moveosdb
         .config_store
         .get_startup_info()?
@@ -426,13 +638,18 @@ fn init_storage(store_config: &StoreConfig) -> Result<(MoveOSStore, RoochStore)>
     }
     let moveos_store = MoveOSStore::new_with_root(moveosdb, lastest_state_root)?;
 
-    let rooch_store = RoochStore::new(StoreInstance::new_db_instance(RocksDB::new(
+    let rooch_instance = StoreInstance::new_db_instance(RocksDB::new(
         rooch_db_path,
         rooch_store::StoreMeta::get_column_family_names().to_vec(),
         store_config.rocksdb_config(),
-        None,
-    )?))?;
-    Ok((moveos_store, rooch_store))
+        Some(store_metrics),
+    )?);
+    let rooch_store = RoochStore::new(rooch_instance.clone())?;
+    let store_instances = vec![
+        ("moveos".to_owned(), moveos_instance),
+        ("rooch".to_owned(), rooch_instance),
+    ];
+    Ok((moveos_store, rooch_store, store_instances))
 }
 
 fn init_indexer(indexer_config: &IndexerConfig) -> Result<(IndexerStore, IndexerReader)> {
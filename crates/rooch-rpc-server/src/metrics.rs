@@ -0,0 +1,41 @@
+// Copyright (c) RoochNetwork
+// SPDX-License-Identifier: Apache-2.0
+
+use metrics::{
+    register, HistogramOpts, HistogramVec, Opts, PrometheusError, Registry, UIntCounterVec,
+};
+
+#[derive(Clone)]
+pub struct RpcMetrics {
+    pub rpc_requests_total: UIntCounterVec,
+    pub rpc_request_latency_seconds: HistogramVec,
+}
+
+impl RpcMetrics {
+    pub fn register(registry: &Registry) -> Result<Self, PrometheusError> {
+        let rpc_requests_total = register(
+            UIntCounterVec::new(
+                Opts::new(
+                    "rpc_requests_total",
+                    "Counters of the JSON-RPC requests by method and result",
+                ),
+                &["method", "result"],
+            )?,
+            registry,
+        )?;
+        let rpc_request_latency_seconds = register(
+            HistogramVec::new(
+                HistogramOpts::new(
+                    "rpc_request_latency_seconds",
+                    "Histogram of the time used to handle JSON-RPC requests",
+                ),
+                &["method"],
+            )?,
+            registry,
+        )?;
+        Ok(Self {
+            rpc_requests_total,
+            rpc_request_latency_seconds,
+        })
+    }
+}
@@ -0,0 +1,176 @@
+// Copyright (c) RoochNetwork
+// SPDX-License-Identifier: Apache-2.0
+
+use crate::service::rpc_service::RpcService;
+use anyhow::anyhow;
+use jsonrpsee::{
+    core::{async_trait, RpcResult},
+    RpcModule,
+};
+use raw_store::StoreInstance;
+use rooch_da::messages::DAStatus;
+use rooch_da::proxy::DAProxy;
+use rooch_proposer::actor::messages::ProposerStatus;
+use rooch_relayer::actor::messages::RelayerStatus;
+use rooch_relayer::proxy::RelayerProxy;
+use rooch_rpc_api::api::admin_api::AdminAPIServer;
+use rooch_rpc_api::api::RoochRpcModule;
+use rooch_rpc_api::jsonrpc_types::admin_view::{
+    ActorHealthView, DAStatusView, NodeStatusView, ProposerStatusView, RelayerStatusView,
+};
+use std::future::Future;
+use std::time::Duration;
+
+/// An actor that does not answer a health check ping in time is reported as unhealthy.
+const HEALTH_CHECK_TIMEOUT: Duration = Duration::from_secs(3);
+
+pub struct AdminServer {
+    rpc_service: RpcService,
+    da: DAProxy,
+    relayer: Option<RelayerProxy>,
+    /// The store instances to flush, with the name of the store
+    stores: Vec<(String, StoreInstance)>,
+}
+
+impl AdminServer {
+    pub fn new(
+        rpc_service: RpcService,
+        da: DAProxy,
+        relayer: Option<RelayerProxy>,
+        stores: Vec<(String, StoreInstance)>,
+    ) -> Self {
+        Self {
+            rpc_service,
+            da,
+            relayer,
+            stores,
+        }
+    }
+
+    fn relayer(&self) -> anyhow::Result<&RelayerProxy> {
+        self.relayer
+            .as_ref()
+            .ok_or_else(|| anyhow!("The relayer is not running on this node"))
+    }
+
+    async fn set_relayer_paused(&self, paused: bool) -> anyhow::Result<RelayerStatusView> {
+        let relayer = self.relayer()?;
+        relayer.set_paused(paused).await?;
+        Ok(relayer_status_view(relayer.get_status().await?))
+    }
+}
+
+async fn ping<T>(name: &str, fut: impl Future<Output = anyhow::Result<T>>) -> ActorHealthView {
+    let result = match tokio::time::timeout(HEALTH_CHECK_TIMEOUT, fut).await {
+        Ok(result) => result.map(|_| ()),
+        Err(_) => Err(anyhow!(
+            "No response in {} seconds",
+            HEALTH_CHECK_TIMEOUT.as_secs()
+        )),
+    };
+    ActorHealthView::new(name, result)
+}
+
+fn proposer_status_view(status: ProposerStatus) -> ProposerStatusView {
+    ProposerStatusView {
+        last_block_number: status.last_block_number.map(Into::into),
+        pending_tx_count: status.pending_tx_count.into(),
+    }
+}
+
+fn da_status_view(status: DAStatus) -> DAStatusView {
+    DAStatusView {
+        servers: status.servers,
+        submit_threshold: status.submit_threshold.into(),
+        last_submitted_block_number: status.last_submitted_block_number.map(Into::into),
+        failed_batch_count: status.failed_batch_count.into(),
+    }
+}
+
+fn relayer_status_view(status: RelayerStatus) -> RelayerStatusView {
+    RelayerStatusView {
+        paused: status.paused,
+        relayers: status.relayers,
+    }
+}
+
+#[async_trait]
+impl AdminAPIServer for AdminServer {
+    async fn get_node_status(&self) -> RpcResult<NodeStatusView> {
+        let sequencer_order = self
+            .rpc_service
+            .get_sequencer_order()
+            .await?
+            .map_or(0, |order| order.last_order);
+        let startup_info = self.rpc_service.executor.get_startup_info().await?;
+        let proposer = self.rpc_service.proposer.get_status().await?;
+        let da = self.da.get_status().await?;
+        let relayer = match &self.relayer {
+            Some(relayer) => Some(relayer_status_view(relayer.get_status().await?)),
+            None => None,
+        };
+        Ok(NodeStatusView {
+            sequencer_order: sequencer_order.into(),
+            state_root: startup_info.map(|info| info.state_root_hash.into()),
+            follower: self.rpc_service.upstream().is_some(),
+            proposer: proposer_status_view(proposer),
+            da: da_status_view(da),
+            relayer,
+        })
+    }
+
+    async fn health_check(&self) -> RpcResult<Vec<ActorHealthView>> {
+        let mut health = vec![
+            ping(
+                "Sequencer",
+                self.rpc_service.sequencer.get_sequencer_order(),
+            )
+            .await,
+            ping("Executor", self.rpc_service.executor.get_startup_info()).await,
+            ping("Proposer", self.rpc_service.proposer.get_status()).await,
+            ping("DA", self.da.get_status()).await,
+            ping("Mempool", self.rpc_service.mempool.get_mempool_status()).await,
+        ];
+        if let Some(relayer) = &self.relayer {
+            health.push(ping("Relayer", relayer.get_status()).await);
+        }
+        if let Some(status) = self.rpc_service.follower_status() {
+            health.push(ActorHealthView::new("Follower", status.check()));
+        }
+        Ok(health)
+    }
+
+    async fn pause_relayer(&self) -> RpcResult<RelayerStatusView> {
+        Ok(self.set_relayer_paused(true).await?)
+    }
+
+    async fn resume_relayer(&self) -> RpcResult<RelayerStatusView> {
+        Ok(self.set_relayer_paused(false).await?)
+    }
+
+    async fn propose_block(&self) -> RpcResult<()> {
+        Ok(self.rpc_service.proposer.propose_block().await?)
+    }
+
+    async fn flush_store(&self) -> RpcResult<()> {
+        let stores = self.stores.clone();
+        tokio::task::spawn_blocking(move || -> anyhow::Result<()> {
+            for (name, store) in stores {
+                if let Some(db) = store.db() {
+                    db.flush_all()
+                        .map_err(|e| anyhow!("Flush {} store failed: {}", name, e))?;
+                }
+            }
+            Ok(())
+        })
+        .await
+        .map_err(|e| anyhow!(e))??;
+        Ok(())
+    }
+}
+
+impl RoochRpcModule for AdminServer {
+    fn rpc(self) -> RpcModule<Self> {
+        self.into_rpc()
+    }
+}
@@ -2,20 +2,32 @@
 // SPDX-License-Identifier: Apache-2.0
 
 use crate::service::{aggregate_service::AggregateService, rpc_service::RpcService};
+use bitcoin_move::natives::ord::inscription_id::InscriptionId;
 use jsonrpsee::{
     core::{async_trait, RpcResult},
     RpcModule,
 };
 use move_core_types::account_address::AccountAddress;
 use rooch_rpc_api::api::btc_api::BtcAPIServer;
-use rooch_rpc_api::api::{RoochRpcModule, DEFAULT_RESULT_LIMIT_USIZE, MAX_RESULT_LIMIT_USIZE};
-use rooch_rpc_api::jsonrpc_types::btc::ord::{InscriptionFilterView, InscriptionStateView};
+use rooch_rpc_api::api::{RoochRpcModule, DEFAULT_RESULT_LIMIT_USIZE};
+use rooch_rpc_api::jsonrpc_types::address::BitcoinAddressView;
+use rooch_rpc_api::jsonrpc_types::btc::brc20::{
+    BRC20BalanceView, BRC20OperationFilterView, BRC20OperationView, BRC20TickView,
+};
+use rooch_rpc_api::jsonrpc_types::btc::ord::{
+    InscriptionContentView, InscriptionFilterView, InscriptionStateView,
+};
 use rooch_rpc_api::jsonrpc_types::btc::utxo::{UTXOFilterView, UTXOStateView};
-use rooch_rpc_api::jsonrpc_types::{InscriptionPageView, StrView, UTXOPageView};
+use rooch_rpc_api::jsonrpc_types::{
+    BRC20BalancePageView, BRC20OperationPageView, BRC20TickPageView, InscriptionPageView, StrView,
+    UTXOPageView,
+};
 use rooch_types::address::MultiChainAddress;
+use rooch_types::indexer::event_filter::IndexerEventID;
 use rooch_types::indexer::state::IndexerStateID;
 use rooch_types::multichain_id::RoochMultiChainID;
 use std::cmp::min;
+use std::str::FromStr;
 
 pub struct BtcServer {
     rpc_service: RpcService,
@@ -35,6 +47,20 @@ impl BtcServer {
             btc_network,
         }
     }
+
+    async fn resolve_bitcoin_address(
+        &self,
+        address: &BitcoinAddressView,
+    ) -> RpcResult<AccountAddress> {
+        let multi_chain_address = MultiChainAddress::try_from_str_with_multichain_id(
+            RoochMultiChainID::Bitcoin,
+            address.to_string().as_str(),
+        )?;
+        Ok(self
+            .rpc_service
+            .resolve_address(multi_chain_address)
+            .await?)
+    }
 }
 
 #[async_trait]
@@ -49,7 +75,7 @@ impl BtcAPIServer for BtcServer {
     ) -> RpcResult<UTXOPageView> {
         let limit_of = min(
             limit.map(Into::into).unwrap_or(DEFAULT_RESULT_LIMIT_USIZE),
-            MAX_RESULT_LIMIT_USIZE,
+            self.rpc_service.max_page_size(),
         );
         let descending_order = descending_order.unwrap_or(true);
 
@@ -103,7 +129,7 @@ impl BtcAPIServer for BtcServer {
     ) -> RpcResult<InscriptionPageView> {
         let limit_of = min(
             limit.map(Into::into).unwrap_or(DEFAULT_RESULT_LIMIT_USIZE),
-            MAX_RESULT_LIMIT_USIZE,
+            self.rpc_service.max_page_size(),
         );
         let descending_order = descending_order.unwrap_or(true);
 
@@ -120,12 +146,30 @@ impl BtcAPIServer for BtcServer {
             _ => AccountAddress::ZERO,
         };
 
-        let global_state_filter =
-            InscriptionFilterView::into_global_state_filter(filter, resolve_address);
-        let states = self
-            .rpc_service
-            .query_global_states(global_state_filter, cursor, limit_of + 1, descending_order)
-            .await?;
+        let states = match InscriptionFilterView::into_inscription_filter(filter.clone()) {
+            Some(inscription_filter) => {
+                self.rpc_service
+                    .query_inscription_states(
+                        inscription_filter,
+                        cursor,
+                        limit_of + 1,
+                        descending_order,
+                    )
+                    .await?
+            }
+            None => {
+                let global_state_filter =
+                    InscriptionFilterView::into_global_state_filter(filter, resolve_address);
+                self.rpc_service
+                    .query_global_states(
+                        global_state_filter,
+                        cursor,
+                        limit_of + 1,
+                        descending_order,
+                    )
+                    .await?
+            }
+        };
 
         let mut data = self
             .aggregate_service
@@ -147,6 +191,187 @@ impl BtcAPIServer for BtcServer {
             has_next_page,
         })
     }
+
+    async fn get_inscription_content(
+        &self,
+        inscription_id: String,
+    ) -> RpcResult<Option<InscriptionContentView>> {
+        let inscription_id =
+            InscriptionId::from_str(inscription_id.as_str()).map_err(anyhow::Error::from)?;
+        Ok(self
+            .aggregate_service
+            .get_inscription(&inscription_id.into())
+            .await?
+            .map(Into::into))
+    }
+
+    async fn get_brc20_tick(&self, tick: String) -> RpcResult<Option<BRC20TickView>> {
+        let tick = self
+            .rpc_service
+            .query_brc20_ticks(Some(tick), None, 1)
+            .await?
+            .pop();
+        let tick = match tick {
+            Some(tick) => tick,
+            None => return Ok(None),
+        };
+        let mut bitcoin_addresses = self
+            .aggregate_service
+            .get_bitcoin_addresses(vec![tick.deployer])
+            .await?;
+        let deployer_bitcoin_address = bitcoin_addresses.remove(&tick.deployer).flatten();
+        Ok(Some(BRC20TickView::try_new_from_indexer_tick(
+            tick,
+            deployer_bitcoin_address,
+            self.btc_network,
+        )?))
+    }
+
+    async fn list_brc20_ticks(
+        &self,
+        // exclusive cursor if `Some`, otherwise start from the beginning
+        cursor: Option<String>,
+        limit: Option<StrView<usize>>,
+    ) -> RpcResult<BRC20TickPageView> {
+        let limit_of = min(
+            limit.map(Into::into).unwrap_or(DEFAULT_RESULT_LIMIT_USIZE),
+            self.rpc_service.max_page_size(),
+        );
+
+        let ticks = self
+            .rpc_service
+            .query_brc20_ticks(None, cursor.clone(), limit_of + 1)
+            .await?;
+        let deployers = ticks.iter().map(|t| t.deployer).collect::<Vec<_>>();
+        let bitcoin_addresses = self
+            .aggregate_service
+            .get_bitcoin_addresses(deployers)
+            .await?;
+
+        let mut data = ticks
+            .into_iter()
+            .map(|t| {
+                let deployer_bitcoin_address =
+                    bitcoin_addresses.get(&t.deployer).cloned().flatten();
+                BRC20TickView::try_new_from_indexer_tick(
+                    t,
+                    deployer_bitcoin_address,
+                    self.btc_network,
+                )
+            })
+            .collect::<Result<Vec<_>, _>>()?;
+
+        let has_next_page = data.len() > limit_of;
+        data.truncate(limit_of);
+        let next_cursor = data.last().map_or(cursor, |t| Some(t.tick.clone()));
+
+        Ok(BRC20TickPageView {
+            data,
+            next_cursor,
+            has_next_page,
+        })
+    }
+
+    async fn get_brc20_balances(
+        &self,
+        address: BitcoinAddressView,
+        // exclusive cursor if `Some`, otherwise start from the beginning
+        cursor: Option<String>,
+        limit: Option<StrView<usize>>,
+    ) -> RpcResult<BRC20BalancePageView> {
+        let limit_of = min(
+            limit.map(Into::into).unwrap_or(DEFAULT_RESULT_LIMIT_USIZE),
+            self.rpc_service.max_page_size(),
+        );
+
+        let owner = self.resolve_bitcoin_address(&address).await?;
+        let balances = self
+            .rpc_service
+            .query_brc20_balances(owner, cursor.clone(), limit_of + 1)
+            .await?;
+
+        let mut data = balances
+            .into_iter()
+            .map(|b| {
+                BRC20BalanceView::try_new_from_indexer_balance(
+                    b,
+                    Some(address.0.clone()),
+                    self.btc_network,
+                )
+            })
+            .collect::<Result<Vec<_>, _>>()?;
+
+        let has_next_page = data.len() > limit_of;
+        data.truncate(limit_of);
+        let next_cursor = data.last().map_or(cursor, |t| Some(t.tick.clone()));
+
+        Ok(BRC20BalancePageView {
+            data,
+            next_cursor,
+            has_next_page,
+        })
+    }
+
+    async fn query_brc20_operations(
+        &self,
+        filter: BRC20OperationFilterView,
+        // exclusive cursor if `Some`, otherwise start from the beginning
+        cursor: Option<IndexerEventID>,
+        limit: Option<StrView<usize>>,
+        descending_order: Option<bool>,
+    ) -> RpcResult<BRC20OperationPageView> {
+        let limit_of = min(
+            limit.map(Into::into).unwrap_or(DEFAULT_RESULT_LIMIT_USIZE),
+            self.rpc_service.max_page_size(),
+        );
+        let descending_order = descending_order.unwrap_or(true);
+
+        let resolve_address = match &filter {
+            BRC20OperationFilterView::Address(address) => {
+                self.resolve_bitcoin_address(address).await?
+            }
+            _ => AccountAddress::ZERO,
+        };
+
+        let operation_filter =
+            BRC20OperationFilterView::into_brc20_operation_filter(filter, resolve_address);
+        let operations = self
+            .rpc_service
+            .query_brc20_operations(operation_filter, cursor, limit_of + 1, descending_order)
+            .await?;
+        let addresses = operations
+            .iter()
+            .flat_map(|o| [o.from, o.to])
+            .collect::<Vec<_>>();
+        let bitcoin_addresses = self
+            .aggregate_service
+            .get_bitcoin_addresses(addresses)
+            .await?;
+
+        let mut data = operations
+            .into_iter()
+            .map(|o| {
+                let from_bitcoin_address = bitcoin_addresses.get(&o.from).cloned().flatten();
+                let to_bitcoin_address = bitcoin_addresses.get(&o.to).cloned().flatten();
+                BRC20OperationView::try_new_from_indexer_operation(
+                    o,
+                    from_bitcoin_address,
+                    to_bitcoin_address,
+                    self.btc_network,
+                )
+            })
+            .collect::<Result<Vec<_>, _>>()?;
+
+        let has_next_page = data.len() > limit_of;
+        data.truncate(limit_of);
+        let next_cursor = data.last().map_or(cursor, |t| Some(t.indexer_event_id));
+
+        Ok(BRC20OperationPageView {
+            data,
+            next_cursor,
+            has_next_page,
+        })
+    }
 }
 
 impl RoochRpcModule for BtcServer {
@@ -1,6 +1,7 @@
 // Copyright (c) RoochNetwork
 // SPDX-License-Identifier: Apache-2.0
 
+use crate::server::to_rpc_error;
 use crate::service::{aggregate_service::AggregateService, rpc_service::RpcService};
 use ethers::types::{H160, U256, U64};
 use jsonrpsee::{
@@ -353,6 +354,9 @@ impl EthAPIServer for EthServer {
 
     async fn send_raw_transaction(&self, bytes: BytesView) -> RpcResult<H256View> {
         info!("send_raw_transaction: {:?}", bytes);
+        if let Some(upstream) = self.rpc_service.upstream() {
+            return Ok(upstream.eth.send_raw_transaction(bytes).await?);
+        }
         let eth_tx = EthereumTransaction::decode(&bytes.0)?;
         info!("send_raw_transaction input: {:?}", eth_tx.0.input);
         let action = eth_tx.decode_calldata_to_action()?;
@@ -372,7 +376,11 @@ impl EthAPIServer for EthServer {
         );
 
         let hash = H256View::from(tx.tx_hash());
-        let _output = self.rpc_service.execute_tx(tx).await?;
+        let _output = self
+            .rpc_service
+            .execute_tx(tx)
+            .await
+            .map_err(to_rpc_error)?;
         Ok(hash)
     }
 
@@ -1,6 +1,25 @@
 // Copyright (c) RoochNetwork
 // SPDX-License-Identifier: Apache-2.0
 
+use jsonrpsee::core::Error as JsonRpcError;
+use jsonrpsee::types::error::{CallError, ErrorObject};
+use rooch_types::error::VMErrorInfo;
+
+pub mod admin_server;
 pub mod btc_server;
 pub mod eth_server;
 pub mod rooch_server;
+
+/// Convert the error to a JSON-RPC error, the VM errors are returned with their stable error code
+/// and the explained VM status as the error data.
+pub(crate) fn to_rpc_error(error: anyhow::Error) -> JsonRpcError {
+    match error.downcast::<VMErrorInfo>() {
+        Ok(vm_error) => CallError::Custom(ErrorObject::owned(
+            vm_error.kind.error_code(),
+            vm_error.message.clone(),
+            Some(vm_error),
+        ))
+        .into(),
+        Err(error) => error.into(),
+    }
+}
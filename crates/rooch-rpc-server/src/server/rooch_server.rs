@@ -1,6 +1,7 @@
 // Copyright (c) RoochNetwork
 // SPDX-License-Identifier: Apache-2.0
 
+use crate::server::to_rpc_error;
 use crate::service::aggregate_service::AggregateService;
 use crate::service::rpc_service::RpcService;
 use anyhow::Result;
@@ -11,31 +12,29 @@ use jsonrpsee::{
 use move_core_types::account_address::AccountAddress;
 use moveos_types::h256::H256;
 use rooch_rpc_api::jsonrpc_types::event_view::{EventFilterView, EventView, IndexerEventView};
+use rooch_rpc_api::jsonrpc_types::mempool_view::{MempoolStatusView, PendingTransactionView};
 use rooch_rpc_api::jsonrpc_types::transaction_view::TransactionFilterView;
+use rooch_rpc_api::jsonrpc_types::BytesView;
 use rooch_rpc_api::jsonrpc_types::{
     account_view::BalanceInfoView, GlobalStateFilterView, IndexerEventPageView,
     IndexerGlobalStatePageView, IndexerGlobalStateView, IndexerTableChangeSetPageView,
     IndexerTableChangeSetView, IndexerTableStatePageView, IndexerTableStateView, KeyStateKVView,
-    KeyStateView, StateOptions, StateSyncFilterView, TableStateFilterView,
+    KeyStateView, SessionKeyPageView, StateOptions, StateSyncFilterView, TableStateFilterView,
 };
 use rooch_rpc_api::jsonrpc_types::{transaction_view::TransactionWithInfoView, EventOptions};
 use rooch_rpc_api::jsonrpc_types::{
-    AccessPathView, AccountAddressView, BalanceInfoPageView, EventPageView,
-    ExecuteTransactionResponseView, FunctionCallView, H256View, StateView, StatesPageView, StrView,
-    StructTagView, TransactionWithInfoPageView,
+    AccessPathView, AccountAddressView, BalanceInfoPageView, DryRunTransactionResponseView,
+    EventPageView, ExecuteTransactionResponseView, FunctionCallView, H256View, StateView,
+    StatesPageView, StrView, StructTagView, TransactionWithInfoPageView,
 };
 use rooch_rpc_api::{api::rooch_api::RoochAPIServer, api::DEFAULT_RESULT_LIMIT};
 use rooch_rpc_api::{
     api::{RoochRpcModule, DEFAULT_RESULT_LIMIT_USIZE},
     jsonrpc_types::AnnotatedFunctionResultView,
 };
-use rooch_rpc_api::{
-    api::{MAX_RESULT_LIMIT, MAX_RESULT_LIMIT_USIZE},
-    jsonrpc_types::BytesView,
-};
 use rooch_types::indexer::event_filter::IndexerEventID;
 use rooch_types::indexer::state::IndexerStateID;
-use rooch_types::transaction::rooch::RoochTransaction;
+use rooch_types::transaction::rooch::{RoochTransaction, SponsoredRoochTransaction};
 use rooch_types::transaction::{AbstractTransaction, TypedTransaction};
 use rooch_types::{address::MultiChainAddress, multichain_id::RoochMultiChainID};
 use std::cmp::min;
@@ -67,10 +66,14 @@ impl RoochAPIServer for RoochServer {
         let tx = bcs::from_bytes::<RoochTransaction>(&payload.0).map_err(anyhow::Error::from)?;
         info!("send_raw_transaction tx: {:?}", tx);
 
+        if let Some(upstream) = self.rpc_service.upstream() {
+            return Ok(upstream.rooch.send_tx(tx).await?.into());
+        }
         let hash = tx.tx_hash();
         self.rpc_service
             .quene_tx(TypedTransaction::Rooch(tx))
-            .await?;
+            .await
+            .map_err(to_rpc_error)?;
         Ok(hash.into())
     }
 
@@ -79,10 +82,61 @@ impl RoochAPIServer for RoochServer {
         payload: BytesView,
     ) -> RpcResult<ExecuteTransactionResponseView> {
         let tx = bcs::from_bytes::<RoochTransaction>(&payload.0).map_err(anyhow::Error::from)?;
+        if let Some(upstream) = self.rpc_service.upstream() {
+            return Ok(upstream.rooch.execute_tx(tx).await?);
+        }
         Ok(self
             .rpc_service
             .execute_tx(TypedTransaction::Rooch(tx))
-            .await?
+            .await
+            .map_err(to_rpc_error)?
+            .into())
+    }
+
+    async fn send_raw_sponsored_transaction(&self, payload: BytesView) -> RpcResult<H256View> {
+        let tx = bcs::from_bytes::<SponsoredRoochTransaction>(&payload.0)
+            .map_err(anyhow::Error::from)?;
+        info!("send_raw_sponsored_transaction tx: {:?}", tx);
+
+        if let Some(upstream) = self.rpc_service.upstream() {
+            return Ok(upstream.rooch.send_sponsored_tx(tx).await?.into());
+        }
+        let hash = tx.tx_hash();
+        self.rpc_service
+            .quene_tx(TypedTransaction::RoochSponsored(tx))
+            .await
+            .map_err(to_rpc_error)?;
+        Ok(hash.into())
+    }
+
+    async fn execute_raw_sponsored_transaction(
+        &self,
+        payload: BytesView,
+    ) -> RpcResult<ExecuteTransactionResponseView> {
+        let tx = bcs::from_bytes::<SponsoredRoochTransaction>(&payload.0)
+            .map_err(anyhow::Error::from)?;
+        if let Some(upstream) = self.rpc_service.upstream() {
+            return Ok(upstream.rooch.execute_sponsored_tx(tx).await?);
+        }
+        Ok(self
+            .rpc_service
+            .execute_tx(TypedTransaction::RoochSponsored(tx))
+            .await
+            .map_err(to_rpc_error)?
+            .into())
+    }
+
+    async fn dry_run_raw_transaction(
+        &self,
+        payload: BytesView,
+        profile_gas: Option<bool>,
+    ) -> RpcResult<DryRunTransactionResponseView> {
+        let tx = bcs::from_bytes::<RoochTransaction>(&payload.0).map_err(anyhow::Error::from)?;
+        Ok(self
+            .rpc_service
+            .dry_run_tx(TypedTransaction::Rooch(tx), profile_gas.unwrap_or(false))
+            .await
+            .map_err(to_rpc_error)?
             .into())
     }
 
@@ -90,10 +144,13 @@ impl RoochAPIServer for RoochServer {
         &self,
         function_call: FunctionCallView,
     ) -> RpcResult<AnnotatedFunctionResultView> {
+        let sender = function_call.sender.map(Into::into);
+        let max_gas_amount = function_call.max_gas_amount.map(|gas| gas.0);
         Ok(self
             .rpc_service
-            .execute_view_function(function_call.into())
-            .await?
+            .execute_view_function(function_call.into(), sender, max_gas_amount)
+            .await
+            .map_err(to_rpc_error)?
             .into())
     }
 
@@ -132,7 +189,7 @@ impl RoochAPIServer for RoochServer {
         let state_option = state_option.unwrap_or_default();
         let limit_of = min(
             limit.map(Into::into).unwrap_or(DEFAULT_RESULT_LIMIT_USIZE),
-            MAX_RESULT_LIMIT_USIZE,
+            self.rpc_service.max_page_size(),
         );
         let cursor_of = cursor.clone().map(|v| v.0);
         let mut data: Vec<KeyStateKVView> = if state_option.decode {
@@ -180,7 +237,10 @@ impl RoochAPIServer for RoochServer {
         let limit = limit.map(|v| v.0);
 
         // NOTE: fetch one more object to check if there is next page
-        let limit_of = min(limit.unwrap_or(DEFAULT_RESULT_LIMIT), MAX_RESULT_LIMIT);
+        let limit_of = min(
+            limit.unwrap_or(DEFAULT_RESULT_LIMIT),
+            self.rpc_service.max_page_size() as u64,
+        );
         let limit = limit_of + 1;
         let mut data = if event_options.decode {
             self.rpc_service
@@ -239,21 +299,33 @@ impl RoochAPIServer for RoochServer {
         cursor: Option<StrView<u64>>,
         limit: Option<StrView<u64>>,
     ) -> RpcResult<TransactionWithInfoPageView> {
-        let last_sequencer_order = self
-            .rpc_service
-            .get_sequencer_order()
-            .await?
-            .map_or(0, |v| v.last_order);
-
-        let limit_of = limit.map(Into::into).unwrap_or(DEFAULT_RESULT_LIMIT);
+        let limit_of = min(
+            limit.map(Into::into).unwrap_or(DEFAULT_RESULT_LIMIT),
+            self.rpc_service.max_page_size() as u64,
+        );
         let cursor = cursor.map(|v| v.0);
+        let last_sequencer_order = match self.rpc_service.get_sequencer_order().await? {
+            Some(order) => order.last_order,
+            None => {
+                return Ok(TransactionWithInfoPageView {
+                    data: vec![],
+                    next_cursor: cursor,
+                    has_next_page: false,
+                })
+            }
+        };
         let start = cursor.unwrap_or(0);
-        let end = min(start + (limit_of + 1), last_sequencer_order + 1);
 
+        // Fetch one more tx order to check whether there is a next page
         let mut tx_orders: Vec<_> = if cursor.is_some() {
-            ((start + 1)..=end).collect()
+            (start.saturating_add(1)
+                ..=min(
+                    start.saturating_add(limit_of).saturating_add(1),
+                    last_sequencer_order,
+                ))
+                .collect()
         } else {
-            (start..end).collect()
+            (start..=min(start.saturating_add(limit_of), last_sequencer_order)).collect()
         };
 
         // Since tx order is strictly incremental, traversing the SMT Tree can be optimized into a multi get query to improve query performance.
@@ -317,7 +389,7 @@ impl RoochAPIServer for RoochServer {
     ) -> RpcResult<BalanceInfoPageView> {
         let limit_of = min(
             limit.map(Into::into).unwrap_or(DEFAULT_RESULT_LIMIT_USIZE),
-            MAX_RESULT_LIMIT_USIZE,
+            self.rpc_service.max_page_size(),
         );
         let cursor_of = cursor.clone().map(|v| v.0);
 
@@ -344,6 +416,41 @@ impl RoochAPIServer for RoochServer {
         })
     }
 
+    async fn get_session_keys(
+        &self,
+        account_addr: AccountAddressView,
+        cursor: Option<BytesView>,
+        limit: Option<StrView<usize>>,
+    ) -> RpcResult<SessionKeyPageView> {
+        let limit_of = min(
+            limit.map(Into::into).unwrap_or(DEFAULT_RESULT_LIMIT_USIZE),
+            self.rpc_service.max_page_size(),
+        );
+        let cursor_of = cursor.clone().map(|v| v.0);
+
+        let mut data = self
+            .aggregate_service
+            .get_session_keys(account_addr.into(), cursor_of, limit_of + 1)
+            .await?;
+
+        let has_next_page = data.len() > limit_of;
+        data.truncate(limit_of);
+
+        let next_cursor = data
+            .last()
+            .cloned()
+            .map_or(cursor, |(key, _session_key)| Some(StrView(key)));
+
+        Ok(SessionKeyPageView {
+            data: data
+                .into_iter()
+                .map(|(_, session_key)| session_key)
+                .collect(),
+            next_cursor,
+            has_next_page,
+        })
+    }
+
     async fn query_transactions(
         &self,
         filter: TransactionFilterView,
@@ -354,7 +461,7 @@ impl RoochAPIServer for RoochServer {
     ) -> RpcResult<TransactionWithInfoPageView> {
         let limit_of = min(
             limit.map(Into::into).unwrap_or(DEFAULT_RESULT_LIMIT_USIZE),
-            MAX_RESULT_LIMIT_USIZE,
+            self.rpc_service.max_page_size(),
         );
         let cursor = cursor.map(|v| v.0);
         let descending_order = descending_order.unwrap_or(true);
@@ -391,7 +498,7 @@ impl RoochAPIServer for RoochServer {
     ) -> RpcResult<IndexerEventPageView> {
         let limit_of = min(
             limit.map(Into::into).unwrap_or(DEFAULT_RESULT_LIMIT_USIZE),
-            MAX_RESULT_LIMIT_USIZE,
+            self.rpc_service.max_page_size(),
         );
         let descending_order = descending_order.unwrap_or(true);
 
@@ -427,7 +534,7 @@ impl RoochAPIServer for RoochServer {
     ) -> RpcResult<IndexerGlobalStatePageView> {
         let limit_of = min(
             limit.map(Into::into).unwrap_or(DEFAULT_RESULT_LIMIT_USIZE),
-            MAX_RESULT_LIMIT_USIZE,
+            self.rpc_service.max_page_size(),
         );
         let descending_order = descending_order.unwrap_or(true);
 
@@ -480,7 +587,7 @@ impl RoochAPIServer for RoochServer {
     ) -> RpcResult<IndexerTableStatePageView> {
         let limit_of = min(
             limit.map(Into::into).unwrap_or(DEFAULT_RESULT_LIMIT_USIZE),
-            MAX_RESULT_LIMIT_USIZE,
+            self.rpc_service.max_page_size(),
         );
         let descending_order = descending_order.unwrap_or(true);
 
@@ -515,7 +622,7 @@ impl RoochAPIServer for RoochServer {
     ) -> RpcResult<IndexerTableChangeSetPageView> {
         let limit_of = min(
             limit.map(Into::into).unwrap_or(DEFAULT_RESULT_LIMIT_USIZE),
-            MAX_RESULT_LIMIT_USIZE,
+            self.rpc_service.max_page_size(),
         );
         // Sync from asc by default
         let descending_order = descending_order.unwrap_or(false);
@@ -545,6 +652,30 @@ impl RoochAPIServer for RoochServer {
             has_next_page,
         })
     }
+
+    async fn get_pending_transactions(
+        &self,
+        sender: Option<AccountAddressView>,
+        limit: Option<StrView<usize>>,
+    ) -> RpcResult<Vec<PendingTransactionView>> {
+        let limit_of = min(
+            limit.map(Into::into).unwrap_or(DEFAULT_RESULT_LIMIT_USIZE),
+            self.rpc_service.max_page_size(),
+        );
+        let data = self
+            .rpc_service
+            .get_pending_transactions(sender.map(Into::into))
+            .await?
+            .into_iter()
+            .take(limit_of)
+            .map(PendingTransactionView::from)
+            .collect();
+        Ok(data)
+    }
+
+    async fn get_mempool_status(&self) -> RpcResult<MempoolStatusView> {
+        Ok(self.rpc_service.get_mempool_status().await?.into())
+    }
 }
 
 impl RoochRpcModule for RoochServer {
@@ -0,0 +1,151 @@
+// Copyright (c) RoochNetwork
+// SPDX-License-Identifier: Apache-2.0
+
+use hyper::header::AUTHORIZATION;
+use hyper::{Body, Request, Response, StatusCode};
+use std::future::Future;
+use std::pin::Pin;
+use std::sync::Arc;
+use std::task::{Context, Poll};
+use tower::{Layer, Service};
+
+/// A tower layer which rejects the HTTP requests without the `Authorization: Bearer <token>` header
+/// with `401 Unauthorized`, it protects the admin JSON-RPC server.
+#[derive(Clone)]
+pub struct AdminAuthLayer {
+    token: Arc<String>,
+}
+
+impl AdminAuthLayer {
+    pub fn new(token: String) -> Self {
+        Self {
+            token: Arc::new(token),
+        }
+    }
+}
+
+impl<S> Layer<S> for AdminAuthLayer {
+    type Service = AdminAuth<S>;
+
+    fn layer(&self, inner: S) -> Self::Service {
+        AdminAuth {
+            inner,
+            token: self.token.clone(),
+        }
+    }
+}
+
+#[derive(Clone)]
+pub struct AdminAuth<S> {
+    inner: S,
+    token: Arc<String>,
+}
+
+impl<S> AdminAuth<S> {
+    fn is_authorized(&self, request: &Request<Body>) -> bool {
+        // An empty token never authorizes a request
+        !self.token.is_empty()
+            && request
+                .headers()
+                .get(AUTHORIZATION)
+                .and_then(|value| value.to_str().ok())
+                .and_then(|value| value.strip_prefix("Bearer "))
+                .map_or(false, |token| constant_time_eq(token, &self.token))
+    }
+}
+
+impl<S> Service<Request<Body>> for AdminAuth<S>
+where
+    S: Service<Request<Body>, Response = Response<Body>>,
+    S::Future: Send + 'static,
+{
+    type Response = Response<Body>;
+    type Error = S::Error;
+    type Future = Pin<Box<dyn Future<Output = Result<Self::Response, Self::Error>> + Send>>;
+
+    fn poll_ready(&mut self, cx: &mut Context<'_>) -> Poll<Result<(), Self::Error>> {
+        self.inner.poll_ready(cx)
+    }
+
+    fn call(&mut self, request: Request<Body>) -> Self::Future {
+        if self.is_authorized(&request) {
+            Box::pin(self.inner.call(request))
+        } else {
+            Box::pin(async {
+                let mut response = Response::new(Body::from("Unauthorized"));
+                *response.status_mut() = StatusCode::UNAUTHORIZED;
+                Ok(response)
+            })
+        }
+    }
+}
+
+/// Compare the tokens without returning early, so the response time does not leak the token.
+fn constant_time_eq(a: &str, b: &str) -> bool {
+    a.len() == b.len()
+        && a.bytes()
+            .zip(b.bytes())
+            .fold(0u8, |acc, (x, y)| acc | (x ^ y))
+            == 0
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::convert::Infallible;
+    use tower::{service_fn, ServiceExt};
+
+    async fn call_admin(token: &str, authorization: Option<&str>) -> StatusCode {
+        let inner = service_fn(|_request: Request<Body>| async {
+            Ok::<_, Infallible>(Response::new(Body::from("ok")))
+        });
+        let service = AdminAuthLayer::new(token.to_owned()).layer(inner);
+        let mut request = Request::builder();
+        if let Some(authorization) = authorization {
+            request = request.header(AUTHORIZATION, authorization);
+        }
+        service
+            .oneshot(request.body(Body::empty()).unwrap())
+            .await
+            .unwrap()
+            .status()
+    }
+
+    #[test]
+    fn test_constant_time_eq() {
+        assert!(constant_time_eq("", ""));
+        assert!(constant_time_eq("token", "token"));
+        assert!(!constant_time_eq("token", "tokem"));
+        assert!(!constant_time_eq("token", "token1"));
+        assert!(!constant_time_eq("", "token"));
+    }
+
+    #[tokio::test]
+    async fn test_admin_auth_without_token() {
+        assert_eq!(call_admin("token", None).await, StatusCode::UNAUTHORIZED);
+    }
+
+    #[tokio::test]
+    async fn test_admin_auth_with_wrong_token() {
+        assert_eq!(
+            call_admin("token", Some("Bearer wrong")).await,
+            StatusCode::UNAUTHORIZED
+        );
+        assert_eq!(
+            call_admin("token", Some("Basic token")).await,
+            StatusCode::UNAUTHORIZED
+        );
+        assert_eq!(
+            call_admin("", Some("Bearer ")).await,
+            StatusCode::UNAUTHORIZED
+        );
+    }
+
+    #[tokio::test]
+    async fn test_admin_auth_with_token() {
+        assert_eq!(
+            call_admin("token", Some("Bearer token")).await,
+            StatusCode::OK
+        );
+    }
+}
@@ -12,15 +12,17 @@ use moveos_types::moveos_std::object::ObjectID;
 use moveos_types::moveos_std::raw_table::TableInfo;
 use moveos_types::state::{AnnotatedKeyState, KeyState, PlaceholderStruct, State};
 use moveos_types::state_resolver::{AnnotatedKeyStateKV, KeyStateKV};
-use rooch_rpc_api::jsonrpc_types::account_view::BalanceInfoView;
+use rooch_rpc_api::jsonrpc_types::account_view::{BalanceInfoView, SessionKeyView};
 use rooch_rpc_api::jsonrpc_types::CoinInfoView;
 use rooch_types::address::{BitcoinAddress, MultiChainAddress};
-use rooch_types::bitcoin::ord::{Inscription, InscriptionState};
+use rooch_types::bitcoin::ord::{Inscription, InscriptionID, InscriptionState};
 use rooch_types::bitcoin::utxo::{UTXOState, UTXO};
 use rooch_types::framework::account_coin_store::AccountCoinStoreModule;
 use rooch_types::framework::address_mapping::AddressMapping;
 use rooch_types::framework::coin::{CoinInfo, CoinModule};
 use rooch_types::framework::coin_store::CoinStore;
+use rooch_types::framework::session_key::{SessionKey, SessionKeyModule};
+use rooch_types::framework::timestamp::TimestampModule;
 use rooch_types::indexer::state::IndexerGlobalState;
 use rooch_types::multichain_id::RoochMultiChainID;
 use rooch_types::transaction::{TransactionSequenceInfoMapping, TransactionWithInfo};
@@ -184,6 +186,44 @@ impl AggregateService {
         }
     }
 
+    pub async fn get_session_keys(
+        &self,
+        account_addr: AccountAddress,
+        cursor: Option<Vec<u8>>,
+        limit: usize,
+    ) -> Result<Vec<(Vec<u8>, SessionKeyView)>> {
+        let session_key_module = self
+            .rpc_service
+            .executor
+            .as_module_binding::<SessionKeyModule>();
+        let session_keys_handle_opt = session_key_module.session_keys_handle(account_addr)?;
+
+        match session_keys_handle_opt {
+            Some(session_keys_handle) => {
+                // Use the on-chain timestamp to check the expiration, same as the session key validator.
+                let now_seconds = self
+                    .rpc_service
+                    .executor
+                    .as_module_binding::<TimestampModule>()
+                    .now_seconds()?;
+                self.rpc_service
+                    .list_states(
+                        AccessPath::table_without_keys(session_keys_handle),
+                        cursor,
+                        limit,
+                    )
+                    .await?
+                    .into_iter()
+                    .map(|(k, v)| {
+                        let session_key = v.cast::<SessionKey>()?;
+                        Ok((k, SessionKeyView::new(session_key, now_seconds)))
+                    })
+                    .collect::<Result<Vec<_>>>()
+            }
+            None => Ok(vec![]),
+        }
+    }
+
     pub async fn get_transaction_with_info(
         &self,
         tx_hashes: Vec<H256>,
@@ -518,4 +558,54 @@ impl AggregateService {
             .collect::<Result<Vec<_>>>()?;
         Ok(data)
     }
+
+    /// Resolve the bitcoin addresses of the rooch addresses via the reverse address mapping
+    pub async fn get_bitcoin_addresses(
+        &self,
+        addresses: Vec<AccountAddress>,
+    ) -> Result<HashMap<AccountAddress, Option<BitcoinAddress>>> {
+        let address_keys = addresses.iter().map(|m| m.to_vec()).collect::<Vec<_>>();
+
+        let address_mapping_module = self
+            .rpc_service
+            .executor
+            .as_module_binding::<AddressMapping>();
+        let (_address_mapping_handle, _mapping_handle, reverse_mapping_handle) =
+            address_mapping_module.address_mapping_handle()?;
+
+        let access_path = AccessPath::table(reverse_mapping_handle, address_keys);
+        let bitcoin_addresses = self
+            .rpc_service
+            .get_states(access_path)
+            .await?
+            .into_iter()
+            .zip(addresses)
+            .map(|(state_opt, address)| {
+                let reverse_mapping_opt = state_opt
+                    .map(|state| state.cast_unchecked::<Vec<MultiChainAddress>>())
+                    .transpose()?;
+                let bitcoin_address = reverse_mapping_opt.and_then(|m| {
+                    m.iter()
+                        .find(|v| v.multichain_id == RoochMultiChainID::Bitcoin)
+                        .map(|p| BitcoinAddress::new(p.raw_address.clone()))
+                });
+                Ok((address, bitcoin_address))
+            })
+            .collect::<Result<HashMap<_, _>>>()?;
+        Ok(bitcoin_addresses)
+    }
+
+    pub async fn get_inscription(
+        &self,
+        inscription_id: &InscriptionID,
+    ) -> Result<Option<Inscription>> {
+        let access_path = AccessPath::object(inscription_id.object_id());
+        self.rpc_service
+            .get_states(access_path)
+            .await?
+            .pop()
+            .flatten()
+            .map(|state| Ok(state.as_object_uncheck::<Inscription>()?.value))
+            .transpose()
+    }
 }
@@ -0,0 +1,249 @@
+// Copyright (c) RoochNetwork
+// SPDX-License-Identifier: Apache-2.0
+
+use crate::service::inscription_content::CONTENT_PATH_PREFIX;
+use crate::service::rate_limit::{
+    error_response, read_body, MethodClass, INVALID_REQUEST_ERROR_CODE,
+};
+use crate::service::rpc_service::RpcService;
+use anyhow::{bail, Result};
+use hyper::{Body, Method, Request, Response, StatusCode};
+use rooch_rpc_api::jsonrpc_types::{TransactionWithInfoPageView, TransactionWithInfoView};
+use rooch_rpc_client::Client;
+use rooch_types::transaction::{TransactionSequenceInfo, TypedTransaction};
+use serde_json::Value;
+use std::future::Future;
+use std::pin::Pin;
+use std::sync::{Arc, RwLock};
+use std::task::{Context, Poll};
+use std::time::Duration;
+use tower::{Layer, Service};
+
+/// The max number of transactions pulled from the upstream node in one request.
+const FOLLOWER_SYNC_BATCH_SIZE: u64 = 100;
+/// The interval to poll the upstream node when the follower catches up.
+const FOLLOWER_POLL_INTERVAL: Duration = Duration::from_secs(1);
+
+/// The JSON-RPC error code of the calls refused by a stopped follower, `Resource unavailable` defined in EIP-1474.
+pub const FOLLOWER_STOPPED_ERROR_CODE: i64 = -32002;
+
+/// The status of a follower node, the follower is unhealthy after it stops following the upstream
+/// node, such as the local state diverges from the upstream node.
+#[derive(Clone, Default)]
+pub struct FollowerStatus {
+    stopped_reason: Arc<RwLock<Option<String>>>,
+}
+
+impl FollowerStatus {
+    /// Return an error with the reason if the follower has stopped.
+    pub fn check(&self) -> Result<()> {
+        match self
+            .stopped_reason
+            .read()
+            .expect("follower status lock poisoned")
+            .as_ref()
+        {
+            Some(reason) => bail!(
+                "The follower stopped following the upstream node: {}",
+                reason
+            ),
+            None => Ok(()),
+        }
+    }
+
+    fn stop(&self, reason: String) {
+        *self
+            .stopped_reason
+            .write()
+            .expect("follower status lock poisoned") = Some(reason);
+    }
+}
+
+/// Follower pulls the ordered transactions from the upstream node and executes them locally.
+pub struct Follower {
+    rpc_service: RpcService,
+    upstream: Client,
+}
+
+impl Follower {
+    pub fn new(rpc_service: RpcService, upstream: Client) -> Self {
+        Self {
+            rpc_service,
+            upstream,
+        }
+    }
+
+    /// Sync the transactions from the upstream node until the local state diverges.
+    /// This loop should be spawned once after the RpcService is created.
+    pub async fn run(self) {
+        loop {
+            let page = match self.fetch_next_transactions().await {
+                Ok(page) => page,
+                Err(error) => {
+                    log::warn!("Follower fetch transactions from upstream error: {}", error);
+                    tokio::time::sleep(FOLLOWER_POLL_INTERVAL).await;
+                    continue;
+                }
+            };
+            for tx_with_info in page.data {
+                let tx_order = tx_with_info.sequence_info.tx_order.0;
+                // The transactions are appended in order, we can not skip a failed one.
+                if let Err(error) = self.follow_transaction(tx_with_info).await {
+                    log::error!(
+                        "Follower stopped, follow transaction order {} error: {:?}",
+                        tx_order,
+                        error
+                    );
+                    // The local state is behind or diverges from the upstream node, refuse the reads
+                    if let Some(status) = self.rpc_service.follower_status() {
+                        status.stop(format!(
+                            "follow transaction order {} error: {}",
+                            tx_order, error
+                        ));
+                    }
+                    return;
+                }
+            }
+            if !page.has_next_page {
+                tokio::time::sleep(FOLLOWER_POLL_INTERVAL).await;
+            }
+        }
+    }
+
+    async fn fetch_next_transactions(&self) -> Result<TransactionWithInfoPageView> {
+        // The cursor is exclusive, and `None` means start from the first transaction
+        let cursor = self
+            .rpc_service
+            .get_sequencer_order()
+            .await?
+            .map(|order| order.last_order);
+        self.upstream
+            .rooch
+            .get_transactions_by_order(cursor, Some(FOLLOWER_SYNC_BATCH_SIZE))
+            .await
+    }
+
+    async fn follow_transaction(&self, tx_with_info: TransactionWithInfoView) -> Result<()> {
+        let TransactionWithInfoView {
+            transaction,
+            sequence_info,
+            execution_info,
+        } = tx_with_info;
+        let tx = TypedTransaction::try_from(transaction)?;
+        let sequence_info = TransactionSequenceInfo::from(sequence_info);
+        self.rpc_service
+            .follow_tx(tx, sequence_info, execution_info.state_root.into())
+            .await?;
+        Ok(())
+    }
+}
+
+/// A tower layer which refuses the read requests after the follower stops, the local state is
+/// stale or diverges from the upstream node. The write calls are still forwarded to the upstream node.
+#[derive(Clone)]
+pub struct FollowerStatusLayer {
+    status: FollowerStatus,
+}
+
+impl FollowerStatusLayer {
+    pub fn new(status: FollowerStatus) -> Self {
+        Self { status }
+    }
+}
+
+impl<S> Layer<S> for FollowerStatusLayer {
+    type Service = FollowerStatusService<S>;
+
+    fn layer(&self, inner: S) -> Self::Service {
+        FollowerStatusService {
+            inner,
+            status: self.status.clone(),
+        }
+    }
+}
+
+#[derive(Clone)]
+pub struct FollowerStatusService<S> {
+    inner: S,
+    status: FollowerStatus,
+}
+
+impl<S> Service<Request<Body>> for FollowerStatusService<S>
+where
+    S: Service<Request<Body>, Response = Response<Body>> + Clone + Send + 'static,
+    S::Future: Send + 'static,
+    S::Error: Send + 'static,
+{
+    type Response = Response<Body>;
+    type Error = S::Error;
+    type Future = Pin<Box<dyn Future<Output = Result<Self::Response, Self::Error>> + Send>>;
+
+    fn poll_ready(&mut self, cx: &mut Context<'_>) -> Poll<Result<(), Self::Error>> {
+        self.inner.poll_ready(cx)
+    }
+
+    fn call(&mut self, request: Request<Body>) -> Self::Future {
+        let stopped = match self.status.check() {
+            Ok(()) => return Box::pin(self.inner.call(request)),
+            Err(error) => error.to_string(),
+        };
+        if request.method() == Method::GET && request.uri().path().starts_with(CONTENT_PATH_PREFIX)
+        {
+            return Box::pin(async move { Ok(unavailable(Value::Null, stopped)) });
+        }
+        if request.method() != Method::POST {
+            return Box::pin(self.inner.call(request));
+        }
+        // The inner service is ready, take it and leave a clone for the next request
+        let clone = self.inner.clone();
+        let mut inner = std::mem::replace(&mut self.inner, clone);
+        Box::pin(async move {
+            let (parts, body) = request.into_parts();
+            let body = match read_body(body).await {
+                Some(body) => body,
+                None => {
+                    return Ok(error_response(
+                        StatusCode::PAYLOAD_TOO_LARGE,
+                        INVALID_REQUEST_ERROR_CODE,
+                        Value::Null,
+                        "Failed to read the request body or the body is too large".to_owned(),
+                    ))
+                }
+            };
+            let forwarded = match serde_json::from_slice::<Value>(&body) {
+                Ok(Value::Array(calls)) => calls.iter().all(is_forwarded_call),
+                Ok(call) if !is_forwarded_call(&call) => {
+                    let id = call.get("id").cloned().unwrap_or(Value::Null);
+                    return Ok(unavailable(id, stopped));
+                }
+                Ok(_) => true,
+                Err(_) => false,
+            };
+            if !forwarded {
+                return Ok(unavailable(Value::Null, stopped));
+            }
+            inner
+                .call(Request::from_parts(parts, Body::from(body)))
+                .await
+        })
+    }
+}
+
+/// The write calls are forwarded to the upstream node, except the dry run, which executes the
+/// transaction on the local state.
+fn is_forwarded_call(call: &Value) -> bool {
+    call.get("method")
+        .and_then(Value::as_str)
+        .map_or(false, |method| {
+            MethodClass::of(method) == MethodClass::Write && method != "rooch_dryRunRawTransaction"
+        })
+}
+
+fn unavailable(id: Value, message: String) -> Response<Body> {
+    error_response(
+        StatusCode::SERVICE_UNAVAILABLE,
+        FOLLOWER_STOPPED_ERROR_CODE,
+        id,
+        message,
+    )
+}
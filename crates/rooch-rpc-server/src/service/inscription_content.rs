@@ -0,0 +1,126 @@
+// Copyright (c) RoochNetwork
+// SPDX-License-Identifier: Apache-2.0
+
+use crate::service::aggregate_service::AggregateService;
+use bitcoin_move::natives::ord::inscription_id::InscriptionId;
+use hyper::header::{
+    CACHE_CONTROL, CONTENT_ENCODING, CONTENT_SECURITY_POLICY, CONTENT_TYPE, X_CONTENT_TYPE_OPTIONS,
+};
+use hyper::{Body, Method, Request, Response, StatusCode};
+use moveos_types::move_std::string::MoveString;
+use std::future::Future;
+use std::pin::Pin;
+use std::str::FromStr;
+use std::task::{Context, Poll};
+use tower::{Layer, Service};
+
+pub(crate) const CONTENT_PATH_PREFIX: &str = "/content/";
+const DEFAULT_CONTENT_TYPE: &str = "application/octet-stream";
+/// The inscription content is immutable, so the response can be cached by the browsers and proxies.
+const CONTENT_CACHE_CONTROL: &str = "public, max-age=1209600, immutable";
+/// The inscription content is untrusted and served from the node origin, so it can only load the
+/// resources of the node, such as the other inscriptions, and the inline and data resources.
+const CONTENT_SECURITY_POLICY_VALUE: &str =
+    "default-src 'self' 'unsafe-eval' 'unsafe-inline' data: blob:";
+
+/// A tower layer which serves the raw inscription content on `GET /content/<inscription_id>`,
+/// with the inscription content type, so the inscriptions can be rendered straight from the node.
+/// The other requests are passed to the JSON-RPC server.
+#[derive(Clone)]
+pub struct InscriptionContentLayer {
+    aggregate_service: AggregateService,
+}
+
+impl InscriptionContentLayer {
+    pub fn new(aggregate_service: AggregateService) -> Self {
+        Self { aggregate_service }
+    }
+}
+
+impl<S> Layer<S> for InscriptionContentLayer {
+    type Service = InscriptionContent<S>;
+
+    fn layer(&self, inner: S) -> Self::Service {
+        InscriptionContent {
+            inner,
+            aggregate_service: self.aggregate_service.clone(),
+        }
+    }
+}
+
+#[derive(Clone)]
+pub struct InscriptionContent<S> {
+    inner: S,
+    aggregate_service: AggregateService,
+}
+
+impl<S> Service<Request<Body>> for InscriptionContent<S>
+where
+    S: Service<Request<Body>, Response = Response<Body>>,
+    S::Future: Send + 'static,
+{
+    type Response = Response<Body>;
+    type Error = S::Error;
+    type Future = Pin<Box<dyn Future<Output = Result<Self::Response, Self::Error>> + Send>>;
+
+    fn poll_ready(&mut self, cx: &mut Context<'_>) -> Poll<Result<(), Self::Error>> {
+        self.inner.poll_ready(cx)
+    }
+
+    fn call(&mut self, request: Request<Body>) -> Self::Future {
+        let inscription_id = match request.uri().path().strip_prefix(CONTENT_PATH_PREFIX) {
+            Some(inscription_id) if request.method() == Method::GET => inscription_id.to_string(),
+            _ => return Box::pin(self.inner.call(request)),
+        };
+        let aggregate_service = self.aggregate_service.clone();
+        Box::pin(async move { Ok(content_response(aggregate_service, inscription_id).await) })
+    }
+}
+
+async fn content_response(
+    aggregate_service: AggregateService,
+    inscription_id: String,
+) -> Response<Body> {
+    let inscription_id = match InscriptionId::from_str(inscription_id.as_str()) {
+        Ok(inscription_id) => inscription_id,
+        Err(e) => {
+            return error_response(
+                StatusCode::BAD_REQUEST,
+                format!("Invalid inscription id: {}", e),
+            )
+        }
+    };
+    let inscription = match aggregate_service
+        .get_inscription(&inscription_id.into())
+        .await
+    {
+        Ok(Some(inscription)) => inscription,
+        Ok(None) => {
+            return error_response(StatusCode::NOT_FOUND, "Inscription not found".to_string())
+        }
+        Err(e) => return error_response(StatusCode::INTERNAL_SERVER_ERROR, e.to_string()),
+    };
+
+    let content_type = Option::<MoveString>::from(inscription.content_type)
+        .map(|content_type| content_type.to_string())
+        .unwrap_or_else(|| DEFAULT_CONTENT_TYPE.to_string());
+    let mut builder = Response::builder()
+        .status(StatusCode::OK)
+        .header(CONTENT_TYPE, content_type)
+        .header(CACHE_CONTROL, CONTENT_CACHE_CONTROL)
+        .header(CONTENT_SECURITY_POLICY, CONTENT_SECURITY_POLICY_VALUE)
+        // The browsers must not sniff a different content type than the inscription one
+        .header(X_CONTENT_TYPE_OPTIONS, "nosniff");
+    if let Some(content_encoding) = Option::<MoveString>::from(inscription.content_encoding) {
+        builder = builder.header(CONTENT_ENCODING, content_encoding.to_string());
+    }
+    builder
+        .body(Body::from(inscription.body))
+        .unwrap_or_else(|e| error_response(StatusCode::INTERNAL_SERVER_ERROR, e.to_string()))
+}
+
+fn error_response(status: StatusCode, message: String) -> Response<Body> {
+    let mut response = Response::new(Body::from(message));
+    *response.status_mut() = status;
+    response
+}
@@ -1,6 +1,11 @@
 // Copyright (c) RoochNetwork
 // SPDX-License-Identifier: Apache-2.0
 
+pub mod admin_auth;
 pub mod aggregate_service;
+pub mod follower;
+pub mod inscription_content;
+pub mod rate_limit;
+pub mod rpc_forward;
 pub mod rpc_logger;
 pub mod rpc_service;
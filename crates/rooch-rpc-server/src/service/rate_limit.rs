@@ -0,0 +1,667 @@
+// Copyright (c) RoochNetwork
+// SPDX-License-Identifier: Apache-2.0
+
+use crate::service::inscription_content::CONTENT_PATH_PREFIX;
+use crate::service::rpc_forward::RemoteAddr;
+use hyper::body::HttpBody;
+use hyper::header::{HeaderMap, HeaderValue, CONTENT_TYPE, RETRY_AFTER};
+use hyper::{Body, Method, Request, Response, StatusCode};
+use rooch_config::rate_limit_config::{MethodClassQuotas, RateLimitConfig, RateLimitQuota};
+use serde_json::{json, Value};
+use std::collections::{HashMap, HashSet};
+use std::future::Future;
+use std::net::{IpAddr, SocketAddr};
+use std::pin::Pin;
+use std::sync::{Arc, Mutex};
+use std::task::{Context, Poll};
+use std::time::{Duration, Instant};
+use tower::{Layer, Service};
+
+/// The JSON-RPC error code of the rate limited calls, `Limit exceeded` defined in EIP-1474.
+pub const RATE_LIMIT_ERROR_CODE: i64 = -32005;
+pub(crate) const INVALID_REQUEST_ERROR_CODE: i64 = -32600;
+/// The same as the default max request body size of the jsonrpsee server.
+const MAX_REQUEST_BODY_SIZE: usize = 10 * 1024 * 1024;
+/// When more buckets are tracked, the full buckets are dropped, they are the same as new buckets.
+const MAX_TRACKED_BUCKETS: usize = 100_000;
+
+const API_KEY_HEADER: &str = "x-api-key";
+const FORWARDED_FOR_HEADER: &str = "x-forwarded-for";
+const REAL_IP_HEADER: &str = "x-real-ip";
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum MethodClass {
+    Write,
+    View,
+    Query,
+}
+
+/// The class of every registered JSON-RPC method, a method which executes a transaction is a
+/// write call even if it does not apply the state changes, a method which returns a page of
+/// states or scans the indexer is a query call. The server refuses to start if a registered
+/// method is not in the table.
+const METHOD_CLASSES: &[(&str, MethodClass)] = &[
+    ("rooch_getChainID", MethodClass::View),
+    ("rooch_sendRawTransaction", MethodClass::Write),
+    ("rooch_executeRawTransaction", MethodClass::Write),
+    ("rooch_sendRawSponsoredTransaction", MethodClass::Write),
+    ("rooch_executeRawSponsoredTransaction", MethodClass::Write),
+    ("rooch_dryRunRawTransaction", MethodClass::Write),
+    ("rooch_executeViewFunction", MethodClass::View),
+    ("rooch_getStates", MethodClass::View),
+    ("rooch_listStates", MethodClass::Query),
+    ("rooch_getEventsByEventHandle", MethodClass::Query),
+    ("rooch_getTransactionsByHash", MethodClass::View),
+    ("rooch_getTransactionsByOrder", MethodClass::Query),
+    ("rooch_getBalance", MethodClass::View),
+    ("rooch_getBalances", MethodClass::Query),
+    ("rooch_getSessionKeys", MethodClass::Query),
+    ("rooch_queryTransactions", MethodClass::Query),
+    ("rooch_queryEvents", MethodClass::Query),
+    ("rooch_queryGlobalStates", MethodClass::Query),
+    ("rooch_queryTableStates", MethodClass::Query),
+    ("rooch_syncStates", MethodClass::Query),
+    ("rooch_getPendingTransactions", MethodClass::Query),
+    ("rooch_getMempoolStatus", MethodClass::View),
+    ("eth_chainId", MethodClass::View),
+    ("eth_blockNumber", MethodClass::View),
+    ("eth_getBlockByNumber", MethodClass::View),
+    ("eth_getBalance", MethodClass::View),
+    ("eth_estimateGas", MethodClass::View),
+    ("eth_feeHistory", MethodClass::View),
+    ("eth_gasPrice", MethodClass::View),
+    ("eth_getTransactionCount", MethodClass::View),
+    ("eth_sendTransaction", MethodClass::Write),
+    ("eth_sendRawTransaction", MethodClass::Write),
+    ("eth_getTransactionReceipt", MethodClass::View),
+    ("eth_getTransactionByHash", MethodClass::View),
+    ("eth_getBlockByHash", MethodClass::View),
+    ("eth_getCode", MethodClass::View),
+    ("net_version", MethodClass::View),
+    ("btc_queryUTXOs", MethodClass::Query),
+    ("btc_queryInscriptions", MethodClass::Query),
+    ("btc_getInscriptionContent", MethodClass::View),
+    ("btc_getBRC20Tick", MethodClass::View),
+    ("btc_listBRC20Ticks", MethodClass::Query),
+    ("btc_getBRC20Balances", MethodClass::Query),
+    ("btc_queryBRC20Operations", MethodClass::Query),
+];
+
+impl MethodClass {
+    /// The class of a registered method, `None` if the method is not in the table.
+    pub fn try_of(method: &str) -> Option<Self> {
+        METHOD_CLASSES
+            .iter()
+            .find(|(name, _)| *name == method)
+            .map(|(_, class)| *class)
+    }
+
+    /// The unknown methods are view calls, the server answers them with an error.
+    pub fn of(method: &str) -> Self {
+        Self::try_of(method).unwrap_or(MethodClass::View)
+    }
+
+    fn quota(&self, quotas: &MethodClassQuotas) -> RateLimitQuota {
+        match self {
+            MethodClass::Write => quotas.write,
+            MethodClass::View => quotas.view,
+            MethodClass::Query => quotas.query,
+        }
+    }
+}
+
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+enum ClientId {
+    ApiKey(String),
+    /// The requests without the remote address share one budget
+    Ip(Option<IpAddr>),
+}
+
+struct TokenBucket {
+    tokens: f64,
+    last_refill: Instant,
+}
+
+impl TokenBucket {
+    fn new(quota: RateLimitQuota, now: Instant) -> Self {
+        Self {
+            tokens: quota.burst as f64,
+            last_refill: now,
+        }
+    }
+
+    fn refill(&mut self, quota: RateLimitQuota, now: Instant) {
+        let elapsed = now
+            .saturating_duration_since(self.last_refill)
+            .as_secs_f64();
+        self.tokens = (self.tokens + elapsed * quota.per_second as f64).min(quota.burst as f64);
+        self.last_refill = now;
+    }
+
+    /// The time to wait until `cost` tokens are available, zero if they are available now.
+    fn wait_time(&self, quota: RateLimitQuota, cost: u32) -> Duration {
+        let lack = cost as f64 - self.tokens;
+        if lack <= 0.0 {
+            Duration::ZERO
+        } else if quota.per_second == 0 || cost > quota.burst {
+            Duration::MAX
+        } else {
+            Duration::from_secs_f64(lack / quota.per_second as f64)
+        }
+    }
+
+    fn is_full(&self, quota: RateLimitQuota) -> bool {
+        self.tokens >= quota.burst as f64
+    }
+}
+
+pub struct RateLimiter {
+    config: RateLimitConfig,
+    api_keys: HashSet<String>,
+    trusted_proxies: HashSet<IpAddr>,
+    buckets: Mutex<HashMap<(ClientId, MethodClass), TokenBucket>>,
+}
+
+impl RateLimiter {
+    pub fn new(config: RateLimitConfig) -> Self {
+        let api_keys = config.api_keys.iter().cloned().collect();
+        let trusted_proxies = config.trusted_proxies.iter().cloned().collect();
+        Self {
+            config,
+            api_keys,
+            trusted_proxies,
+            buckets: Mutex::new(HashMap::new()),
+        }
+    }
+
+    fn client_id(&self, headers: &HeaderMap, remote_addr: Option<&RemoteAddr>) -> ClientId {
+        if let Some(api_key) = headers
+            .get(API_KEY_HEADER)
+            .and_then(|value| value.to_str().ok())
+            .filter(|key| self.api_keys.contains(*key))
+        {
+            return ClientId::ApiKey(api_key.to_owned());
+        }
+        let remote_ip = remote_addr.map(|remote_addr| remote_addr.0.ip());
+        match remote_ip {
+            // The forwarded headers are only trusted on the connections from the trusted proxies
+            Some(ip) if self.trusted_proxies.contains(&ip) => {
+                ClientId::Ip(self.forwarded_ip(headers).or(remote_ip))
+            }
+            _ => ClientId::Ip(remote_ip),
+        }
+    }
+
+    /// Every proxy appends the address it receives the request from to `X-Forwarded-For`, so the
+    /// last address which is not a trusted proxy is the client address.
+    fn forwarded_ip(&self, headers: &HeaderMap) -> Option<IpAddr> {
+        let forwarded_for = headers
+            .get_all(FORWARDED_FOR_HEADER)
+            .iter()
+            .filter_map(|value| value.to_str().ok())
+            .flat_map(|value| value.split(','))
+            .collect::<Vec<_>>();
+        if forwarded_for.is_empty() {
+            return headers
+                .get(REAL_IP_HEADER)
+                .and_then(|value| value.to_str().ok())
+                .and_then(parse_ip);
+        }
+        let mut client_ip = None;
+        for hop in forwarded_for.into_iter().rev() {
+            let ip = parse_ip(hop)?;
+            client_ip = Some(ip);
+            if !self.trusted_proxies.contains(&ip) {
+                break;
+            }
+        }
+        client_ip
+    }
+
+    fn quotas(&self, client: &ClientId) -> &MethodClassQuotas {
+        match client {
+            ClientId::ApiKey(_) => &self.config.api_key,
+            ClientId::Ip(_) => &self.config.ip,
+        }
+    }
+
+    /// Take the tokens of all the calls of a request, the request is either admitted as a whole
+    /// or rejected with the time to wait.
+    fn try_acquire(
+        &self,
+        client: &ClientId,
+        costs: &HashMap<MethodClass, u32>,
+    ) -> Result<(), Duration> {
+        let quotas = self.quotas(client);
+        let now = Instant::now();
+        let mut buckets = self
+            .buckets
+            .lock()
+            .expect("rate limit buckets lock poisoned");
+        if buckets.len() > MAX_TRACKED_BUCKETS {
+            buckets.retain(|(client, class), bucket| {
+                let quota = class.quota(self.quotas(client));
+                bucket.refill(quota, now);
+                !bucket.is_full(quota)
+            });
+        }
+
+        let mut wait_time = Duration::ZERO;
+        for (class, cost) in costs {
+            let quota = class.quota(quotas);
+            let bucket = buckets
+                .entry((client.clone(), *class))
+                .or_insert_with(|| TokenBucket::new(quota, now));
+            bucket.refill(quota, now);
+            wait_time = wait_time.max(bucket.wait_time(quota, *cost));
+        }
+        if !wait_time.is_zero() {
+            return Err(wait_time);
+        }
+        for (class, cost) in costs {
+            if let Some(bucket) = buckets.get_mut(&(client.clone(), *class)) {
+                bucket.tokens -= *cost as f64;
+            }
+        }
+        Ok(())
+    }
+}
+
+/// A tower layer which limits the JSON-RPC calls and the inscription content requests of every
+/// client with token buckets, the rejected requests get `429 Too Many Requests` with a JSON-RPC error.
+#[derive(Clone)]
+pub struct RateLimitLayer {
+    limiter: Arc<RateLimiter>,
+}
+
+impl RateLimitLayer {
+    pub fn new(limiter: Arc<RateLimiter>) -> Self {
+        Self { limiter }
+    }
+}
+
+impl<S> Layer<S> for RateLimitLayer {
+    type Service = RateLimit<S>;
+
+    fn layer(&self, inner: S) -> Self::Service {
+        RateLimit {
+            inner,
+            limiter: self.limiter.clone(),
+        }
+    }
+}
+
+#[derive(Clone)]
+pub struct RateLimit<S> {
+    inner: S,
+    limiter: Arc<RateLimiter>,
+}
+
+impl<S> Service<Request<Body>> for RateLimit<S>
+where
+    S: Service<Request<Body>, Response = Response<Body>> + Clone + Send + 'static,
+    S::Future: Send + 'static,
+    S::Error: Send + 'static,
+{
+    type Response = Response<Body>;
+    type Error = S::Error;
+    type Future = Pin<Box<dyn Future<Output = Result<Self::Response, Self::Error>> + Send>>;
+
+    fn poll_ready(&mut self, cx: &mut Context<'_>) -> Poll<Result<(), Self::Error>> {
+        self.inner.poll_ready(cx)
+    }
+
+    fn call(&mut self, request: Request<Body>) -> Self::Future {
+        // The inscription content route is not a JSON-RPC call, it costs one view call
+        let is_content_request = request.method() == Method::GET
+            && request.uri().path().starts_with(CONTENT_PATH_PREFIX);
+        if request.method() != Method::POST && !is_content_request {
+            return Box::pin(self.inner.call(request));
+        }
+        // The inner service is ready, take it and leave a clone for the next request
+        let clone = self.inner.clone();
+        let mut inner = std::mem::replace(&mut self.inner, clone);
+        let limiter = self.limiter.clone();
+        Box::pin(async move {
+            let client = limiter.client_id(request.headers(), request.extensions().get());
+            if is_content_request {
+                let costs = HashMap::from([(MethodClass::View, 1)]);
+                if let Err(wait_time) = limiter.try_acquire(&client, &costs) {
+                    return Ok(too_many_requests(Value::Null, wait_time));
+                }
+                return inner.call(request).await;
+            }
+            let (parts, body) = request.into_parts();
+            let body = match read_body(body).await {
+                Some(body) => body,
+                None => {
+                    return Ok(error_response(
+                        StatusCode::PAYLOAD_TOO_LARGE,
+                        INVALID_REQUEST_ERROR_CODE,
+                        Value::Null,
+                        "Failed to read the request body or the body is too large".to_owned(),
+                    ))
+                }
+            };
+
+            let (costs, id) = parse_calls(&body);
+            if let Err(wait_time) = limiter.try_acquire(&client, &costs) {
+                return Ok(too_many_requests(id, wait_time));
+            }
+            inner
+                .call(Request::from_parts(parts, Body::from(body)))
+                .await
+        })
+    }
+}
+
+/// Parse an IP address with or without the port.
+fn parse_ip(value: &str) -> Option<IpAddr> {
+    let value = value.trim();
+    value
+        .parse::<IpAddr>()
+        .or_else(|_| value.parse::<SocketAddr>().map(|addr| addr.ip()))
+        .ok()
+}
+
+pub(crate) async fn read_body(mut body: Body) -> Option<Vec<u8>> {
+    let mut bytes = Vec::new();
+    while let Some(chunk) = body.data().await {
+        let chunk = chunk.ok()?;
+        if bytes.len() + chunk.len() > MAX_REQUEST_BODY_SIZE {
+            return None;
+        }
+        bytes.extend_from_slice(&chunk);
+    }
+    Some(bytes)
+}
+
+/// Count the calls of each method class in a single or batch request, and return the id of a
+/// single request. An invalid request costs one view call, the server answers it with an error.
+fn parse_calls(body: &[u8]) -> (HashMap<MethodClass, u32>, Value) {
+    let mut costs = HashMap::new();
+    let method_of = |call: &Value| {
+        call.get("method")
+            .and_then(Value::as_str)
+            .map(MethodClass::of)
+    };
+    let id = match serde_json::from_slice::<Value>(body) {
+        Ok(Value::Array(calls)) => {
+            for call in calls.iter() {
+                let class = method_of(call).unwrap_or(MethodClass::View);
+                *costs.entry(class).or_insert(0) += 1;
+            }
+            Value::Null
+        }
+        Ok(call) => {
+            let class = method_of(&call).unwrap_or(MethodClass::View);
+            costs.insert(class, 1);
+            call.get("id").cloned().unwrap_or(Value::Null)
+        }
+        Err(_) => Value::Null,
+    };
+    if costs.is_empty() {
+        costs.insert(MethodClass::View, 1);
+    }
+    (costs, id)
+}
+
+fn too_many_requests(id: Value, wait_time: Duration) -> Response<Body> {
+    // Round up, a client retrying after `Retry-After` seconds should be admitted
+    let retry_after = wait_time.as_secs_f64().ceil().min(u32::MAX as f64) as u64;
+    let mut response = error_response(
+        StatusCode::TOO_MANY_REQUESTS,
+        RATE_LIMIT_ERROR_CODE,
+        id,
+        format!("Too many requests, retry after {} seconds", retry_after),
+    );
+    response
+        .headers_mut()
+        .insert(RETRY_AFTER, HeaderValue::from(retry_after));
+    response
+}
+
+pub(crate) fn error_response(
+    status: StatusCode,
+    code: i64,
+    id: Value,
+    message: String,
+) -> Response<Body> {
+    let body = json!({
+        "jsonrpc": "2.0",
+        "error": {
+            "code": code,
+            "message": message,
+        },
+        "id": id,
+    });
+    let mut response = Response::new(Body::from(body.to_string()));
+    *response.status_mut() = status;
+    response
+        .headers_mut()
+        .insert(CONTENT_TYPE, HeaderValue::from_static("application/json"));
+    response
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::convert::Infallible;
+    use tower::{service_fn, ServiceExt};
+
+    fn limiter(ip: MethodClassQuotas, trusted_proxies: Vec<IpAddr>) -> RateLimiter {
+        RateLimiter::new(RateLimitConfig {
+            ip,
+            api_keys: vec!["key".to_owned()],
+            trusted_proxies,
+            ..Default::default()
+        })
+    }
+
+    fn remote_addr(ip: [u8; 4]) -> RemoteAddr {
+        RemoteAddr(SocketAddr::from((ip, 1234)))
+    }
+
+    #[test]
+    fn test_method_class() {
+        assert_eq!(
+            MethodClass::of("rooch_sendRawTransaction"),
+            MethodClass::Write
+        );
+        assert_eq!(
+            MethodClass::of("eth_sendRawTransaction"),
+            MethodClass::Write
+        );
+        assert_eq!(
+            MethodClass::of("rooch_executeRawTransaction"),
+            MethodClass::Write
+        );
+        assert_eq!(
+            MethodClass::of("rooch_executeRawSponsoredTransaction"),
+            MethodClass::Write
+        );
+        assert_eq!(
+            MethodClass::of("rooch_dryRunRawTransaction"),
+            MethodClass::Write
+        );
+        assert_eq!(
+            MethodClass::of("rooch_queryTransactions"),
+            MethodClass::Query
+        );
+        assert_eq!(MethodClass::of("btc_queryUTXOs"), MethodClass::Query);
+        assert_eq!(MethodClass::of("rooch_syncStates"), MethodClass::Query);
+        assert_eq!(MethodClass::of("rooch_listStates"), MethodClass::Query);
+        assert_eq!(MethodClass::of("rooch_getStates"), MethodClass::View);
+        assert_eq!(
+            MethodClass::of("rooch_executeViewFunction"),
+            MethodClass::View
+        );
+        assert_eq!(MethodClass::try_of("unknown"), None);
+        assert_eq!(MethodClass::of("unknown"), MethodClass::View);
+    }
+
+    #[test]
+    fn test_method_classes_are_unique() {
+        let names = METHOD_CLASSES
+            .iter()
+            .map(|(name, _)| *name)
+            .collect::<HashSet<_>>();
+        assert_eq!(names.len(), METHOD_CLASSES.len());
+    }
+
+    #[test]
+    fn test_parse_calls() {
+        let (costs, id) = parse_calls(
+            br#"[{"method":"rooch_sendRawTransaction","id":1},{"method":"rooch_getStates","id":2},{"method":"rooch_getStates","id":3}]"#,
+        );
+        assert_eq!(costs.get(&MethodClass::Write), Some(&1));
+        assert_eq!(costs.get(&MethodClass::View), Some(&2));
+        assert_eq!(id, Value::Null);
+
+        let (costs, id) = parse_calls(br#"{"method":"rooch_queryEvents","id":7}"#);
+        assert_eq!(costs, HashMap::from([(MethodClass::Query, 1)]));
+        assert_eq!(id, json!(7));
+
+        let (costs, _) = parse_calls(b"invalid");
+        assert_eq!(costs, HashMap::from([(MethodClass::View, 1)]));
+    }
+
+    #[test]
+    fn test_token_bucket_refill() {
+        let quota = RateLimitQuota::new(2, 4);
+        let now = Instant::now();
+        let mut bucket = TokenBucket::new(quota, now);
+        assert!(bucket.is_full(quota));
+        bucket.tokens = 0.0;
+        assert_eq!(bucket.wait_time(quota, 1), Duration::from_millis(500));
+        assert_eq!(bucket.wait_time(quota, 5), Duration::MAX);
+
+        bucket.refill(quota, now + Duration::from_secs(1));
+        assert_eq!(bucket.tokens, 2.0);
+        assert_eq!(bucket.wait_time(quota, 2), Duration::ZERO);
+
+        // The bucket does not refill beyond the burst
+        bucket.refill(quota, now + Duration::from_secs(10));
+        assert!(bucket.is_full(quota));
+        assert_eq!(bucket.tokens, 4.0);
+    }
+
+    #[test]
+    fn test_batch_is_admitted_as_a_whole() {
+        let quotas = MethodClassQuotas {
+            write: RateLimitQuota::new(0, 1),
+            view: RateLimitQuota::new(0, 3),
+            query: RateLimitQuota::new(0, 1),
+        };
+        let limiter = limiter(quotas, vec![]);
+        let client = ClientId::Ip(Some(IpAddr::from([1, 2, 3, 4])));
+
+        // The write calls exceed the quota, so the view calls of the batch do not take tokens
+        let batch = HashMap::from([(MethodClass::View, 2), (MethodClass::Write, 2)]);
+        assert_eq!(limiter.try_acquire(&client, &batch), Err(Duration::MAX));
+        let batch = HashMap::from([(MethodClass::View, 3), (MethodClass::Write, 1)]);
+        assert_eq!(limiter.try_acquire(&client, &batch), Ok(()));
+        let batch = HashMap::from([(MethodClass::View, 1)]);
+        assert!(limiter.try_acquire(&client, &batch).is_err());
+
+        // Other clients have their own buckets
+        let other = ClientId::Ip(Some(IpAddr::from([5, 6, 7, 8])));
+        assert_eq!(limiter.try_acquire(&other, &batch), Ok(()));
+    }
+
+    #[test]
+    fn test_client_id() {
+        let limiter = limiter(
+            MethodClassQuotas::default_ip_quotas(),
+            vec![IpAddr::from([10, 0, 0, 1]), IpAddr::from([10, 0, 0, 2])],
+        );
+        let mut headers = HeaderMap::new();
+        headers.insert(
+            FORWARDED_FOR_HEADER,
+            HeaderValue::from_static("1.1.1.1, 2.2.2.2, 10.0.0.2"),
+        );
+        headers.insert(REAL_IP_HEADER, HeaderValue::from_static("3.3.3.3"));
+
+        // The forwarded headers from an untrusted connection are ignored
+        let client = limiter.client_id(&headers, Some(&remote_addr([4, 4, 4, 4])));
+        assert_eq!(client, ClientId::Ip(Some(IpAddr::from([4, 4, 4, 4]))));
+        assert_eq!(limiter.client_id(&headers, None), ClientId::Ip(None));
+
+        // The last address which is not a trusted proxy is the client address
+        let client = limiter.client_id(&headers, Some(&remote_addr([10, 0, 0, 1])));
+        assert_eq!(client, ClientId::Ip(Some(IpAddr::from([2, 2, 2, 2]))));
+
+        headers.remove(FORWARDED_FOR_HEADER);
+        let client = limiter.client_id(&headers, Some(&remote_addr([10, 0, 0, 1])));
+        assert_eq!(client, ClientId::Ip(Some(IpAddr::from([3, 3, 3, 3]))));
+
+        headers.insert(API_KEY_HEADER, HeaderValue::from_static("key"));
+        let client = limiter.client_id(&headers, Some(&remote_addr([4, 4, 4, 4])));
+        assert_eq!(client, ClientId::ApiKey("key".to_owned()));
+    }
+
+    #[tokio::test]
+    async fn test_too_many_requests() {
+        let quotas = MethodClassQuotas {
+            write: RateLimitQuota::new(1, 1),
+            view: RateLimitQuota::new(1, 1),
+            query: RateLimitQuota::new(1, 1),
+        };
+        let inner = service_fn(|_request: Request<Body>| async {
+            Ok::<_, Infallible>(Response::new(Body::from("ok")))
+        });
+        let service = RateLimitLayer::new(Arc::new(limiter(quotas, vec![]))).layer(inner);
+        let request = || {
+            let mut request = Request::post("/")
+                .body(Body::from(
+                    r#"{"jsonrpc":"2.0","method":"rooch_getStates","id":1}"#,
+                ))
+                .unwrap();
+            request.extensions_mut().insert(remote_addr([1, 2, 3, 4]));
+            request
+        };
+
+        let response = service.clone().oneshot(request()).await.unwrap();
+        assert_eq!(response.status(), StatusCode::OK);
+
+        let response = service.oneshot(request()).await.unwrap();
+        assert_eq!(response.status(), StatusCode::TOO_MANY_REQUESTS);
+        assert_eq!(response.headers().get(RETRY_AFTER).unwrap(), "1");
+        let body = hyper::body::to_bytes(response.into_body()).await.unwrap();
+        let body: Value = serde_json::from_slice(&body).unwrap();
+        assert_eq!(body["error"]["code"], json!(RATE_LIMIT_ERROR_CODE));
+        assert_eq!(body["id"], json!(1));
+    }
+
+    #[tokio::test]
+    async fn test_content_requests_are_limited() {
+        let quotas = MethodClassQuotas {
+            write: RateLimitQuota::new(1, 1),
+            view: RateLimitQuota::new(1, 1),
+            query: RateLimitQuota::new(1, 1),
+        };
+        let inner = service_fn(|_request: Request<Body>| async {
+            Ok::<_, Infallible>(Response::new(Body::from("ok")))
+        });
+        let service = RateLimitLayer::new(Arc::new(limiter(quotas, vec![]))).layer(inner);
+        let request = |uri: &str| {
+            let mut request = Request::get(uri).body(Body::empty()).unwrap();
+            request.extensions_mut().insert(remote_addr([1, 2, 3, 4]));
+            request
+        };
+
+        // The other GET requests are not JSON-RPC calls, they are not limited
+        for _ in 0..2 {
+            let response = service.clone().oneshot(request("/health")).await.unwrap();
+            assert_eq!(response.status(), StatusCode::OK);
+        }
+
+        let content_uri =
+            "/content/6fb976ab49dcec017f1e201e84395983204ae1a7c2abf7ced0a85d692e442799i0";
+        let response = service.clone().oneshot(request(content_uri)).await.unwrap();
+        assert_eq!(response.status(), StatusCode::OK);
+        let response = service.oneshot(request(content_uri)).await.unwrap();
+        assert_eq!(response.status(), StatusCode::TOO_MANY_REQUESTS);
+        assert_eq!(response.headers().get(RETRY_AFTER).unwrap(), "1");
+    }
+}
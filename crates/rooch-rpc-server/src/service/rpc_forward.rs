@@ -0,0 +1,58 @@
+// Copyright (c) RoochNetwork
+// SPDX-License-Identifier: Apache-2.0
+
+use hyper::client::{HttpConnector, ResponseFuture};
+use hyper::{Body, Client, Request, Response, Uri};
+use std::net::SocketAddr;
+use std::task::{Context, Poll};
+use tower::Service;
+
+/// The remote address of the connection a request comes from, the public HTTP server inserts it
+/// into the request extensions.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct RemoteAddr(pub SocketAddr);
+
+/// A service which forwards the HTTP requests to the JSON-RPC server listening on localhost.
+///
+/// The jsonrpsee server does not pass the remote address of the connection to its middleware,
+/// so when the rate limit is enabled, the public address is served by a hyper server, which runs
+/// the middleware with the remote address and forwards the admitted requests to the JSON-RPC server.
+#[derive(Clone)]
+pub struct RpcForward {
+    client: Client<HttpConnector>,
+    rpc_addr: SocketAddr,
+}
+
+impl RpcForward {
+    pub fn new(rpc_addr: SocketAddr) -> Self {
+        Self {
+            client: Client::new(),
+            rpc_addr,
+        }
+    }
+}
+
+impl Service<Request<Body>> for RpcForward {
+    type Response = Response<Body>;
+    type Error = hyper::Error;
+    type Future = ResponseFuture;
+
+    fn poll_ready(&mut self, _cx: &mut Context<'_>) -> Poll<Result<(), Self::Error>> {
+        Poll::Ready(Ok(()))
+    }
+
+    fn call(&mut self, mut request: Request<Body>) -> Self::Future {
+        let path_and_query = request
+            .uri()
+            .path_and_query()
+            .map_or("/", |path_and_query| path_and_query.as_str())
+            .to_owned();
+        *request.uri_mut() = Uri::builder()
+            .scheme("http")
+            .authority(self.rpc_addr.to_string())
+            .path_and_query(path_and_query)
+            .build()
+            .expect("the path of a request uri should be a valid uri path");
+        self.client.request(request)
+    }
+}
@@ -1,11 +1,21 @@
 // Copyright (c) RoochNetwork
 // SPDX-License-Identifier: Apache-2.0
 
+use crate::metrics::RpcMetrics;
 use jsonrpsee::server::logger::Logger;
 use tracing::Level;
 
-#[derive(Debug, Clone)]
-pub struct RpcLogger;
+#[derive(Clone, Default)]
+pub struct RpcLogger {
+    metrics: Option<RpcMetrics>,
+}
+
+impl RpcLogger {
+    pub fn with_metrics(mut self, metrics: RpcMetrics) -> Self {
+        self.metrics = Some(metrics);
+        self
+    }
+}
 
 impl Logger for RpcLogger {
     type Instant = std::time::Instant;
@@ -53,7 +63,19 @@ impl Logger for RpcLogger {
         started_at: Self::Instant,
         _transport: jsonrpsee::server::logger::TransportProtocol,
     ) {
-        let elapsed_millis = started_at.elapsed().as_millis();
+        let elapsed = started_at.elapsed();
+        if let Some(metrics) = &self.metrics {
+            let result = if success { "ok" } else { "error" };
+            metrics
+                .rpc_requests_total
+                .with_label_values(&[method_name, result])
+                .inc();
+            metrics
+                .rpc_request_latency_seconds
+                .with_label_values(&[method_name])
+                .observe(elapsed.as_secs_f64());
+        }
+        let elapsed_millis = elapsed.as_millis();
         tracing::event!(
             Level::INFO,
             event = "on_result",
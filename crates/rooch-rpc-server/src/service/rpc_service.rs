@@ -1,7 +1,8 @@
 // Copyright (c) RoochNetwork
 // SPDX-License-Identifier: Apache-2.0
 
-use anyhow::Result;
+use crate::service::follower::FollowerStatus;
+use anyhow::{anyhow, bail, Result};
 use move_core_types::account_address::AccountAddress;
 use move_core_types::language_storage::StructTag;
 
@@ -10,25 +11,49 @@ use moveos_types::function_return_value::AnnotatedFunctionResult;
 use moveos_types::h256::H256;
 use moveos_types::moveos_std::event::{AnnotatedEvent, Event, EventID};
 use moveos_types::state::{AnnotatedState, MoveStructType, State};
-use moveos_types::transaction::{FunctionCall, TransactionExecutionInfo};
+use moveos_types::transaction::{
+    FunctionCall, TransactionExecutionInfo, TransactionOutput, VerifiedMoveOSTransaction,
+};
 use rooch_executor::proxy::ExecutorProxy;
 use rooch_indexer::proxy::IndexerProxy;
+use rooch_mempool::messages::ReadyTransaction;
+use rooch_mempool::proxy::MempoolProxy;
 use rooch_proposer::proxy::ProposerProxy;
 use rooch_relayer::TxSubmiter;
-use rooch_rpc_api::jsonrpc_types::{ExecuteTransactionResponse, ExecuteTransactionResponseView};
+use rooch_rpc_api::api::MAX_RESULT_LIMIT_USIZE;
+use rooch_rpc_api::jsonrpc_types::{
+    DryRunTransactionResponse, ExecuteTransactionResponse, ExecuteTransactionResponseView,
+};
+use rooch_rpc_client::Client;
 use rooch_sequencer::proxy::SequencerProxy;
 use rooch_types::account::Account;
 use rooch_types::address::{MultiChainAddress, RoochAddress};
+use rooch_types::indexer::brc20::{
+    BRC20OperationFilter, IndexerBRC20Balance, IndexerBRC20Operation, IndexerBRC20Tick,
+};
 use rooch_types::indexer::event_filter::{EventFilter, IndexerEvent, IndexerEventID};
+use rooch_types::indexer::inscription::InscriptionFilter;
 use rooch_types::indexer::state::{
     GlobalStateFilter, IndexerGlobalState, IndexerStateID, IndexerTableChangeSet,
     IndexerTableState, StateSyncFilter, TableStateFilter,
 };
 use rooch_types::indexer::transaction_filter::TransactionFilter;
+use rooch_types::mempool::{MempoolStatus, PendingTransaction};
 use rooch_types::sequencer::SequencerOrder;
 use rooch_types::transaction::rooch::RoochTransaction;
+use rooch_types::transaction::{AbstractTransaction, TransactionWithInfo, TypedTransaction};
 use rooch_types::transaction::{TransactionSequenceInfo, TransactionSequenceInfoMapping};
-use rooch_types::transaction::{TransactionWithInfo, TypedTransaction};
+use std::collections::HashMap;
+use std::sync::{Arc, Mutex};
+use std::time::{Duration, Instant};
+use tokio::sync::{oneshot, Notify};
+
+/// The interval to re-check the mempool when no transaction is submitted,
+/// the transactions waiting for a sequence number gap may become ready in the meantime.
+const MEMPOOL_POLL_INTERVAL: Duration = Duration::from_millis(100);
+const MEMPOOL_EVICTION_INTERVAL: Duration = Duration::from_secs(10);
+
+type TxResultSender = oneshot::Sender<Result<ExecuteTransactionResponse>>;
 
 /// RpcService is the implementation of the RPC service.
 /// It is the glue between the RPC server(EthAPIServer,RoochApiServer) and the rooch's actors.
@@ -40,6 +65,16 @@ pub struct RpcService {
     pub(crate) sequencer: SequencerProxy,
     pub(crate) proposer: ProposerProxy,
     pub(crate) indexer: IndexerProxy,
+    pub(crate) mempool: MempoolProxy,
+    /// The upstream node of a follower node, the write requests are forwarded to it
+    upstream: Option<Client>,
+    /// The status of a follower node, None if the node is not a follower
+    follower_status: Option<FollowerStatus>,
+    /// The max number of items of a page returned by the list and query methods
+    max_page_size: usize,
+    /// The senders waiting for the execution result of the transactions in the mempool
+    tx_waiters: Arc<Mutex<HashMap<H256, TxResultSender>>>,
+    tx_notify: Arc<Notify>,
 }
 
 impl RpcService {
@@ -49,6 +84,7 @@ impl RpcService {
         sequencer: SequencerProxy,
         proposer: ProposerProxy,
         indexer: IndexerProxy,
+        mempool: MempoolProxy,
     ) -> Self {
         Self {
             chain_id,
@@ -56,8 +92,26 @@ impl RpcService {
             sequencer,
             proposer,
             indexer,
+            mempool,
+            upstream: None,
+            follower_status: None,
+            max_page_size: MAX_RESULT_LIMIT_USIZE,
+            tx_waiters: Arc::new(Mutex::new(HashMap::new())),
+            tx_notify: Arc::new(Notify::new()),
         }
     }
+
+    /// Run the service as a read-only follower of the `upstream` node.
+    pub fn with_upstream(mut self, upstream: Client) -> Self {
+        self.upstream = Some(upstream);
+        self.follower_status = Some(FollowerStatus::default());
+        self
+    }
+
+    pub fn with_max_page_size(mut self, max_page_size: usize) -> Self {
+        self.max_page_size = max_page_size;
+        self
+    }
 }
 
 impl RpcService {
@@ -65,14 +119,176 @@ impl RpcService {
         self.chain_id
     }
 
+    /// The upstream node if the service is a follower, the transactions should be forwarded to it.
+    pub fn upstream(&self) -> Option<&Client> {
+        self.upstream.as_ref()
+    }
+
+    /// The status of the follower node, it is unhealthy after the follower stops.
+    pub fn follower_status(&self) -> Option<&FollowerStatus> {
+        self.follower_status.as_ref()
+    }
+
+    pub fn max_page_size(&self) -> usize {
+        self.max_page_size
+    }
+
+    /// Add the transaction to the mempool and return without waiting for the execution.
     pub async fn quene_tx(&self, tx: TypedTransaction) -> Result<()> {
-        //TODO implement quene tx and do not wait to execute
-        let _ = self.execute_tx(tx).await?;
+        let _ = self.submit_to_mempool(tx).await?;
         Ok(())
     }
 
+    /// Add the transaction to the mempool and wait for the execution result.
     pub async fn execute_tx(&self, tx: TypedTransaction) -> Result<ExecuteTransactionResponse> {
-        // First, validate the transactin
+        let tx_hash = tx.tx_hash();
+        let receiver = self.submit_to_mempool(tx).await?;
+        receiver
+            .await
+            .map_err(|_| anyhow!("Transaction {:?} was removed from the mempool", tx_hash))?
+    }
+
+    /// Execute the transaction without applying the state changes, optionally with the gas profiler.
+    pub async fn dry_run_tx(
+        &self,
+        tx: TypedTransaction,
+        profile_gas: bool,
+    ) -> Result<DryRunTransactionResponse> {
+        let moveos_tx = self.executor.validate_transaction(tx).await?;
+        let result = self
+            .executor
+            .dry_run_transaction(moveos_tx, profile_gas)
+            .await?;
+        Ok(DryRunTransactionResponse {
+            output: result.output,
+            gas_profile: result.gas_profile,
+        })
+    }
+
+    async fn submit_to_mempool(
+        &self,
+        tx: TypedTransaction,
+    ) -> Result<oneshot::Receiver<Result<ExecuteTransactionResponse>>> {
+        let tx_hash = tx.tx_hash();
+        if self.upstream.is_some() {
+            bail!(
+                "The follower node does not accept transaction {:?}, send it to the upstream node",
+                tx_hash
+            );
+        }
+        // First, validate the transaction before admitting it to the mempool
+        let moveos_tx = self.executor.validate_transaction(tx.clone()).await?;
+        let sender = moveos_tx.ctx.sender;
+        let account_sequence_number = self.get_account_sequence_number(sender).await?;
+
+        let (result_sender, receiver) = oneshot::channel();
+        {
+            let mut waiters = self
+                .tx_waiters
+                .lock()
+                .expect("lock tx waiters should success");
+            if waiters.contains_key(&tx_hash) {
+                bail!("Transaction {:?} already exists in mempool", tx_hash);
+            }
+            waiters.insert(tx_hash, result_sender);
+        }
+        let result = self
+            .mempool
+            .add_transaction(
+                tx,
+                tx_hash,
+                sender,
+                moveos_tx.ctx.sequence_number,
+                moveos_tx.ctx.tx_size,
+                account_sequence_number,
+            )
+            .await;
+        match result {
+            Ok(removed) => {
+                self.drop_tx_waiters(removed);
+                self.tx_notify.notify_one();
+                Ok(receiver)
+            }
+            Err(error) => {
+                self.drop_tx_waiters(vec![tx_hash]);
+                Err(error)
+            }
+        }
+    }
+
+    /// Drop the waiters of the transactions removed from the mempool without execution.
+    fn drop_tx_waiters(&self, tx_hashes: Vec<H256>) {
+        if tx_hashes.is_empty() {
+            return;
+        }
+        let mut waiters = self
+            .tx_waiters
+            .lock()
+            .expect("lock tx waiters should success");
+        for tx_hash in tx_hashes {
+            waiters.remove(&tx_hash);
+        }
+    }
+
+    /// Move the ready transactions from the mempool to the sequencer one by one.
+    /// This loop should be spawned once after the RpcService is created.
+    pub async fn run_mempool_dispatcher(self) {
+        let mut last_eviction = Instant::now();
+        loop {
+            if last_eviction.elapsed() >= MEMPOOL_EVICTION_INTERVAL {
+                match self.mempool.evict_expired().await {
+                    Ok(evicted) => self.drop_tx_waiters(evicted),
+                    Err(error) => log::error!("Mempool evict expired error: {}", error),
+                }
+                last_eviction = Instant::now();
+            }
+            match self.mempool.pop_ready_transaction().await {
+                Ok(Some(ready)) => self.dispatch_tx(ready).await,
+                Ok(None) => {
+                    let _ = tokio::time::timeout(MEMPOOL_POLL_INTERVAL, self.tx_notify.notified())
+                        .await;
+                }
+                Err(error) => {
+                    log::error!("Mempool pop ready transaction error: {}", error);
+                    tokio::time::sleep(MEMPOOL_POLL_INTERVAL).await;
+                }
+            }
+        }
+    }
+
+    async fn dispatch_tx(&self, ready: ReadyTransaction) {
+        let result = self.sequence_and_execute_tx(ready.tx).await;
+        if let Err(error) = &result {
+            log::warn!("Execute transaction {:?} error: {}", ready.tx_hash, error);
+        }
+        // Sync the sender's sequence number, the transaction may fail without increasing it
+        let synced = match self.get_account_sequence_number(ready.sender).await {
+            Ok(account_sequence_number) => {
+                self.mempool
+                    .sync_sender(ready.sender, account_sequence_number)
+                    .await
+            }
+            Err(error) => Err(error),
+        };
+        match synced {
+            Ok(stale) => self.drop_tx_waiters(stale),
+            Err(error) => log::error!("Mempool sync sender {} error: {}", ready.sender, error),
+        }
+        let waiter = self
+            .tx_waiters
+            .lock()
+            .expect("lock tx waiters should success")
+            .remove(&ready.tx_hash);
+        if let Some(waiter) = waiter {
+            let _ = waiter.send(result);
+        }
+    }
+
+    async fn sequence_and_execute_tx(
+        &self,
+        tx: TypedTransaction,
+    ) -> Result<ExecuteTransactionResponse> {
+        // Validate the transaction again, the state may have changed since it was admitted
         let moveos_tx = self.executor.validate_transaction(tx.clone()).await?;
         let sequence_info = self.sequencer.sequence_transaction(tx.clone()).await?;
         // Then execute
@@ -81,6 +297,46 @@ impl RpcService {
             .propose_transaction(tx.clone(), execution_info.clone(), sequence_info.clone())
             .await?;
 
+        self.apply_executed_tx(tx, moveos_tx, sequence_info, output, execution_info)
+            .await
+    }
+
+    /// Execute and append a transaction sequenced by the upstream sequencer, the state root after
+    /// execution must match the `expected_state_root` of the upstream node.
+    /// The tx order and the state root are checked before anything is persisted, so the local state
+    /// is not changed if the transaction diverges from the upstream node.
+    pub async fn follow_tx(
+        &self,
+        tx: TypedTransaction,
+        sequence_info: TransactionSequenceInfo,
+        expected_state_root: H256,
+    ) -> Result<ExecuteTransactionResponse> {
+        let moveos_tx = self.executor.validate_transaction(tx.clone()).await?;
+        // The sequencer checks the tx order and its signature
+        self.sequencer
+            .check_append_transaction(tx.tx_hash(), sequence_info.clone())
+            .await?;
+        // The output is applied only if the state root matches
+        let (output, execution_info) = self
+            .executor
+            .execute_followed_transaction(moveos_tx.clone(), expected_state_root)
+            .await?;
+        self.sequencer
+            .append_transaction(tx.clone(), sequence_info.clone())
+            .await?;
+
+        self.apply_executed_tx(tx, moveos_tx, sequence_info, output, execution_info)
+            .await
+    }
+
+    async fn apply_executed_tx(
+        &self,
+        tx: TypedTransaction,
+        moveos_tx: VerifiedMoveOSTransaction,
+        sequence_info: TransactionSequenceInfo,
+        output: TransactionOutput,
+        execution_info: TransactionExecutionInfo,
+    ) -> Result<ExecuteTransactionResponse> {
         // Sync lastest state root from writer executor to reader executor
         self.executor
             .refresh_state(execution_info.state_root, output.is_upgrade)
@@ -127,8 +383,13 @@ impl RpcService {
     pub async fn execute_view_function(
         &self,
         function_call: FunctionCall,
+        sender: Option<AccountAddress>,
+        max_gas_amount: Option<u64>,
     ) -> Result<AnnotatedFunctionResult> {
-        let resp = self.executor.execute_view_function(function_call).await?;
+        let resp = self
+            .executor
+            .execute_view_function_with_options(function_call, sender, max_gas_amount)
+            .await?;
         Ok(resp)
     }
 
@@ -140,6 +401,17 @@ impl RpcService {
         self.executor.get_states(access_path).await
     }
 
+    pub async fn get_account_sequence_number(&self, address: AccountAddress) -> Result<u64> {
+        Ok(self
+            .get_states(AccessPath::resource(address, Account::struct_tag()))
+            .await?
+            .pop()
+            .flatten()
+            .map(|state| state.cast::<Account>())
+            .transpose()?
+            .map_or(0, |account| account.sequence_number))
+    }
+
     pub async fn exists_account(&self, address: AccountAddress) -> Result<bool> {
         let mut resp = self
             .get_states(AccessPath::resource(address, Account::struct_tag()))
@@ -310,6 +582,46 @@ impl RpcService {
         Ok(resp)
     }
 
+    pub async fn query_brc20_ticks(
+        &self,
+        tick: Option<String>,
+        // exclusive cursor if `Some`, otherwise start from the beginning
+        cursor: Option<String>,
+        limit: usize,
+    ) -> Result<Vec<IndexerBRC20Tick>> {
+        let resp = self.indexer.query_brc20_ticks(tick, cursor, limit).await?;
+        Ok(resp)
+    }
+
+    pub async fn query_brc20_balances(
+        &self,
+        owner: AccountAddress,
+        // exclusive cursor if `Some`, otherwise start from the beginning
+        cursor: Option<String>,
+        limit: usize,
+    ) -> Result<Vec<IndexerBRC20Balance>> {
+        let resp = self
+            .indexer
+            .query_brc20_balances(owner, cursor, limit)
+            .await?;
+        Ok(resp)
+    }
+
+    pub async fn query_brc20_operations(
+        &self,
+        filter: BRC20OperationFilter,
+        // exclusive cursor if `Some`, otherwise start from the beginning
+        cursor: Option<IndexerEventID>,
+        limit: usize,
+        descending_order: bool,
+    ) -> Result<Vec<IndexerBRC20Operation>> {
+        let resp = self
+            .indexer
+            .query_brc20_operations(filter, cursor, limit, descending_order)
+            .await?;
+        Ok(resp)
+    }
+
     pub async fn query_global_states(
         &self,
         filter: GlobalStateFilter,
@@ -325,6 +637,21 @@ impl RpcService {
         Ok(resp)
     }
 
+    pub async fn query_inscription_states(
+        &self,
+        filter: InscriptionFilter,
+        // exclusive cursor if `Some`, otherwise start from the beginning
+        cursor: Option<IndexerStateID>,
+        limit: usize,
+        descending_order: bool,
+    ) -> Result<Vec<IndexerGlobalState>> {
+        let resp = self
+            .indexer
+            .query_inscription_states(filter, cursor, limit, descending_order)
+            .await?;
+        Ok(resp)
+    }
+
     pub async fn query_table_states(
         &self,
         filter: TableStateFilter,
@@ -354,6 +681,17 @@ impl RpcService {
             .await?;
         Ok(resp)
     }
+
+    pub async fn get_pending_transactions(
+        &self,
+        sender: Option<AccountAddress>,
+    ) -> Result<Vec<PendingTransaction>> {
+        self.mempool.get_pending_transactions(sender).await
+    }
+
+    pub async fn get_mempool_status(&self) -> Result<MempoolStatus> {
+        self.mempool.get_mempool_status().await
+    }
 }
 
 //TODO we need to make the RpcService to an Actor, and implement TxSubmiter for it's actor proxy.
@@ -364,14 +702,7 @@ impl TxSubmiter for RpcService {
     }
     //TODO provide a trait to abstract the async state reader, elemiate the duplicated code bwteen RpcService and Client
     async fn get_sequence_number(&self, address: RoochAddress) -> Result<u64> {
-        Ok(self
-            .get_states(AccessPath::resource(address.into(), Account::struct_tag()))
-            .await?
-            .pop()
-            .flatten()
-            .map(|state| state.cast::<Account>())
-            .transpose()?
-            .map_or(0, |account| account.sequence_number))
+        self.get_account_sequence_number(address.into()).await
     }
     async fn submit_tx(&self, tx: RoochTransaction) -> Result<ExecuteTransactionResponseView> {
         Ok(self.execute_tx(TypedTransaction::Rooch(tx)).await?.into())
@@ -2,17 +2,20 @@
 // SPDX-License-Identifier: Apache-2.0
 
 use crate::messages::{
-    GetSequencerOrderMessage, GetTransactionByHashMessage, GetTransactionsByHashMessage,
-    GetTxSequenceInfoMappingByHashMessage, GetTxSequenceInfoMappingByOrderMessage,
-    GetTxSequenceInfosMessage, TransactionSequenceMessage,
+    CheckTransactionAppendMessage, GetSequencerOrderMessage, GetTransactionByHashMessage,
+    GetTransactionsByHashMessage, GetTxSequenceInfoMappingByHashMessage,
+    GetTxSequenceInfoMappingByOrderMessage, GetTxSequenceInfosMessage, TransactionAppendMessage,
+    TransactionSequenceMessage,
 };
-use anyhow::Result;
+use crate::metrics::SequencerMetrics;
+use anyhow::{bail, ensure, Result};
 use async_trait::async_trait;
 use coerce::actor::{context::ActorContext, message::Handler, Actor};
-use moveos_types::h256::{self, H256};
+use moveos_types::h256::H256;
 use rooch_store::meta_store::MetaStore;
 use rooch_store::transaction_store::TransactionStore;
 use rooch_store::RoochStore;
+use rooch_types::address::RoochAddress;
 use rooch_types::sequencer::SequencerOrder;
 use rooch_types::transaction::{
     TransactionSequenceInfo, TransactionSequenceInfoMapping, TypedTransaction,
@@ -25,8 +28,11 @@ use tracing::info;
 
 pub struct SequencerActor {
     last_order: u64,
-    sequencer_key: RoochKeyPair,
+    /// The sequencer key, `None` if the node follows an upstream sequencer
+    sequencer_key: Option<RoochKeyPair>,
+    sequencer_address: RoochAddress,
     rooch_store: RoochStore,
+    metrics: Option<SequencerMetrics>,
 }
 
 impl SequencerActor {
@@ -34,6 +40,20 @@ impl SequencerActor {
         sequencer_key: RoochKeyPair,
         rooch_store: RoochStore,
         _is_genesis: bool,
+    ) -> Result<Self> {
+        let sequencer_address = sequencer_key.public().address();
+        Self::new_inner(Some(sequencer_key), sequencer_address, rooch_store)
+    }
+
+    /// Create a SequencerActor of a follower node, it only appends the transactions sequenced by the `sequencer_address`.
+    pub fn new_follower(sequencer_address: RoochAddress, rooch_store: RoochStore) -> Result<Self> {
+        Self::new_inner(None, sequencer_address, rooch_store)
+    }
+
+    fn new_inner(
+        sequencer_key: Option<RoochKeyPair>,
+        sequencer_address: RoochAddress,
+        rooch_store: RoochStore,
     ) -> Result<Self> {
         let last_order_opt = rooch_store
             .get_meta_store()
@@ -44,39 +64,53 @@ impl SequencerActor {
         Ok(Self {
             last_order,
             sequencer_key,
+            sequencer_address,
             rooch_store,
+            metrics: None,
         })
     }
-}
 
-impl Actor for SequencerActor {}
+    pub fn with_metrics(mut self, metrics: SequencerMetrics) -> Self {
+        metrics.sequencer_order.set(self.last_order);
+        self.metrics = Some(metrics);
+        self
+    }
 
-#[async_trait]
-impl Handler<TransactionSequenceMessage> for SequencerActor {
-    async fn handle(
-        &mut self,
-        msg: TransactionSequenceMessage,
-        _ctx: &mut ActorContext,
-    ) -> Result<TransactionSequenceInfo> {
-        let tx = msg.tx;
-        let tx_order = if self.last_order == 0 {
+    fn next_tx_order(&self) -> Result<u64> {
+        if self.last_order == 0 {
             let last_order_opt = self
                 .rooch_store
                 .get_meta_store()
                 .get_sequencer_order()?
                 .map(|order| order.last_order);
-            match last_order_opt {
+            Ok(match last_order_opt {
                 Some(last_order) => last_order + 1,
                 None => 0,
-            }
+            })
         } else {
-            self.last_order + 1
-        };
+            Ok(self.last_order + 1)
+        }
+    }
+
+    /// Check the tx order and the signature of a transaction sequenced by the upstream sequencer
+    fn check_append(&self, tx_hash: H256, sequence_info: &TransactionSequenceInfo) -> Result<()> {
+        let expected_tx_order = self.next_tx_order()?;
+        ensure!(
+            sequence_info.tx_order == expected_tx_order,
+            "Unexpected tx order {}, expected {}",
+            sequence_info.tx_order,
+            expected_tx_order
+        );
+        sequence_info.verify_tx_order_signature(tx_hash, self.sequencer_address)
+    }
+
+    fn save_sequenced_tx(
+        &mut self,
+        tx: TypedTransaction,
+        tx_sequence_info: TransactionSequenceInfo,
+    ) -> Result<()> {
         let hash = tx.tx_hash();
-        let mut witness_data = hash.as_ref().to_vec();
-        witness_data.extend(tx_order.to_le_bytes().iter());
-        let witness_hash = h256::sha3_256_of(&witness_data);
-        let tx_order_signature = Signature::new_hashed(&witness_hash.0, &self.sequencer_key).into();
+        let tx_order = tx_sequence_info.tx_order;
         self.last_order = tx_order;
 
         self.rooch_store.save_transaction(tx)?;
@@ -87,6 +121,36 @@ impl Handler<TransactionSequenceMessage> for SequencerActor {
 
         self.rooch_store
             .save_sequencer_order(SequencerOrder::new(self.last_order))?;
+        self.rooch_store.save_tx_sequence_info(tx_sequence_info)?;
+        if let Some(metrics) = &self.metrics {
+            metrics.sequencer_order.set(tx_order);
+        }
+        Ok(())
+    }
+}
+
+impl Actor for SequencerActor {}
+
+#[async_trait]
+impl Handler<TransactionSequenceMessage> for SequencerActor {
+    async fn handle(
+        &mut self,
+        msg: TransactionSequenceMessage,
+        _ctx: &mut ActorContext,
+    ) -> Result<TransactionSequenceInfo> {
+        let timer = self
+            .metrics
+            .as_ref()
+            .map(|metrics| metrics.sequencer_sequence_latency_seconds.start_timer());
+        let tx = msg.tx;
+        let sequencer_key = match &self.sequencer_key {
+            Some(sequencer_key) => sequencer_key,
+            None => bail!("The follower node can not sequence transactions"),
+        };
+        let tx_order = self.next_tx_order()?;
+        let hash = tx.tx_hash();
+        let witness_hash = TransactionSequenceInfo::tx_order_witness_hash(hash, tx_order);
+        let tx_order_signature = Signature::new_hashed(&witness_hash.0, sequencer_key).into();
 
         let tx_accumulator_root = H256::random();
         let tx_sequence_info = TransactionSequenceInfo {
@@ -94,12 +158,36 @@ impl Handler<TransactionSequenceMessage> for SequencerActor {
             tx_order_signature,
             tx_accumulator_root,
         };
-        self.rooch_store
-            .save_tx_sequence_info(tx_sequence_info.clone())?;
+        self.save_sequenced_tx(tx, tx_sequence_info.clone())?;
+        drop(timer);
         Ok(tx_sequence_info)
     }
 }
 
+#[async_trait]
+impl Handler<TransactionAppendMessage> for SequencerActor {
+    async fn handle(
+        &mut self,
+        msg: TransactionAppendMessage,
+        _ctx: &mut ActorContext,
+    ) -> Result<()> {
+        let TransactionAppendMessage { tx, sequence_info } = msg;
+        self.check_append(tx.tx_hash(), &sequence_info)?;
+        self.save_sequenced_tx(tx, sequence_info)
+    }
+}
+
+#[async_trait]
+impl Handler<CheckTransactionAppendMessage> for SequencerActor {
+    async fn handle(
+        &mut self,
+        msg: CheckTransactionAppendMessage,
+        _ctx: &mut ActorContext,
+    ) -> Result<()> {
+        self.check_append(msg.tx_hash, &msg.sequence_info)
+    }
+}
+
 #[async_trait]
 impl Handler<GetTransactionByHashMessage> for SequencerActor {
     async fn handle(
@@ -3,4 +3,5 @@
 
 pub mod actor;
 pub mod messages;
+pub mod metrics;
 pub mod proxy;
@@ -19,6 +19,28 @@ impl Message for TransactionSequenceMessage {
     type Result = Result<TransactionSequenceInfo>;
 }
 
+/// Append Transaction Message, append a transaction already sequenced by the upstream sequencer
+#[derive(Debug, Serialize, Deserialize)]
+pub struct TransactionAppendMessage {
+    pub tx: TypedTransaction,
+    pub sequence_info: TransactionSequenceInfo,
+}
+
+impl Message for TransactionAppendMessage {
+    type Result = Result<()>;
+}
+
+/// Check whether a transaction sequenced by the upstream sequencer can be appended, without appending it
+#[derive(Debug, Serialize, Deserialize)]
+pub struct CheckTransactionAppendMessage {
+    pub tx_hash: H256,
+    pub sequence_info: TransactionSequenceInfo,
+}
+
+impl Message for CheckTransactionAppendMessage {
+    type Result = Result<()>;
+}
+
 /// Get Transaction By Hash Message
 #[derive(Debug, Serialize, Deserialize)]
 pub struct GetTransactionByHashMessage {
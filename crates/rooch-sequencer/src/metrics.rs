@@ -0,0 +1,33 @@
+// Copyright (c) RoochNetwork
+// SPDX-License-Identifier: Apache-2.0
+
+use metrics::{register, Histogram, HistogramOpts, Opts, PrometheusError, Registry, UIntGauge};
+
+#[derive(Clone)]
+pub struct SequencerMetrics {
+    pub sequencer_order: UIntGauge,
+    pub sequencer_sequence_latency_seconds: Histogram,
+}
+
+impl SequencerMetrics {
+    pub fn register(registry: &Registry) -> Result<Self, PrometheusError> {
+        let sequencer_order = register(
+            UIntGauge::with_opts(Opts::new(
+                "sequencer_order",
+                "The order of the last sequenced transaction",
+            ))?,
+            registry,
+        )?;
+        let sequencer_sequence_latency_seconds = register(
+            Histogram::with_opts(HistogramOpts::new(
+                "sequencer_sequence_latency_seconds",
+                "Histogram of the time used to sequence a transaction",
+            ))?,
+            registry,
+        )?;
+        Ok(Self {
+            sequencer_order,
+            sequencer_sequence_latency_seconds,
+        })
+    }
+}
@@ -2,9 +2,9 @@
 // SPDX-License-Identifier: Apache-2.0
 
 use crate::messages::{
-    GetSequencerOrderMessage, GetTransactionByHashMessage, GetTransactionsByHashMessage,
-    GetTxSequenceInfoMappingByHashMessage, GetTxSequenceInfoMappingByOrderMessage,
-    GetTxSequenceInfosMessage,
+    CheckTransactionAppendMessage, GetSequencerOrderMessage, GetTransactionByHashMessage,
+    GetTransactionsByHashMessage, GetTxSequenceInfoMappingByHashMessage,
+    GetTxSequenceInfoMappingByOrderMessage, GetTxSequenceInfosMessage, TransactionAppendMessage,
 };
 use crate::{actor::sequencer::SequencerActor, messages::TransactionSequenceMessage};
 use anyhow::Result;
@@ -31,6 +31,30 @@ impl SequencerProxy {
         self.actor.send(TransactionSequenceMessage { tx }).await?
     }
 
+    pub async fn append_transaction(
+        &self,
+        tx: TypedTransaction,
+        sequence_info: TransactionSequenceInfo,
+    ) -> Result<()> {
+        self.actor
+            .send(TransactionAppendMessage { tx, sequence_info })
+            .await?
+    }
+
+    /// Check the tx order and the signature of the sequencer before executing the transaction
+    pub async fn check_append_transaction(
+        &self,
+        tx_hash: H256,
+        sequence_info: TransactionSequenceInfo,
+    ) -> Result<()> {
+        self.actor
+            .send(CheckTransactionAppendMessage {
+                tx_hash,
+                sequence_info,
+            })
+            .await?
+    }
+
     pub async fn get_transaction_by_hash(&self, hash: H256) -> Result<Option<TypedTransaction>> {
         self.actor
             .send(GetTransactionByHashMessage { hash })
@@ -71,6 +71,54 @@ impl MoveStructState for Op {
     }
 }
 
+/// The event emitted for every executed brc20 operation, with the tick info and the balances after the operation
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct BRC20OpEvent {
+    pub op: MoveString,
+    pub tick: MoveString,
+    pub from: AccountAddress,
+    pub to: AccountAddress,
+    pub amt: MoveString,
+    pub success: bool,
+    pub max: U256,
+    pub lim: U256,
+    pub dec: u64,
+    pub supply: U256,
+    pub from_balance: U256,
+    pub to_balance: U256,
+}
+
+impl BRC20OpEvent {
+    pub const OP_DEPLOY: &'static str = "deploy";
+    pub const OP_MINT: &'static str = "mint";
+    pub const OP_TRANSFER: &'static str = "transfer";
+}
+
+impl MoveStructType for BRC20OpEvent {
+    const MODULE_NAME: &'static IdentStr = MODULE_NAME;
+    const STRUCT_NAME: &'static IdentStr = ident_str!("BRC20OpEvent");
+    const ADDRESS: AccountAddress = BITCOIN_MOVE_ADDRESS;
+}
+
+impl MoveStructState for BRC20OpEvent {
+    fn struct_layout() -> move_core_types::value::MoveStructLayout {
+        move_core_types::value::MoveStructLayout::new(vec![
+            MoveString::type_layout(),
+            MoveString::type_layout(),
+            move_core_types::value::MoveTypeLayout::Address,
+            move_core_types::value::MoveTypeLayout::Address,
+            MoveString::type_layout(),
+            move_core_types::value::MoveTypeLayout::Bool,
+            move_core_types::value::MoveTypeLayout::U256,
+            move_core_types::value::MoveTypeLayout::U256,
+            move_core_types::value::MoveTypeLayout::U64,
+            move_core_types::value::MoveTypeLayout::U256,
+            move_core_types::value::MoveTypeLayout::U256,
+            move_core_types::value::MoveTypeLayout::U256,
+        ])
+    }
+}
+
 /// Rust bindings for BitcoinMove brc20 module
 pub struct BRC20Module<'a> {
     caller: &'a dyn MoveFunctionCaller,
@@ -4,7 +4,7 @@
 use super::types::Header;
 use crate::{addresses::BITCOIN_MOVE_ADDRESS, into_address::IntoAddress};
 use anyhow::Result;
-use bitcoin::{BlockHash, Txid};
+use bitcoin::{hashes::Hash, BlockHash, Txid};
 use move_core_types::{
     account_address::AccountAddress, ident_str, identifier::IdentStr, value::MoveValue,
 };
@@ -95,6 +95,8 @@ impl<'a> BitcoinLightClientModule<'a> {
     pub const GET_BLOCK_FUNCTION_NAME: &'static IdentStr = ident_str!("get_block");
     pub const GET_BLOCK_BY_HEIGHT_FUNCTION_NAME: &'static IdentStr =
         ident_str!("get_block_by_height");
+    pub const GET_BLOCK_HASH_BY_HEIGHT_FUNCTION_NAME: &'static IdentStr =
+        ident_str!("get_block_hash_by_height");
     pub const GET_BLOCK_HEIGHT_FUNCTION_NAME: &'static IdentStr = ident_str!("get_block_height");
     pub const GET_LATEST_BLOCK_HEIGHT_FUNCTION_NAME: &'static IdentStr =
         ident_str!("get_latest_block_height");
@@ -104,6 +106,10 @@ impl<'a> BitcoinLightClientModule<'a> {
     pub const SUBMIT_NEW_BLOCK_ENTRY_FUNCTION_NAME: &'static IdentStr =
         ident_str!("submit_new_block");
     pub const PROCESS_UTXOS_ENTRY_FUNCTION_NAME: &'static IdentStr = ident_str!("process_utxos");
+    pub const ROLLBACK_TO_FORK_POINT_ENTRY_FUNCTION_NAME: &'static IdentStr =
+        ident_str!("rollback_to_fork_point");
+    pub const GET_ROLLBACK_FORK_HEIGHT_FUNCTION_NAME: &'static IdentStr =
+        ident_str!("get_rollback_fork_height");
 
     pub fn get_block(&self, block_hash: BlockHash) -> Result<Option<Header>> {
         let call = Self::create_function_call(
@@ -149,6 +155,29 @@ impl<'a> BitcoinLightClientModule<'a> {
         Ok(block_header.into())
     }
 
+    pub fn get_block_hash_by_height(&self, block_height: u64) -> Result<Option<BlockHash>> {
+        let call = Self::create_function_call(
+            Self::GET_BLOCK_HASH_BY_HEIGHT_FUNCTION_NAME,
+            vec![],
+            vec![
+                MoveValue::Address(BitcoinBlockStore::object_id().into()),
+                MoveValue::U64(block_height),
+            ],
+        );
+        let ctx = TxContext::new_readonly_ctx(AccountAddress::ZERO);
+        let block_hash =
+            self.caller
+                .call_function(&ctx, call)?
+                .into_result()
+                .map(|mut values| {
+                    let value = values.pop().expect("should have one return value");
+                    bcs::from_bytes::<MoveOption<AccountAddress>>(&value.value)
+                        .expect("should be a valid MoveOption<address>")
+                })?;
+        let block_hash: Option<AccountAddress> = block_hash.into();
+        Ok(block_hash.map(|hash| BlockHash::from_byte_array(hash.into())))
+    }
+
     pub fn get_block_height(&self, block_hash: BlockHash) -> Result<Option<u64>> {
         let call = Self::create_function_call(
             Self::GET_BLOCK_HEIGHT_FUNCTION_NAME,
@@ -190,6 +219,26 @@ impl<'a> BitcoinLightClientModule<'a> {
         Ok(height.into())
     }
 
+    /// The fork height of the unfinished rollback, the rollback should be continued before relaying new blocks
+    pub fn get_rollback_fork_height(&self) -> Result<Option<u64>> {
+        let call = Self::create_function_call(
+            Self::GET_ROLLBACK_FORK_HEIGHT_FUNCTION_NAME,
+            vec![],
+            vec![MoveValue::Address(BitcoinBlockStore::object_id().into())],
+        );
+        let ctx = TxContext::new_readonly_ctx(AccountAddress::ZERO);
+        let height = self
+            .caller
+            .call_function(&ctx, call)?
+            .into_result()
+            .map(|mut values| {
+                let value = values.pop().expect("should have one return value");
+                bcs::from_bytes::<MoveOption<u64>>(&value.value)
+                    .expect("should be a valid MoveOption<u64>")
+            })?;
+        Ok(height.into())
+    }
+
     pub fn get_utxo(&self, tx_id: Txid, vout: u32) -> Result<Option<ObjectID>> {
         let call = Self::create_function_call(
             Self::GET_UTXO_FUNCTION_NAME,
@@ -251,6 +300,26 @@ impl<'a> BitcoinLightClientModule<'a> {
         )
     }
 
+    /// Rollback the blocks above the fork point, the fork block must be the one stored in the light client.
+    /// At most `batch_size` txs are reverted in a call, the call should be repeated until the rollback is finished.
+    pub fn create_rollback_to_fork_point_call(
+        fork_height: u64,
+        fork_block_hash: BlockHash,
+        batch_size: u64,
+    ) -> FunctionCall {
+        Self::create_function_call(
+            Self::ROLLBACK_TO_FORK_POINT_ENTRY_FUNCTION_NAME,
+            vec![],
+            vec![
+                MoveValue::Address(BitcoinBlockStore::object_id().into()),
+                MoveValue::Address(BitcoinUTXOStore::object_id().into()),
+                MoveValue::U64(fork_height),
+                MoveValue::Address(fork_block_hash.into_address()),
+                MoveValue::U64(batch_size),
+            ],
+        )
+    }
+
     pub fn create_process_utxos_call(batch_size: u64) -> FunctionCall {
         Self::create_function_call(
             Self::PROCESS_UTXOS_ENTRY_FUNCTION_NAME,
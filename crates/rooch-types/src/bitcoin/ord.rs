@@ -34,6 +34,11 @@ impl InscriptionID {
     pub fn new(txid: AccountAddress, index: u32) -> Self {
         Self { txid, index }
     }
+
+    /// The object id of the inscription, same as `object::custom_object_id<InscriptionID,Inscription>` in ord.move
+    pub fn object_id(&self) -> ObjectID {
+        object::custom_object_id(self, &Inscription::struct_tag())
+    }
 }
 
 impl MoveStructType for InscriptionID {
@@ -447,6 +447,9 @@ pub trait RoochSignature: Sized + ToFromBytes {
     fn verify_secure<T>(&self, value: &T, author: RoochAddress) -> RoochResult<()>
     where
         T: Serialize;
+
+    /// Verify the signature of the message already in hashed form, the counterpart of `Signature::new_hashed`.
+    fn verify_hashed(&self, hashed_msg: &[u8], author: RoochAddress) -> RoochResult<()>;
 }
 
 impl<S: RoochSignatureInner + Sized> RoochSignature for S {
@@ -480,6 +483,14 @@ impl<S: RoochSignatureInner + Sized> RoochSignature for S {
                 error: format!("Fail to verify user sig {}", e),
             })
     }
+
+    fn verify_hashed(&self, hashed_msg: &[u8], author: RoochAddress) -> RoochResult<()> {
+        let (sig, pk) = &self.get_verification_inputs(author)?;
+        pk.verify(hashed_msg, sig)
+            .map_err(|e| RoochError::InvalidSignature {
+                error: format!("Fail to verify hashed sig {}", e),
+            })
+    }
 }
 
 //
@@ -2,7 +2,15 @@
 // SPDX-License-Identifier: Apache-2.0
 
 use move_binary_format::errors::VMError;
+use move_core_types::errmap::ErrorMapping;
+use move_core_types::resolver::MoveResolver;
+use move_core_types::vm_status::{AbortLocation, VMStatus};
+use moveos::vm::vm_status_explainer::{
+    explain_move_abort, explain_vm_status, MoveAbortExplain, VMStatusExplainView,
+};
 use moveos_types::genesis_info::GenesisInfo;
+use serde::{Deserialize, Serialize};
+use std::fmt;
 use std::io;
 use thiserror::Error;
 
@@ -158,3 +166,124 @@ pub enum GenesisError {
     #[error("Genesis block not exist in {0}.")]
     GenesisNotExist(String),
 }
+
+/// The stage in which the VM rejected a transaction or a function call.
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, Eq, PartialEq)]
+#[serde(rename_all = "snake_case")]
+pub enum VMErrorKind {
+    Validation,
+    Execution,
+}
+
+impl VMErrorKind {
+    /// The stable JSON-RPC error code returned to the clients for this kind of VM error.
+    pub fn error_code(&self) -> i32 {
+        match self {
+            VMErrorKind::Validation => -32010,
+            VMErrorKind::Execution => -32011,
+        }
+    }
+}
+
+impl fmt::Display for VMErrorKind {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            VMErrorKind::Validation => write!(f, "Transaction validation failed"),
+            VMErrorKind::Execution => write!(f, "Transaction execution failed"),
+        }
+    }
+}
+
+/// A VM error explained for the RPC clients.
+#[derive(Debug, Clone, Serialize, Deserialize, Eq, PartialEq, Error)]
+#[error("{message}")]
+pub struct VMErrorInfo {
+    pub kind: VMErrorKind,
+    /// The major status name, e.g. ABORTED or OUT_OF_GAS.
+    pub major_status: String,
+    pub status_code: u64,
+    pub abort_location: Option<String>,
+    pub abort_code: Option<u64>,
+    /// The abort code decoded with the error map of the aborting module.
+    pub abort_explain: Option<MoveAbortExplain>,
+    pub function_name: Option<String>,
+    pub code_offset: Option<u16>,
+    pub message: String,
+}
+
+impl VMErrorInfo {
+    pub fn new<T>(
+        kind: VMErrorKind,
+        vm_status: VMStatus,
+        module_resolver: T,
+        error_mapping: &ErrorMapping,
+    ) -> Self
+    where
+        T: MoveResolver,
+    {
+        let status_code = vm_status.status_code();
+        let mut error = Self {
+            kind,
+            major_status: format!("{:?}", status_code),
+            status_code: status_code.into(),
+            abort_location: None,
+            abort_code: None,
+            abort_explain: None,
+            function_name: None,
+            code_offset: None,
+            message: String::new(),
+        };
+        match explain_vm_status(module_resolver, vm_status) {
+            Ok(VMStatusExplainView::MoveAbort {
+                location,
+                reason_code,
+            }) => {
+                error.abort_explain =
+                    Some(explain_move_abort(&location, reason_code, error_mapping));
+                error.abort_location = Some(abort_location_to_string(&location));
+                error.abort_code = Some(reason_code);
+            }
+            Ok(VMStatusExplainView::ExecutionFailure {
+                location,
+                function_name,
+                code_offset,
+                ..
+            }) => {
+                error.abort_location = Some(abort_location_to_string(&location));
+                error.function_name = function_name;
+                error.code_offset = Some(code_offset);
+            }
+            _ => {}
+        }
+        error.message = error.format_message();
+        error
+    }
+
+    fn format_message(&self) -> String {
+        let mut message = format!("{}: {}", self.kind, self.major_status);
+        if let Some(abort_location) = &self.abort_location {
+            message.push_str(&format!(" in {}", abort_location));
+        }
+        if let Some(function_name) = &self.function_name {
+            message.push_str(&format!(", function {}", function_name));
+        }
+        if let Some(abort_code) = self.abort_code {
+            message.push_str(&format!(", abort code {}", abort_code));
+        }
+        if let Some(reason_name) = self
+            .abort_explain
+            .as_ref()
+            .and_then(|explain| explain.reason_name.as_ref())
+        {
+            message.push_str(&format!(" ({})", reason_name));
+        }
+        message
+    }
+}
+
+fn abort_location_to_string(location: &AbortLocation) -> String {
+    match location {
+        AbortLocation::Module(module_id) => module_id.short_str_lossless(),
+        AbortLocation::Script => "script".to_owned(),
+    }
+}
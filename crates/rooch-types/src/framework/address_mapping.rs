@@ -25,17 +25,22 @@ impl<'a> AddressMapping<'a> {
     const ADDRESS_MAPPING_HANDLE_FUNCTION_NAME: &'static IdentStr =
         ident_str!("address_mapping_handle");
 
+    /// The view function call of `address_mapping::resolve`, the return value is `Option<address>`
+    pub fn create_resolve_call(multichain_address: &MultiChainAddress) -> FunctionCall {
+        FunctionCall::new(
+            Self::function_id(Self::RESOLVE_FUNCTION_NAME),
+            vec![],
+            vec![multichain_address.to_bytes()],
+        )
+    }
+
     pub fn resolve(&self, multichain_address: MultiChainAddress) -> Result<Option<AccountAddress>> {
         if multichain_address.is_rooch_address() {
             let rooch_address: RoochAddress = multichain_address.try_into()?;
             Ok(Some(rooch_address.into()))
         } else {
             let ctx = TxContext::zero();
-            let call = FunctionCall::new(
-                Self::function_id(Self::RESOLVE_FUNCTION_NAME),
-                vec![],
-                vec![multichain_address.to_bytes()],
-            );
+            let call = Self::create_resolve_call(&multichain_address);
             let result = self
                 .caller
                 .call_function(&ctx, call)?
@@ -14,6 +14,7 @@ use serde::{Deserialize, Serialize};
 
 use moveos_types::{
     module_binding::{ModuleBinding, MoveFunctionCaller},
+    move_std::option::MoveOption,
     moveos_std::tx_context::TxContext,
     transaction::FunctionCall,
 };
@@ -97,6 +98,9 @@ pub struct EthereumLightClientModule<'a> {
 
 impl<'a> EthereumLightClientModule<'a> {
     pub const GET_BLOCK_FUNCTION_NAME: &'static IdentStr = ident_str!("get_block");
+    pub const EXISTS_BLOCK_FUNCTION_NAME: &'static IdentStr = ident_str!("exists_block");
+    pub const GET_LATEST_BLOCK_NUMBER_FUNCTION_NAME: &'static IdentStr =
+        ident_str!("get_latest_block_number");
     pub const SUBMIT_NEW_BLOCK_ENTRY_FUNCTION_NAME: &'static IdentStr =
         ident_str!("submit_new_block");
 
@@ -119,6 +123,41 @@ impl<'a> EthereumLightClientModule<'a> {
         Ok(block_header)
     }
 
+    pub fn exists_block(&self, block_number: u64) -> Result<bool> {
+        let call = Self::create_function_call(
+            Self::EXISTS_BLOCK_FUNCTION_NAME,
+            vec![],
+            vec![MoveValue::U64(block_number)],
+        );
+        let ctx = TxContext::new_readonly_ctx(AccountAddress::ZERO);
+        let exists = self
+            .caller
+            .call_function(&ctx, call)?
+            .into_result()
+            .map(|mut values| {
+                let value = values.pop().expect("should have one return value");
+                bcs::from_bytes::<bool>(&value.value).expect("should be a valid bool")
+            })?;
+        Ok(exists)
+    }
+
+    /// Get the number of the latest relayed block, None if no block is relayed
+    pub fn get_latest_block_number(&self) -> Result<Option<u64>> {
+        let call =
+            Self::create_function_call(Self::GET_LATEST_BLOCK_NUMBER_FUNCTION_NAME, vec![], vec![]);
+        let ctx = TxContext::new_readonly_ctx(AccountAddress::ZERO);
+        let number = self
+            .caller
+            .call_function(&ctx, call)?
+            .into_result()
+            .map(|mut values| {
+                let value = values.pop().expect("should have one return value");
+                bcs::from_bytes::<MoveOption<u64>>(&value.value)
+                    .expect("should be a valid MoveOption<u64>")
+            })?;
+        Ok(number.into())
+    }
+
     pub fn create_submit_new_block_call(block_header: &BlockHeader) -> FunctionCall {
         Self::create_function_call(
             Self::SUBMIT_NEW_BLOCK_ENTRY_FUNCTION_NAME,
@@ -20,6 +20,7 @@ pub mod ethereum_validator;
 pub mod gas_coin;
 pub mod genesis;
 pub mod native_validator;
+pub mod onchain_config;
 pub mod session_key;
 pub mod timestamp;
 pub mod transaction_validator;
@@ -0,0 +1,76 @@
+// Copyright (c) RoochNetwork
+// SPDX-License-Identifier: Apache-2.0
+
+use crate::addresses::ROOCH_FRAMEWORK_ADDRESS;
+use anyhow::Result;
+use move_core_types::{
+    account_address::AccountAddress, ident_str, identifier::IdentStr, value::MoveValue,
+};
+use moveos_types::{
+    module_binding::{ModuleBinding, MoveFunctionCaller},
+    moveos_std::tx_context::TxContext,
+    transaction::FunctionCall,
+};
+
+pub const MODULE_NAME: &IdentStr = ident_str!("onchain_config");
+
+/// Rust bindings for RoochFramework onchain_config module
+pub struct OnchainConfigModule<'a> {
+    caller: &'a dyn MoveFunctionCaller,
+}
+
+impl<'a> OnchainConfigModule<'a> {
+    pub const IS_RELAYER_FUNCTION_NAME: &'static IdentStr = ident_str!("is_relayer");
+    pub const ADD_RELAYER_ENTRY_FUNCTION_NAME: &'static IdentStr = ident_str!("add_relayer_entry");
+    pub const REMOVE_RELAYER_ENTRY_FUNCTION_NAME: &'static IdentStr =
+        ident_str!("remove_relayer_entry");
+
+    /// Whether the account can relay the blocks to the light clients
+    pub fn is_relayer(&self, account: AccountAddress) -> Result<bool> {
+        let call = Self::create_function_call(
+            Self::IS_RELAYER_FUNCTION_NAME,
+            vec![],
+            vec![MoveValue::Address(account)],
+        );
+        let ctx = TxContext::new_readonly_ctx(AccountAddress::ZERO);
+        let is_relayer =
+            self.caller
+                .call_function(&ctx, call)?
+                .into_result()
+                .map(|mut values| {
+                    let value = values.pop().expect("should have one return value");
+                    bcs::from_bytes::<bool>(&value.value).expect("should be a valid bool")
+                })?;
+        Ok(is_relayer)
+    }
+
+    /// Add the relayer to the relayer whitelist, the call must be sent by the sequencer
+    pub fn create_add_relayer_call(relayer: AccountAddress) -> FunctionCall {
+        Self::create_function_call(
+            Self::ADD_RELAYER_ENTRY_FUNCTION_NAME,
+            vec![],
+            vec![MoveValue::Address(relayer)],
+        )
+    }
+
+    /// Remove the relayer from the relayer whitelist, the call must be sent by the sequencer
+    pub fn create_remove_relayer_call(relayer: AccountAddress) -> FunctionCall {
+        Self::create_function_call(
+            Self::REMOVE_RELAYER_ENTRY_FUNCTION_NAME,
+            vec![],
+            vec![MoveValue::Address(relayer)],
+        )
+    }
+}
+
+impl<'a> ModuleBinding<'a> for OnchainConfigModule<'a> {
+    const MODULE_NAME: &'static IdentStr = MODULE_NAME;
+    const MODULE_ADDRESS: AccountAddress = ROOCH_FRAMEWORK_ADDRESS;
+
+    fn new(caller: &'a impl MoveFunctionCaller) -> Self
+    where
+        Self: Sized,
+    {
+        Self { caller }
+    }
+}
@@ -10,6 +10,7 @@ use moveos_types::{
     module_binding::{ModuleBinding, MoveFunctionCaller},
     move_std::ascii::MoveAsciiString,
     move_std::option::MoveOption,
+    moveos_std::object::ObjectID,
     moveos_std::tx_context::TxContext,
     serde::Readable,
     state::{MoveState, MoveStructState, MoveStructType},
@@ -125,14 +126,18 @@ impl SessionKey {
         AuthenticationKey::new(self.authentication_key.clone())
     }
     pub fn is_expired(&self) -> bool {
-        if self.max_inactive_interval == 0 {
-            return false;
-        }
         let now = std::time::SystemTime::now()
             .duration_since(std::time::UNIX_EPOCH)
             .expect("should get system time")
             .as_secs();
-        now - self.last_active_time > self.max_inactive_interval
+        self.is_expired_at(now)
+    }
+
+    /// Check the session key is expired at the given timestamp in seconds,
+    /// it should be the same as the `session_key::is_expired` in Move.
+    pub fn is_expired_at(&self, now_seconds: u64) -> bool {
+        self.max_inactive_interval > 0
+            && self.last_active_time + self.max_inactive_interval < now_seconds
     }
 
     pub fn is_scope_match(&self, function: &FunctionCall) -> bool {
@@ -177,8 +182,12 @@ pub struct SessionKeyModule<'a> {
 
 impl<'a> SessionKeyModule<'a> {
     pub const GET_SESSION_KEY_FUNCTION_NAME: &'static IdentStr = ident_str!("get_session_key");
+    pub const SESSION_KEYS_HANDLE_FUNCTION_NAME: &'static IdentStr =
+        ident_str!("session_keys_handle");
     pub const CREATE_SESSION_KEY_ENTRY_FUNCTION_NAME: &'static IdentStr =
         ident_str!("create_session_key_entry");
+    pub const REMOVE_SESSION_KEY_ENTRY_FUNCTION_NAME: &'static IdentStr =
+        ident_str!("remove_session_key_entry");
 
     pub fn get_session_key(
         &self,
@@ -211,6 +220,29 @@ impl<'a> SessionKeyModule<'a> {
         Ok(session_key)
     }
 
+    /// Return the SessionKeys table handle of the account, the table key is the authentication key
+    pub fn session_keys_handle(&self, account_address: AccountAddress) -> Result<Option<ObjectID>> {
+        let call = FunctionCall::new(
+            Self::function_id(Self::SESSION_KEYS_HANDLE_FUNCTION_NAME),
+            vec![],
+            vec![MoveValue::Address(account_address)
+                .simple_serialize()
+                .unwrap()],
+        );
+        let ctx = TxContext::new_readonly_ctx(account_address);
+        let handle = self
+            .caller
+            .call_function(&ctx, call)?
+            .into_result()
+            .map(|mut values| {
+                let value = values.pop().expect("should have one return value");
+                bcs::from_bytes::<MoveOption<ObjectID>>(&value.value)
+                    .expect("should be a valid MoveOption<ObjectID>")
+                    .into()
+            })?;
+        Ok(handle)
+    }
+
     pub fn create_session_key_action(
         authentication_key: Vec<u8>,
         scope: SessionScope,
@@ -228,6 +260,14 @@ impl<'a> SessionKeyModule<'a> {
             ],
         )
     }
+
+    pub fn remove_session_key_action(authentication_key: Vec<u8>) -> MoveAction {
+        Self::create_move_action(
+            Self::REMOVE_SESSION_KEY_ENTRY_FUNCTION_NAME,
+            vec![],
+            vec![MoveValue::vector_u8(authentication_key)],
+        )
+    }
 }
 
 impl<'a> ModuleBinding<'a> for SessionKeyModule<'a> {
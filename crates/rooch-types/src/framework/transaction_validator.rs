@@ -15,8 +15,8 @@ use moveos_types::{
 };
 
 use crate::addresses::ROOCH_FRAMEWORK_ADDRESS;
-use crate::framework::auth_validator::TxValidateResult;
-use crate::transaction::AuthenticatorInfo;
+use crate::framework::auth_validator::{AuthValidator, TxValidateResult};
+use crate::transaction::{AuthenticatorInfo, FeePayerInfo};
 
 /// Rust bindings for RoochFramework transaction_validator module
 pub struct TransactionValidator<'a> {
@@ -25,6 +25,8 @@ pub struct TransactionValidator<'a> {
 
 impl<'a> TransactionValidator<'a> {
     pub const VALIDATE_FUNCTION_NAME: &'static IdentStr = ident_str!("validate");
+    pub const VALIDATE_FEE_PAYER_FUNCTION_NAME: &'static IdentStr =
+        ident_str!("validate_fee_payer");
     pub const PRE_EXECUTE_FUNCTION_NAME: &'static IdentStr = ident_str!("pre_execute");
     pub const POST_EXECUTE_FUNCTION_NAME: &'static IdentStr = ident_str!("post_execute");
 
@@ -57,6 +59,34 @@ impl<'a> TransactionValidator<'a> {
         Ok(auth_validator)
     }
 
+    pub fn validate_fee_payer(
+        &self,
+        ctx: &TxContext,
+        fee_payer: &FeePayerInfo,
+    ) -> Result<DecodedFunctionResult<AuthValidator>> {
+        let validate_fee_payer_call = FunctionCall::new(
+            Self::function_id(Self::VALIDATE_FEE_PAYER_FUNCTION_NAME),
+            vec![],
+            vec![
+                MoveValue::Address(fee_payer.address)
+                    .simple_serialize()
+                    .unwrap(),
+                MoveValue::U64(fee_payer.authenticator.auth_validator_id)
+                    .simple_serialize()
+                    .unwrap(),
+            ],
+        );
+        let auth_validator = self
+            .caller
+            .call_function(ctx, validate_fee_payer_call)?
+            .decode(|mut values| {
+                let value = values.pop().expect("should have one return value");
+                let result = bcs::from_bytes::<AuthValidator>(&value.value)?;
+                Ok(result)
+            })?;
+        Ok(auth_validator)
+    }
+
     pub fn pre_execute_function_id() -> FunctionId {
         Self::function_id(Self::PRE_EXECUTE_FUNCTION_NAME)
     }
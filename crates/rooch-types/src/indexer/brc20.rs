@@ -0,0 +1,64 @@
+// Copyright (c) RoochNetwork
+// SPDX-License-Identifier: Apache-2.0
+
+use crate::indexer::event_filter::IndexerEventID;
+use move_core_types::account_address::AccountAddress;
+use move_core_types::u256::U256;
+use moveos_types::h256::H256;
+use serde::{Deserialize, Serialize};
+
+#[derive(Eq, PartialEq, Clone, Debug, Serialize, Deserialize)]
+pub struct IndexerBRC20Tick {
+    /// The tick in lower case
+    pub tick: String,
+    pub max: U256,
+    pub lim: U256,
+    pub dec: u64,
+    pub supply: U256,
+    /// The rooch address of the deployer
+    pub deployer: AccountAddress,
+    /// The tx order of the last operation which changed the tick info
+    pub tx_order: u64,
+    pub created_at: u64,
+    pub updated_at: u64,
+}
+
+#[derive(Eq, PartialEq, Clone, Debug, Serialize, Deserialize)]
+pub struct IndexerBRC20Balance {
+    pub tick: String,
+    /// The rooch address of the owner
+    pub owner: AccountAddress,
+    pub balance: U256,
+    /// The tx order of the last operation which changed the balance
+    pub tx_order: u64,
+    pub updated_at: u64,
+}
+
+#[derive(Eq, PartialEq, Clone, Debug, Serialize, Deserialize)]
+pub struct IndexerBRC20Operation {
+    /// The event which the operation was indexed from
+    pub indexer_event_id: IndexerEventID,
+    pub tx_hash: H256,
+    /// deploy, mint or transfer
+    pub op: String,
+    pub tick: String,
+    pub from: AccountAddress,
+    pub to: AccountAddress,
+    /// The inscribed amount of mint and transfer, or the max supply of deploy
+    pub amt: String,
+    pub success: bool,
+    pub created_at: u64,
+}
+
+#[derive(Clone, Debug, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub enum BRC20OperationFilter {
+    /// Query by tick
+    Tick(String),
+    /// Query by the from or to address of the operation
+    Address(AccountAddress),
+    /// Query by the operation type, deploy, mint or transfer
+    Op(String),
+    /// Return the operations in the given transaction
+    TxHash(H256),
+}
@@ -0,0 +1,16 @@
+// Copyright (c) RoochNetwork
+// SPDX-License-Identifier: Apache-2.0
+
+use moveos_types::moveos_std::object::ObjectID;
+use serde::{Deserialize, Serialize};
+
+#[derive(Clone, Debug, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub enum InscriptionFilter {
+    /// Query by the content type of the inscription, such as `image/png`
+    ContentType(String),
+    /// Query by the media class of the content type, such as `image` or `text`
+    MediaClass(String),
+    /// Query the children of the parent inscription
+    Parent(ObjectID),
+}
@@ -1,6 +1,8 @@
 // Copyright (c) RoochNetwork
 // SPDX-License-Identifier: Apache-2.0
+pub mod brc20;
 pub mod event_filter;
+pub mod inscription;
 pub mod state;
 pub mod transaction_filter;
 
@@ -16,6 +16,7 @@ pub mod function_arg;
 pub mod indexer;
 pub mod into_address;
 pub mod key_struct;
+pub mod mempool;
 pub mod multichain_id;
 pub mod sequencer;
 pub mod stdlib_version;
@@ -0,0 +1,30 @@
+// Copyright (c) RoochNetwork
+// SPDX-License-Identifier: Apache-2.0
+
+use move_core_types::account_address::AccountAddress;
+use moveos_types::h256::H256;
+use serde::{Deserialize, Serialize};
+
+/// The summary of a pending transaction in the mempool.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct PendingTransaction {
+    pub tx_hash: H256,
+    pub sender: AccountAddress,
+    pub sequence_number: u64,
+    pub gas_price: u64,
+    pub tx_size: u64,
+    /// Whether the transaction can be sequenced now.
+    pub ready: bool,
+    /// The time in milliseconds the transaction has been waiting in the mempool.
+    pub waiting_millis: u64,
+}
+
+#[derive(Debug, Clone, Default, Eq, PartialEq, Serialize, Deserialize)]
+pub struct MempoolStatus {
+    pub pending_count: u64,
+    pub ready_count: u64,
+    pub sender_count: u64,
+    pub total_bytes: u64,
+    pub max_count: u64,
+    pub max_bytes: u64,
+}
@@ -1,7 +1,7 @@
 // Copyright (c) RoochNetwork
 // SPDX-License-Identifier: Apache-2.0
 
-use super::{authenticator::Authenticator, AbstractTransaction, AuthenticatorInfo};
+use super::{authenticator::Authenticator, AbstractTransaction, AuthenticatorInfo, FeePayerInfo};
 use crate::multichain_id::{MultiChainID, ETHER};
 use crate::{
     address::{EthereumAddress, RoochAddress},
@@ -383,6 +383,10 @@ impl AbstractTransaction for EthereumTransaction {
         Ok(AuthenticatorInfo::new(chain_id, authenticator))
     }
 
+    fn fee_payer_info(&self) -> Result<Option<FeePayerInfo>> {
+        Ok(None)
+    }
+
     fn multi_chain_id(&self) -> MultiChainID {
         MultiChainID::from(ETHER)
     }
@@ -1,13 +1,19 @@
 // Copyright (c) RoochNetwork
 // SPDX-License-Identifier: Apache-2.0
 
-use self::{authenticator::Authenticator, ethereum::EthereumTransaction, rooch::RoochTransaction};
-use crate::address::MultiChainAddress;
+use self::{
+    authenticator::Authenticator,
+    ethereum::EthereumTransaction,
+    rooch::{RoochTransaction, SponsoredRoochTransaction},
+};
+use crate::address::{MultiChainAddress, RoochAddress};
+use crate::crypto::{RoochSignature, Signature, ToFromBytes};
+use crate::framework::auth_validator::BuiltinAuthValidator;
 use crate::multichain_id::{MultiChainID, ETHER, ROOCH};
-use anyhow::{format_err, Result};
+use anyhow::{ensure, format_err, Result};
 use move_core_types::account_address::AccountAddress;
 use moveos_types::transaction::TransactionExecutionInfo;
-use moveos_types::{h256::H256, transaction::MoveOSTransaction};
+use moveos_types::{h256, h256::H256, transaction::MoveOSTransaction};
 use serde::{Deserialize, Serialize};
 use std::fmt;
 use std::fmt::{Display, Formatter};
@@ -21,6 +27,8 @@ pub mod rooch;
 pub enum TransactionType {
     Rooch,
     Ethereum,
+    /// A Rooch transaction whose gas fee is paid by a fee payer
+    RoochSponsored,
 }
 
 impl TransactionType {
@@ -34,6 +42,7 @@ impl Display for TransactionType {
         match self {
             TransactionType::Rooch => write!(f, "Rooch"),
             TransactionType::Ethereum => write!(f, "Ethereum"),
+            TransactionType::RoochSponsored => write!(f, "RoochSponsored"),
         }
     }
 }
@@ -45,6 +54,7 @@ impl FromStr for TransactionType {
         match s {
             "Rooch" => Ok(TransactionType::Rooch),
             "Ethereum" => Ok(TransactionType::Ethereum),
+            "RoochSponsored" => Ok(TransactionType::RoochSponsored),
             s => Err(format_err!("Unknown transaction type: {}", s)),
         }
     }
@@ -81,6 +91,22 @@ impl From<AuthenticatorInfo> for Vec<u8> {
     }
 }
 
+/// The fee payer of a sponsored transaction and its authenticator
+#[derive(Clone, Debug, Eq, PartialEq, Hash, Serialize, Deserialize)]
+pub struct FeePayerInfo {
+    pub address: AccountAddress,
+    pub authenticator: Authenticator,
+}
+
+impl FeePayerInfo {
+    pub fn new(address: AccountAddress, authenticator: Authenticator) -> Self {
+        Self {
+            address,
+            authenticator,
+        }
+    }
+}
+
 pub trait AbstractTransaction {
     fn transaction_type(&self) -> TransactionType;
 
@@ -97,6 +123,9 @@ pub trait AbstractTransaction {
 
     fn authenticator_info(&self) -> Result<AuthenticatorInfo>;
 
+    /// The fee payer of the transaction, `None` if the sender pays the gas fee.
+    fn fee_payer_info(&self) -> Result<Option<FeePayerInfo>>;
+
     fn construct_moveos_transaction(
         self,
         resolved_sender: AccountAddress,
@@ -110,6 +139,7 @@ pub trait AbstractTransaction {
 pub enum TypedTransaction {
     Rooch(RoochTransaction),
     Ethereum(EthereumTransaction),
+    RoochSponsored(SponsoredRoochTransaction),
 }
 
 impl TryFrom<RawTransaction> for TypedTransaction {
@@ -125,6 +155,10 @@ impl TryFrom<RawTransaction> for TypedTransaction {
                 let tx = EthereumTransaction::decode(&raw.raw)?;
                 Ok(TypedTransaction::Ethereum(tx))
             }
+            TransactionType::RoochSponsored => {
+                let tx = SponsoredRoochTransaction::decode(&raw.raw)?;
+                Ok(TypedTransaction::RoochSponsored(tx))
+            }
         }
     }
 }
@@ -134,6 +168,7 @@ impl AbstractTransaction for TypedTransaction {
         match self {
             TypedTransaction::Rooch(_) => TransactionType::Rooch,
             TypedTransaction::Ethereum(_) => TransactionType::Ethereum,
+            TypedTransaction::RoochSponsored(_) => TransactionType::RoochSponsored,
         }
     }
 
@@ -149,6 +184,7 @@ impl AbstractTransaction for TypedTransaction {
         match self {
             TypedTransaction::Rooch(tx) => tx.encode(),
             TypedTransaction::Ethereum(tx) => tx.encode(),
+            TypedTransaction::RoochSponsored(tx) => tx.encode(),
         }
     }
 
@@ -156,6 +192,7 @@ impl AbstractTransaction for TypedTransaction {
         match self {
             TypedTransaction::Rooch(tx) => AbstractTransaction::sender(tx),
             TypedTransaction::Ethereum(tx) => tx.sender(),
+            TypedTransaction::RoochSponsored(tx) => AbstractTransaction::sender(tx),
         }
     }
 
@@ -163,6 +200,7 @@ impl AbstractTransaction for TypedTransaction {
         match self {
             TypedTransaction::Rooch(tx) => tx.original_address_str(),
             TypedTransaction::Ethereum(tx) => tx.original_address_str(),
+            TypedTransaction::RoochSponsored(tx) => tx.original_address_str(),
         }
     }
 
@@ -170,6 +208,7 @@ impl AbstractTransaction for TypedTransaction {
         match self {
             TypedTransaction::Rooch(tx) => tx.tx_hash(),
             TypedTransaction::Ethereum(tx) => tx.tx_hash(),
+            TypedTransaction::RoochSponsored(tx) => tx.tx_hash(),
         }
     }
 
@@ -177,6 +216,15 @@ impl AbstractTransaction for TypedTransaction {
         match self {
             TypedTransaction::Rooch(tx) => tx.authenticator_info(),
             TypedTransaction::Ethereum(tx) => tx.authenticator_info(),
+            TypedTransaction::RoochSponsored(tx) => tx.authenticator_info(),
+        }
+    }
+
+    fn fee_payer_info(&self) -> Result<Option<FeePayerInfo>> {
+        match self {
+            TypedTransaction::Rooch(tx) => tx.fee_payer_info(),
+            TypedTransaction::Ethereum(tx) => tx.fee_payer_info(),
+            TypedTransaction::RoochSponsored(tx) => tx.fee_payer_info(),
         }
     }
 
@@ -187,6 +235,9 @@ impl AbstractTransaction for TypedTransaction {
         match self {
             TypedTransaction::Rooch(tx) => tx.construct_moveos_transaction(resolved_sender),
             TypedTransaction::Ethereum(tx) => tx.construct_moveos_transaction(resolved_sender),
+            TypedTransaction::RoochSponsored(tx) => {
+                tx.construct_moveos_transaction(resolved_sender)
+            }
         }
     }
 
@@ -194,6 +245,7 @@ impl AbstractTransaction for TypedTransaction {
         match self {
             TypedTransaction::Rooch(_tx) => MultiChainID::from(ROOCH),
             TypedTransaction::Ethereum(_tx) => MultiChainID::from(ETHER),
+            TypedTransaction::RoochSponsored(_tx) => MultiChainID::from(ROOCH),
         }
     }
 
@@ -225,6 +277,28 @@ impl TransactionSequenceInfo {
             tx_accumulator_root,
         }
     }
+
+    /// The hash signed by the sequencer to commit the order of the transaction.
+    pub fn tx_order_witness_hash(tx_hash: H256, tx_order: u64) -> H256 {
+        let mut witness_data = tx_hash.as_ref().to_vec();
+        witness_data.extend(tx_order.to_le_bytes().iter());
+        h256::sha3_256_of(&witness_data)
+    }
+
+    /// Verify the tx order signature is signed by the `sequencer` for the transaction `tx_hash`.
+    pub fn verify_tx_order_signature(&self, tx_hash: H256, sequencer: RoochAddress) -> Result<()> {
+        ensure!(
+            self.tx_order_signature.auth_validator_id
+                == u64::from(BuiltinAuthValidator::Rooch.flag()),
+            "Unsupported tx order signature auth validator id: {}",
+            self.tx_order_signature.auth_validator_id
+        );
+        let signature = Signature::from_bytes(&self.tx_order_signature.payload)
+            .map_err(|e| format_err!("Invalid tx order signature: {:?}", e))?;
+        let witness_hash = Self::tx_order_witness_hash(tx_hash, self.tx_order);
+        signature.verify_hashed(&witness_hash.0, sequencer)?;
+        Ok(())
+    }
 }
 
 /// Transaction with sequence info and execution info.
@@ -251,7 +325,14 @@ impl TransactionSequenceInfoMapping {
 
 #[cfg(test)]
 mod tests {
-    use super::rooch::RoochTransaction;
+    use super::rooch::{RoochTransaction, SponsoredRoochTransaction};
+    use super::{AbstractTransaction, TransactionSequenceInfo};
+    use crate::address::{RoochAddress, RoochSupportedAddress};
+    use crate::crypto::{RoochKeyPair, Signature};
+    use fastcrypto::ed25519::Ed25519KeyPair;
+    use fastcrypto::traits::KeyPair;
+    use moveos_types::h256::H256;
+    use rand::{rngs::StdRng, SeedableRng};
 
     fn test_serialize_deserialize_roundtrip<T>(tx: T)
     where
@@ -267,4 +348,53 @@ mod tests {
         let tx = RoochTransaction::mock();
         test_serialize_deserialize_roundtrip(tx)
     }
+
+    #[test]
+    fn test_sponsored_transaction_envelope() {
+        let mut rng = StdRng::seed_from_u64(0);
+        let sender_key = RoochKeyPair::Ed25519(Ed25519KeyPair::generate(&mut rng));
+        let fee_payer_key = RoochKeyPair::Ed25519(Ed25519KeyPair::generate(&mut rng));
+        let tx = RoochTransaction::mock();
+        let sponsored = tx
+            .data()
+            .clone()
+            .sign_sponsored(&sender_key, fee_payer_key.public().address())
+            .sign_as_fee_payer(&fee_payer_key);
+        test_serialize_deserialize_roundtrip(sponsored.clone());
+        // The sponsored envelope and the plain transaction can not be decoded as each other
+        assert!(RoochTransaction::decode(&sponsored.encode()).is_err());
+        assert!(SponsoredRoochTransaction::decode(&tx.encode()).is_err());
+        assert_ne!(sponsored.tx_hash(), tx.tx_hash());
+        assert!(sponsored.fee_payer_info().unwrap().is_some());
+        assert!(tx.fee_payer_info().unwrap().is_none());
+    }
+
+    #[test]
+    fn test_verify_tx_order_signature() {
+        let mut rng = StdRng::seed_from_u64(0);
+        let sequencer_key = RoochKeyPair::Ed25519(Ed25519KeyPair::generate(&mut rng));
+        let sequencer = sequencer_key.public().address();
+        let tx_hash = RoochTransaction::mock().tx_hash();
+        let tx_order = 1;
+        let witness_hash = TransactionSequenceInfo::tx_order_witness_hash(tx_hash, tx_order);
+        let tx_order_signature = Signature::new_hashed(&witness_hash.0, &sequencer_key).into();
+        let sequence_info =
+            TransactionSequenceInfo::new(tx_order, tx_order_signature, H256::random());
+
+        sequence_info
+            .verify_tx_order_signature(tx_hash, sequencer)
+            .unwrap();
+        // The signature is bound to the tx hash, the tx order and the sequencer
+        assert!(sequence_info
+            .verify_tx_order_signature(H256::random(), sequencer)
+            .is_err());
+        assert!(sequence_info
+            .verify_tx_order_signature(tx_hash, RoochAddress::random())
+            .is_err());
+        let mut reordered = sequence_info.clone();
+        reordered.tx_order = 2;
+        assert!(reordered
+            .verify_tx_order_signature(tx_hash, sequencer)
+            .is_err());
+    }
 }
@@ -2,12 +2,13 @@
 // SPDX-License-Identifier: Apache-2.0
 
 use super::{
-    authenticator::Authenticator, AbstractTransaction, AuthenticatorInfo, TransactionType,
+    authenticator::Authenticator, AbstractTransaction, AuthenticatorInfo, FeePayerInfo,
+    TransactionType,
 };
 use crate::crypto::{Ed25519RoochSignature, RoochKeyPair, Signature};
 use crate::multichain_id::{MultiChainID, ROOCH};
 use crate::{address::RoochAddress, chain_id::RoochChainID};
-use anyhow::Result;
+use anyhow::{bail, Result};
 use move_core_types::account_address::AccountAddress;
 use moveos_types::gas_config::GasConfig;
 use moveos_types::h256::H256;
@@ -67,12 +68,38 @@ impl RoochTransactionData {
         moveos_types::h256::sha3_256_of(self.encode().as_slice())
     }
 
+    /// The hash of a sponsored transaction, both the sender and the fee payer sign this hash,
+    /// so the sender agrees on who pays the gas fee.
+    pub fn sponsored_hash(&self, fee_payer: RoochAddress) -> H256 {
+        let data = bcs::to_bytes(&(self, fee_payer)).expect("encode transaction should success");
+        moveos_types::h256::sha3_256_of(data.as_slice())
+    }
+
     pub fn sign(self, kp: &RoochKeyPair) -> RoochTransaction {
         let signature = Signature::new_hashed(self.hash().as_bytes(), kp);
         //TODO implement Signature into Authenticator
         let authenticator = Authenticator::rooch(signature);
         RoochTransaction::new(self, authenticator)
     }
+
+    /// Sign the transaction as the sender of a sponsored transaction.
+    /// The fee payer should co-sign it via `SponsoredRoochTransaction::sign_as_fee_payer` before submitting.
+    pub fn sign_sponsored(
+        self,
+        kp: &RoochKeyPair,
+        fee_payer: RoochAddress,
+    ) -> SponsoredRoochTransaction {
+        let signature = Signature::new_hashed(self.sponsored_hash(fee_payer).as_bytes(), kp);
+        SponsoredRoochTransaction::new(self, Authenticator::rooch(signature), fee_payer)
+    }
+}
+
+/// The fee payer of a sponsored transaction, it pays the gas fee instead of the sender.
+#[derive(Clone, Debug, Hash, Eq, PartialEq, Serialize, Deserialize)]
+pub struct FeePayer {
+    pub address: RoochAddress,
+    /// The fee payer's authenticator, it is `None` before the fee payer co-signs the transaction.
+    pub authenticator: Option<Authenticator>,
 }
 
 #[derive(Clone, Debug, Hash, Eq, PartialEq, Serialize, Deserialize)]
@@ -122,6 +149,10 @@ impl RoochTransaction {
         &self.data.action
     }
 
+    pub fn data(&self) -> &RoochTransactionData {
+        &self.data
+    }
+
     //TODO use protest Arbitrary to generate mock data
     #[cfg(test)]
     pub fn mock() -> RoochTransaction {
@@ -195,6 +226,151 @@ impl AbstractTransaction for RoochTransaction {
         ))
     }
 
+    fn fee_payer_info(&self) -> Result<Option<FeePayerInfo>> {
+        Ok(None)
+    }
+
+    fn construct_moveos_transaction(
+        self,
+        resolved_sender: AccountAddress,
+    ) -> Result<MoveOSTransaction> {
+        debug_assert!(self.sender() == resolved_sender.into());
+        Ok(self.into())
+    }
+
+    fn sender(&self) -> crate::address::MultiChainAddress {
+        self.sender().into()
+    }
+
+    fn original_address_str(&self) -> String {
+        self.data.sender.to_string()
+    }
+
+    fn multi_chain_id(&self) -> MultiChainID {
+        MultiChainID::from(ROOCH)
+    }
+
+    fn tx_size(&self) -> u64 {
+        self.encode().len() as u64
+    }
+}
+
+/// A Rooch transaction whose gas fee is paid by the fee payer instead of the sender.
+/// It wraps the transaction data in a separate envelope, so the encoding of `RoochTransaction` is unchanged.
+#[derive(Clone, Debug, Hash, Eq, PartialEq, Serialize, Deserialize)]
+pub struct SponsoredRoochTransaction {
+    data: RoochTransactionData,
+    /// The sender's authenticator over the sponsored hash
+    authenticator: Authenticator,
+    fee_payer: FeePayer,
+}
+
+impl SponsoredRoochTransaction {
+    /// Create a sponsored transaction signed by the sender, waiting for the fee payer's signature.
+    pub fn new(
+        data: RoochTransactionData,
+        authenticator: Authenticator,
+        fee_payer: RoochAddress,
+    ) -> Self {
+        Self {
+            data,
+            authenticator,
+            fee_payer: FeePayer {
+                address: fee_payer,
+                authenticator: None,
+            },
+        }
+    }
+
+    /// Set the fee payer's authenticator of the sponsored transaction.
+    pub fn with_fee_payer_authenticator(mut self, authenticator: Authenticator) -> Self {
+        self.fee_payer.authenticator = Some(authenticator);
+        self
+    }
+
+    /// Co-sign the sponsored transaction as the fee payer.
+    pub fn sign_as_fee_payer(self, kp: &RoochKeyPair) -> Self {
+        let signature = Signature::new_hashed(self.tx_hash().as_bytes(), kp);
+        self.with_fee_payer_authenticator(Authenticator::rooch(signature))
+    }
+
+    pub fn sender(&self) -> RoochAddress {
+        self.data.sender
+    }
+
+    pub fn sequence_number(&self) -> u64 {
+        self.data.sequence_number
+    }
+
+    pub fn chain_id(&self) -> u64 {
+        self.data.chain_id
+    }
+
+    pub fn action(&self) -> &MoveAction {
+        &self.data.action
+    }
+
+    pub fn data(&self) -> &RoochTransactionData {
+        &self.data
+    }
+
+    pub fn fee_payer(&self) -> &FeePayer {
+        &self.fee_payer
+    }
+}
+
+impl From<SponsoredRoochTransaction> for MoveOSTransaction {
+    fn from(tx: SponsoredRoochTransaction) -> Self {
+        let tx_hash = tx.tx_hash();
+        let tx_size = tx.tx_size();
+        let tx_ctx = TxContext::new(
+            tx.data.sender.into(),
+            tx.data.sequence_number,
+            tx.data.max_gas_amount,
+            tx_hash,
+            tx_size,
+        );
+        MoveOSTransaction::new(tx_ctx, tx.data.action)
+    }
+}
+
+impl AbstractTransaction for SponsoredRoochTransaction {
+    fn transaction_type(&self) -> super::TransactionType {
+        TransactionType::RoochSponsored
+    }
+
+    fn decode(bytes: &[u8]) -> Result<Self>
+    where
+        Self: std::marker::Sized,
+    {
+        bcs::from_bytes::<Self>(bytes).map_err(Into::into)
+    }
+
+    fn encode(&self) -> Vec<u8> {
+        bcs::to_bytes(self).expect("encode transaction should success")
+    }
+
+    fn tx_hash(&self) -> H256 {
+        self.data.sponsored_hash(self.fee_payer.address)
+    }
+
+    fn authenticator_info(&self) -> Result<AuthenticatorInfo> {
+        Ok(AuthenticatorInfo::new(
+            self.chain_id(),
+            self.authenticator.clone(),
+        ))
+    }
+
+    fn fee_payer_info(&self) -> Result<Option<FeePayerInfo>> {
+        match &self.fee_payer.authenticator {
+            Some(authenticator) => Ok(Some(FeePayerInfo::new(
+                self.fee_payer.address.into(),
+                authenticator.clone(),
+            ))),
+            None => bail!("Missing the fee payer {} signature", self.fee_payer.address),
+        }
+    }
+
     fn construct_moveos_transaction(
         self,
         resolved_sender: AccountAddress,
@@ -13,7 +13,7 @@ use moveos_types::move_std::ascii::MoveAsciiString;
 use moveos_types::move_std::string::MoveString;
 use moveos_types::transaction::MoveAction;
 use rooch_types::error::RoochResult;
-use rooch_types::transaction::rooch::RoochTransaction;
+use rooch_types::transaction::rooch::{RoochTransaction, SponsoredRoochTransaction};
 use serde_reflection::{Samples, Tracer, TracerConfig};
 use std::fmt::Debug;
 use std::fs;
@@ -88,6 +88,9 @@ fn export_rooch_types_yaml(file_path: &String) -> RoochResult<()> {
     tracer.trace_type::<TypeTag>(&samples).unwrap();
     tracer.trace_type::<MoveAction>(&samples).unwrap();
     tracer.trace_type::<RoochTransaction>(&samples).unwrap();
+    tracer
+        .trace_type::<SponsoredRoochTransaction>(&samples)
+        .unwrap();
 
     // More types
     let example_ascii_string: MoveAsciiString = MoveAsciiString::from_str("test").unwrap();
@@ -0,0 +1,62 @@
+// Copyright (c) RoochNetwork
+// SPDX-License-Identifier: Apache-2.0
+
+use crate::cli_types::WalletContextOptions;
+use clap::Parser;
+use rooch_key::key_derive::verify_password;
+use rooch_key::keystore::account_keystore::AccountKeystore;
+use rooch_types::error::{RoochError, RoochResult};
+use rpassword::prompt_password;
+
+/// Change the password of rooch.keystore.
+/// All the keys, session keys and mnemonics are re-encrypted with the new password.
+#[derive(Debug, Parser)]
+pub struct ChangePasswordCommand {
+    #[clap(flatten)]
+    pub context_options: WalletContextOptions,
+}
+
+impl ChangePasswordCommand {
+    pub async fn execute(self) -> RoochResult<()> {
+        let mut context = self.context_options.build()?;
+
+        let old_password = if context.keystore.get_if_password_is_empty() {
+            None
+        } else {
+            let password = prompt_password("Enter the current password:").unwrap_or_default();
+            let is_verified =
+                verify_password(Some(password.clone()), context.keystore.get_password_hash())?;
+
+            if !is_verified {
+                return Err(RoochError::InvalidPasswordError(
+                    "Password is invalid".to_owned(),
+                ));
+            }
+            Some(password)
+        };
+
+        let new_password = prompt_password(
+            "Enter a new password to encrypt the keys. Press enter to leave it an empty password: ",
+        )
+        .unwrap_or_default();
+        let confirm_password = prompt_password("Confirm the new password:").unwrap_or_default();
+        if new_password != confirm_password {
+            return Err(RoochError::InvalidPasswordError(
+                "The two passwords do not match".to_owned(),
+            ));
+        }
+        let new_password = if new_password.is_empty() {
+            None
+        } else {
+            Some(new_password)
+        };
+
+        context
+            .keystore
+            .change_password(old_password, new_password)
+            .map_err(|e| RoochError::UpdateAccountError(e.to_string()))?;
+        println!("The password of the keystore is changed");
+
+        Ok(())
+    }
+}
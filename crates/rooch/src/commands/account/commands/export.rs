@@ -0,0 +1,74 @@
+// Copyright (c) RoochNetwork
+// SPDX-License-Identifier: Apache-2.0
+
+use crate::cli_types::WalletContextOptions;
+use clap::{Parser, ValueEnum};
+use move_command_line_common::address::ParsedAddress;
+use rooch_key::key_derive::{private_key_bytes, verify_password};
+use rooch_key::keystore::account_keystore::AccountKeystore;
+use rooch_types::address::RoochAddress;
+use rooch_types::crypto::EncodeDecodeBase64;
+use rooch_types::error::{RoochError, RoochResult};
+use rpassword::prompt_password;
+
+#[derive(Debug, Clone, Copy, ValueEnum)]
+pub enum PrivateKeyFormat {
+    /// Base64 encoded `flag || privkey`, the standard Rooch keypair format
+    Base64,
+    /// Hex encoded raw ed25519 private key
+    Hex,
+}
+
+/// Export the private key of an address in rooch.keystore.
+/// The exported key can be imported by `rooch account import`.
+#[derive(Debug, Parser)]
+pub struct ExportCommand {
+    #[clap(short = 'a', long = "address", value_parser=ParsedAddress::parse, default_value = "default")]
+    /// The account's address to export, if absent, use the default active account.
+    address: ParsedAddress,
+
+    /// The format of the exported private key
+    #[clap(long, value_enum, default_value = "base64")]
+    format: PrivateKeyFormat,
+
+    #[clap(flatten)]
+    pub context_options: WalletContextOptions,
+}
+
+impl ExportCommand {
+    pub async fn execute(self) -> RoochResult<String> {
+        let context = self.context_options.build()?;
+        let address: RoochAddress = context.resolve_address(self.address)?.into();
+
+        let password = if context.keystore.get_if_password_is_empty() {
+            None
+        } else {
+            let password =
+                prompt_password("Enter the password to export the key pair:").unwrap_or_default();
+            let is_verified =
+                verify_password(Some(password.clone()), context.keystore.get_password_hash())?;
+
+            if !is_verified {
+                return Err(RoochError::InvalidPasswordError(
+                    "Password is invalid".to_owned(),
+                ));
+            }
+            Some(password)
+        };
+
+        let key_pair = context
+            .keystore
+            .get_key_pair_with_password(&address, password)?;
+        let private_key = match self.format {
+            PrivateKeyFormat::Base64 => key_pair.encode_base64(),
+            PrivateKeyFormat::Hex => hex::encode(private_key_bytes(&key_pair)),
+        };
+
+        println!(
+            "Exported the private key of address [{}], keep it secret",
+            address
+        );
+
+        Ok(private_key)
+    }
+}
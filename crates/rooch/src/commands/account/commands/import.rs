@@ -0,0 +1,60 @@
+// Copyright (c) RoochNetwork
+// SPDX-License-Identifier: Apache-2.0
+
+use crate::cli_types::WalletContextOptions;
+use clap::Parser;
+use move_core_types::account_address::AccountAddress;
+use rooch_key::key_derive::{parse_private_key, verify_password};
+use rooch_key::keystore::account_keystore::AccountKeystore;
+use rooch_types::error::{RoochError, RoochResult};
+use rpassword::prompt_password;
+
+/// Import a raw private key into rooch.keystore.
+/// The private key can be a hex encoded ed25519 private key,
+/// or a Base64 encoded key exported by `rooch account export`.
+#[derive(Debug, Parser)]
+pub struct ImportCommand {
+    /// The private key to import, if absent, read it from the prompt
+    #[clap(long)]
+    private_key: Option<String>,
+
+    #[clap(flatten)]
+    pub context_options: WalletContextOptions,
+}
+
+impl ImportCommand {
+    pub async fn execute(self) -> RoochResult<String> {
+        let mut context = self.context_options.build()?;
+
+        let private_key = match self.private_key {
+            Some(private_key) => private_key,
+            None => prompt_password("Enter the private key to import:")
+                .map_err(|e| RoochError::CommandArgumentError(e.to_string()))?,
+        };
+        let key_pair = parse_private_key(&private_key)?;
+
+        let password = if context.keystore.get_if_password_is_empty() {
+            None
+        } else {
+            let password =
+                prompt_password("Enter the password to import the key pair:").unwrap_or_default();
+            let is_verified =
+                verify_password(Some(password.clone()), context.keystore.get_password_hash())?;
+
+            if !is_verified {
+                return Err(RoochError::InvalidPasswordError(
+                    "Password is invalid".to_owned(),
+                ));
+            }
+            Some(password)
+        };
+
+        let address = context
+            .keystore
+            .import_private_key(&key_pair, password)
+            .map_err(|e| RoochError::ImportAccountError(e.to_string()))?;
+        println!("Imported key pair for address [{}]", address);
+
+        Ok(AccountAddress::from(address).to_hex_literal())
+    }
+}
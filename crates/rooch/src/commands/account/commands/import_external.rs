@@ -0,0 +1,49 @@
+// Copyright (c) RoochNetwork
+// SPDX-License-Identifier: Apache-2.0
+
+use crate::cli_types::WalletContextOptions;
+use clap::Parser;
+use move_core_types::account_address::AccountAddress;
+use rooch_key::keystore::account_keystore::AccountKeystore;
+use rooch_key::keystore::types::LocalExternalSigner;
+use rooch_key::signer::{ExternalSignerConfig, Signer};
+use rooch_types::crypto::EncodeDecodeBase64;
+use rooch_types::error::{RoochError, RoochResult};
+use std::path::PathBuf;
+
+/// Import an address whose private key is held by an external signer.
+/// The keystore only records the public key and the signer config,
+/// the transactions of the address are signed by the external signer.
+#[derive(Debug, Parser)]
+pub struct ImportExternalCommand {
+    /// The path of the Unix socket which the external signer is listening on
+    #[clap(long)]
+    socket: PathBuf,
+
+    #[clap(flatten)]
+    pub context_options: WalletContextOptions,
+}
+
+impl ImportExternalCommand {
+    pub async fn execute(self) -> RoochResult<String> {
+        let mut context = self.context_options.build()?;
+
+        let config = ExternalSignerConfig::UnixSocket { path: self.socket };
+        let public_key = config
+            .build()?
+            .public_key()
+            .map_err(|e| RoochError::CommandArgumentError(e.to_string()))?;
+        let rooch_address = public_key.address();
+
+        println!(
+            "Imported external signer for address [{}] with public key [{}]",
+            rooch_address,
+            public_key.encode_base64()
+        );
+        context
+            .keystore
+            .add_external_signer(rooch_address, LocalExternalSigner { public_key, config })?;
+
+        Ok(AccountAddress::from(rooch_address).to_hex_literal())
+    }
+}
@@ -2,7 +2,12 @@
 // SPDX-License-Identifier: Apache-2.0
 
 pub mod balance;
+pub mod change_password;
 pub mod create;
+pub mod export;
+pub mod import;
+pub mod import_external;
 pub mod list;
 pub mod nullify;
 pub mod switch;
+pub mod transfer;
@@ -0,0 +1,148 @@
+// Copyright (c) RoochNetwork
+// SPDX-License-Identifier: Apache-2.0
+
+use crate::cli_types::{CommandAction, WalletContextOptions};
+use async_trait::async_trait;
+use clap::Parser;
+use move_command_line_common::address::ParsedAddress;
+use move_command_line_common::types::ParsedStructType;
+use move_core_types::account_address::AccountAddress;
+use move_core_types::language_storage::StructTag;
+use move_core_types::u256::U256;
+use rooch_key::key_derive::verify_password;
+use rooch_key::keystore::account_keystore::AccountKeystore;
+use rooch_rpc_api::jsonrpc_types::ExecuteTransactionResponseView;
+use rooch_rpc_client::rooch_client::RoochRpcClient;
+use rooch_types::{
+    address::{MultiChainAddress, RoochAddress},
+    error::{RoochError, RoochResult},
+};
+use rpassword::prompt_password;
+use std::str::FromStr;
+
+/// Transfer coins to an address.
+/// The receiver can be a Rooch address, or a multichain address like `btc:<address>`, `eth:<address>` or `nostr:<address>`,
+/// the multichain address is resolved to the Rooch address via the on-chain address mapping.
+#[derive(Debug, Parser)]
+pub struct TransferCommand {
+    /// The receiver address, a Rooch address or a multichain address in `<multichain_id>:<address>` format
+    #[clap(long)]
+    to: String,
+
+    /// The amount of the coin to transfer, the amount is in the minimal unit of the coin
+    #[clap(long)]
+    amount: U256,
+
+    /// Struct name as `<ADDRESS>::<MODULE_ID>::<STRUCT_NAME><TypeParam>`
+    /// Example: `0x3::gas_coin::GasCoin`, `0x123::Coin::Box<0x123::coin_box::FCoin>`
+    #[clap(long, value_parser=ParsedStructType::parse, default_value = "0x3::gas_coin::GasCoin")]
+    coin_type: ParsedStructType,
+
+    /// Sender account address.
+    #[clap(long, value_parser=ParsedAddress::parse, default_value = "default")]
+    sender: ParsedAddress,
+
+    #[clap(flatten)]
+    pub context_options: WalletContextOptions,
+}
+
+#[async_trait]
+impl CommandAction<ExecuteTransactionResponseView> for TransferCommand {
+    async fn execute(self) -> RoochResult<ExecuteTransactionResponseView> {
+        let context = self.context_options.build()?;
+        let mapping = context.address_mapping();
+        let sender: RoochAddress = context.resolve_address(self.sender)?.into();
+        let coin_type = self.coin_type.into_struct_tag(&mapping)?;
+        let to = match MultiChainAddress::from_str(&self.to) {
+            Ok(multichain_address) => multichain_address,
+            Err(_) => {
+                let address = ParsedAddress::parse(&self.to).map_err(|e| {
+                    RoochError::CommandArgumentError(format!(
+                        "Invalid receiver address {}: {}",
+                        self.to, e
+                    ))
+                })?;
+                RoochAddress::from(context.resolve_address(address)?).into()
+            }
+        };
+
+        let client = context.get_client().await?;
+        let action = client
+            .rooch
+            .build_transfer_action(to.clone(), self.amount, coin_type.clone())
+            .await?;
+
+        let sender_before = balance_of(&client.rooch, sender.into(), &coin_type).await?;
+        let receiver = client.rooch.resolve_address(to.clone()).await?;
+        let receiver_before = match receiver {
+            Some(receiver) => balance_of(&client.rooch, receiver, &coin_type).await?,
+            None => U256::zero(),
+        };
+
+        let result = if context.keystore.get_if_password_is_empty() {
+            context.sign_and_execute(sender, action, None).await?
+        } else {
+            let password =
+                prompt_password("Enter the password to transfer coins:").unwrap_or_default();
+            let is_verified =
+                verify_password(Some(password.clone()), context.keystore.get_password_hash())?;
+
+            if !is_verified {
+                return Err(RoochError::InvalidPasswordError(
+                    "Password is invalid".to_owned(),
+                ));
+            }
+
+            context
+                .sign_and_execute(sender, action, Some(password))
+                .await?
+        };
+        let result = context.assert_execute_success(result)?;
+
+        // The multichain address is mapped after the transfer if it is not mapped before
+        let sender_after = balance_of(&client.rooch, sender.into(), &coin_type).await?;
+        let receiver = client.rooch.resolve_address(to.clone()).await?;
+        let receiver_after = match receiver {
+            Some(receiver) => balance_of(&client.rooch, receiver, &coin_type).await?,
+            None => U256::zero(),
+        };
+
+        println!("Balance changes of coin [{}]", coin_type);
+        println!(
+            "{0: ^66} | {1: ^32} | {2: ^32} | {3: ^32} ",
+            "Address", "Before", "After", "Change"
+        );
+        println!("{}", ["-"; 68].join(""));
+        print_balance_change(
+            &AccountAddress::from(sender).to_hex_literal(),
+            sender_before,
+            sender_after,
+        );
+        print_balance_change(&to.to_string(), receiver_before, receiver_after);
+
+        Ok(result)
+    }
+}
+
+async fn balance_of(
+    client: &RoochRpcClient,
+    address: AccountAddress,
+    coin_type: &StructTag,
+) -> RoochResult<U256> {
+    let balance_info = client
+        .get_balance(address.into(), coin_type.clone().into())
+        .await?;
+    Ok(balance_info.balance.0)
+}
+
+fn print_balance_change(address: &str, before: U256, after: U256) {
+    let change = if after >= before {
+        format!("+{}", after - before)
+    } else {
+        format!("-{}", before - after)
+    };
+    println!(
+        "{0: ^66} | {1: ^32} | {2: ^32} | {3: ^32} ",
+        address, before, after, change
+    );
+}
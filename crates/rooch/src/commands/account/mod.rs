@@ -5,7 +5,9 @@ use crate::cli_types::CommandAction;
 use crate::commands::account::commands::balance::BalanceCommand;
 use async_trait::async_trait;
 use commands::{
-    create::CreateCommand, list::ListCommand, nullify::NullifyCommand, switch::SwitchCommand,
+    change_password::ChangePasswordCommand, create::CreateCommand, export::ExportCommand,
+    import::ImportCommand, import_external::ImportExternalCommand, list::ListCommand,
+    nullify::NullifyCommand, switch::SwitchCommand, transfer::TransferCommand,
 };
 use rooch_types::error::{RoochError, RoochResult};
 use std::path::PathBuf;
@@ -33,6 +35,21 @@ impl CommandAction<String> for Account {
             AccountCommand::Switch(switch) => switch.execute().await.map(|_| "".to_owned()),
             AccountCommand::Nullify(nullify) => nullify.execute().await.map(|_| "".to_owned()),
             AccountCommand::Balance(balance) => balance.execute().await.map(|_| "".to_owned()),
+            AccountCommand::Transfer(transfer) => transfer.execute_serialized().await,
+            AccountCommand::Export(export) => export.execute().await.map(|resp| {
+                serde_json::to_string_pretty(&resp).expect("Failed to serialize response")
+            }),
+            AccountCommand::Import(import) => import.execute().await.map(|resp| {
+                serde_json::to_string_pretty(&resp).expect("Failed to serialize response")
+            }),
+            AccountCommand::ChangePassword(change_password) => {
+                change_password.execute().await.map(|_| "".to_owned())
+            }
+            AccountCommand::ImportExternal(import_external) => {
+                import_external.execute().await.map(|resp| {
+                    serde_json::to_string_pretty(&resp).expect("Failed to serialize response")
+                })
+            }
         }
         .map_err(RoochError::from)
     }
@@ -46,4 +63,9 @@ pub enum AccountCommand {
     Switch(SwitchCommand),
     Nullify(NullifyCommand),
     Balance(BalanceCommand),
+    Transfer(TransferCommand),
+    Export(ExportCommand),
+    Import(ImportCommand),
+    ChangePassword(ChangePasswordCommand),
+    ImportExternal(ImportExternalCommand),
 }
@@ -8,6 +8,7 @@ pub mod event;
 pub mod init;
 pub mod move_cli;
 pub mod object;
+pub mod relayer;
 pub mod resource;
 pub mod rpc;
 pub mod server;
@@ -10,3 +10,4 @@ pub mod publish;
 pub mod run_function;
 pub mod run_view_function;
 pub mod unit_test;
+pub mod upgrade_policy;
@@ -10,7 +10,7 @@ use move_core_types::language_storage::TypeTag;
 use moveos_types::transaction::MoveAction;
 use rooch_key::key_derive::verify_password;
 use rooch_key::keystore::account_keystore::AccountKeystore;
-use rooch_rpc_api::jsonrpc_types::ExecuteTransactionResponseView;
+use rooch_rpc_api::jsonrpc_types::{DryRunTransactionResponseView, ExecuteTransactionResponseView};
 use rooch_types::function_arg::parse_function_arg;
 use rooch_types::{
     address::RoochAddress,
@@ -19,6 +19,8 @@ use rooch_types::{
     transaction::rooch::RoochTransaction,
 };
 use rpassword::prompt_password;
+use serde::{Deserialize, Serialize};
+use std::path::PathBuf;
 
 /// Run a Move function
 #[derive(Parser)]
@@ -52,11 +54,31 @@ pub struct RunFunction {
 
     #[clap(flatten)]
     tx_options: TransactionOptions,
+
+    /// Execute the transaction without applying the state changes
+    #[clap(long)]
+    pub dry_run: bool,
+
+    /// Dry run the transaction with the gas profiler, and output the gas profile
+    #[clap(long)]
+    pub profile_gas: bool,
+
+    /// Write the gas profile in the folded stack format to the file, it can be read by flamegraph tools.
+    /// Only works with `--profile-gas`
+    #[clap(long, requires = "profile_gas")]
+    pub profile_output: Option<PathBuf>,
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+#[serde(untagged)]
+pub enum RunFunctionOutput {
+    Execute(ExecuteTransactionResponseView),
+    DryRun(DryRunTransactionResponseView),
 }
 
 #[async_trait]
-impl CommandAction<ExecuteTransactionResponseView> for RunFunction {
-    async fn execute(self) -> RoochResult<ExecuteTransactionResponseView> {
+impl CommandAction<RunFunctionOutput> for RunFunction {
+    async fn execute(self) -> RoochResult<RunFunctionOutput> {
         let context = self.context.build()?;
         let address_mapping = context.address_mapping();
         let sender: RoochAddress = context.resolve_address(self.tx_options.sender)?.into();
@@ -76,17 +98,16 @@ impl CommandAction<ExecuteTransactionResponseView> for RunFunction {
             })
             .collect::<Result<Vec<_>>>()?;
         let action = MoveAction::new_function_call(function_id, type_args, args);
-        match (self.tx_options.authenticator, self.tx_options.session_key) {
+        let tx = match (self.tx_options.authenticator, self.tx_options.session_key) {
             (Some(authenticator), _) => {
                 let tx_data = context.build_tx_data(sender, action).await?;
                 //TODO the authenticator usually is associalted with the RoochTransactinData
                 //So we need to find a way to let user generate the authenticator based on the tx_data.
-                let tx = RoochTransaction::new(tx_data, authenticator.into());
-                context.execute(tx).await
+                RoochTransaction::new(tx_data, authenticator.into())
             }
             (_, Some(session_key)) => {
                 let tx_data = context.build_tx_data(sender, action).await?;
-                let tx = if context.keystore.get_if_password_is_empty() {
+                if context.keystore.get_if_password_is_empty() {
                     context
                         .keystore
                         .sign_transaction_via_session_key(&sender, tx_data, &session_key, None)
@@ -114,12 +135,11 @@ impl CommandAction<ExecuteTransactionResponseView> for RunFunction {
                             Some(password),
                         )
                         .map_err(|e| RoochError::SignMessageError(e.to_string()))?
-                };
-                context.execute(tx).await
+                }
             }
             (None, None) => {
                 if context.keystore.get_if_password_is_empty() {
-                    context.sign_and_execute(sender, action, None).await
+                    context.sign(sender, action, None).await?
                 } else {
                     let password =
                         prompt_password("Enter the password to run functions:").unwrap_or_default();
@@ -134,11 +154,25 @@ impl CommandAction<ExecuteTransactionResponseView> for RunFunction {
                         ));
                     }
 
-                    context
-                        .sign_and_execute(sender, action, Some(password))
-                        .await
+                    context.sign(sender, action, Some(password)).await?
                 }
             }
+        };
+
+        if !self.dry_run && !self.profile_gas {
+            return Ok(RunFunctionOutput::Execute(context.execute(tx).await?));
+        }
+        let client = context.get_client().await?;
+        let response = client
+            .rooch
+            .dry_run_tx(tx, self.profile_gas)
+            .await
+            .map_err(|e| RoochError::TransactionError(e.to_string()))?;
+        if let (Some(profile_output), Some(gas_profile)) =
+            (self.profile_output, response.gas_profile.as_ref())
+        {
+            std::fs::write(profile_output, gas_profile.to_folded_stacks())?;
         }
+        Ok(RunFunctionOutput::DryRun(response))
     }
 }
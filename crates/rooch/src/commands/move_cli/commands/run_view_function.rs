@@ -5,6 +5,7 @@ use crate::cli_types::{CommandAction, WalletContextOptions};
 use anyhow::Result;
 use async_trait::async_trait;
 use clap::Parser;
+use move_command_line_common::address::ParsedAddress;
 use move_command_line_common::types::ParsedStructType;
 use move_core_types::language_storage::TypeTag;
 use moveos_types::transaction::FunctionCall;
@@ -40,6 +41,14 @@ pub struct RunViewFunction {
     #[clap(long = "args")]
     pub args: Vec<FunctionArg>,
 
+    /// The sender of the view function call, default is 0x0.
+    #[clap(long, value_parser=ParsedAddress::parse)]
+    pub sender: Option<ParsedAddress>,
+
+    /// The max gas amount of the view function call.
+    #[clap(long)]
+    pub max_gas_amount: Option<u64>,
+
     /// RPC client options.
     #[clap(flatten)]
     context: WalletContextOptions,
@@ -66,12 +75,17 @@ impl CommandAction<AnnotatedFunctionResultView> for RunViewFunction {
             })
             .collect::<Result<Vec<_>>>()?;
 
+        let sender = self
+            .sender
+            .map(|sender| context.resolve_address(sender))
+            .transpose()?;
+
         let function_call = FunctionCall::new(function_id, type_args, args);
 
         let client = context.get_client().await?;
         client
             .rooch
-            .execute_view_function(function_call)
+            .execute_view_function_with_options(function_call, sender, self.max_gas_amount)
             .await
             .map_err(|e| RoochError::ViewFunctionError(e.to_string()))
     }
@@ -0,0 +1,113 @@
+// Copyright (c) RoochNetwork
+// SPDX-License-Identifier: Apache-2.0
+
+use crate::cli_types::{CommandAction, WalletContextOptions};
+use async_trait::async_trait;
+use clap::Parser;
+use move_command_line_common::address::ParsedAddress;
+use moveos_types::access_path::AccessPath;
+use moveos_types::moveos_std::move_module::{UpgradePolicies, UpgradePolicy};
+use moveos_types::state::MoveStructType;
+use rooch_key::key_derive::verify_password;
+use rooch_key::keystore::account_keystore::AccountKeystore;
+use rooch_rpc_api::jsonrpc_types::ExecuteTransactionResponseView;
+use rooch_types::address::RoochAddress;
+use rooch_types::error::{RoochError, RoochResult};
+use rpassword::prompt_password;
+use serde::Serialize;
+use std::collections::BTreeMap;
+
+/// Show or set the upgrade policy of a package under the sender's address,
+/// the package is given by the names of its modules.
+/// The policy can only be changed to a stricter one, from loose to strict:
+/// `compatible`, `additive`, `dep_only`, `immutable`.
+#[derive(Debug, Parser)]
+pub struct UpgradePolicyCommand {
+    /// The names of the modules in the package, separated by commas
+    #[clap(long, value_delimiter = ',', required = true)]
+    modules: Vec<String>,
+
+    /// The new upgrade policy, show the current policies if it is not provided
+    #[clap(long)]
+    policy: Option<UpgradePolicy>,
+
+    /// The account address of the modules.
+    #[clap(long, alias = "sender-account", value_parser=ParsedAddress::parse, default_value = "default")]
+    sender: ParsedAddress,
+
+    #[clap(flatten)]
+    context_options: WalletContextOptions,
+}
+
+#[derive(Debug, Serialize)]
+pub struct UpgradePolicyOutput {
+    pub address: RoochAddress,
+    /// The upgrade policy of each module
+    pub policies: BTreeMap<String, String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub execution: Option<ExecuteTransactionResponseView>,
+}
+
+#[async_trait]
+impl CommandAction<UpgradePolicyOutput> for UpgradePolicyCommand {
+    async fn execute(self) -> RoochResult<UpgradePolicyOutput> {
+        let context = self.context_options.build()?;
+        let sender: RoochAddress = context.resolve_address(self.sender)?.into();
+
+        let execution = match self.policy {
+            Some(policy) => {
+                let action = policy.create_set_upgrade_policy_action(self.modules.clone());
+                let result = if context.keystore.get_if_password_is_empty() {
+                    context.sign_and_execute(sender, action, None).await?
+                } else {
+                    let password = prompt_password("Enter the password to set the upgrade policy:")
+                        .unwrap_or_default();
+                    let is_verified = verify_password(
+                        Some(password.clone()),
+                        context.keystore.get_password_hash(),
+                    )?;
+                    if !is_verified {
+                        return Err(RoochError::InvalidPasswordError(
+                            "Password is invalid".to_owned(),
+                        ));
+                    }
+                    context
+                        .sign_and_execute(sender, action, Some(password))
+                        .await?
+                };
+                Some(context.assert_execute_success(result)?)
+            }
+            None => None,
+        };
+
+        let client = context.get_client().await?;
+        let states = client
+            .rooch
+            .get_states(AccessPath::resource(
+                sender.into(),
+                UpgradePolicies::struct_tag(),
+            ))
+            .await?;
+        let upgrade_policies = match states.into_iter().next().flatten() {
+            Some(state) => Some(bcs::from_bytes::<UpgradePolicies>(&state.value.0)?),
+            None => None,
+        };
+        let policies = self
+            .modules
+            .into_iter()
+            .map(|name| {
+                let policy = upgrade_policies
+                    .as_ref()
+                    .map(|policies| policies.module_upgrade_policy(&name))
+                    .unwrap_or_default();
+                (name, policy.to_string())
+            })
+            .collect();
+
+        Ok(UpgradePolicyOutput {
+            address: sender,
+            policies,
+            execution,
+        })
+    }
+}
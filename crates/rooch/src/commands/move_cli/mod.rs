@@ -6,7 +6,7 @@ use clap::{Parser, Subcommand};
 use commands::{
     build::Build, framework_upgrade::FrameworkUpgrade, integration_test::IntegrationTest, new::New,
     publish::Publish, run_function::RunFunction, run_view_function::RunViewFunction,
-    unit_test::Test,
+    unit_test::Test, upgrade_policy::UpgradePolicyCommand,
 };
 use move_cli::{
     base::{
@@ -48,6 +48,7 @@ pub enum MoveCommand {
     IntegrationTest(IntegrationTest),
     Explain(Explain),
     FrameworkUpgrade(FrameworkUpgrade),
+    UpgradePolicy(UpgradePolicyCommand),
 }
 
 #[async_trait]
@@ -128,6 +129,7 @@ impl CommandAction<String> for MoveCli {
                 .map(|_| "Success".to_owned())
                 .map_err(RoochError::from),
             MoveCommand::FrameworkUpgrade(c) => c.execute_serialized().await,
+            MoveCommand::UpgradePolicy(c) => c.execute_serialized().await,
         }
     }
 }
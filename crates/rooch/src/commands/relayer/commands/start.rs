@@ -0,0 +1,116 @@
+// Copyright (c) RoochNetwork
+// SPDX-License-Identifier: Apache-2.0
+
+use crate::cli_types::{CommandAction, WalletContextOptions};
+use async_trait::async_trait;
+use clap::Parser;
+use coerce::actor::scheduler::timer::Timer;
+use coerce::actor::{system::ActorSystem, IntoActor};
+use rooch_config::relayer_config::RelayerOpt;
+use rooch_key::key_derive::verify_password;
+use rooch_key::keystore::account_keystore::AccountKeystore;
+use rooch_relayer::actor::messages::RelayTick;
+use rooch_relayer::actor::relayer::RelayerActor;
+use rooch_types::address::RoochAddress;
+use rooch_types::error::{RoochError, RoochResult};
+use rpassword::prompt_password;
+use std::str::FromStr;
+use std::time::Duration;
+use tokio::signal::ctrl_c;
+#[cfg(unix)]
+use tokio::signal::unix::{signal, SignalKind};
+use tracing::info;
+
+/// Start the relayer, it relays the L1 blocks to the Rooch node via the Rooch RPC
+#[derive(Debug, Parser)]
+pub struct StartCommand {
+    #[clap(flatten)]
+    opt: RelayerOpt,
+
+    #[clap(flatten)]
+    pub context_options: WalletContextOptions,
+}
+
+#[async_trait]
+impl CommandAction<()> for StartCommand {
+    async fn execute(self) -> RoochResult<()> {
+        let ethereum_config = self.opt.ethereum_relayer_config();
+        let bitcoin_config = self.opt.bitcoin_relayer_config();
+        if ethereum_config.is_none() && bitcoin_config.is_none() {
+            return Err(RoochError::CommandArgumentError(
+                "The relayer needs at least one of `--eth-rpc-url` and `--btc-rpc-url`".to_owned(),
+            ));
+        }
+
+        let context = self.context_options.build()?;
+        let relayer_account = match &self.opt.relayer_account {
+            Some(relayer_account) => RoochAddress::from_str(relayer_account).map_err(|e| {
+                RoochError::CommandArgumentError(format!("Invalid relayer account address: {}", e))
+            })?,
+            None => context
+                .client_config
+                .active_address
+                .ok_or(RoochError::ActiveAddressDoesNotExistError)?,
+        };
+        let password = if context.keystore.get_if_password_is_empty() {
+            None
+        } else {
+            let password = prompt_password("Enter the password:").unwrap_or_default();
+            let is_verified =
+                verify_password(Some(password.clone()), context.keystore.get_password_hash())?;
+            if !is_verified {
+                return Err(RoochError::InvalidPasswordError(
+                    "Password is invalid".to_owned(),
+                ));
+            }
+            Some(password)
+        };
+        let relayer_keypair = context
+            .keystore
+            .get_key_pair_with_password(&relayer_account, password)
+            .map_err(|e| RoochError::RelayerKeyPairDoesNotExistError(e.to_string()))?;
+
+        let rooch_rpc_url = self.opt.rooch_rpc_url();
+        info!(
+            "Start relayer, relayer address: {:?}, rooch rpc url: {}",
+            relayer_account, rooch_rpc_url
+        );
+        let actor_system = ActorSystem::global_system();
+        let relayer = RelayerActor::new_for_client(
+            relayer_keypair,
+            ethereum_config,
+            bitcoin_config,
+            &rooch_rpc_url,
+        )
+        .await?
+        .into_actor(Some("Relayer"), &actor_system)
+        .await
+        .map_err(|e| RoochError::UnexpectedError(e.to_string()))?;
+        let relayer_timer = Timer::start(
+            relayer,
+            Duration::from_secs(self.opt.relay_interval()),
+            RelayTick {},
+        );
+
+        #[cfg(unix)]
+        {
+            let mut sig_int = signal(SignalKind::interrupt()).map_err(RoochError::from)?;
+            let mut sig_term = signal(SignalKind::terminate()).map_err(RoochError::from)?;
+            tokio::select! {
+                _ = sig_int.recv() => info!("receive SIGINT"),
+                _ = sig_term.recv() => info!("receive SIGTERM"),
+                _ = ctrl_c() => info!("receive Ctrl C"),
+            }
+        }
+        #[cfg(not(unix))]
+        {
+            tokio::select! {
+                _ = ctrl_c() => info!("receive Ctrl C"),
+            }
+        }
+
+        relayer_timer.stop();
+        info!("Shutdown Relayer");
+        Ok(())
+    }
+}
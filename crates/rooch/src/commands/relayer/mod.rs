@@ -0,0 +1,32 @@
+// Copyright (c) RoochNetwork
+// SPDX-License-Identifier: Apache-2.0
+
+use crate::cli_types::CommandAction;
+use async_trait::async_trait;
+use clap::Parser;
+use commands::start::StartCommand;
+use rooch_types::error::RoochResult;
+
+pub mod commands;
+
+/// Run the relayer as a standalone process
+#[derive(Parser)]
+pub struct Relayer {
+    #[clap(subcommand)]
+    cmd: RelayerCommand,
+}
+
+#[async_trait]
+impl CommandAction<String> for Relayer {
+    async fn execute(self) -> RoochResult<String> {
+        match self.cmd {
+            RelayerCommand::Start(start) => start.execute_serialized().await,
+        }
+    }
+}
+
+#[derive(clap::Subcommand)]
+#[clap(name = "relayer")]
+pub enum RelayerCommand {
+    Start(StartCommand),
+}
@@ -0,0 +1,52 @@
+// Copyright (c) RoochNetwork
+// SPDX-License-Identifier: Apache-2.0
+
+use crate::cli_types::{CommandAction, WalletContextOptions};
+use async_trait::async_trait;
+use clap::Parser;
+use move_command_line_common::address::ParsedAddress;
+use moveos_types::module_binding::MoveFunctionCaller;
+use rooch_rpc_api::jsonrpc_types::account_view::SessionKeyView;
+use rooch_types::{
+    authentication_key::AuthenticationKey,
+    error::{RoochError, RoochResult},
+    framework::{session_key::SessionKeyModule, timestamp::TimestampModule},
+};
+
+/// Inspect the on-chain session key of the account by the authentication key
+#[derive(Debug, Parser)]
+pub struct InspectCommand {
+    #[clap(short = 'a', long = "address", value_parser=ParsedAddress::parse, default_value = "default")]
+    /// The account's address of the session key, if absent, use the default active account.
+    address: ParsedAddress,
+
+    /// The authentication key of the session key, in hex format
+    #[clap(long)]
+    authentication_key: AuthenticationKey,
+
+    #[clap(flatten)]
+    pub context_options: WalletContextOptions,
+}
+
+#[async_trait]
+impl CommandAction<SessionKeyView> for InspectCommand {
+    async fn execute(self) -> RoochResult<SessionKeyView> {
+        let context = self.context_options.build()?;
+        let address = context.resolve_address(self.address)?;
+        let client = context.get_client().await?;
+
+        let session_key = client
+            .as_module_binding::<SessionKeyModule>()
+            .get_session_key(address, &self.authentication_key)?
+            .ok_or_else(|| {
+                RoochError::ViewFunctionError(format!(
+                    "Can not find session key {} for address {}",
+                    self.authentication_key, address
+                ))
+            })?;
+        let now_seconds = client
+            .as_module_binding::<TimestampModule>()
+            .now_seconds()?;
+        Ok(SessionKeyView::new(session_key, now_seconds))
+    }
+}
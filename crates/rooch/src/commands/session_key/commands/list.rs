@@ -0,0 +1,45 @@
+// Copyright (c) RoochNetwork
+// SPDX-License-Identifier: Apache-2.0
+
+use crate::cli_types::{CommandAction, WalletContextOptions};
+use async_trait::async_trait;
+use clap::Parser;
+use move_command_line_common::address::ParsedAddress;
+use rooch_rpc_api::api::MAX_RESULT_LIMIT_USIZE;
+use rooch_rpc_api::jsonrpc_types::account_view::SessionKeyView;
+use rooch_types::error::RoochResult;
+
+/// List all session keys of the account on-chain
+#[derive(Debug, Parser)]
+pub struct ListCommand {
+    #[clap(short = 'a', long = "address", value_parser=ParsedAddress::parse, default_value = "default")]
+    /// The account's address to list session keys, if absent, use the default active account.
+    address: ParsedAddress,
+
+    #[clap(flatten)]
+    pub context_options: WalletContextOptions,
+}
+
+#[async_trait]
+impl CommandAction<Vec<SessionKeyView>> for ListCommand {
+    async fn execute(self) -> RoochResult<Vec<SessionKeyView>> {
+        let context = self.context_options.build()?;
+        let address = context.resolve_address(self.address)?;
+        let client = context.get_client().await?;
+
+        let mut session_keys = vec![];
+        let mut cursor = None;
+        loop {
+            let page = client
+                .rooch
+                .get_session_keys(address.into(), cursor, Some(MAX_RESULT_LIMIT_USIZE))
+                .await?;
+            session_keys.extend(page.data);
+            if !page.has_next_page {
+                break;
+            }
+            cursor = page.next_cursor;
+        }
+        Ok(session_keys)
+    }
+}
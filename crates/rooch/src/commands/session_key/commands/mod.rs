@@ -2,3 +2,6 @@
 // SPDX-License-Identifier: Apache-2.0
 
 pub mod create;
+pub mod inspect;
+pub mod list;
+pub mod remove;
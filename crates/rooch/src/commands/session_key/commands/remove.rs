@@ -0,0 +1,72 @@
+// Copyright (c) RoochNetwork
+// SPDX-License-Identifier: Apache-2.0
+
+use crate::cli_types::{CommandAction, TransactionOptions, WalletContextOptions};
+use async_trait::async_trait;
+use clap::Parser;
+use rooch_key::key_derive::verify_password;
+use rooch_key::keystore::account_keystore::AccountKeystore;
+use rooch_rpc_api::jsonrpc_types::ExecuteTransactionResponseView;
+use rooch_types::{
+    address::RoochAddress,
+    authentication_key::AuthenticationKey,
+    error::{RoochError, RoochResult},
+    framework::session_key::SessionKeyModule,
+};
+use rpassword::prompt_password;
+
+/// Remove the session key on-chain, and delete it from the local keystore
+#[derive(Debug, Parser)]
+pub struct RemoveCommand {
+    /// The authentication key of the session key to remove, in hex format
+    #[clap(long)]
+    authentication_key: AuthenticationKey,
+
+    #[clap(flatten)]
+    pub tx_options: TransactionOptions,
+
+    #[clap(flatten)]
+    pub context_options: WalletContextOptions,
+}
+
+#[async_trait]
+impl CommandAction<ExecuteTransactionResponseView> for RemoveCommand {
+    async fn execute(self) -> RoochResult<ExecuteTransactionResponseView> {
+        let mut context = self.context_options.build()?;
+
+        let sender: RoochAddress = context.resolve_address(self.tx_options.sender)?.into();
+        let action =
+            SessionKeyModule::remove_session_key_action(self.authentication_key.as_ref().to_vec());
+
+        let result = if context.keystore.get_if_password_is_empty() {
+            context.sign_and_execute(sender, action, None).await?
+        } else {
+            let password = prompt_password("Enter the password to remove the session key:")
+                .unwrap_or_default();
+            let is_verified =
+                verify_password(Some(password.clone()), context.keystore.get_password_hash())?;
+
+            if !is_verified {
+                return Err(RoochError::InvalidPasswordError(
+                    "Password is invalid".to_owned(),
+                ));
+            }
+
+            context
+                .sign_and_execute(sender, action, Some(password))
+                .await?
+        };
+        let result = context.assert_execute_success(result)?;
+
+        // Delete the session key from the local keystore after the on-chain session key is removed
+        context
+            .keystore
+            .remove_session_key(&sender, &self.authentication_key)?;
+
+        println!(
+            "Removed session key {} for address [{}]",
+            self.authentication_key, sender
+        );
+        Ok(result)
+    }
+}
@@ -4,7 +4,9 @@
 use crate::cli_types::CommandAction;
 use async_trait::async_trait;
 use clap::Parser;
-use commands::create::CreateCommand;
+use commands::{
+    create::CreateCommand, inspect::InspectCommand, list::ListCommand, remove::RemoveCommand,
+};
 use rooch_types::error::RoochResult;
 
 pub mod commands;
@@ -23,6 +25,9 @@ impl CommandAction<String> for SessionKey {
             SessionKeyCommand::Create(create) => create.execute().await.map(|resp| {
                 serde_json::to_string_pretty(&resp).expect("Failed to serialize response")
             }),
+            SessionKeyCommand::List(list) => list.execute_serialized().await,
+            SessionKeyCommand::Inspect(inspect) => inspect.execute_serialized().await,
+            SessionKeyCommand::Remove(remove) => remove.execute_serialized().await,
         }
     }
 }
@@ -31,4 +36,7 @@ impl CommandAction<String> for SessionKey {
 #[clap(name = "session_key")]
 pub enum SessionKeyCommand {
     Create(CreateCommand),
+    List(ListCommand),
+    Inspect(InspectCommand),
+    Remove(RemoveCommand),
 }
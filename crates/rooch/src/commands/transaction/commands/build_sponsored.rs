@@ -0,0 +1,104 @@
+// Copyright (c) RoochNetwork
+// SPDX-License-Identifier: Apache-2.0
+
+use crate::cli_types::{CommandAction, FunctionArg, WalletContextOptions};
+use anyhow::Result;
+use async_trait::async_trait;
+use clap::Parser;
+use move_command_line_common::address::ParsedAddress;
+use move_command_line_common::types::ParsedStructType;
+use move_core_types::language_storage::TypeTag;
+use moveos_types::transaction::MoveAction;
+use rooch_key::key_derive::verify_password;
+use rooch_key::keystore::account_keystore::AccountKeystore;
+use rooch_types::function_arg::parse_function_arg;
+use rooch_types::transaction::AbstractTransaction;
+use rooch_types::{
+    address::RoochAddress,
+    error::{RoochError, RoochResult},
+    function_arg::ParsedFunctionId,
+};
+use rpassword::prompt_password;
+
+/// Build a sponsored transaction which calls a Move function, and sign it as the sender.
+/// The gas fee is paid by the fee payer, the output hex encoded transaction should be co-signed
+/// and submitted by the fee payer via `rooch transaction sponsor`.
+#[derive(Parser)]
+pub struct BuildSponsoredCommand {
+    /// Function name as `<ADDRESS>::<MODULE_ID>::<FUNCTION_NAME>`
+    /// Example: `0x42::message::set_message`, `rooch_framework::empty::empty`
+    #[clap(long)]
+    pub function: ParsedFunctionId,
+
+    /// TypeTag arguments separated by spaces.
+    ///
+    /// Example: `0x1::M::T1 0x1::M::T2 rooch_framework::empty::Empty`
+    #[clap(
+        long = "type-args",
+        value_parser=ParsedStructType::parse,
+    )]
+    pub type_args: Vec<ParsedStructType>,
+
+    /// Arguments combined with their type separated by spaces.
+    ///
+    /// Example: `address:0x1 bool:true u8:0 u256:1234 'vector<u32>:a,b,c,d'`
+    #[clap(long = "args", value_parser=parse_function_arg)]
+    pub args: Vec<FunctionArg>,
+
+    /// Sender account address.
+    #[clap(long, value_parser=ParsedAddress::parse, default_value = "default")]
+    pub sender: ParsedAddress,
+
+    /// The fee payer account address, it pays the gas fee of the transaction.
+    #[clap(long, value_parser=ParsedAddress::parse)]
+    pub fee_payer: ParsedAddress,
+
+    #[clap(flatten)]
+    pub(crate) context_options: WalletContextOptions,
+}
+
+#[async_trait]
+impl CommandAction<String> for BuildSponsoredCommand {
+    async fn execute(self) -> RoochResult<String> {
+        let context = self.context_options.build()?;
+        let address_mapping = context.address_mapping();
+        let sender: RoochAddress = context.resolve_address(self.sender)?.into();
+        let fee_payer: RoochAddress = context.resolve_address(self.fee_payer)?.into();
+        let function_id = self.function.into_function_id(&address_mapping)?;
+        let args = self
+            .args
+            .into_iter()
+            .map(|arg| arg.into_bytes(&address_mapping))
+            .collect::<Result<Vec<_>>>()?;
+        let type_args = self
+            .type_args
+            .into_iter()
+            .map(|tag| {
+                Ok(TypeTag::Struct(Box::new(
+                    tag.into_struct_tag(&address_mapping)?,
+                )))
+            })
+            .collect::<Result<Vec<_>>>()?;
+        let action = MoveAction::new_function_call(function_id, type_args, args);
+
+        let password = if context.keystore.get_if_password_is_empty() {
+            None
+        } else {
+            let password =
+                prompt_password("Enter the password to sign the transaction:").unwrap_or_default();
+            let is_verified =
+                verify_password(Some(password.clone()), context.keystore.get_password_hash())?;
+            if !is_verified {
+                return Err(RoochError::InvalidPasswordError(
+                    "Password is invalid".to_owned(),
+                ));
+            }
+            Some(password)
+        };
+
+        let tx = context
+            .sign_sponsored(sender, fee_payer, action, password)
+            .await?;
+        Ok(format!("0x{}", hex::encode(tx.encode())))
+    }
+}
@@ -1,5 +1,7 @@
 // Copyright (c) RoochNetwork
 // SPDX-License-Identifier: Apache-2.0
 
+pub mod build_sponsored;
 pub mod get_transactions_by_hash;
 pub mod get_transactions_by_order;
+pub mod sponsor;
@@ -0,0 +1,54 @@
+// Copyright (c) RoochNetwork
+// SPDX-License-Identifier: Apache-2.0
+
+use crate::cli_types::{CommandAction, WalletContextOptions};
+use async_trait::async_trait;
+use clap::Parser;
+use rooch_key::key_derive::verify_password;
+use rooch_key::keystore::account_keystore::AccountKeystore;
+use rooch_rpc_api::jsonrpc_types::ExecuteTransactionResponseView;
+use rooch_types::error::{RoochError, RoochResult};
+use rooch_types::transaction::rooch::SponsoredRoochTransaction;
+use rooch_types::transaction::AbstractTransaction;
+use rpassword::prompt_password;
+
+/// Co-sign a sponsored transaction as the fee payer and submit it.
+/// The transaction is built and signed by the sender via `rooch transaction build-sponsored`.
+#[derive(Debug, Parser)]
+pub struct SponsorCommand {
+    /// The hex encoded sponsored transaction signed by the sender
+    #[clap(long)]
+    pub tx: String,
+
+    #[clap(flatten)]
+    pub(crate) context_options: WalletContextOptions,
+}
+
+#[async_trait]
+impl CommandAction<ExecuteTransactionResponseView> for SponsorCommand {
+    async fn execute(self) -> RoochResult<ExecuteTransactionResponseView> {
+        let context = self.context_options.build()?;
+        let tx_bytes = hex::decode(self.tx.strip_prefix("0x").unwrap_or(&self.tx))
+            .map_err(|e| RoochError::CommandArgumentError(format!("Invalid tx hex: {}", e)))?;
+        let tx = SponsoredRoochTransaction::decode(&tx_bytes)
+            .map_err(|e| RoochError::CommandArgumentError(format!("Invalid tx: {}", e)))?;
+
+        let password = if context.keystore.get_if_password_is_empty() {
+            None
+        } else {
+            let password = prompt_password("Enter the password to sponsor the transaction:")
+                .unwrap_or_default();
+            let is_verified =
+                verify_password(Some(password.clone()), context.keystore.get_password_hash())?;
+            if !is_verified {
+                return Err(RoochError::InvalidPasswordError(
+                    "Password is invalid".to_owned(),
+                ));
+            }
+            Some(password)
+        };
+
+        let tx = context.sign_as_fee_payer(tx, password)?;
+        context.execute_sponsored(tx).await
+    }
+}
@@ -3,8 +3,8 @@
 
 use crate::cli_types::CommandAction;
 use crate::commands::transaction::commands::{
-    get_transactions_by_hash::GetTransactionsByHashCommand,
-    get_transactions_by_order::GetTransactionsByOrderCommand,
+    build_sponsored::BuildSponsoredCommand, get_transactions_by_hash::GetTransactionsByHashCommand,
+    get_transactions_by_order::GetTransactionsByOrderCommand, sponsor::SponsorCommand,
 };
 use async_trait::async_trait;
 use clap::{Parser, Subcommand};
@@ -25,6 +25,8 @@ impl CommandAction<String> for Transaction {
         match self.cmd {
             TransactionCommand::GetTransactionsByOrder(cmd) => cmd.execute_serialized().await,
             TransactionCommand::GetTransactionsByHash(cmd) => cmd.execute_serialized().await,
+            TransactionCommand::BuildSponsored(cmd) => cmd.execute_serialized().await,
+            TransactionCommand::Sponsor(cmd) => cmd.execute_serialized().await,
         }
     }
 }
@@ -33,4 +35,6 @@ impl CommandAction<String> for Transaction {
 pub enum TransactionCommand {
     GetTransactionsByOrder(GetTransactionsByOrderCommand),
     GetTransactionsByHash(GetTransactionsByHashCommand),
+    BuildSponsored(BuildSponsoredCommand),
+    Sponsor(SponsorCommand),
 }
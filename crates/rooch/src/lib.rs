@@ -5,7 +5,7 @@ use crate::commands::event::EventCommand;
 use cli_types::CommandAction;
 use commands::{
     abi::ABI, account::Account, env::Env, init::Init, move_cli::MoveCli, object::ObjectCommand,
-    resource::ResourceCommand, rpc::Rpc, server::Server, session_key::SessionKey,
+    relayer::Relayer, resource::ResourceCommand, rpc::Rpc, server::Server, session_key::SessionKey,
     state::StateCommand, transaction::Transaction,
 };
 use rooch_types::error::RoochResult;
@@ -28,6 +28,7 @@ pub enum Command {
     Init(Init),
     Move(MoveCli),
     Server(Server),
+    Relayer(Relayer),
     State(StateCommand),
     Object(ObjectCommand),
     Resource(ResourceCommand),
@@ -44,6 +45,7 @@ pub async fn run_cli(opt: RoochCli) -> RoochResult<String> {
         Command::Account(account) => account.execute().await,
         Command::Move(move_cli) => move_cli.execute().await,
         Command::Server(server) => server.execute().await,
+        Command::Relayer(relayer) => relayer.execute().await,
         Command::Init(init) => init.execute_serialized().await,
         Command::State(state) => state.execute_serialized().await,
         Command::Object(object) => object.execute_serialized().await,
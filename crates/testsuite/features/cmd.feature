@@ -37,6 +37,7 @@ Feature: Rooch CLI integration tests
 
       Then cmd: "account create"
       Then cmd: "account list"
+      Then cmd: "account export --format hex"
       #Then cmd: "account nullify --address 0xebf29d2aed4da3d2e13a32d71266a302fbfd5ceb3ff1f465c006fa207f1789ce"
 
       Then cmd: "rpc request --method rooch_getBalance --params '["{{$.address_mapping.default}}", "0x3::gas_coin::GasCoin"]'"
@@ -46,11 +47,23 @@ Feature: Rooch CLI integration tests
       # Get gas
       Then cmd: "move run --function rooch_framework::gas_coin::faucet_entry"
       Then assert: "{{$.move[-1].execution_info.status.type}} == executed"
+
+      # transfer
+      Then cmd: "account transfer --to 0x42 --amount 100 --coin-type rooch_framework::gas_coin::GasCoin"
+      Then assert: "{{$.account[-1].execution_info.status.type}} == executed"
+      Then cmd: "rpc request --method rooch_getBalance --params '["0x42", "0x3::gas_coin::GasCoin"]'"
+      Then assert: "'{{$.rpc[-1].balance}}' == '100'"
       
       # session key
       Then cmd: "session-key create  --scope 0x3::empty::empty"
       Then cmd: "move run --function 0x3::empty::empty  --session-key {{$.session-key[-1].authentication_key}}"
       Then assert: "{{$.move[-1].execution_info.status.type}} == executed"
+      Then cmd: "session-key list"
+      Then assert: "{{$.session-key[-1][0].is_expired}} == false"
+      Then cmd: "session-key inspect --authentication-key {{$.session-key[-1][0].authentication_key}}"
+      Then assert: "{{$.session-key[-1].max_inactive_interval}} == 3600"
+      Then cmd: "session-key remove --authentication-key {{$.session-key[-1].authentication_key}}"
+      Then assert: "{{$.session-key[-1].execution_info.status.type}} == executed"
 
       # transaction
       Then cmd: "transaction get-transactions-by-order --cursor 0 --limit 1"
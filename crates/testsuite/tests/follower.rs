@@ -0,0 +1,148 @@
+// Copyright (c) RoochNetwork
+// SPDX-License-Identifier: Apache-2.0
+
+use anyhow::{bail, Result};
+use moveos_types::transaction::MoveAction;
+use rooch_config::{RoochOpt, ServerOpt};
+use rooch_key::key_derive::{generate_new_key_pair, retrieve_key_pair};
+use rooch_key::keystore::account_keystore::AccountKeystore;
+use rooch_key::keystore::memory_keystore::InMemKeystore;
+use rooch_rpc_client::{Client, ClientBuilder};
+use rooch_rpc_server::Service;
+use rooch_types::address::RoochAddress;
+use rooch_types::bitcoin::network::Network;
+use rooch_types::crypto::RoochKeyPair;
+use rooch_types::framework::empty::Empty;
+use rooch_types::transaction::rooch::RoochTransactionData;
+use std::time::Duration;
+
+const UPSTREAM_PORT: u16 = 50151;
+const FOLLOWER_PORT: u16 = 50152;
+const DIVERGED_FOLLOWER_PORT: u16 = 50153;
+
+async fn start_server(
+    port: u16,
+    upstream: Option<(u16, RoochAddress)>,
+    btc_network: Network,
+    server_opt: ServerOpt,
+) -> Result<(Service, Client)> {
+    let mut opt = RoochOpt::new_with_temp_store();
+    opt.port = Some(port);
+    opt.metrics_port = Some(port + 1000);
+    opt.btc_network = Some(btc_network.to_num());
+    if let Some((upstream_port, sequencer_account)) = upstream {
+        opt.upstream_url = Some(format!("http://127.0.0.1:{}", upstream_port));
+        opt.sequencer_account = Some(sequencer_account.to_string());
+    }
+    let mut service = Service::new();
+    service.start(&opt, server_opt).await?;
+    let client = ClientBuilder::default()
+        .build(format!("http://127.0.0.1:{}", port))
+        .await?;
+    Ok((service, client))
+}
+
+async fn execute_empty_tx(
+    client: &Client,
+    keystore: &InMemKeystore,
+    sender: RoochAddress,
+    sequence_number: u64,
+) -> Result<()> {
+    let tx_data = RoochTransactionData::new_for_test(
+        sender,
+        sequence_number,
+        MoveAction::new_function_call(Empty::empty_function_id(), vec![], vec![]),
+    );
+    let tx = keystore.sign_transaction(&sender, tx_data, None)?;
+    client.rooch.execute_tx(tx).await?;
+    Ok(())
+}
+
+async fn tx_count(client: &Client) -> Result<usize> {
+    Ok(client
+        .rooch
+        .get_transactions_by_order(None, None)
+        .await?
+        .data
+        .len())
+}
+
+async fn wait_for_tx_count(client: &Client, count: usize) -> Result<()> {
+    for _ in 0..60 {
+        if tx_count(client).await.unwrap_or_default() >= count {
+            return Ok(());
+        }
+        tokio::time::sleep(Duration::from_secs(1)).await;
+    }
+    bail!("The follower did not sync {} transactions in time", count)
+}
+
+async fn wait_for_stopped(client: &Client) -> Result<()> {
+    for _ in 0..60 {
+        if client.rooch.get_chain_id().await.is_err() {
+            return Ok(());
+        }
+        tokio::time::sleep(Duration::from_secs(1)).await;
+    }
+    bail!("The diverged follower did not stop in time")
+}
+
+#[tokio::test]
+async fn test_follower() -> Result<()> {
+    let result = generate_new_key_pair(None, None, None, None)?;
+    let kp: RoochKeyPair = retrieve_key_pair(&result.key_pair_data.private_key_encryption, None)?;
+    let sequencer_account: RoochAddress = (&kp.public()).into();
+    let server_opt = ServerOpt {
+        sequencer_keypair: Some(kp.copy()),
+        proposer_keypair: Some(kp.copy()),
+        relayer_keypair: Some(kp.copy()),
+        active_env: None,
+    };
+
+    let (upstream, upstream_client) =
+        start_server(UPSTREAM_PORT, None, Network::NetworkBitcoin, server_opt).await?;
+    let (follower, follower_client) = start_server(
+        FOLLOWER_PORT,
+        Some((UPSTREAM_PORT, sequencer_account)),
+        Network::NetworkBitcoin,
+        ServerOpt::new(),
+    )
+    .await?;
+    // A different Bitcoin network gives the follower a different genesis state,
+    // so the first followed transaction does not reach the upstream state root.
+    let (diverged_follower, diverged_client) = start_server(
+        DIVERGED_FOLLOWER_PORT,
+        Some((UPSTREAM_PORT, sequencer_account)),
+        Network::NetworkRegtest,
+        ServerOpt::new(),
+    )
+    .await?;
+
+    let keystore = InMemKeystore::new_insecure_for_tests(1);
+    let sender = keystore.addresses()[0];
+
+    // The follower syncs the transactions sequenced by the upstream node
+    execute_empty_tx(&upstream_client, &keystore, sender, 0).await?;
+    let upstream_count = tx_count(&upstream_client).await?;
+    wait_for_tx_count(&follower_client, upstream_count).await?;
+    assert_eq!(tx_count(&follower_client).await?, upstream_count);
+
+    // The write requests sent to the follower are forwarded to the upstream node
+    execute_empty_tx(&follower_client, &keystore, sender, 1).await?;
+    assert_eq!(tx_count(&upstream_client).await?, upstream_count + 1);
+    wait_for_tx_count(&follower_client, upstream_count + 1).await?;
+
+    // The diverged follower stops on the state root mismatch and refuses the reads,
+    // but it still forwards the write requests to the upstream node
+    wait_for_stopped(&diverged_client).await?;
+    execute_empty_tx(&diverged_client, &keystore, sender, 2).await?;
+    assert_eq!(tx_count(&upstream_client).await?, upstream_count + 2);
+    assert!(diverged_client.rooch.get_chain_id().await.is_err());
+    assert!(tx_count(&diverged_client).await.is_err());
+    assert!(follower_client.rooch.get_chain_id().await.is_ok());
+
+    diverged_follower.stop()?;
+    follower.stop()?;
+    upstream.stop()?;
+    Ok(())
+}
@@ -26,6 +26,7 @@ module rooch_examples::counter {
       Self::increase_(ctx)
    }
 
+   #[view]
    public fun value(ctx: &Context): u64 {
       let counter = context::borrow_resource<Counter>(ctx, @rooch_examples);
       counter.value
@@ -55,6 +55,7 @@ module rooch_examples::kv_store {
       remove(kv, key);
    }
 
+   #[view]
    public fun get_value(ctx: &Context, key: String): String {
       let kv = borrow_kv_store(ctx);
       //std::debug::print(&key);
@@ -6,6 +6,7 @@ module bitcoin_move::brc20 {
     use std::string::{Self, String};
     use moveos_std::json;
     use moveos_std::context::{Self, Context};
+    use moveos_std::event;
     use moveos_std::object::{Self, Object};
     use moveos_std::table::{Self, Table};
     use moveos_std::simple_map::{Self, SimpleMap};
@@ -46,6 +47,27 @@ module bitcoin_move::brc20 {
         object::borrow_mut(brc20_store_obj)
     }
 
+    /// Emitted for every executed brc20 operation. It carries the tick info and the balances after the operation,
+    /// so the indexer maintains the brc20 ticks and balances from the events.
+    struct BRC20OpEvent has copy, drop, store {
+        /// deploy, mint or transfer
+        op: String,
+        tick: String,
+        from: address,
+        to: address,
+        /// The inscribed amount of mint and transfer, or the max supply of deploy
+        amt: String,
+        success: bool,
+        /// The tick info after the operation, all zero if the tick is not deployed
+        max: u256,
+        lim: u256,
+        dec: u64,
+        supply: u256,
+        /// The balances of `from` and `to` after the operation
+        from_balance: u256,
+        to_balance: u256,
+    }
+
     /// The brc20 operation
     struct Op has store {
         from: address,
@@ -284,12 +306,41 @@ module bitcoin_move::brc20 {
         }
     }
 
+    fun emit_op_event(ctx: &mut Context, op: vector<u8>, tick: String, from: address, to: address, amt: String, success: bool){
+        let brc20_store = borrow_store(ctx);
+        let (max, lim, dec, supply, from_balance, to_balance) = if(table::contains(&brc20_store.coins, tick)){
+            let balance_info = table::borrow(&brc20_store.coins, tick);
+            let coin_info = &balance_info.info;
+            let from_balance = *table::borrow_with_default(&balance_info.balance, from, &0u256);
+            let to_balance = *table::borrow_with_default(&balance_info.balance, to, &0u256);
+            (coin_info.max, coin_info.lim, coin_info.dec, coin_info.supply, from_balance, to_balance)
+        }else{
+            (0u256, 0u256, 0u64, 0u256, 0u256, 0u256)
+        };
+        event::emit(BRC20OpEvent{
+            op: string::utf8(op),
+            tick,
+            from,
+            to,
+            amt,
+            success,
+            max,
+            lim,
+            dec,
+            supply,
+            from_balance,
+            to_balance,
+        });
+    }
+
     public(friend) fun process_utxo_op(ctx: &mut Context, op: Op) : bool {
         let result = if(is_transfer(&op)){
             let transfer_op_opt = as_transfer(&op);
             if(option::is_some(&transfer_op_opt)){
                 let transfer_op = option::destroy_some(transfer_op_opt);
-                execute_transfer(ctx, transfer_op)
+                let success = execute_transfer(ctx, transfer_op);
+                emit_op_event(ctx, b"transfer", transfer_op.tick, transfer_op.from, transfer_op.to, transfer_op.amt, success);
+                success
             }else{
                 std::debug::print(&string::utf8(b"invalid transfer op"));
                 std::debug::print(&op);
@@ -313,7 +364,9 @@ module bitcoin_move::brc20 {
                 false
             }else{
                 let deploy_op = option::destroy_some(deploy_op_opt);
-                execute_deploy(ctx, deploy_op)
+                let success = execute_deploy(ctx, deploy_op);
+                emit_op_event(ctx, b"deploy", deploy_op.tick, deploy_op.from, deploy_op.to, deploy_op.max, success);
+                success
             }
         }else if(is_mint(&op)){
             let mint_op_opt = as_mint(&op);
@@ -323,7 +376,9 @@ module bitcoin_move::brc20 {
                 false
             }else{
                 let mint_op = option::destroy_some(mint_op_opt);
-                execute_mint(ctx, mint_op)
+                let success = execute_mint(ctx, mint_op);
+                emit_op_event(ctx, b"mint", mint_op.tick, mint_op.from, mint_op.to, mint_op.amt, success);
+                success
             }
         }else if(is_transfer(&op)){
             let transfer_op_opt = as_transfer(&op);
@@ -333,7 +388,9 @@ module bitcoin_move::brc20 {
                 false
             }else{
                 let transfer_op = option::destroy_some(transfer_op_opt);
-                execute_transfer(ctx, transfer_op)
+                let success = execute_transfer(ctx, transfer_op);
+                emit_op_event(ctx, b"transfer", transfer_op.tick, transfer_op.from, transfer_op.to, transfer_op.amt, success);
+                success
             }
         }else{
             std::debug::print(&string::utf8(b"unknown brc20 op"));
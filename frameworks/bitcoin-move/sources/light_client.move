@@ -7,7 +7,7 @@ module bitcoin_move::light_client{
     use std::vector;
     use std::string::{String};
     use moveos_std::type_info;
-    //use moveos_std::event;
+    use moveos_std::event;
     use moveos_std::context::{Self, Context};
     use moveos_std::table::{Self, Table};
     use moveos_std::bcs;
@@ -16,6 +16,7 @@ module bitcoin_move::light_client{
     use moveos_std::simple_multimap;
     use moveos_std::signer;
     use rooch_framework::timestamp;
+    use rooch_framework::onchain_config;
     use bitcoin_move::types::{Self, Block, Header, Transaction, OutPoint};    
     use bitcoin_move::ord::{Self, Inscription, bind_multichain_address};
     use bitcoin_move::utxo::{Self, UTXOSeal};
@@ -25,12 +26,39 @@ module bitcoin_move::light_client{
 
     const ErrorBlockNotFound:u64 = 1;
     const ErrorBlockAlreadyProcessed:u64 = 2;
+    const ErrorBlockHeightAlreadyExists:u64 = 3;
+    const ErrorPrevBlockHashMismatch:u64 = 4;
+    const ErrorForkPointMismatch:u64 = 5;
+    const ErrorReorgTooDeep:u64 = 6;
+    const ErrorNotRelayer:u64 = 7;
+    const ErrorRollbackInProgress:u64 = 8;
+    const ErrorBRC20RollbackNotSupported:u64 = 9;
+
+    /// The max depth of a reorg that can be rolled back, same as the Bitcoin coinbase maturity.
+    /// The undo journal of the txs deeper than this is pruned.
+    const MAX_REORG_DEPTH:u64 = 100;
 
     struct TxProgressErrorLogEvent has copy, drop{
         txid: address,
         message: String,
     }
 
+    /// Emitted when the blocks above the fork point are rolled back
+    struct BitcoinReorgEvent has copy, drop{
+        fork_height: u64,
+        fork_block_hash: address,
+        rollback_block_count: u64,
+    }
+
+    /// The UTXO spent by a tx, it is recorded to restore the UTXO when the tx is rolled back
+    struct SpentUTXO has store, drop{
+        txid: address,
+        vout: u32,
+        value: u64,
+        owner: address,
+        inscriptions: vector<ObjectID>,
+    }
+
     
     struct BitcoinBlockStore has key{
         latest_block_height: Option<u64>,
@@ -44,6 +72,10 @@ module bitcoin_move::light_client{
         txs: Table<address, Transaction>,
         /// tx id list, we can use this to scan txs
         tx_ids: TableVec<address>,
+        /// block hash -> the index of the block's first tx in tx_ids
+        block_tx_start: Table<address, u64>,
+        /// The fork height of the unfinished rollback, the blocks can not be submitted until the rollback is finished
+        rollback_fork_height: Option<u64>,
     }
 
     struct BitcoinUTXOStore has key{
@@ -51,6 +83,10 @@ module bitcoin_move::light_client{
         next_tx_index: u64,
         /// outpoint -> txout
         utxo: Table<OutPoint, ObjectID>,
+        /// tx index -> the UTXOs spent by the tx, the undo journal for rolling back blocks
+        spent_utxos: Table<u64, vector<SpentUTXO>>,
+        /// The journal of the txs before this index is pruned
+        journal_start_index: u64,
     }
 
     public(friend) fun genesis_init(ctx: &mut Context, _genesis_account: &signer){
@@ -61,6 +97,8 @@ module bitcoin_move::light_client{
             hash_to_height: context::new_table(ctx),
             txs: context::new_table(ctx),
             tx_ids: context::new_table_vec(ctx),
+            block_tx_start: context::new_table(ctx),
+            rollback_fork_height: option::none(),
         };
         let obj = context::new_named_object(ctx, btc_block_store);
         object::to_shared(obj);
@@ -68,6 +106,8 @@ module bitcoin_move::light_client{
         let btc_utxo_store = BitcoinUTXOStore{
             next_tx_index: 0,
             utxo: context::new_table(ctx),
+            spent_utxos: context::new_table(ctx),
+            journal_start_index: 0,
         };
         let obj = context::new_named_object(ctx, btc_utxo_store);
         object::to_shared(obj);
@@ -79,14 +119,16 @@ module bitcoin_move::light_client{
         //already processed
         assert!(!table::contains(&btc_block_store.hash_to_height, block_hash), ErrorBlockAlreadyProcessed);
 
+        //The relayer should rollback to the fork point before submitting a block of the new best chain
+        assert!(!table::contains(&btc_block_store.height_to_hash, block_height), ErrorBlockHeightAlreadyExists);
+
         let block = bcs::from_bytes<Block>(block_bytes);
         validate_block(btc_block_store, block_height, block_hash, &block);
+        let tx_start_index = table_vec::length(&btc_block_store.tx_ids);
+        table::add(&mut btc_block_store.block_tx_start, block_hash, tx_start_index);
         process_txs(btc_block_store, &block); 
         let block_header = types::header(&block);
 
-        if(table::contains(&btc_block_store.height_to_hash, block_height)){
-            //TODO handle reorg
-        };
         let time = types::time(block_header);
         table::add(&mut btc_block_store.height_to_hash, block_height, block_hash);
         table::add(&mut btc_block_store.hash_to_height, block_hash, block_height);
@@ -95,9 +137,12 @@ module bitcoin_move::light_client{
         time 
     }
 
-    fun validate_block(_btc_block_store: &BitcoinBlockStore, _block_height: u64, _block_hash: address, _block: &Block){
+    fun validate_block(btc_block_store: &BitcoinBlockStore, block_height: u64, _block_hash: address, block: &Block){
+        if(block_height > 0 && table::contains(&btc_block_store.height_to_hash, block_height - 1)){
+            let prev_block_hash = *table::borrow(&btc_block_store.height_to_hash, block_height - 1);
+            assert!(types::prev_blockhash(types::header(block)) == prev_block_hash, ErrorPrevBlockHashMismatch);
+        };
         //TODO validate the block via bitcoin consensus
-        // validate prev block hash
         // validate block hash
         // validate block nonce
         //TODO validate txid
@@ -119,18 +164,21 @@ module bitcoin_move::light_client{
         table_vec::push_back(&mut btc_block_store.tx_ids, txid);
     }
 
-    fun process_utxo(ctx: &mut Context, btc_utxo_store: &mut BitcoinUTXOStore, tx: &Transaction){
+    fun process_utxo(ctx: &mut Context, btc_utxo_store: &mut BitcoinUTXOStore, tx_index: u64, tx: &Transaction){
         let txid = types::tx_id(tx);
         let txinput = types::tx_input(tx);
         let idx = 0;
         let output_seals = simple_multimap::new<u64, UTXOSeal>();
+        let spent_utxos = vector::empty<SpentUTXO>();
         while(idx < vector::length(txinput)){
             let txin = vector::borrow(txinput, idx);
             let outpoint = *types::txin_previous_output(txin);
             if(table::contains(&btc_utxo_store.utxo, outpoint)){
                 let object_id = table::remove(&mut btc_utxo_store.utxo, outpoint);
-                let (_owner, utxo_obj) = utxo::take(ctx, object_id);
+                let (owner, utxo_obj) = utxo::take(ctx, object_id);
+                let value = utxo::value(object::borrow(&utxo_obj));
                 let seal_outs = ord::spend_utxo(ctx, &mut utxo_obj, tx);
+                let inscriptions = vector::empty<ObjectID>();
                 if(!vector::is_empty(&seal_outs)){
                     let protocol = type_info::type_name<Inscription>();
                     let j = 0;
@@ -138,6 +186,7 @@ module bitcoin_move::light_client{
                     while(j < seal_outs_len){
                         let seal_out = vector::pop_back(&mut seal_outs);
                         let (output_index, object_id) = utxo::unpack_seal_out(seal_out);
+                        vector::push_back(&mut inscriptions, object_id);
                         let utxo_seal = utxo::new_utxo_seal(protocol, object_id);
                         simple_multimap::add(&mut output_seals, output_index, utxo_seal);
                         j = j + 1;
@@ -146,6 +195,13 @@ module bitcoin_move::light_client{
                 let seals = utxo::remove(utxo_obj);
                 //The seals should be empty after utxo is spent
                 simple_multimap::destroy_empty(seals);
+                vector::push_back(&mut spent_utxos, SpentUTXO{
+                    txid: types::outpoint_txid(&outpoint),
+                    vout: types::outpoint_vout(&outpoint),
+                    value,
+                    owner,
+                    inscriptions,
+                });
             }else{
                 //We allow the utxo not exists in the utxo store, because we may not sync the block from genesis
             };
@@ -198,11 +254,76 @@ module bitcoin_move::light_client{
             idx = idx + 1;
         };
         simple_multimap::drop(output_seals);
+        if(!vector::is_empty(&spent_utxos)){
+            table::add(&mut btc_utxo_store.spent_utxos, tx_index, spent_utxos);
+        };
+    }
+
+    /// Revert the UTXO changes of the tx: remove the created UTXOs and inscriptions, and restore the spent UTXOs
+    /// The BRC-20 operations can not be reverted, so a tx that inscribes or spends a BRC-20 inscription can not be rolled back.
+    fun rollback_utxo(ctx: &mut Context, btc_utxo_store: &mut BitcoinUTXOStore, tx_index: u64, tx: &Transaction){
+        let txid = types::tx_id(tx);
+        assert!(!ord::has_brc20_inscription(ctx, txid), ErrorBRC20RollbackNotSupported);
+        let txoutput_len = vector::length(types::tx_output(tx));
+        let idx = 0;
+        while(idx < txoutput_len){
+            let outpoint = types::new_outpoint(txid, (idx as u32));
+            if(table::contains(&btc_utxo_store.utxo, outpoint)){
+                let object_id = table::remove(&mut btc_utxo_store.utxo, outpoint);
+                let (_owner, utxo_obj) = utxo::take(ctx, object_id);
+                //The sealed inscriptions are either created by this tx or restored to the spent UTXOs below
+                let seals = utxo::remove(utxo_obj);
+                simple_multimap::drop(seals);
+            };
+            idx = idx + 1;
+        };
+        ord::remove_tx_inscriptions(ctx, txid);
+
+        if(table::contains(&btc_utxo_store.spent_utxos, tx_index)){
+            let spent_utxos = table::remove(&mut btc_utxo_store.spent_utxos, tx_index);
+            let protocol = type_info::type_name<Inscription>();
+            while(!vector::is_empty(&spent_utxos)){
+                let SpentUTXO{txid, vout, value, owner, inscriptions} = vector::pop_back(&mut spent_utxos);
+                let utxo_obj = utxo::new(ctx, txid, vout, value);
+                let utxo = object::borrow_mut(&mut utxo_obj);
+                let j = 0;
+                let inscriptions_len = vector::length(&inscriptions);
+                while(j < inscriptions_len){
+                    let object_id = *vector::borrow(&inscriptions, j);
+                    assert!(!ord::is_brc20_inscription(ctx, object_id), ErrorBRC20RollbackNotSupported);
+                    utxo::add_seal(utxo, utxo::new_utxo_seal(protocol, object_id));
+                    ord::transfer_inscription(ctx, object_id, owner);
+                    j = j + 1;
+                };
+                let object_id = object::id(&utxo_obj);
+                table::add(&mut btc_utxo_store.utxo, types::new_outpoint(txid, vout), object_id);
+                utxo::transfer(utxo_obj, owner);
+            };
+        };
+    }
+
+    /// The tx index before which the blocks are deeper than MAX_REORG_DEPTH and can not be rolled back
+    fun stable_tx_index(btc_block_store: &BitcoinBlockStore): u64{
+        if(option::is_none(&btc_block_store.latest_block_height)){
+            return 0
+        };
+        let latest_block_height = *option::borrow(&btc_block_store.latest_block_height);
+        if(latest_block_height < MAX_REORG_DEPTH){
+            return 0
+        };
+        let stable_block_height = latest_block_height - MAX_REORG_DEPTH;
+        if(!table::contains(&btc_block_store.height_to_hash, stable_block_height)){
+            return 0
+        };
+        let block_hash = *table::borrow(&btc_block_store.height_to_hash, stable_block_height);
+        *table::borrow(&btc_block_store.block_tx_start, block_hash)
     }
 
 
-    /// The relay server submit a new Bitcoin block to the light client.
+    /// The relay server submit a new Bitcoin block to the light client, only the relayers can submit the blocks.
     entry fun submit_new_block(ctx: &mut Context, btc_block_store_obj: &mut Object<BitcoinBlockStore>, block_height: u64, block_hash: address, block_bytes: vector<u8>){
+        assert!(onchain_config::is_relayer(ctx, context::sender(ctx)), ErrorNotRelayer);
+        assert!(option::is_none(&object::borrow(btc_block_store_obj).rollback_fork_height), ErrorRollbackInProgress);
         let time = process_block(btc_block_store_obj, block_height, block_hash, block_bytes);
 
         let timestamp_seconds = (time as u64);
@@ -210,6 +331,7 @@ module bitcoin_move::light_client{
         timestamp::try_update_global_time(ctx, &module_signer, timestamp::seconds_to_milliseconds(timestamp_seconds));      
     }
 
+    #[view]
     public fun remaining_tx_count(btc_block_store_obj: &Object<BitcoinBlockStore>, btc_utxo_store_obj: &Object<BitcoinUTXOStore>): u64{
         let btc_block_store = object::borrow(btc_block_store_obj);
         let btc_utxo_store = object::borrow(btc_utxo_store_obj);
@@ -222,8 +344,11 @@ module bitcoin_move::light_client{
         }
     }
     
+    /// The relay server processes the UTXOs of the submitted txs in batches, only the relayers can process the UTXOs.
     entry fun process_utxos(ctx: &mut Context, btc_block_store_obj: &Object<BitcoinBlockStore>, btc_utxo_store_obj: &mut Object<BitcoinUTXOStore>, batch_size: u64){
+        assert!(onchain_config::is_relayer(ctx, context::sender(ctx)), ErrorNotRelayer);
         let btc_block_store = object::borrow(btc_block_store_obj);
+        assert!(option::is_none(&btc_block_store.rollback_fork_height), ErrorRollbackInProgress);
         let btc_utxo_store = object::borrow_mut(btc_utxo_store_obj);
         let start_tx_index = btc_utxo_store.next_tx_index;
         let max_tx_count = table_vec::length(&btc_block_store.tx_ids);
@@ -235,11 +360,91 @@ module bitcoin_move::light_client{
         while(processed_tx_count < batch_size && process_tx_index < max_tx_count){
             let txid = *table_vec::borrow(&btc_block_store.tx_ids, process_tx_index);
             let tx = table::borrow(&btc_block_store.txs, txid);
-            process_utxo(ctx, btc_utxo_store, tx);
+            process_utxo(ctx, btc_utxo_store, process_tx_index, tx);
             processed_tx_count = processed_tx_count + 1;
             process_tx_index = process_tx_index + 1;
         };
         btc_utxo_store.next_tx_index = process_tx_index;
+
+        //Prune the undo journal of the txs that can not be rolled back
+        let stable_tx_index = stable_tx_index(btc_block_store);
+        while(btc_utxo_store.journal_start_index < stable_tx_index && btc_utxo_store.journal_start_index < btc_utxo_store.next_tx_index){
+            let tx_index = btc_utxo_store.journal_start_index;
+            if(table::contains(&btc_utxo_store.spent_utxos, tx_index)){
+                table::remove(&mut btc_utxo_store.spent_utxos, tx_index);
+            };
+            btc_utxo_store.journal_start_index = tx_index + 1;
+        };
+    }
+
+    /// The relay server rollbacks the blocks above the fork point when the Bitcoin best chain is reorganized.
+    /// The UTXO changes of at most `batch_size` txs are reverted in a call, like `process_utxos`, so the relay server
+    /// should call it with the same fork point until the rollback is finished, then the blocks of the new best chain can be submitted.
+    /// The headers and txs of the rolled back blocks are removed in the last call. Only the relayers can rollback the blocks.
+    entry fun rollback_to_fork_point(ctx: &mut Context, btc_block_store_obj: &mut Object<BitcoinBlockStore>, btc_utxo_store_obj: &mut Object<BitcoinUTXOStore>, fork_height: u64, fork_block_hash: address, batch_size: u64){
+        assert!(onchain_config::is_relayer(ctx, context::sender(ctx)), ErrorNotRelayer);
+        let btc_block_store = object::borrow_mut(btc_block_store_obj);
+        let btc_utxo_store = object::borrow_mut(btc_utxo_store_obj);
+        assert!(option::is_some(&btc_block_store.latest_block_height), ErrorBlockNotFound);
+        let latest_block_height = *option::borrow(&btc_block_store.latest_block_height);
+        if(option::is_some(&btc_block_store.rollback_fork_height)){
+            //Continue the unfinished rollback
+            assert!(*option::borrow(&btc_block_store.rollback_fork_height) == fork_height, ErrorForkPointMismatch);
+        };
+        assert!(fork_height <= latest_block_height, ErrorForkPointMismatch);
+        assert!(table::contains(&btc_block_store.height_to_hash, fork_height), ErrorForkPointMismatch);
+        assert!(*table::borrow(&btc_block_store.height_to_hash, fork_height) == fork_block_hash, ErrorForkPointMismatch);
+        assert!(latest_block_height - fork_height <= MAX_REORG_DEPTH, ErrorReorgTooDeep);
+        if(fork_height == latest_block_height){
+            return
+        };
+        btc_block_store.rollback_fork_height = option::some(fork_height);
+
+        let first_block_hash = *table::borrow(&btc_block_store.height_to_hash, fork_height + 1);
+        let rollback_tx_index = *table::borrow(&btc_block_store.block_tx_start, first_block_hash);
+        let rollback_tx_count = 0;
+        //Revert the UTXO changes in reverse order
+        while(rollback_tx_count < batch_size && btc_utxo_store.next_tx_index > rollback_tx_index){
+            let tx_index = btc_utxo_store.next_tx_index - 1;
+            assert!(tx_index >= btc_utxo_store.journal_start_index, ErrorReorgTooDeep);
+            let txid = *table_vec::borrow(&btc_block_store.tx_ids, tx_index);
+            let tx = table::borrow(&btc_block_store.txs, txid);
+            rollback_utxo(ctx, btc_utxo_store, tx_index, tx);
+            btc_utxo_store.next_tx_index = tx_index;
+            rollback_tx_count = rollback_tx_count + 1;
+        };
+        //The txs are removed after their UTXO changes are reverted
+        while(rollback_tx_count < batch_size && btc_utxo_store.next_tx_index <= rollback_tx_index && table_vec::length(&btc_block_store.tx_ids) > rollback_tx_index){
+            let txid = table_vec::pop_back(&mut btc_block_store.tx_ids);
+            table::remove(&mut btc_block_store.txs, txid);
+            rollback_tx_count = rollback_tx_count + 1;
+        };
+        if(table_vec::length(&btc_block_store.tx_ids) > rollback_tx_index){
+            return
+        };
+
+        let block_height = latest_block_height;
+        while(block_height > fork_height){
+            let block_hash = table::remove(&mut btc_block_store.height_to_hash, block_height);
+            table::remove(&mut btc_block_store.hash_to_height, block_hash);
+            table::remove(&mut btc_block_store.blocks, block_hash);
+            table::remove(&mut btc_block_store.block_tx_start, block_hash);
+            block_height = block_height - 1;
+        };
+        btc_block_store.latest_block_height = option::some(fork_height);
+        btc_block_store.rollback_fork_height = option::none();
+        event::emit(BitcoinReorgEvent{
+            fork_height,
+            fork_block_hash,
+            rollback_block_count: latest_block_height - fork_height,
+        });
+    }
+
+    /// Get the fork height of the unfinished rollback
+    #[view]
+    public fun get_rollback_fork_height(btc_block_store_obj: &Object<BitcoinBlockStore>): Option<u64>{
+        let btc_block_store = object::borrow(btc_block_store_obj);
+        btc_block_store.rollback_fork_height
     }
 
     public fun txs(btc_block_store_obj: &Object<BitcoinBlockStore>): &Table<address, Transaction>{
@@ -262,6 +467,7 @@ module bitcoin_move::light_client{
     }
 
     /// Get block via block_hash
+    #[view]
     public fun get_block(btc_block_store_obj: &Object<BitcoinBlockStore>, block_hash: address): Option<Header>{
         let btc_block_store = object::borrow(btc_block_store_obj);
         if(table::contains(&btc_block_store.blocks, block_hash)){
@@ -271,6 +477,7 @@ module bitcoin_move::light_client{
         }
     }
 
+    #[view]
     public fun get_block_height(btc_block_store_obj: &Object<BitcoinBlockStore>, block_hash: address): Option<u64>{
         let btc_block_store = object::borrow(btc_block_store_obj);
         if(table::contains(&btc_block_store.hash_to_height, block_hash)){
@@ -281,6 +488,7 @@ module bitcoin_move::light_client{
     }
 
     /// Get block via block_height
+    #[view]
     public fun get_block_by_height(btc_block_store_obj: &Object<BitcoinBlockStore>, block_height: u64): Option<Header>{
         let btc_block_store = object::borrow(btc_block_store_obj);
         if(table::contains(&btc_block_store.height_to_hash, block_height)){
@@ -291,13 +499,26 @@ module bitcoin_move::light_client{
         }
     }
 
+    /// Get block hash via block_height
+    #[view]
+    public fun get_block_hash_by_height(btc_block_store_obj: &Object<BitcoinBlockStore>, block_height: u64): Option<address>{
+        let btc_block_store = object::borrow(btc_block_store_obj);
+        if(table::contains(&btc_block_store.height_to_hash, block_height)){
+            option::some(*table::borrow(&btc_block_store.height_to_hash, block_height))
+        }else{
+            option::none()
+        }
+    }
+
     /// Get block via block_height
+    #[view]
     public fun get_latest_block_height(btc_block_store_obj: &Object<BitcoinBlockStore>): Option<u64> {
         let btc_block_store = object::borrow(btc_block_store_obj);
         btc_block_store.latest_block_height
     }
 
     /// Get UTXO via txid and vout
+    #[view]
     public fun get_utxo(btc_utxo_store_obj: &Object<BitcoinUTXOStore>, txid: address, vout: u32): Option<ObjectID>{
         let outpoint = types::new_outpoint(txid, vout);
         let btc_utxo_store = object::borrow(btc_utxo_store_obj);
@@ -24,6 +24,8 @@ module bitcoin_move::ord {
 
     friend bitcoin_move::light_client;
 
+    const ErrorInscriptionStoreMismatch: u64 = 1;
+
     struct InscriptionID has store, copy, drop {
         txid: address,
         index: u32,
@@ -229,6 +231,56 @@ module bitcoin_move::ord {
         };
     }
 
+    /// Check whether the inscription is a BRC-20 inscription
+    public(friend) fun is_brc20_inscription(ctx: &Context, object_id: ObjectID): bool{
+        let inscription_obj = context::borrow_object<Inscription>(ctx, object_id);
+        let inscription = object::borrow(inscription_obj);
+        brc20::is_brc20(&inscription.json_body)
+    }
+
+    /// Check whether the transaction creates a BRC-20 inscription
+    public(friend) fun has_brc20_inscription(ctx: &Context, txid: address): bool{
+        let index = 0;
+        while(exists_inscription(ctx, txid, index)){
+            let inscription = object::borrow(borrow_inscription(ctx, txid, index));
+            if(brc20::is_brc20(&inscription.json_body)){
+                return true
+            };
+            index = index + 1;
+        };
+        false
+    }
+
+    /// Transfer the inscription to the `to` address, it is used to restore the spent UTXO when a block is rolled back.
+    public(friend) fun transfer_inscription(ctx: &mut Context, object_id: ObjectID, to: address){
+        let (_origin_owner, inscription_obj) = context::take_object_extend<Inscription>(ctx, object_id);
+        object::transfer_extend(inscription_obj, to);
+    }
+
+    /// Remove the inscriptions created by the transaction, it is used to revert the transaction when a block is rolled back.
+    /// The inscriptions are removed in reverse order of creation, so the tail of the InscriptionStore is popped.
+    public(friend) fun remove_tx_inscriptions(ctx: &mut Context, txid: address){
+        let count = 0;
+        while(exists_inscription(ctx, txid, count)){
+            count = count + 1;
+        };
+        while(count > 0){
+            count = count - 1;
+            let id = InscriptionID{
+                txid: txid,
+                index: count,
+            };
+            let object_id = object::custom_object_id<InscriptionID,Inscription>(id);
+            let (_owner, inscription_obj) = context::take_object_extend<Inscription>(ctx, object_id);
+            drop(object::remove(inscription_obj));
+            let store_obj_id = object::named_object_id<InscriptionStore>();
+            let store_obj = context::borrow_mut_object_shared<InscriptionStore>(ctx, store_obj_id);
+            let store = object::borrow_mut(store_obj);
+            let last_id = table_vec::pop_back(&mut store.inscriptions);
+            assert!(last_id == id, ErrorInscriptionStoreMismatch);
+        };
+    }
+
     fun validate_inscription_records(tx_id: address, input_index: u64, record: vector<InscriptionRecord>): vector<InscriptionRecord>{
         let len = vector::length(&record);
         let idx = 0;
@@ -12,7 +12,7 @@ use std::{
 };
 
 #[derive(Debug, PartialEq, Copy, Clone)]
-pub(crate) enum Media {
+pub enum Media {
     Audio,
     Code(Language),
     Font,
@@ -27,7 +27,7 @@ pub(crate) enum Media {
 }
 
 #[derive(Debug, PartialEq, Copy, Clone)]
-pub(crate) enum Language {
+pub enum Language {
     Css,
     JavaScript,
     Json,
@@ -51,7 +51,37 @@ impl Display for Language {
     }
 }
 
+/// Display the media class, the code of all languages is displayed as `code`
+impl Display for Media {
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        write!(
+            f,
+            "{}",
+            match self {
+                Self::Audio => "audio",
+                Self::Code(_) => "code",
+                Self::Font => "font",
+                Self::Iframe => "iframe",
+                Self::Image => "image",
+                Self::Markdown => "markdown",
+                Self::Model => "model",
+                Self::Pdf => "pdf",
+                Self::Text => "text",
+                Self::Unknown => "unknown",
+                Self::Video => "video",
+            }
+        )
+    }
+}
+
 impl Media {
+    /// Classify the inscription content by the content type, the unknown content type is `Media::Unknown`
+    pub fn from_content_type(content_type: Option<&str>) -> Self {
+        content_type
+            .and_then(|content_type| content_type.parse().ok())
+            .unwrap_or(Media::Unknown)
+    }
+
     #[rustfmt::skip]
     const TABLE: &'static [(&'static str, BrotliEncoderMode, Media, &'static [&'static str])] = &[
     ("application/cbor",            BROTLI_MODE_GENERIC, Media::Unknown,                    &["cbor"]),
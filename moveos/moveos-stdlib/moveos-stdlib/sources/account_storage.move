@@ -6,13 +6,15 @@
 module moveos_std::account_storage {
 
     use std::string::String;
+    #[test_only]
+    use std::string;
     use std::vector;
     use moveos_std::bcs;
     use moveos_std::type_table::{Self, TypeTable};
     use moveos_std::table::{Self, Table};
     use moveos_std::object::{Self, ObjectID, Object};
     use moveos_std::tx_context;
-    use moveos_std::move_module::{Self, MoveModule};
+    use moveos_std::move_module::{Self, MoveModule, UpgradePolicies};
 
     friend moveos_std::context;
 
@@ -20,6 +22,10 @@ module moveos_std::account_storage {
     const ErrorResourceAlreadyExists: u64 = 1;
     /// The resource with the given type not exists 
     const ErrorResourceNotExists: u64 = 2;
+    /// The new module can not be added to the package under the package's upgrade policy
+    const ErrorNewModuleNotAllowed: u64 = 3;
+    /// The module with the given name not exists
+    const ErrorModuleNotExists: u64 = 4;
 
     const NamedTableResource: u64 = 0;
     const NamedTableModule: u64 = 1;
@@ -106,13 +112,55 @@ module moveos_std::account_storage {
         exists_module_at_account_storage(self, name) 
     }
 
+    /// Get the upgrade policy of the package which the module belongs to
+    public fun upgrade_policy(self: &AccountStorage, name: &String): u8 {
+        if (type_table::contains<UpgradePolicies>(&self.resources)) {
+            move_module::module_upgrade_policy(type_table::borrow<UpgradePolicies>(&self.resources), name)
+        } else {
+            move_module::upgrade_policy_compatible()
+        }
+    }
+
+    /// Set the upgrade policy of the package, the package is given by the names of its modules.
+    /// The policy can only be changed to a stricter one.
+    public(friend) fun set_upgrade_policy(self: &mut AccountStorage, module_names: vector<String>, policy: u8) {
+        let i = 0;
+        let len = vector::length(&module_names);
+        while (i < len) {
+            let name = *vector::borrow(&module_names, i);
+            assert!(exists_module_at_account_storage(self, name), ErrorModuleNotExists);
+            set_module_upgrade_policy(self, name, policy);
+            i = i + 1;
+        };
+    }
+
+    fun set_module_upgrade_policy(self: &mut AccountStorage, name: String, policy: u8) {
+        if (!type_table::contains<UpgradePolicies>(&self.resources)) {
+            type_table::add(&mut self.resources, move_module::new_upgrade_policies());
+        };
+        let upgrade_policies = type_table::borrow_mut<UpgradePolicies>(&mut self.resources);
+        move_module::set_module_upgrade_policy(upgrade_policies, name, policy);
+    }
+
     /// Publish modules to the account's storage
+    /// The modules published together are a package, every existing module is upgraded under its own upgrade policy,
+    /// and the new modules are added to the package under the strictest policy of the existing ones.
     /// Return true if the modules are upgraded
     public(friend) fun publish_modules(self: &mut AccountStorage, account_address: address, modules: vector<MoveModule>) : bool {        
         let i = 0;
         let len = vector::length(&modules);
         let (module_names, module_names_with_init_fn, indices) = move_module::sort_and_verify_modules(&modules, account_address);
+        let package_policy = move_module::upgrade_policy_compatible();
+        while (i < len) {
+            let name = vector::borrow(&module_names, i);
+            let policy = upgrade_policy(self, name);
+            if (table::contains(&self.modules, *name) && policy > package_policy) {
+                package_policy = policy;
+            };
+            i = i + 1;
+        };
         
+        let i = 0;
         let upgrade_flag = false;
         while (i < len) {
             let name = vector::pop_back(&mut module_names);
@@ -122,9 +170,13 @@ module moveos_std::account_storage {
             // The module already exists, which means we are upgrading the module
             if (table::contains(&self.modules, name)) {
                 let old_m = table::remove(&mut self.modules, name);
-                move_module::check_comatibility(m, &old_m);
+                move_module::check_compatibility_with_policy(m, &old_m, upgrade_policy(self, &name));
                 upgrade_flag = true;
             } else {
+                assert!(move_module::allow_new_module(package_policy), ErrorNewModuleNotAllowed);
+                if (package_policy != move_module::upgrade_policy_compatible()) {
+                    set_module_upgrade_policy(self, name, package_policy);
+                };
                 // request init function invoking
                 if (vector::contains(&module_names_with_init_fn, &name)) {
                     move_module::request_init_functions(vector::singleton(copy name), account_address);
@@ -310,4 +362,32 @@ module moveos_std::account_storage {
         Self::publish_modules(&mut account_storage, sender, vector::singleton(m));
         Self::drop_account_storage(account_storage);
     }
-}
\ No newline at end of file
+
+    #[test(sender=@0x42)]
+    #[expected_failure(abort_code = 3, location = moveos_std::move_module)]
+    fun test_publish_modules_with_immutable_policy(sender: address) {
+        let account_storage = create_account_storage(sender);
+        let module_bytes: vector<u8> = x"a11ceb0b060000000b010004020408030c26043206053832076a7308dd0140069d02220abf02050cc402560d9a03020000010100020c00010300000004000100000500010000060201000007030400010807080108010909010108010a0a0b0108040605060606010708010002070801060c0106080101030107080001080002070801050107090003070801060c090002060801050106090007636f756e74657207636f6e7465787407436f756e74657207436f6e7465787408696e63726561736509696e6372656173655f04696e69740576616c756513626f72726f775f6d75745f7265736f75726365106d6f76655f7265736f757263655f746f0f626f72726f775f7265736f75726365000000000000000000000000000000000000000000000000000000000000004200000000000000000000000000000000000000000000000000000000000000020520000000000000000000000000000000000000000000000000000000000000004200020107030001040001030b0011010201010000050d0b00070038000c010a01100014060100000000000000160b010f0015020200000001060b000b0106000000000000000012003801020301000001060b000700380210001402000000";
+        let m: MoveModule = move_module::new(module_bytes);
+        Self::publish_modules(&mut account_storage, sender, vector::singleton(m));
+        let name = string::utf8(b"counter");
+        Self::set_upgrade_policy(&mut account_storage, vector::singleton(name), move_module::upgrade_policy_immutable());
+        assert!(Self::upgrade_policy(&account_storage, &name) == move_module::upgrade_policy_immutable(), 1);
+        // republish the same module should fail
+        Self::publish_modules(&mut account_storage, sender, vector::singleton(m));
+        Self::drop_account_storage(account_storage);
+    }
+
+    #[test(sender=@0x42)]
+    #[expected_failure(abort_code = 5, location = moveos_std::move_module)]
+    fun test_loosen_upgrade_policy_failure(sender: address) {
+        let account_storage = create_account_storage(sender);
+        let module_bytes: vector<u8> = x"a11ceb0b060000000b010004020408030c26043206053832076a7308dd0140069d02220abf02050cc402560d9a03020000010100020c00010300000004000100000500010000060201000007030400010807080108010909010108010a0a0b0108040605060606010708010002070801060c0106080101030107080001080002070801050107090003070801060c090002060801050106090007636f756e74657207636f6e7465787407436f756e74657207436f6e7465787408696e63726561736509696e6372656173655f04696e69740576616c756513626f72726f775f6d75745f7265736f75726365106d6f76655f7265736f757263655f746f0f626f72726f775f7265736f75726365000000000000000000000000000000000000000000000000000000000000004200000000000000000000000000000000000000000000000000000000000000020520000000000000000000000000000000000000000000000000000000000000004200020107030001040001030b0011010201010000050d0b00070038000c010a01100014060100000000000000160b010f0015020200000001060b000b0106000000000000000012003801020301000001060b000700380210001402000000";
+        let m: MoveModule = move_module::new(module_bytes);
+        Self::publish_modules(&mut account_storage, sender, vector::singleton(m));
+        let name = string::utf8(b"counter");
+        Self::set_upgrade_policy(&mut account_storage, vector::singleton(name), move_module::upgrade_policy_dep_only());
+        Self::set_upgrade_policy(&mut account_storage, vector::singleton(name), move_module::upgrade_policy_compatible());
+        Self::drop_account_storage(account_storage);
+    }
+}
@@ -50,6 +50,7 @@ module moveos_std::context {
     // Wrap functions for TxContext
 
     /// Return the address of the user that signed the current transaction
+    #[view]
     public fun sender(self: &Context): address {
         tx_context::sender(&self.tx_context)
     } 
@@ -98,10 +99,20 @@ module moveos_std::context {
         tx_context::tx_meta(&self.tx_context)
     }
 
+    /// Check whether the gas payment account is set, the VM sets it before the transaction validation.
+    public fun has_gas_payment_account(self: &Context): bool {
+        tx_context::has_gas_payment_account(&self.tx_context)
+    }
+
     public fun tx_gas_payment_account(self: &Context): address {
         tx_context::tx_gas_payment_account(&self.tx_context)
     }
 
+    /// Check whether the gas is paid by the module account of the called function.
+    public fun tx_gas_paid_by_module_account(self: &Context): bool {
+        tx_context::tx_gas_paid_by_module_account(&self.tx_context)
+    }
+
     public fun tx_result(self: &Context): TxResult {
         tx_context::tx_result(&self.tx_context)
     }
@@ -193,6 +204,30 @@ module moveos_std::context {
         }
     }
 
+    /// Get the upgrade policy of the package which the module under the account belongs to
+    public fun upgrade_policy(self: &Context, account: address, name: String): u8 {
+        if (exist_account_storage(self, account)) {
+            let account_storage = borrow_account_storage(self, account);
+            account_storage::upgrade_policy(account_storage, &name)
+        }else{
+            move_module::upgrade_policy_compatible()
+        }
+    }
+
+    /// Set the upgrade policy of the package under the account, the package is given by the names of its modules.
+    /// The policy can only be changed to a stricter one.
+    public fun set_upgrade_policy(self: &mut Context, account: &signer, module_names: vector<String>, policy: u8) {
+        let account_address = signer::address_of(account);
+        ensure_account_storage(self, account_address);
+        let account_storage = borrow_account_storage_mut(self, account_address);
+        account_storage::set_upgrade_policy(account_storage, module_names, policy);
+    }
+
+    /// Entry function to set the upgrade policy of the package under the account
+    public entry fun set_upgrade_policy_entry(ctx: &mut Context, account: &signer, module_names: vector<String>, policy: u8) {
+        Self::set_upgrade_policy(ctx, account, module_names, policy);
+    }
+
     /// Entry function to publish modules
     /// The order of modules must be sorted by dependency order.
     public entry fun publish_modules_entry(ctx: &mut Context, account: &signer, modules: vector<vector<u8>>) {
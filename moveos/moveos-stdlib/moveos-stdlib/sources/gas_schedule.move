@@ -0,0 +1,138 @@
+// Copyright (c) RoochNetwork
+// SPDX-License-Identifier: Apache-2.0
+
+/// `gas_schedule` stores the gas schedule on chain.
+/// MoveOS loads the gas schedule to build the instruction, storage and native gas parameters,
+/// so the gas can be repriced without a binary release.
+module moveos_std::gas_schedule {
+    use std::signer;
+    use std::string::String;
+    use std::vector;
+    use moveos_std::bcs;
+    use moveos_std::context::{Self, Context};
+    use moveos_std::object;
+
+    /// The signer is not the moveos_std account
+    const ErrorNotMoveOSStdAccount: u64 = 1;
+    /// The gas schedule already exists
+    const ErrorGasScheduleAlreadyExists: u64 = 2;
+    /// The gas schedule does not exist
+    const ErrorGasScheduleNotExists: u64 = 3;
+    /// The gas schedule is empty
+    const ErrorEmptyGasSchedule: u64 = 4;
+    /// The feature version of the new gas schedule is not greater than the current one
+    const ErrorInvalidGasScheduleVersion: u64 = 5;
+
+    #[data_struct]
+    struct GasEntry has store, copy, drop {
+        key: String,
+        val: u64,
+    }
+
+    #[data_struct]
+    struct GasScheduleConfig has copy, drop, store {
+        feature_version: u64,
+        entries: vector<GasEntry>,
+    }
+
+    /// The on-chain gas schedule, it is stored as a named object.
+    struct GasSchedule has key {
+        feature_version: u64,
+        entries: vector<GasEntry>,
+    }
+
+    /// Initialize the gas schedule with the bcs serialized `GasScheduleConfig`.
+    /// Only the moveos_std account can initialize the gas schedule, usually in the genesis.
+    public fun initialize(ctx: &mut Context, account: &signer, gas_schedule_blob: vector<u8>) {
+        assert!(signer::address_of(account) == @moveos_std, ErrorNotMoveOSStdAccount);
+        assert!(!exists_gas_schedule(ctx), ErrorGasScheduleAlreadyExists);
+        let config = new_config(gas_schedule_blob);
+        let obj = context::new_named_object(ctx, GasSchedule {
+            feature_version: config.feature_version,
+            entries: config.entries,
+        });
+        object::transfer_extend(obj, @moveos_std);
+    }
+
+    /// Replace the gas schedule with the bcs serialized `GasScheduleConfig`.
+    /// The feature version of the new gas schedule must be greater than the current one.
+    /// MoveOS uses the new gas schedule from the next transaction.
+    public fun update(ctx: &mut Context, account: &signer, gas_schedule_blob: vector<u8>) {
+        assert!(signer::address_of(account) == @moveos_std, ErrorNotMoveOSStdAccount);
+        assert!(exists_gas_schedule(ctx), ErrorGasScheduleNotExists);
+        let config = new_config(gas_schedule_blob);
+        let object_id = object::named_object_id<GasSchedule>();
+        let obj = context::borrow_mut_object_extend<GasSchedule>(ctx, object_id);
+        let gas_schedule = object::borrow_mut(obj);
+        assert!(config.feature_version > gas_schedule.feature_version, ErrorInvalidGasScheduleVersion);
+        gas_schedule.feature_version = config.feature_version;
+        gas_schedule.entries = config.entries;
+    }
+
+    public fun exists_gas_schedule(ctx: &Context): bool {
+        context::exists_object<GasSchedule>(ctx, object::named_object_id<GasSchedule>())
+    }
+
+    public fun feature_version(ctx: &Context): u64 {
+        borrow_gas_schedule(ctx).feature_version
+    }
+
+    /// Get the value of the gas parameter, return 0 if the parameter does not exist.
+    public fun gas_parameter(ctx: &Context, key: String): u64 {
+        let entries = &borrow_gas_schedule(ctx).entries;
+        let i = 0;
+        let len = vector::length(entries);
+        while (i < len) {
+            let entry = vector::borrow(entries, i);
+            if (entry.key == key) {
+                return entry.val
+            };
+            i = i + 1;
+        };
+        0
+    }
+
+    fun borrow_gas_schedule(ctx: &Context): &GasSchedule {
+        assert!(exists_gas_schedule(ctx), ErrorGasScheduleNotExists);
+        let object_id = object::named_object_id<GasSchedule>();
+        object::borrow(context::borrow_object<GasSchedule>(ctx, object_id))
+    }
+
+    fun new_config(gas_schedule_blob: vector<u8>): GasScheduleConfig {
+        let config = bcs::from_bytes<GasScheduleConfig>(gas_schedule_blob);
+        assert!(!vector::is_empty(&config.entries), ErrorEmptyGasSchedule);
+        config
+    }
+
+    #[test_only]
+    use std::string;
+
+    #[test_only]
+    fun gas_schedule_blob(feature_version: u64): vector<u8> {
+        std::bcs::to_bytes(&GasScheduleConfig {
+            feature_version,
+            entries: vector[GasEntry { key: string::utf8(b"instr.add"), val: feature_version }],
+        })
+    }
+
+    #[test(account = @moveos_std)]
+    fun test_update_gas_schedule(account: &signer) {
+        let ctx = context::new_test_context(@moveos_std);
+        initialize(&mut ctx, account, gas_schedule_blob(1));
+        assert!(feature_version(&ctx) == 1, 1000);
+        update(&mut ctx, account, gas_schedule_blob(2));
+        assert!(feature_version(&ctx) == 2, 1001);
+        assert!(gas_parameter(&ctx, string::utf8(b"instr.add")) == 2, 1002);
+        assert!(gas_parameter(&ctx, string::utf8(b"instr.sub")) == 0, 1003);
+        context::drop_test_context(ctx);
+    }
+
+    #[test(account = @moveos_std)]
+    #[expected_failure(abort_code = ErrorInvalidGasScheduleVersion, location = Self)]
+    fun test_update_gas_schedule_with_old_version(account: &signer) {
+        let ctx = context::new_test_context(@moveos_std);
+        initialize(&mut ctx, account, gas_schedule_blob(2));
+        update(&mut ctx, account, gas_schedule_blob(2));
+        context::drop_test_context(ctx);
+    }
+}
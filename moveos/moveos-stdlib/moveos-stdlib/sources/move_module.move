@@ -5,6 +5,7 @@
 module moveos_std::move_module {
     use std::vector;
     use std::string::{Self, String};
+    use moveos_std::simple_map::{Self, SimpleMap};
 
     friend moveos_std::account_storage;
     
@@ -16,11 +17,36 @@ module moveos_std::move_module {
     const ErrorModuleIncompatible: u64 = 3;
     /// Vector length not match
     const ErrorLengthNotMatch: u64 = 4;
+    /// The upgrade policy is invalid or looser than the current one
+    const ErrorInvalidUpgradePolicy: u64 = 5;
+    /// The function is not a view function, it is raised by the VM when calling a view function
+    const ErrorNotViewFunction: u64 = 6;
+
+    /// The upgrade must keep the public functions and struct layouts compatible.
+    const UPGRADE_POLICY_COMPATIBLE: u8 = 0;
+    /// The upgrade can only add new modules, functions and structs, the existing code must keep the same.
+    const UPGRADE_POLICY_ADDITIVE: u8 = 1;
+    /// The upgrade can only change the dependencies, the code of the modules must keep the same.
+    const UPGRADE_POLICY_DEP_ONLY: u8 = 2;
+    /// The modules can not be upgraded and no new modules can be added to the package.
+    const UPGRADE_POLICY_IMMUTABLE: u8 = 3;
     
     struct MoveModule has copy, store, drop {
         byte_codes: vector<u8>,
     }
 
+    /// The upgrade policy of a package, the package is the modules published or upgraded together.
+    /// The policy is recorded on every module of the package.
+    struct UpgradePolicy has copy, drop, store {
+        policy: u8,
+    }
+
+    /// The upgrade policies of the modules under an account by module name, it is stored in the account's storage.
+    /// The module without UpgradePolicy uses the compatible policy.
+    struct UpgradePolicies has key {
+        policies: SimpleMap<String, UpgradePolicy>,
+    }
+
     public fun new(byte_codes: vector<u8>) : MoveModule {
         //TODO quick check the Magic number to test if it is Move bytecode
         MoveModule {
@@ -83,7 +109,78 @@ module moveos_std::move_module {
     /// Check module compatibility when upgrading
     /// Abort if the new module is not compatible with the old module.
     public fun check_comatibility(new_module: &MoveModule, old_module: &MoveModule) {
-        check_compatibililty_inner(new_module.byte_codes, old_module.byte_codes);
+        check_compatibililty_inner(new_module.byte_codes, old_module.byte_codes, UPGRADE_POLICY_COMPATIBLE);
+    }
+
+    /// Check module compatibility with the given upgrade policy when upgrading
+    /// Abort if the new module is not allowed to replace the old module under the policy.
+    public fun check_compatibility_with_policy(new_module: &MoveModule, old_module: &MoveModule, policy: u8) {
+        check_compatibililty_inner(new_module.byte_codes, old_module.byte_codes, policy);
+    }
+
+    // ==== Upgrade policy functions ====
+
+    public fun upgrade_policy_compatible(): u8 {
+        UPGRADE_POLICY_COMPATIBLE
+    }
+
+    public fun upgrade_policy_additive(): u8 {
+        UPGRADE_POLICY_ADDITIVE
+    }
+
+    public fun upgrade_policy_dep_only(): u8 {
+        UPGRADE_POLICY_DEP_ONLY
+    }
+
+    public fun upgrade_policy_immutable(): u8 {
+        UPGRADE_POLICY_IMMUTABLE
+    }
+
+    /// Whether new modules can be published under the given upgrade policy
+    public fun allow_new_module(policy: u8): bool {
+        policy <= UPGRADE_POLICY_ADDITIVE
+    }
+
+    public(friend) fun new_upgrade_policy(policy: u8): UpgradePolicy {
+        assert!(policy <= UPGRADE_POLICY_IMMUTABLE, ErrorInvalidUpgradePolicy);
+        UpgradePolicy {
+            policy,
+        }
+    }
+
+    public fun policy(self: &UpgradePolicy): u8 {
+        self.policy
+    }
+
+    /// Update the upgrade policy, the policy can only be changed to a stricter one.
+    public(friend) fun update_policy(self: &mut UpgradePolicy, policy: u8) {
+        assert!(policy <= UPGRADE_POLICY_IMMUTABLE, ErrorInvalidUpgradePolicy);
+        assert!(policy >= self.policy, ErrorInvalidUpgradePolicy);
+        self.policy = policy;
+    }
+
+    public(friend) fun new_upgrade_policies(): UpgradePolicies {
+        UpgradePolicies {
+            policies: simple_map::create(),
+        }
+    }
+
+    /// Get the upgrade policy of the given module, the module without policy uses the compatible policy.
+    public fun module_upgrade_policy(self: &UpgradePolicies, name: &String): u8 {
+        if (simple_map::contains_key(&self.policies, name)) {
+            simple_map::borrow(&self.policies, name).policy
+        } else {
+            UPGRADE_POLICY_COMPATIBLE
+        }
+    }
+
+    /// Set the upgrade policy of the given module, the policy can only be changed to a stricter one.
+    public(friend) fun set_module_upgrade_policy(self: &mut UpgradePolicies, name: String, policy: u8) {
+        if (simple_map::contains_key(&self.policies, &name)) {
+            update_policy(simple_map::borrow_mut(&mut self.policies, &name), policy);
+        } else {
+            simple_map::add(&mut self.policies, name, new_upgrade_policy(policy));
+        }
     }
 
     /// Binding given module's address to the new address
@@ -226,7 +323,7 @@ module moveos_std::move_module {
     /// account_address: address of all the modules
     native public(friend) fun request_init_functions(module_names: vector<String>, account_address: address);
 
-    native fun check_compatibililty_inner(new_bytecodes: vector<u8>, old_bytecodes: vector<u8>);
+    native fun check_compatibililty_inner(new_bytecodes: vector<u8>, old_bytecodes: vector<u8>, policy: u8);
 
     /// Native function to replace addresses identifier in module binary where the length of
     /// `old_addresses` must equal to that of `new_addresses`.  
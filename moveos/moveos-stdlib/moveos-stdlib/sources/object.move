@@ -23,6 +23,15 @@ module moveos_std::object {
     const ErrorObjectAlreadyExist: u64 = 1;
     const ErrorObjectFrozen: u64 = 2;
     const ErrorInvalidOwnerAddress:u64 = 3;
+    // The following errors are raised by the VM when it loads the object arguments of a transaction
+    /// The object passed as an argument does not exist
+    const ErrorObjectArgumentNotFound: u64 = 4;
+    /// The type of the object passed as an argument does not match the parameter type
+    const ErrorObjectArgumentTypeMismatch: u64 = 5;
+    /// The object passed as `&mut Object<T>` is frozen, or it is neither shared nor owned by the sender
+    const ErrorObjectArgumentNotMutable: u64 = 6;
+    /// The object passed as `&mut Object<T>` is passed again as another argument of the same call
+    const ErrorObjectArgumentMutablyBorrowed: u64 = 7;
 
     const SYSTEM_OWNER_ADDRESS: address = @0x0;
     
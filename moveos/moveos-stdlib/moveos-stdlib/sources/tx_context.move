@@ -136,12 +136,22 @@ module moveos_std::tx_context {
         option::extract(&mut meta)
     }
 
+    public(friend) fun has_gas_payment_account(self: &TxContext): bool {
+        contains<GasPaymentAccount>(self)
+    }
+
     public(friend) fun tx_gas_payment_account(self: &TxContext): address {
         let gas_payment_account = get<GasPaymentAccount>(self);
         assert!(option::is_some(&gas_payment_account), ErrorInvalidContext);
         option::extract(&mut gas_payment_account).account
     }
 
+    public(friend) fun tx_gas_paid_by_module_account(self: &TxContext): bool {
+        let gas_payment_account = get<GasPaymentAccount>(self);
+        assert!(option::is_some(&gas_payment_account), ErrorInvalidContext);
+        option::extract(&mut gas_payment_account).pay_by_module_account
+    }
+
     /// The result is only available in the `post_execute` function.
     public(friend) fun tx_result(self: &TxContext): TxResult {
         let result = get<TxResult>(self);
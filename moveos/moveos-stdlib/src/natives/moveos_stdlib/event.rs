@@ -22,6 +22,10 @@ pub struct NativeEventContext {
 }
 
 impl NativeEventContext {
+    pub fn events(&self) -> &[(StructTag, Vec<u8>)] {
+        &self.events
+    }
+
     pub fn into_events(self) -> Vec<(StructTag, Vec<u8>)> {
         self.events
     }
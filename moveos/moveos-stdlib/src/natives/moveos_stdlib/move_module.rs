@@ -5,9 +5,8 @@ use crate::natives::helpers::{make_module_natives, make_native};
 use better_any::{Tid, TidAble};
 use itertools::zip_eq;
 use move_binary_format::{
-    compatibility::Compatibility,
     errors::{PartialVMError, PartialVMResult},
-    normalized, CompiledModule,
+    CompiledModule,
 };
 use move_core_types::u256::U256;
 use move_core_types::{
@@ -27,6 +26,7 @@ use move_vm_types::{
     values::{Struct, Value, Vector, VectorRef},
 };
 use moveos_stdlib_builder::dependency_order::sort_by_dependency_order;
+use moveos_types::moveos_std::move_module::UpgradePolicy;
 use smallvec::smallvec;
 use std::collections::{BTreeSet, HashMap, VecDeque};
 use std::hash::Hash;
@@ -225,10 +225,11 @@ fn request_init_functions(
 /***************************************************************************************************
  * native fun check_compatibililty_inner(
  *      new_bytecodes: vector<u8>,
- *      old_bytecodes: vector<u8>
+ *      old_bytecodes: vector<u8>,
+ *      policy: u8
  * );
- * Check module compatibility when upgrading,
- * Abort if the new module is not compatible with the old module.
+ * Check module compatibility with the upgrade policy when upgrading,
+ * Abort if the new module is not allowed to replace the old module.
  **************************************************************************************************/
 
 #[derive(Debug, Clone)]
@@ -244,22 +245,17 @@ fn check_compatibililty_inner(
     mut args: VecDeque<Value>,
 ) -> PartialVMResult<NativeResult> {
     let mut cost = gas_params.base;
-    // TODO: config compatibility through global configuration
-    let compat = Compatibility::full_check();
-    if compat.need_check_compat() {
-        let old_bytecodes = pop_arg!(args, Vec<u8>);
-        let new_bytecodes = pop_arg!(args, Vec<u8>);
-        cost += gas_params.per_byte * NumBytes::new(new_bytecodes.len() as u64);
-        cost += gas_params.per_byte * NumBytes::new(old_bytecodes.len() as u64);
-        let new_module = CompiledModule::deserialize(&new_bytecodes)?;
-        let old_module = CompiledModule::deserialize(&old_bytecodes)?;
-        let new_m = normalized::Module::new(&new_module);
-        let old_m = normalized::Module::new(&old_module);
-
-        match compat.check(&old_m, &new_m) {
-            Ok(_) => {}
-            Err(_) => return Ok(NativeResult::err(cost, E_MODULE_INCOMPATIBLE)),
-        }
+    let policy = UpgradePolicy::new(pop_arg!(args, u8));
+    let old_bytecodes = pop_arg!(args, Vec<u8>);
+    let new_bytecodes = pop_arg!(args, Vec<u8>);
+    cost += gas_params.per_byte * NumBytes::new(new_bytecodes.len() as u64);
+    cost += gas_params.per_byte * NumBytes::new(old_bytecodes.len() as u64);
+    let new_module = CompiledModule::deserialize(&new_bytecodes)?;
+    let old_module = CompiledModule::deserialize(&old_bytecodes)?;
+
+    match policy.check_upgrade(&old_module, &new_module) {
+        Ok(_) => {}
+        Err(_) => return Ok(NativeResult::err(cost, E_MODULE_INCOMPATIBLE)),
     }
     Ok(NativeResult::ok(cost, smallvec![]))
 }
@@ -24,7 +24,7 @@ use move_vm_types::{
     loaded_data::runtime_types::Type,
     natives::function::NativeResult,
     pop_arg,
-    values::{GlobalValue, Struct, Value},
+    values::{GlobalValue, Reference, Struct, Value},
 };
 use moveos_types::{
     moveos_std::{object::ObjectID, raw_table::TableInfo},
@@ -164,6 +164,29 @@ impl TableRuntimeValue {
         Ok(value)
     }
 
+    /// Serialize the value in the Box<V> without consuming the runtime value,
+    /// return None if the value does not exist.
+    pub fn serialize_box_value(&self) -> PartialVMResult<Option<(TypeTag, Vec<u8>)>> {
+        if !self.exists()? {
+            return Ok(None);
+        }
+        let (value_layout, value_type) = self
+            .value_layout_and_type
+            .as_ref()
+            .expect("The value layout and type should be known if the value exists");
+        let box_value = self
+            .box_value
+            .borrow_global()?
+            .value_as::<Reference>()?
+            .read_ref()?;
+        let val = box_value
+            .value_as::<Struct>()?
+            .unpack()?
+            .next()
+            .ok_or_else(|| partial_extension_error("Box<V> should have one field of type V"))?;
+        Ok(Some((value_type.clone(), serialize(value_layout, &val)?)))
+    }
+
     pub fn into_effect(self) -> Option<(MoveTypeLayout, TypeTag, Op<Value>)> {
         let op_opt = self.box_value.into_effect();
         match (op_opt, self.value_layout_and_type) {
@@ -266,6 +289,10 @@ impl TableData {
         self.tables.contains_key(handle)
     }
 
+    pub fn tables(&self) -> &BTreeMap<ObjectID, Table> {
+        &self.tables
+    }
+
     /// into inner
     pub fn into_inner(
         self,
@@ -383,6 +410,18 @@ impl Table {
     pub fn key_layout(&self) -> &MoveTypeLayout {
         &self.key_layout
     }
+
+    pub fn key_type(&self) -> &TypeTag {
+        &self.key_type
+    }
+
+    pub fn content(&self) -> &BTreeMap<Vec<u8>, TableRuntimeValue> {
+        &self.content
+    }
+
+    pub fn size_increment(&self) -> i64 {
+        self.size_increment
+    }
 }
 
 // =========================================================================================
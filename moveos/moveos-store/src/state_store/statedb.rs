@@ -152,6 +152,10 @@ impl StateDBStore {
         self.global_table.get(id.to_bytes())
     }
 
+    pub fn state_root(&self) -> H256 {
+        self.global_table.state_root()
+    }
+
     pub fn list(&self, cursor: Option<Vec<u8>>, limit: usize) -> Result<Vec<StateKV>> {
         self.global_table.list(cursor, limit)
     }
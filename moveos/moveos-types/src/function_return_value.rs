@@ -12,6 +12,8 @@ use serde::{Deserialize, Serialize};
 pub struct FunctionResult {
     pub vm_status: VMStatus,
     pub return_values: Option<Vec<FunctionReturnValue>>,
+    /// The gas used by the function call, it is zero if the gas is not metered
+    pub gas_used: u64,
 }
 
 impl FunctionResult {
@@ -19,6 +21,7 @@ impl FunctionResult {
         Self {
             vm_status: VMStatus::Executed,
             return_values: Some(return_values),
+            gas_used: 0,
         }
     }
 
@@ -26,9 +29,15 @@ impl FunctionResult {
         Self {
             vm_status: vm_error.into_vm_status(),
             return_values: None,
+            gas_used: 0,
         }
     }
 
+    pub fn with_gas_used(mut self, gas_used: u64) -> Self {
+        self.gas_used = gas_used;
+        self
+    }
+
     pub fn into_result(self) -> Result<Vec<FunctionReturnValue>, VMStatus> {
         match self.vm_status {
             VMStatus::Executed => Ok(self
@@ -53,10 +62,7 @@ impl From<VMResult<Vec<FunctionReturnValue>>> for FunctionResult {
     fn from(result: VMResult<Vec<FunctionReturnValue>>) -> Self {
         match result {
             Ok(return_values) => Self::ok(return_values),
-            Err(vm_error) => Self {
-                vm_status: vm_error.into_vm_status(),
-                return_values: None,
-            },
+            Err(vm_error) => Self::err(vm_error),
         }
     }
 }
@@ -72,6 +78,7 @@ impl TryFrom<AnnotatedFunctionResult> for FunctionResult {
                     .map(|v| v.value)
                     .collect::<Vec<FunctionReturnValue>>()
             }),
+            gas_used: value.gas_used,
         })
     }
 }
@@ -97,6 +104,7 @@ impl<V> DecodedFunctionResult<V> {
 pub struct AnnotatedFunctionResult {
     pub vm_status: VMStatus,
     pub return_values: Option<Vec<AnnotatedFunctionReturnValue>>,
+    pub gas_used: u64,
 }
 
 /// The function return value in MoveOS
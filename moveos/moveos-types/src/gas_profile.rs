@@ -0,0 +1,68 @@
+// Copyright (c) RoochNetwork
+// SPDX-License-Identifier: Apache-2.0
+
+use serde::{Deserialize, Serialize};
+use std::collections::BTreeMap;
+
+/// The separator of the frames in a folded stack
+pub const FOLDED_STACK_SEPARATOR: &str = ";";
+
+/// The gas usage of a Move function or a native function
+#[derive(Debug, Clone, Default, Eq, PartialEq, Serialize, Deserialize)]
+pub struct FunctionGasProfile {
+    /// The number of calls to the function
+    pub calls: u64,
+    /// The number of instructions executed in the function itself, the callees are not included
+    pub instructions: u64,
+    /// The gas used by the function itself, the callees are not included
+    pub gas_used: u64,
+}
+
+/// The gas profile of a transaction, it is recorded by the gas profiler during execution.
+/// Only the metered execution is recorded, the system functions are not included.
+#[derive(Debug, Clone, Default, Eq, PartialEq, Serialize, Deserialize)]
+pub struct GasProfile {
+    pub execution_gas_used: u64,
+    pub storage_gas_used: u64,
+    /// The number of instructions executed
+    pub instructions: u64,
+    /// The gas usage of the Move functions, by function name `<address>::<module>::<function>`
+    pub functions: BTreeMap<String, FunctionGasProfile>,
+    /// The gas usage of the native functions, by function name `<address>::<module>::<function>`
+    pub natives: BTreeMap<String, FunctionGasProfile>,
+    /// The gas used by each call stack, the frames are separated by `;`
+    pub folded_stacks: BTreeMap<String, u64>,
+}
+
+impl GasProfile {
+    pub fn total_gas_used(&self) -> u64 {
+        self.execution_gas_used + self.storage_gas_used
+    }
+
+    /// Render the call stacks in the folded stack format, one `<frames> <gas>` per line,
+    /// which can be read by flamegraph tools, such as `inferno-flamegraph` or `flamegraph.pl`.
+    pub fn to_folded_stacks(&self) -> String {
+        self.folded_stacks
+            .iter()
+            .map(|(stack, gas)| format!("{} {}\n", stack, gas))
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_to_folded_stacks() {
+        let mut profile = GasProfile::default();
+        profile.folded_stacks.insert("0x42::m::f".to_owned(), 10);
+        profile
+            .folded_stacks
+            .insert("0x42::m::f;0x1::vector::length".to_owned(), 3);
+        assert_eq!(
+            profile.to_folded_stacks(),
+            "0x42::m::f 10\n0x42::m::f;0x1::vector::length 3\n"
+        );
+    }
+}
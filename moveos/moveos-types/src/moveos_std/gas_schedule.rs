@@ -0,0 +1,75 @@
+// Copyright (c) RoochNetwork
+// SPDX-License-Identifier: Apache-2.0
+
+use crate::{
+    addresses::MOVEOS_STD_ADDRESS,
+    move_std::string::MoveString,
+    moveos_std::object::{self, ObjectID},
+    state::{MoveState, MoveStructState, MoveStructType},
+};
+use move_core_types::{
+    account_address::AccountAddress,
+    ident_str,
+    identifier::IdentStr,
+    value::{MoveStructLayout, MoveTypeLayout},
+};
+use serde::{Deserialize, Serialize};
+use std::collections::BTreeMap;
+
+pub const MODULE_NAME: &IdentStr = ident_str!("gas_schedule");
+
+/// `GasEntry` is represented `moveos_std::gas_schedule::GasEntry` in Move.
+#[derive(Clone, Debug, Deserialize, Serialize, PartialEq, Eq)]
+pub struct GasEntry {
+    pub key: MoveString,
+    pub val: u64,
+}
+
+impl MoveStructType for GasEntry {
+    const ADDRESS: AccountAddress = MOVEOS_STD_ADDRESS;
+    const MODULE_NAME: &'static IdentStr = MODULE_NAME;
+    const STRUCT_NAME: &'static IdentStr = ident_str!("GasEntry");
+}
+
+impl MoveStructState for GasEntry {
+    fn struct_layout() -> MoveStructLayout {
+        MoveStructLayout::new(vec![MoveString::type_layout(), MoveTypeLayout::U64])
+    }
+}
+
+/// `GasSchedule` is represented `moveos_std::gas_schedule::GasSchedule` in Move.
+/// It is stored as a named object, MoveOS builds the cost table and the native gas parameters from it.
+#[derive(Clone, Debug, Deserialize, Serialize, PartialEq, Eq)]
+pub struct GasSchedule {
+    pub feature_version: u64,
+    pub entries: Vec<GasEntry>,
+}
+
+impl GasSchedule {
+    pub fn object_id() -> ObjectID {
+        object::named_object_id(&Self::struct_tag())
+    }
+
+    /// Convert the entries to a map, the key is the name of the gas parameter.
+    pub fn to_map(&self) -> BTreeMap<String, u64> {
+        self.entries
+            .iter()
+            .map(|entry| (entry.key.to_string(), entry.val))
+            .collect()
+    }
+}
+
+impl MoveStructType for GasSchedule {
+    const ADDRESS: AccountAddress = MOVEOS_STD_ADDRESS;
+    const MODULE_NAME: &'static IdentStr = MODULE_NAME;
+    const STRUCT_NAME: &'static IdentStr = ident_str!("GasSchedule");
+}
+
+impl MoveStructState for GasSchedule {
+    fn struct_layout() -> MoveStructLayout {
+        MoveStructLayout::new(vec![
+            MoveTypeLayout::U64,
+            MoveTypeLayout::Vector(Box::new(MoveTypeLayout::Struct(GasEntry::struct_layout()))),
+        ])
+    }
+}
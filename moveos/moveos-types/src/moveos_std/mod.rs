@@ -7,6 +7,7 @@ pub mod any;
 pub mod context;
 pub mod copyable_any;
 pub mod event;
+pub mod gas_schedule;
 pub mod module_upgrade_flag;
 pub mod move_module;
 pub mod object;
@@ -3,15 +3,36 @@
 
 use crate::{
     addresses::MOVEOS_STD_ADDRESS,
+    move_std::string::MoveString,
+    move_types::FunctionId,
+    moveos_std::simple_map::SimpleMap,
     state::{MoveStructState, MoveStructType},
+    transaction::MoveAction,
+};
+use anyhow::bail;
+use move_binary_format::{
+    access::ModuleAccess,
+    compatibility::Compatibility,
+    errors::{PartialVMError, PartialVMResult},
+    file_format::{
+        Bytecode, Constant, ConstantPoolIndex, FieldHandleIndex, FieldInstantiationIndex,
+        FunctionDefinition, FunctionHandleIndex, FunctionInstantiationIndex, SignatureIndex,
+        StructDefInstantiationIndex, StructDefinitionIndex,
+    },
+    normalized, CompiledModule,
 };
 use move_core_types::{
     account_address::AccountAddress,
     ident_str,
-    identifier::IdentStr,
-    value::{MoveStructLayout, MoveTypeLayout},
+    identifier::{IdentStr, Identifier},
+    language_storage::ModuleId,
+    value::{MoveStructLayout, MoveTypeLayout, MoveValue},
+    vm_status::StatusCode,
 };
 use serde::{Deserialize, Serialize};
+use std::collections::BTreeMap;
+use std::fmt;
+use std::str::FromStr;
 
 /// `MoveModule` is represented `moveos_std::move_module::MoveModule` in Move.
 #[derive(Clone, Debug, Deserialize, Serialize, PartialEq, Eq)]
@@ -36,3 +57,362 @@ impl MoveStructState for MoveModule {
         MoveStructLayout::new(vec![MoveTypeLayout::Vector(Box::new(MoveTypeLayout::U8))])
     }
 }
+
+/// `UpgradePolicy` is represented `moveos_std::move_module::UpgradePolicy` in Move.
+/// It restricts how the modules of a package can be upgraded, the package is the modules published or upgraded together.
+/// The policy is recorded on every module of the package in `UpgradePolicies`.
+/// The policy can only be changed to a stricter one.
+#[derive(Clone, Copy, Debug, Deserialize, Serialize, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct UpgradePolicy {
+    pub policy: u8,
+}
+
+impl UpgradePolicy {
+    /// The upgrade must keep the public functions and struct layouts compatible.
+    pub const COMPATIBLE: u8 = 0;
+    /// The upgrade can only add new modules, functions and structs,
+    /// all existing functions(include the private ones) must keep the same signature and bytecode,
+    /// all existing structs and friends must keep the same.
+    pub const ADDITIVE: u8 = 1;
+    /// The upgrade can only change the dependencies, no modules, functions, structs or friends
+    /// can be added or changed, and the bytecode of the functions must keep the same.
+    pub const DEP_ONLY: u8 = 2;
+    /// The modules can not be upgraded and no new modules can be added to the package.
+    pub const IMMUTABLE: u8 = 3;
+
+    pub fn new(policy: u8) -> Self {
+        Self { policy }
+    }
+
+    pub fn compatible() -> Self {
+        Self::new(Self::COMPATIBLE)
+    }
+
+    pub fn additive() -> Self {
+        Self::new(Self::ADDITIVE)
+    }
+
+    pub fn dep_only() -> Self {
+        Self::new(Self::DEP_ONLY)
+    }
+
+    pub fn immutable() -> Self {
+        Self::new(Self::IMMUTABLE)
+    }
+
+    pub fn is_valid(&self) -> bool {
+        self.policy <= Self::IMMUTABLE
+    }
+
+    /// Create the action to set the upgrade policy of the sender's package to this policy,
+    /// the package is given by the names of its modules.
+    pub fn create_set_upgrade_policy_action(&self, module_names: Vec<String>) -> MoveAction {
+        MoveAction::new_function_call(
+            FunctionId::new(
+                ModuleId::new(MOVEOS_STD_ADDRESS, ident_str!("context").to_owned()),
+                ident_str!("set_upgrade_policy_entry").to_owned(),
+            ),
+            vec![],
+            vec![
+                bcs::to_bytes(&module_names).unwrap(),
+                MoveValue::U8(self.policy).simple_serialize().unwrap(),
+            ],
+        )
+    }
+
+    /// Whether new modules can be added to the package
+    pub fn allow_new_module(&self) -> bool {
+        self.policy <= Self::ADDITIVE
+    }
+
+    /// Check whether the `new_module` can replace the `old_module` under this policy.
+    pub fn check_upgrade(
+        &self,
+        old_module: &CompiledModule,
+        new_module: &CompiledModule,
+    ) -> PartialVMResult<()> {
+        let module_id = old_module.self_id();
+        if self.policy >= Self::IMMUTABLE {
+            return Err(incompatible_error(format!(
+                "Module {} is immutable",
+                module_id
+            )));
+        }
+        let old_m = normalized::Module::new(old_module);
+        let new_m = normalized::Module::new(new_module);
+        Compatibility::full_check().check(&old_m, &new_m)?;
+        if self.policy == Self::COMPATIBLE {
+            return Ok(());
+        }
+
+        let old_functions = all_functions(old_module);
+        let new_functions = all_functions(new_module);
+        for (name, old_function) in &old_functions {
+            if new_functions.get(name) != Some(old_function) {
+                return Err(incompatible_error(format!(
+                    "Function {}::{} is changed or removed under the {} upgrade policy",
+                    module_id, name, self
+                )));
+            }
+        }
+        for (name, old_struct) in &old_m.structs {
+            if new_m.structs.get(name) != Some(old_struct) {
+                return Err(incompatible_error(format!(
+                    "Struct {}::{} is changed or removed under the {} upgrade policy",
+                    module_id, name, self
+                )));
+            }
+        }
+        if !old_m.friends.iter().all(|f| new_m.friends.contains(f)) {
+            return Err(incompatible_error(format!(
+                "Friends of module {} are removed under the {} upgrade policy",
+                module_id, self
+            )));
+        }
+        if self.policy == Self::DEP_ONLY
+            && (old_functions.len() != new_functions.len()
+                || old_m.structs.len() != new_m.structs.len()
+                || old_m.friends.len() != new_m.friends.len())
+        {
+            return Err(incompatible_error(format!(
+                "Module {} can only change the dependencies under the {} upgrade policy",
+                module_id, self
+            )));
+        }
+        Ok(())
+    }
+}
+
+/// The locals and the instructions of a function, the indexes into the module pools are replaced
+/// by the entries they reference, so the code of two versions of a module can be compared.
+type FunctionCode = (Vec<normalized::Type>, Vec<(Bytecode, CodeOperand)>);
+
+/// The pool entry referenced by an instruction
+#[derive(Debug, PartialEq, Eq)]
+enum CodeOperand {
+    None,
+    Constant(Constant),
+    Function(ModuleId, Identifier, Vec<normalized::Type>),
+    Struct(Identifier, Vec<normalized::Type>),
+    Field(Identifier, u16, Vec<normalized::Type>),
+    Types(Vec<normalized::Type>),
+}
+
+fn all_functions(
+    module: &CompiledModule,
+) -> BTreeMap<String, (normalized::Function, Option<FunctionCode>)> {
+    module
+        .function_defs
+        .iter()
+        .map(|def| {
+            let (name, function) = normalized::Function::new(module, def);
+            (name.to_string(), (function, function_code(module, def)))
+        })
+        .collect()
+}
+
+fn function_code(module: &CompiledModule, def: &FunctionDefinition) -> Option<FunctionCode> {
+    let code = def.code.as_ref()?;
+    let instructions = code
+        .code
+        .iter()
+        .map(|instruction| normalize_instruction(module, instruction))
+        .collect();
+    Some((signature_types(module, code.locals), instructions))
+}
+
+fn normalize_instruction(
+    module: &CompiledModule,
+    instruction: &Bytecode,
+) -> (Bytecode, CodeOperand) {
+    use Bytecode::*;
+    let mut instruction = instruction.clone();
+    let operand = match &mut instruction {
+        LdConst(idx) => {
+            let idx = std::mem::replace(idx, ConstantPoolIndex::new(0));
+            CodeOperand::Constant(module.constant_at(idx).clone())
+        }
+        Call(idx) => {
+            let idx = std::mem::replace(idx, FunctionHandleIndex::new(0));
+            function_operand(module, idx, vec![])
+        }
+        CallGeneric(idx) => {
+            let idx = std::mem::replace(idx, FunctionInstantiationIndex::new(0));
+            let instantiation = module.function_instantiation_at(idx);
+            function_operand(
+                module,
+                instantiation.handle,
+                signature_types(module, instantiation.type_parameters),
+            )
+        }
+        Pack(idx) | Unpack(idx) | MutBorrowGlobal(idx) | ImmBorrowGlobal(idx) | Exists(idx)
+        | MoveFrom(idx) | MoveTo(idx) => {
+            let idx = std::mem::replace(idx, StructDefinitionIndex::new(0));
+            CodeOperand::Struct(struct_name(module, idx), vec![])
+        }
+        PackGeneric(idx)
+        | UnpackGeneric(idx)
+        | MutBorrowGlobalGeneric(idx)
+        | ImmBorrowGlobalGeneric(idx)
+        | ExistsGeneric(idx)
+        | MoveFromGeneric(idx)
+        | MoveToGeneric(idx) => {
+            let idx = std::mem::replace(idx, StructDefInstantiationIndex::new(0));
+            let instantiation = module.struct_instantiation_at(idx);
+            CodeOperand::Struct(
+                struct_name(module, instantiation.def),
+                signature_types(module, instantiation.type_parameters),
+            )
+        }
+        MutBorrowField(idx) | ImmBorrowField(idx) => {
+            let idx = std::mem::replace(idx, FieldHandleIndex::new(0));
+            field_operand(module, idx, vec![])
+        }
+        MutBorrowFieldGeneric(idx) | ImmBorrowFieldGeneric(idx) => {
+            let idx = std::mem::replace(idx, FieldInstantiationIndex::new(0));
+            let instantiation = module.field_instantiation_at(idx);
+            field_operand(
+                module,
+                instantiation.handle,
+                signature_types(module, instantiation.type_parameters),
+            )
+        }
+        VecPack(idx, _)
+        | VecLen(idx)
+        | VecImmBorrow(idx)
+        | VecMutBorrow(idx)
+        | VecPushBack(idx)
+        | VecPopBack(idx)
+        | VecUnpack(idx, _)
+        | VecSwap(idx) => {
+            let idx = std::mem::replace(idx, SignatureIndex::new(0));
+            CodeOperand::Types(signature_types(module, idx))
+        }
+        _ => CodeOperand::None,
+    };
+    (instruction, operand)
+}
+
+fn signature_types(module: &CompiledModule, idx: SignatureIndex) -> Vec<normalized::Type> {
+    module
+        .signature_at(idx)
+        .0
+        .iter()
+        .map(|token| normalized::Type::new(module, token))
+        .collect()
+}
+
+fn function_operand(
+    module: &CompiledModule,
+    idx: FunctionHandleIndex,
+    type_arguments: Vec<normalized::Type>,
+) -> CodeOperand {
+    let handle = module.function_handle_at(idx);
+    CodeOperand::Function(
+        module.module_id_for_handle(module.module_handle_at(handle.module)),
+        module.identifier_at(handle.name).to_owned(),
+        type_arguments,
+    )
+}
+
+fn field_operand(
+    module: &CompiledModule,
+    idx: FieldHandleIndex,
+    type_arguments: Vec<normalized::Type>,
+) -> CodeOperand {
+    let handle = module.field_handle_at(idx);
+    CodeOperand::Field(
+        struct_name(module, handle.owner),
+        handle.field,
+        type_arguments,
+    )
+}
+
+fn struct_name(module: &CompiledModule, idx: StructDefinitionIndex) -> Identifier {
+    let handle = module.struct_handle_at(module.struct_def_at(idx).struct_handle);
+    module.identifier_at(handle.name).to_owned()
+}
+
+fn incompatible_error(message: String) -> PartialVMError {
+    PartialVMError::new(StatusCode::BACKWARD_INCOMPATIBLE_MODULE_UPDATE).with_message(message)
+}
+
+impl Default for UpgradePolicy {
+    fn default() -> Self {
+        Self::compatible()
+    }
+}
+
+impl fmt::Display for UpgradePolicy {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self.policy {
+            Self::COMPATIBLE => write!(f, "compatible"),
+            Self::ADDITIVE => write!(f, "additive"),
+            Self::DEP_ONLY => write!(f, "dep_only"),
+            Self::IMMUTABLE => write!(f, "immutable"),
+            policy => write!(f, "unknown({})", policy),
+        }
+    }
+}
+
+impl FromStr for UpgradePolicy {
+    type Err = anyhow::Error;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.to_lowercase().replace('-', "_").as_str() {
+            "compatible" => Ok(Self::compatible()),
+            "additive" => Ok(Self::additive()),
+            "dep_only" => Ok(Self::dep_only()),
+            "immutable" => Ok(Self::immutable()),
+            _ => bail!(
+                "Invalid upgrade policy: {}, expect one of compatible, additive, dep_only, immutable",
+                s
+            ),
+        }
+    }
+}
+
+impl MoveStructType for UpgradePolicy {
+    const ADDRESS: AccountAddress = MOVEOS_STD_ADDRESS;
+    const MODULE_NAME: &'static IdentStr = ident_str!("move_module");
+    const STRUCT_NAME: &'static IdentStr = ident_str!("UpgradePolicy");
+}
+
+impl MoveStructState for UpgradePolicy {
+    fn struct_layout() -> move_core_types::value::MoveStructLayout {
+        MoveStructLayout::new(vec![MoveTypeLayout::U8])
+    }
+}
+
+/// `UpgradePolicies` is represented `moveos_std::move_module::UpgradePolicies` in Move.
+/// It is stored in the account's storage and keeps the upgrade policies of the modules under the account.
+/// The module without `UpgradePolicy` uses the compatible policy.
+#[derive(Clone, Debug, Deserialize, Serialize, PartialEq, Eq)]
+pub struct UpgradePolicies {
+    pub policies: SimpleMap<MoveString, UpgradePolicy>,
+}
+
+impl UpgradePolicies {
+    /// Get the upgrade policy of the package which the module belongs to
+    pub fn module_upgrade_policy(&self, name: &str) -> UpgradePolicy {
+        self.policies
+            .borrow(&MoveString::from(name.to_owned()))
+            .copied()
+            .unwrap_or_default()
+    }
+}
+
+impl MoveStructType for UpgradePolicies {
+    const ADDRESS: AccountAddress = MOVEOS_STD_ADDRESS;
+    const MODULE_NAME: &'static IdentStr = ident_str!("move_module");
+    const STRUCT_NAME: &'static IdentStr = ident_str!("UpgradePolicies");
+}
+
+impl MoveStructState for UpgradePolicies {
+    fn struct_layout() -> move_core_types::value::MoveStructLayout {
+        MoveStructLayout::new(vec![MoveTypeLayout::Struct(SimpleMap::<
+            MoveString,
+            UpgradePolicy,
+        >::struct_layout())])
+    }
+}
@@ -6,7 +6,6 @@ use crate::{
     moveos_std::event::TransactionEvent, moveos_std::tx_context::TxContext,
     moveos_std::tx_meta::TxMeta, state::StateChangeSet,
 };
-use move_core_types::gas_algebra::InternalGas;
 use move_core_types::{
     account_address::AccountAddress,
     effects::ChangeSet,
@@ -260,10 +259,21 @@ impl MoveOSTransaction {
     }
 }
 
-#[derive(Debug, Clone)]
+/// The gas used by a transaction, the storage gas is broken down by what was written.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
 pub struct GasStatement {
-    pub execution_gas_used: InternalGas,
-    pub storage_gas_used: InternalGas,
+    pub execution_gas_used: u64,
+    pub storage_gas_used: u64,
+    /// Storage gas charged for the transaction bytes
+    pub tx_size_gas_used: u64,
+    /// Storage gas charged for the new states
+    pub new_state_gas_used: u64,
+    /// Storage gas charged for the modified states
+    pub modify_state_gas_used: u64,
+    /// Storage gas charged for the deleted states
+    pub delete_state_gas_used: u64,
+    /// Storage gas charged for the event bytes
+    pub event_gas_used: u64,
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -283,6 +293,7 @@ pub struct RawTransactionOutput {
     pub state_changeset: StateChangeSet,
     pub events: Vec<TransactionEvent>,
     pub gas_used: u64,
+    pub gas_statement: GasStatement,
     pub is_upgrade: bool,
 }
 
@@ -295,6 +306,7 @@ pub struct TransactionOutput {
     pub state_changeset: StateChangeSet,
     pub events: Vec<Event>,
     pub gas_used: u64,
+    pub gas_statement: GasStatement,
     pub is_upgrade: bool,
 }
 
@@ -319,6 +331,7 @@ impl TransactionOutput {
             state_changeset: transaction_output.state_changeset,
             events,
             gas_used: transaction_output.gas_used,
+            gas_statement: transaction_output.gas_statement,
             is_upgrade: transaction_output.is_upgrade,
         }
     }
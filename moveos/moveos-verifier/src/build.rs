@@ -241,6 +241,12 @@ pub const BYTECODE_VERSION: u32 = 6;
 /// Rooch specific metadata (`rooch::` here).
 pub static ROOCH_METADATA_KEY: &[u8] = "rooch::metadata_v0".as_bytes();
 
+/// The functions annotated with `#[view]` are stored under their own key, so the
+/// `rooch::metadata_v0` of the published modules keeps its BCS layout.
+/// Every module built by this version carries the key, the modules without it were built
+/// before `#[view]` was introduced.
+pub static ROOCH_VIEW_FUNCTIONS_KEY: &[u8] = "rooch::view_functions_v0".as_bytes();
+
 pub fn build_model(
     package_path: &Path,
     additional_named_addresses: BTreeMap<String, AccountAddress>,
@@ -339,28 +345,36 @@ pub fn inject_runtime_metadata<P: AsRef<Path>>(
     for unit_with_source in pack.root_compiled_units.iter_mut() {
         match &mut unit_with_source.unit {
             CompiledUnit::Module(named_module) => {
-                if let Some(module_metadata) = metadata.get(&named_module.module.self_id()) {
-                    if !module_metadata.is_empty() {
-                        let serialized_metadata =
-                            bcs::to_bytes(&module_metadata).expect("BCS for RuntimeModuleMetadata");
-                        named_module.module.metadata.push(Metadata {
-                            key: ROOCH_METADATA_KEY.to_vec(),
-                            value: serialized_metadata,
-                        });
+                let module_metadata = metadata
+                    .get(&named_module.module.self_id())
+                    .cloned()
+                    .unwrap_or_default();
+                if !module_metadata.is_empty() {
+                    let serialized_metadata =
+                        bcs::to_bytes(&module_metadata).expect("BCS for RuntimeModuleMetadata");
+                    named_module.module.metadata.push(Metadata {
+                        key: ROOCH_METADATA_KEY.to_vec(),
+                        value: serialized_metadata,
+                    });
+                }
+                let serialized_view_functions =
+                    bcs::to_bytes(&module_metadata.view_functions).expect("BCS for view functions");
+                named_module.module.metadata.push(Metadata {
+                    key: ROOCH_VIEW_FUNCTIONS_KEY.to_vec(),
+                    value: serialized_view_functions,
+                });
 
-                        // Also need to update the .mv file on disk.
-                        let path = package_path
-                            .as_ref()
-                            .join(CompiledPackageLayout::CompiledModules.path())
-                            .join(named_module.name.as_str())
-                            .with_extension(MOVE_COMPILED_EXTENSION);
-                        if path.is_file() {
-                            let bytes = unit_with_source
-                                .unit
-                                .serialize(Option::from(BYTECODE_VERSION));
-                            std::fs::write(path, bytes).unwrap();
-                        }
-                    }
+                // Also need to update the .mv file on disk.
+                let path = package_path
+                    .as_ref()
+                    .join(CompiledPackageLayout::CompiledModules.path())
+                    .join(named_module.name.as_str())
+                    .with_extension(MOVE_COMPILED_EXTENSION);
+                if path.is_file() {
+                    let bytes = unit_with_source
+                        .unit
+                        .serialize(Option::from(BYTECODE_VERSION));
+                    std::fs::write(path, bytes).unwrap();
                 }
             }
             CompiledUnit::Script(_) => {}
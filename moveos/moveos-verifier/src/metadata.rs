@@ -4,7 +4,7 @@
 #![allow(clippy::redundant_closure)]
 #![allow(clippy::map_clone)]
 
-use crate::build::ROOCH_METADATA_KEY;
+use crate::build::{ROOCH_METADATA_KEY, ROOCH_VIEW_FUNCTIONS_KEY};
 use crate::verifier::INIT_FN_NAME_IDENTIFIER;
 use itertools::Itertools;
 use move_binary_format::binary_views::BinaryIndexedView;
@@ -24,7 +24,7 @@ use moveos_types::moveos_std::context::Context;
 use moveos_types::state::MoveStructType;
 use once_cell::sync::Lazy;
 use serde::{Deserialize, Serialize};
-use std::collections::BTreeMap;
+use std::collections::{BTreeMap, BTreeSet};
 use std::ops::Deref;
 use thiserror::Error;
 
@@ -49,6 +49,8 @@ const GAS_FREE_CHARGE_POST: &str = "gas_charge_post";
 
 const DATA_STRUCT_ATTRIBUTE: &str = "data_struct";
 
+const VIEW_ATTRIBUTE: &str = "view";
+
 /// Enumeration of potentially known attributes
 #[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Serialize, Deserialize)]
 pub struct KnownAttribute {
@@ -82,6 +84,11 @@ pub struct RuntimeModuleMetadataV1 {
 
     /// Save information for the data_struct in the Move function.
     pub data_struct_func_map: BTreeMap<String, Vec<usize>>,
+
+    /// The functions annotated with `#[view]`, by function name.
+    /// They are stored under `ROOCH_VIEW_FUNCTIONS_KEY`, not in the BCS of this struct.
+    #[serde(skip)]
+    pub view_functions: BTreeSet<String>,
 }
 
 impl RuntimeModuleMetadataV1 {
@@ -108,6 +115,18 @@ pub fn get_metadata_from_compiled_module(
     }
 }
 
+/// Extract the `#[view]` functions from a compiled module.
+/// Returns None if the module was built before `#[view]` was introduced.
+pub fn get_view_functions_from_compiled_module(
+    module: &CompiledModule,
+) -> Option<BTreeSet<String>> {
+    if let Some(data) = find_metadata(module, ROOCH_VIEW_FUNCTIONS_KEY) {
+        bcs::from_bytes::<BTreeSet<String>>(&data.value).ok()
+    } else {
+        None
+    }
+}
+
 /// Run the extended context checker on target modules in the environment and returns a map
 /// from module to extended runtime metadata. Any errors during context checking are reported to
 /// `env`. This is invoked after general build succeeds.
@@ -141,6 +160,7 @@ impl<'a> ExtendedChecker<'a> {
                 self.check_global_storage_access(module);
                 self.check_gas_free_function(module);
                 self.check_data_struct(module);
+                self.check_view_functions(module);
             }
         }
     }
@@ -936,6 +956,68 @@ impl<'a> ExtendedChecker<'a> {
     }
 }
 
+// ----------------------------------------------------------------------------------
+// View Functions
+
+impl<'a> ExtendedChecker<'a> {
+    fn check_view_functions(&mut self, module: &ModuleEnv) {
+        let mut view_functions = BTreeSet::new();
+        for ref fun in module.get_functions() {
+            if !has_attribute(self.env, fun, VIEW_ATTRIBUTE) {
+                continue;
+            }
+
+            if fun.is_entry() {
+                self.env
+                    .error(&fun.get_loc(), "view function cannot be an entry function");
+            }
+
+            if fun.get_return_count() == 0 {
+                self.env
+                    .error(&fun.get_loc(), "view function must return values");
+            }
+
+            for ty in fun.get_parameter_types() {
+                let is_allowed = match &ty {
+                    Type::Primitive(PrimitiveType::Signer) => false,
+                    Type::Reference(ReferenceKind::Immutable, bt) => {
+                        !matches!(bt.as_ref(), Type::Primitive(PrimitiveType::Signer))
+                    }
+                    Type::Reference(ReferenceKind::Mutable, _) => false,
+                    _ => true,
+                };
+                if !is_allowed {
+                    self.env.error(
+                        &fun.get_loc(),
+                        &format!(
+                            "type `{}` is not supported as a parameter type of view function",
+                            ty.display(&self.env.get_type_display_ctx())
+                        ),
+                    );
+                }
+            }
+
+            view_functions.insert(module.symbol_pool().string(fun.get_name()).to_string());
+        }
+
+        if view_functions.is_empty() {
+            return;
+        }
+
+        let verified_module = match module.get_verified_module() {
+            None => {
+                self.env
+                    .error(&module.get_loc(), "The verified module was not found.");
+                return;
+            }
+            Some(module) => module,
+        };
+
+        let module_metadata = self.output.entry(verified_module.self_id()).or_default();
+        module_metadata.view_functions = view_functions;
+    }
+}
+
 impl<'a> ExtendedChecker<'a> {
     fn check_data_struct(&mut self, module_env: &ModuleEnv) {
         for struct_def in module_env.get_structs() {
@@ -1562,6 +1644,7 @@ pub enum MalformedError {
 /// Check if the metadata has unknown key/data types
 pub fn check_metadata_format(module: &CompiledModule) -> Result<(), MalformedError> {
     let mut exist = false;
+    let mut view_functions_exist = false;
     for data in module.metadata.iter() {
         if data.key == ROOCH_METADATA_KEY {
             if exist {
@@ -1573,6 +1656,14 @@ pub fn check_metadata_format(module: &CompiledModule) -> Result<(), MalformedErr
                 bcs::from_bytes::<RuntimeModuleMetadataV1>(&data.value)
                     .map_err(|e| MalformedError::DeserializedError(data.key.clone(), e))?;
             }
+        } else if data.key == ROOCH_VIEW_FUNCTIONS_KEY {
+            if view_functions_exist {
+                return Err(MalformedError::DuplicateKey);
+            }
+            view_functions_exist = true;
+
+            bcs::from_bytes::<BTreeSet<String>>(&data.value)
+                .map_err(|e| MalformedError::DeserializedError(data.key.clone(), e))?;
         } else {
             return Err(MalformedError::UnknownKey(data.key.clone()));
         }
@@ -1585,24 +1676,29 @@ pub fn load_module_metadata(
     module_id: &ModuleId,
     loaded_module_bytes: VMResult<Vec<u8>>,
 ) -> VMResult<Option<RuntimeModuleMetadataV1>> {
-    let compiled_module_opt = {
-        match loaded_module_bytes {
-            Ok(module_bytes) => CompiledModule::deserialize(module_bytes.as_slice()).ok(),
-            Err(err) => {
-                return Err(err);
-            }
-        }
-    };
+    let compiled_module = load_compiled_module(module_id, loaded_module_bytes)?;
+    Ok(get_metadata_from_compiled_module(&compiled_module))
+}
 
-    match compiled_module_opt {
-        None => Err(
-            PartialVMError::new(StatusCode::FAILED_TO_DESERIALIZE_RESOURCE)
-                .with_message(format!(
-                    "failed to deserialize module {:?}",
-                    module_id.to_string()
-                ))
-                .finish(Location::Module(module_id.clone())),
-        ),
-        Some(compiled_module) => Ok(get_metadata_from_compiled_module(&compiled_module)),
-    }
+pub fn load_module_view_functions(
+    module_id: &ModuleId,
+    loaded_module_bytes: VMResult<Vec<u8>>,
+) -> VMResult<Option<BTreeSet<String>>> {
+    let compiled_module = load_compiled_module(module_id, loaded_module_bytes)?;
+    Ok(get_view_functions_from_compiled_module(&compiled_module))
+}
+
+fn load_compiled_module(
+    module_id: &ModuleId,
+    loaded_module_bytes: VMResult<Vec<u8>>,
+) -> VMResult<CompiledModule> {
+    let module_bytes = loaded_module_bytes?;
+    CompiledModule::deserialize(module_bytes.as_slice()).map_err(|_| {
+        PartialVMError::new(StatusCode::FAILED_TO_DESERIALIZE_RESOURCE)
+            .with_message(format!(
+                "failed to deserialize module {:?}",
+                module_id.to_string()
+            ))
+            .finish(Location::Module(module_id.clone()))
+    })
 }
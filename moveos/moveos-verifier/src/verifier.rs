@@ -14,14 +14,17 @@ use move_binary_format::file_format::{
 };
 use move_binary_format::IndexKind;
 use move_binary_format::{access::ModuleAccess, CompiledModule};
-use move_core_types::identifier::Identifier;
+use move_core_types::identifier::{IdentStr, Identifier};
 use move_core_types::language_storage::ModuleId;
 use move_core_types::resolver::ModuleResolver;
 use move_core_types::vm_status::StatusCode;
 use move_vm_runtime::data_cache::TransactionCache;
 use move_vm_runtime::session::{LoadedFunctionInstantiation, Session};
 use move_vm_types::loaded_data::runtime_types::Type;
+use moveos_types::moveos_std::move_module::MoveModule;
+use moveos_types::state::MoveStructType;
 use once_cell::sync::Lazy;
+use std::collections::BTreeSet;
 use std::ops::Deref;
 
 pub static INIT_FN_NAME_IDENTIFIER: Lazy<Identifier> =
@@ -220,6 +223,62 @@ where
     Ok(())
 }
 
+/// The abort code of `moveos_std::move_module::ErrorNotViewFunction`.
+pub const ERROR_NOT_VIEW_FUNCTION: u64 = 6;
+
+/// Verify the function called as a view function.
+/// A view function must return values, and can not take a signer as parameter.
+/// If the module is built with `#[view]` support, only the functions annotated with `#[view]`
+/// can be called as view functions. The modules built before keep the old rule until they are upgraded.
+pub fn verify_view_function(
+    func: &LoadedFunctionInstantiation,
+    function_name: &IdentStr,
+    view_functions: Option<&BTreeSet<String>>,
+) -> VMResult<()> {
+    if let Some(view_functions) = view_functions {
+        if !view_functions.contains(function_name.as_str()) {
+            return Err(not_view_function_error(format!(
+                "function {} is not annotated with #[view]",
+                function_name
+            )));
+        }
+    }
+
+    if func.return_.is_empty() {
+        return Err(not_view_function_error(
+            "view function should return values".to_owned(),
+        ));
+    }
+
+    for (idx, ty) in func.parameters.iter().enumerate() {
+        let is_signer = match ty {
+            Type::Signer => true,
+            Type::Reference(bt) | Type::MutableReference(bt) => {
+                matches!(bt.as_ref(), Type::Signer)
+            }
+            _ => false,
+        };
+        if is_signer {
+            return Err(not_view_function_error(format!(
+                "The type of the {} paramter is not allowed in view function",
+                idx
+            )));
+        }
+    }
+
+    Ok(())
+}
+
+fn not_view_function_error(message: String) -> VMError {
+    PartialVMError::new(StatusCode::ABORTED)
+        .with_sub_status(ERROR_NOT_VIEW_FUNCTION)
+        .with_message(message)
+        .finish(Location::Module(ModuleId::new(
+            MoveModule::module_address(),
+            MoveModule::module_identifier(),
+        )))
+}
+
 fn check_transaction_input_type_at_publish(
     ety: &SignatureToken,
     module_bin_view: &BinaryIndexedView,
@@ -8,6 +8,7 @@ mod gas_member;
 mod native;
 pub mod parameter;
 mod parameters;
+pub mod profiler;
 pub mod table;
 
 pub trait SwitchableGasMeter: GasMeter {
@@ -69,7 +69,6 @@ impl N {
     pub const NUMBER_OF_NATIVE_FUNCTIONS: usize = 47;
 }
 
-// TODO: Place the gas_schedule on the blockchain instead of hardcoding it in the code.
 pub fn v5_native_table() -> Vec<GasCost> {
     let mut raw_native_table = vec![
         (N::SHA2_256, GasCost::new(21, 1)),
@@ -466,7 +465,6 @@ static G_INSTR_STRS: Lazy<Vec<&str>> = Lazy::new(|| {
     ]
 });
 
-// TODO: Place the gas_schedule on the blockchain instead of hardcoding it in the code.
 static G_NATIVE_STRS: Lazy<Vec<&str>> = Lazy::new(|| {
     vec![
         "move_stdlib.hash.sha2_256.per_byte",
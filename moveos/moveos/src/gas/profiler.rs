@@ -0,0 +1,556 @@
+// Copyright (c) RoochNetwork
+// SPDX-License-Identifier: Apache-2.0
+
+use crate::gas::table::ClassifiedGasMeter;
+use crate::gas::SwitchableGasMeter;
+use move_binary_format::errors::PartialVMResult;
+use move_binary_format::file_format::CodeOffset;
+use move_core_types::account_address::AccountAddress;
+use move_core_types::gas_algebra::{InternalGas, NumArgs, NumBytes};
+use move_core_types::language_storage::ModuleId;
+use move_vm_types::gas::{GasMeter, SimpleInstruction};
+use move_vm_types::views::{TypeView, ValueView};
+use moveos_types::gas_profile::{GasProfile, FOLDED_STACK_SEPARATOR};
+use moveos_types::moveos_std::event::TransactionEvent;
+use moveos_types::state::StateChangeSet;
+use moveos_types::transaction::GasStatement;
+use std::cell::RefCell;
+use std::rc::Rc;
+
+/// The frame name of the storage gas in the folded stacks
+pub const STORAGE_FRAME: &str = "[storage]";
+
+struct Frame {
+    name: String,
+    is_native: bool,
+}
+
+struct ProfilerState {
+    root: String,
+    frames: Vec<Frame>,
+    profile: GasProfile,
+}
+
+impl ProfilerState {
+    fn current_function(&self) -> &str {
+        self.frames
+            .last()
+            .map(|frame| frame.name.as_str())
+            .unwrap_or(self.root.as_str())
+    }
+
+    fn current_stack(&self) -> String {
+        std::iter::once(self.root.as_str())
+            .chain(self.frames.iter().map(|frame| frame.name.as_str()))
+            .collect::<Vec<_>>()
+            .join(FOLDED_STACK_SEPARATOR)
+    }
+
+    fn record_instruction(&mut self, gas_used: u64) {
+        let function = self.current_function().to_owned();
+        let stack = self.current_stack();
+        self.profile.instructions += 1;
+        self.profile.execution_gas_used += gas_used;
+        let function_profile = self.profile.functions.entry(function).or_default();
+        function_profile.instructions += 1;
+        function_profile.gas_used += gas_used;
+        if gas_used > 0 {
+            *self.profile.folded_stacks.entry(stack).or_default() += gas_used;
+        }
+    }
+
+    fn record_native(&mut self, gas_used: u64) {
+        let stack = self.current_stack();
+        self.profile.execution_gas_used += gas_used;
+        match self.frames.last() {
+            Some(frame) if frame.is_native => {
+                let native_profile = self.profile.natives.entry(frame.name.clone()).or_default();
+                native_profile.gas_used += gas_used;
+                self.frames.pop();
+            }
+            _ => {
+                let function = self.current_function().to_owned();
+                self.profile.functions.entry(function).or_default().gas_used += gas_used;
+            }
+        }
+        if gas_used > 0 {
+            *self.profile.folded_stacks.entry(stack).or_default() += gas_used;
+        }
+    }
+
+    fn record_storage(&mut self, gas_used: u64) {
+        self.profile.storage_gas_used += gas_used;
+        if gas_used > 0 {
+            let stack = [self.root.as_str(), STORAGE_FRAME].join(FOLDED_STACK_SEPARATOR);
+            *self.profile.folded_stacks.entry(stack).or_default() += gas_used;
+        }
+    }
+
+    fn push_frame(&mut self, name: String) {
+        self.profile
+            .functions
+            .entry(name.clone())
+            .or_default()
+            .calls += 1;
+        self.frames.push(Frame {
+            name,
+            is_native: false,
+        });
+    }
+
+    fn mark_native(&mut self) {
+        if let Some(frame) = self.frames.last_mut() {
+            frame.is_native = true;
+            // The call was counted as a Move function call when the frame was pushed.
+            if let Some(function_profile) = self.profile.functions.get_mut(&frame.name) {
+                function_profile.calls -= 1;
+                if function_profile == &Default::default() {
+                    self.profile.functions.remove(&frame.name);
+                }
+            }
+            self.profile
+                .natives
+                .entry(frame.name.clone())
+                .or_default()
+                .calls += 1;
+        }
+    }
+
+    fn pop_frame(&mut self) {
+        // The return of the entry function has no frame to pop.
+        self.frames.pop();
+    }
+}
+
+/// A gas meter wrapper which records where the gas goes, it traces the call stack,
+/// the instructions and gas of every function, the native calls and the storage gas.
+/// The profiler is cheap to clone, all the clones share the same profile.
+pub struct GasProfiler<G> {
+    inner: G,
+    state: Rc<RefCell<ProfilerState>>,
+}
+
+impl<G: Clone> Clone for GasProfiler<G> {
+    fn clone(&self) -> Self {
+        Self {
+            inner: self.inner.clone(),
+            state: self.state.clone(),
+        }
+    }
+}
+
+impl<G> GasProfiler<G>
+where
+    G: SwitchableGasMeter,
+{
+    /// Wrap the `inner` gas meter, the `root` is the bottom frame of all the call stacks,
+    /// usually it is the name of the transaction action.
+    pub fn new(inner: G, root: String) -> Self {
+        Self {
+            inner,
+            state: Rc::new(RefCell::new(ProfilerState {
+                root,
+                frames: vec![],
+                profile: GasProfile::default(),
+            })),
+        }
+    }
+
+    /// Return the gas profile recorded so far.
+    pub fn gas_profile(&self) -> GasProfile {
+        self.state.borrow().profile.clone()
+    }
+
+    fn gas_used_by<F>(&mut self, charge: F) -> (PartialVMResult<()>, Option<u64>)
+    where
+        F: FnOnce(&mut G) -> PartialVMResult<()>,
+    {
+        let is_metering = self.inner.is_metering();
+        let balance_before = self.inner.balance_internal();
+        let result = charge(&mut self.inner);
+        if !is_metering {
+            return (result, None);
+        }
+        let gas_used: u64 = balance_before
+            .checked_sub(self.inner.balance_internal())
+            .unwrap_or_else(InternalGas::zero)
+            .into();
+        (result, Some(gas_used))
+    }
+
+    fn charge_instruction<F>(&mut self, charge: F) -> PartialVMResult<()>
+    where
+        F: FnOnce(&mut G) -> PartialVMResult<()>,
+    {
+        let (result, gas_used) = self.gas_used_by(charge);
+        if let Some(gas_used) = gas_used {
+            self.state.borrow_mut().record_instruction(gas_used);
+        }
+        result
+    }
+}
+
+impl<G> ClassifiedGasMeter for GasProfiler<G>
+where
+    G: SwitchableGasMeter + ClassifiedGasMeter,
+{
+    fn charge_execution(&mut self, gas_cost: u64) -> PartialVMResult<()> {
+        self.inner.charge_execution(gas_cost)
+    }
+
+    fn charge_io_write(&mut self, data_size: u64) -> PartialVMResult<()> {
+        let (result, gas_used) = self.gas_used_by(|inner| inner.charge_io_write(data_size));
+        if let Some(gas_used) = gas_used {
+            self.state.borrow_mut().record_storage(gas_used);
+        }
+        result
+    }
+
+    fn charge_event(&mut self, events: &[TransactionEvent]) -> PartialVMResult<()> {
+        let (result, gas_used) = self.gas_used_by(|inner| inner.charge_event(events));
+        if let Some(gas_used) = gas_used {
+            self.state.borrow_mut().record_storage(gas_used);
+        }
+        result
+    }
+
+    fn charge_change_set(&mut self, change_set: &StateChangeSet) -> PartialVMResult<()> {
+        let (result, gas_used) = self.gas_used_by(|inner| inner.charge_change_set(change_set));
+        if let Some(gas_used) = gas_used {
+            self.state.borrow_mut().record_storage(gas_used);
+        }
+        result
+    }
+
+    fn check_constrains(&self, max_gas_amount: u64) -> PartialVMResult<()> {
+        self.inner.check_constrains(max_gas_amount)
+    }
+
+    fn gas_statement(&self) -> GasStatement {
+        self.inner.gas_statement()
+    }
+}
+
+impl<G> SwitchableGasMeter for GasProfiler<G>
+where
+    G: SwitchableGasMeter,
+{
+    fn stop_metering(&mut self) {
+        self.inner.stop_metering()
+    }
+
+    fn start_metering(&mut self) {
+        self.inner.start_metering()
+    }
+
+    fn is_metering(&self) -> bool {
+        self.inner.is_metering()
+    }
+}
+
+impl<G> GasMeter for GasProfiler<G>
+where
+    G: SwitchableGasMeter,
+{
+    fn balance_internal(&self) -> InternalGas {
+        self.inner.balance_internal()
+    }
+
+    fn charge_simple_instr(&mut self, instr: SimpleInstruction) -> PartialVMResult<()> {
+        let is_ret = matches!(instr, SimpleInstruction::Ret);
+        let result = self.charge_instruction(|inner| inner.charge_simple_instr(instr));
+        if is_ret {
+            self.state.borrow_mut().pop_frame();
+        }
+        result
+    }
+
+    fn charge_br_true(&mut self, target_offset: Option<CodeOffset>) -> PartialVMResult<()> {
+        self.charge_instruction(|inner| inner.charge_br_true(target_offset))
+    }
+
+    fn charge_br_false(&mut self, target_offset: Option<CodeOffset>) -> PartialVMResult<()> {
+        self.charge_instruction(|inner| inner.charge_br_false(target_offset))
+    }
+
+    fn charge_branch(&mut self, target_offset: CodeOffset) -> PartialVMResult<()> {
+        self.charge_instruction(|inner| inner.charge_branch(target_offset))
+    }
+
+    fn charge_pop(&mut self, popped_val: impl ValueView) -> PartialVMResult<()> {
+        self.charge_instruction(|inner| inner.charge_pop(popped_val))
+    }
+
+    fn charge_call(
+        &mut self,
+        module_id: &ModuleId,
+        func_name: &str,
+        args: impl ExactSizeIterator<Item = impl ValueView>,
+        num_locals: NumArgs,
+    ) -> PartialVMResult<()> {
+        let result = self
+            .charge_instruction(|inner| inner.charge_call(module_id, func_name, args, num_locals));
+        self.state.borrow_mut().push_frame(format!(
+            "{}::{}",
+            module_id.short_str_lossless(),
+            func_name
+        ));
+        result
+    }
+
+    fn charge_call_generic(
+        &mut self,
+        module_id: &ModuleId,
+        func_name: &str,
+        ty_args: impl ExactSizeIterator<Item = impl TypeView>,
+        args: impl ExactSizeIterator<Item = impl ValueView>,
+        num_locals: NumArgs,
+    ) -> PartialVMResult<()> {
+        let result = self.charge_instruction(|inner| {
+            inner.charge_call_generic(module_id, func_name, ty_args, args, num_locals)
+        });
+        self.state.borrow_mut().push_frame(format!(
+            "{}::{}",
+            module_id.short_str_lossless(),
+            func_name
+        ));
+        result
+    }
+
+    fn charge_ld_const(&mut self, size: NumBytes) -> PartialVMResult<()> {
+        self.charge_instruction(|inner| inner.charge_ld_const(size))
+    }
+
+    fn charge_ld_const_after_deserialization(
+        &mut self,
+        val: impl ValueView,
+    ) -> PartialVMResult<()> {
+        self.inner.charge_ld_const_after_deserialization(val)
+    }
+
+    fn charge_copy_loc(&mut self, val: impl ValueView) -> PartialVMResult<()> {
+        self.charge_instruction(|inner| inner.charge_copy_loc(val))
+    }
+
+    fn charge_move_loc(&mut self, val: impl ValueView) -> PartialVMResult<()> {
+        self.charge_instruction(|inner| inner.charge_move_loc(val))
+    }
+
+    fn charge_store_loc(&mut self, val: impl ValueView) -> PartialVMResult<()> {
+        self.charge_instruction(|inner| inner.charge_store_loc(val))
+    }
+
+    fn charge_pack(
+        &mut self,
+        is_generic: bool,
+        args: impl ExactSizeIterator<Item = impl ValueView>,
+    ) -> PartialVMResult<()> {
+        self.charge_instruction(|inner| inner.charge_pack(is_generic, args))
+    }
+
+    fn charge_unpack(
+        &mut self,
+        is_generic: bool,
+        args: impl ExactSizeIterator<Item = impl ValueView>,
+    ) -> PartialVMResult<()> {
+        self.charge_instruction(|inner| inner.charge_unpack(is_generic, args))
+    }
+
+    fn charge_read_ref(&mut self, val: impl ValueView) -> PartialVMResult<()> {
+        self.charge_instruction(|inner| inner.charge_read_ref(val))
+    }
+
+    fn charge_write_ref(
+        &mut self,
+        new_val: impl ValueView,
+        old_val: impl ValueView,
+    ) -> PartialVMResult<()> {
+        self.charge_instruction(|inner| inner.charge_write_ref(new_val, old_val))
+    }
+
+    fn charge_eq(&mut self, lhs: impl ValueView, rhs: impl ValueView) -> PartialVMResult<()> {
+        self.charge_instruction(|inner| inner.charge_eq(lhs, rhs))
+    }
+
+    fn charge_neq(&mut self, lhs: impl ValueView, rhs: impl ValueView) -> PartialVMResult<()> {
+        self.charge_instruction(|inner| inner.charge_neq(lhs, rhs))
+    }
+
+    fn charge_borrow_global(
+        &mut self,
+        is_mut: bool,
+        is_generic: bool,
+        ty: impl TypeView,
+        is_success: bool,
+    ) -> PartialVMResult<()> {
+        self.charge_instruction(|inner| {
+            inner.charge_borrow_global(is_mut, is_generic, ty, is_success)
+        })
+    }
+
+    fn charge_exists(
+        &mut self,
+        is_generic: bool,
+        ty: impl TypeView,
+        exists: bool,
+    ) -> PartialVMResult<()> {
+        self.charge_instruction(|inner| inner.charge_exists(is_generic, ty, exists))
+    }
+
+    fn charge_move_from(
+        &mut self,
+        is_generic: bool,
+        ty: impl TypeView,
+        val: Option<impl ValueView>,
+    ) -> PartialVMResult<()> {
+        self.charge_instruction(|inner| inner.charge_move_from(is_generic, ty, val))
+    }
+
+    fn charge_move_to(
+        &mut self,
+        is_generic: bool,
+        ty: impl TypeView,
+        val: impl ValueView,
+        is_success: bool,
+    ) -> PartialVMResult<()> {
+        self.charge_instruction(|inner| inner.charge_move_to(is_generic, ty, val, is_success))
+    }
+
+    fn charge_vec_pack<'a>(
+        &mut self,
+        ty: impl TypeView + 'a,
+        args: impl ExactSizeIterator<Item = impl ValueView>,
+    ) -> PartialVMResult<()> {
+        self.charge_instruction(|inner| inner.charge_vec_pack(ty, args))
+    }
+
+    fn charge_vec_len(&mut self, ty: impl TypeView) -> PartialVMResult<()> {
+        self.charge_instruction(|inner| inner.charge_vec_len(ty))
+    }
+
+    fn charge_vec_borrow(
+        &mut self,
+        is_mut: bool,
+        ty: impl TypeView,
+        is_success: bool,
+    ) -> PartialVMResult<()> {
+        self.charge_instruction(|inner| inner.charge_vec_borrow(is_mut, ty, is_success))
+    }
+
+    fn charge_vec_push_back(
+        &mut self,
+        ty: impl TypeView,
+        val: impl ValueView,
+    ) -> PartialVMResult<()> {
+        self.charge_instruction(|inner| inner.charge_vec_push_back(ty, val))
+    }
+
+    fn charge_vec_pop_back(
+        &mut self,
+        ty: impl TypeView,
+        val: Option<impl ValueView>,
+    ) -> PartialVMResult<()> {
+        self.charge_instruction(|inner| inner.charge_vec_pop_back(ty, val))
+    }
+
+    fn charge_vec_unpack(
+        &mut self,
+        ty: impl TypeView,
+        expect_num_elements: NumArgs,
+        elems: impl ExactSizeIterator<Item = impl ValueView>,
+    ) -> PartialVMResult<()> {
+        self.charge_instruction(|inner| inner.charge_vec_unpack(ty, expect_num_elements, elems))
+    }
+
+    fn charge_vec_swap(&mut self, ty: impl TypeView) -> PartialVMResult<()> {
+        self.charge_instruction(|inner| inner.charge_vec_swap(ty))
+    }
+
+    fn charge_load_resource(
+        &mut self,
+        addr: AccountAddress,
+        ty: impl TypeView,
+        val: Option<impl ValueView>,
+        bytes_loaded: NumBytes,
+    ) -> PartialVMResult<()> {
+        self.inner.charge_load_resource(addr, ty, val, bytes_loaded)
+    }
+
+    fn charge_native_function(
+        &mut self,
+        amount: InternalGas,
+        ret_vals: Option<impl ExactSizeIterator<Item = impl ValueView>>,
+    ) -> PartialVMResult<()> {
+        let (result, gas_used) =
+            self.gas_used_by(|inner| inner.charge_native_function(amount, ret_vals));
+        let mut state = self.state.borrow_mut();
+        match gas_used {
+            Some(gas_used) => state.record_native(gas_used),
+            None => {
+                if state.frames.last().map_or(false, |frame| frame.is_native) {
+                    state.pop_frame();
+                }
+            }
+        }
+        result
+    }
+
+    fn charge_native_function_before_execution(
+        &mut self,
+        ty_args: impl ExactSizeIterator<Item = impl TypeView>,
+        args: impl ExactSizeIterator<Item = impl ValueView>,
+    ) -> PartialVMResult<()> {
+        self.state.borrow_mut().mark_native();
+        self.inner
+            .charge_native_function_before_execution(ty_args, args)
+    }
+
+    fn charge_drop_frame(
+        &mut self,
+        locals: impl Iterator<Item = impl ValueView>,
+    ) -> PartialVMResult<()> {
+        self.inner.charge_drop_frame(locals)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn new_state() -> ProfilerState {
+        ProfilerState {
+            root: "0x42::m::entry".to_owned(),
+            frames: vec![],
+            profile: GasProfile::default(),
+        }
+    }
+
+    #[test]
+    fn test_profile_call_stack() {
+        let mut state = new_state();
+        state.record_instruction(2);
+        state.push_frame("0x42::m::f".to_owned());
+        state.record_instruction(3);
+        state.push_frame("0x1::vector::length".to_owned());
+        state.mark_native();
+        state.record_native(7);
+        state.record_instruction(1);
+        state.pop_frame();
+        state.record_storage(10);
+
+        let profile = state.profile;
+        assert_eq!(profile.instructions, 3);
+        assert_eq!(profile.execution_gas_used, 13);
+        assert_eq!(profile.storage_gas_used, 10);
+        assert_eq!(profile.functions["0x42::m::f"].calls, 1);
+        assert_eq!(profile.functions["0x42::m::f"].instructions, 2);
+        assert_eq!(profile.functions["0x42::m::f"].gas_used, 4);
+        assert_eq!(profile.natives["0x1::vector::length"].calls, 1);
+        assert_eq!(profile.natives["0x1::vector::length"].gas_used, 7);
+        assert!(!profile.functions.contains_key("0x1::vector::length"));
+        assert_eq!(
+            profile.to_folded_stacks(),
+            "0x42::m::entry 2\n0x42::m::entry;0x42::m::f 4\n0x42::m::entry;0x42::m::f;0x1::vector::length 7\n0x42::m::entry;[storage] 10\n"
+        );
+    }
+}
@@ -433,6 +433,20 @@ pub fn initial_cost_schedule() -> CostTable {
     }
 }
 
+/// Build the cost table from the on-chain gas schedule.
+/// The tiers are not on chain, so the initial tiers are used.
+/// Return `None` if the gas schedule misses some instruction, storage or abstract value size gas parameters.
+pub fn cost_table_from_gas_schedule(gas_schedule: &BTreeMap<String, u64>) -> Option<CostTable> {
+    Some(CostTable {
+        storage_gas_parameter: StorageGasParameter::from_on_chain_gas_schedule(gas_schedule)?,
+        instruction_gas_parameter: InstructionParameter::from_on_chain_gas_schedule(gas_schedule)?,
+        abstract_value_parameter: AbstractValueSizeGasParameter::from_on_chain_gas_schedule(
+            gas_schedule,
+        )?,
+        ..initial_cost_schedule()
+    })
+}
+
 pub fn zero_cost_schedule() -> CostTable {
     let mut zero_tier = BTreeMap::new();
     zero_tier.insert(0, 0);
@@ -477,12 +491,13 @@ impl GasCost {
 #[derive(Debug, Clone)]
 pub struct MoveOSGasMeter {
     cost_table: CostTable,
+    budget: InternalGas,
     gas_left: InternalGas,
     //TODO we do not need to use gas_price in gas meter.
     charge: bool,
 
-    execution_gas_used: Rc<RefCell<InternalGas>>,
-    storage_gas_used: Rc<RefCell<InternalGas>>,
+    // The storage gas charged so far, the execution gas is the rest of the gas used.
+    storage_gas_statement: Rc<RefCell<GasStatement>>,
 
     // The current height of the operand stack, and the maximal height that it has reached.
     stack_height_high_water_mark: u64,
@@ -518,11 +533,11 @@ impl MoveOSGasMeter {
         let (instructions_current_tier_mult, instructions_next_tier_start) =
             cost_table.instruction_tier(0);
         Self {
+            budget: InternalGas::from(budget),
             gas_left: InternalGas::from(budget),
             cost_table,
             charge: true,
-            execution_gas_used: Rc::new(RefCell::new(InternalGas::from(0))),
-            storage_gas_used: Rc::new(RefCell::new(InternalGas::from(0))),
+            storage_gas_statement: Rc::new(RefCell::new(GasStatement::default())),
             stack_height_high_water_mark: 0,
             stack_height_current: 0,
             stack_size_high_water_mark: 0,
@@ -544,10 +559,10 @@ impl MoveOSGasMeter {
     pub fn new_unmetered() -> Self {
         Self {
             cost_table: ZERO_COST_SCHEDULE.clone(),
+            budget: InternalGas::from(0),
             gas_left: InternalGas::from(0),
             charge: false,
-            execution_gas_used: Rc::new(RefCell::new(InternalGas::from(0))),
-            storage_gas_used: Rc::new(RefCell::new(InternalGas::from(0))),
+            storage_gas_statement: Rc::new(RefCell::new(GasStatement::default())),
             stack_height_high_water_mark: 0,
             stack_height_current: 0,
             stack_height_next_tier_start: None,
@@ -657,6 +672,25 @@ impl MoveOSGasMeter {
     pub fn set_metering(&mut self, enabled: bool) {
         self.charge = enabled;
     }
+
+    /// Deduct the storage fee and record it in the storage gas statement.
+    /// If the gas left is not enough, the remaining gas is recorded as the storage gas used.
+    fn deduct_storage_gas<F>(&mut self, fee: u64, record: F) -> PartialVMResult<()>
+    where
+        F: FnOnce(&mut GasStatement, u64),
+    {
+        if !self.charge {
+            return Ok(());
+        }
+
+        let gas_left: u64 = self.gas_left.into();
+        let charged = fee.min(gas_left);
+        let mut statement = self.storage_gas_statement.borrow_mut();
+        statement.storage_gas_used += charged;
+        record(&mut statement, charged);
+        drop(statement);
+        self.deduct_gas(InternalGas::from(fee))
+    }
 }
 
 pub trait ClassifiedGasMeter {
@@ -671,91 +705,66 @@ pub trait ClassifiedGasMeter {
 
 impl ClassifiedGasMeter for MoveOSGasMeter {
     fn charge_execution(&mut self, gas_cost: u64) -> PartialVMResult<()> {
-        if !self.charge {
-            return Ok(());
-        }
-
-        let new_value = self
-            .execution_gas_used
-            .borrow()
-            .add(InternalGas::from(gas_cost));
-        *self.execution_gas_used.borrow_mut() = new_value;
-        Ok(())
+        self.deduct_gas(InternalGas::from(gas_cost))
     }
 
     // fn charge_io_read(&mut self) {}
 
     fn charge_io_write(&mut self, data_size: u64) -> PartialVMResult<()> {
-        if !self.charge {
-            return Ok(());
-        }
-
         let fee = self
             .cost_table
             .storage_gas_parameter
             .storage_fee_per_transaction_byte
             * data_size;
-        let new_value = self.storage_gas_used.borrow().add(InternalGas::from(fee));
-        *self.storage_gas_used.borrow_mut() = new_value;
-        self.deduct_gas(InternalGas::from(fee))
+        self.deduct_storage_gas(fee, |statement, charged| {
+            statement.tx_size_gas_used += charged
+        })
     }
 
     fn charge_event(&mut self, events: &[TransactionEvent]) -> PartialVMResult<()> {
-        if !self.charge {
-            return Ok(());
-        }
-
-        let mut total_event_fee = 0;
-        for event in events {
-            let fee = event.event_data.len() as u64
-                * self
-                    .cost_table
-                    .storage_gas_parameter
-                    .storage_fee_per_event_byte;
-            let new_value = self.storage_gas_used.borrow().add(InternalGas::from(fee));
-            *self.storage_gas_used.borrow_mut() = new_value;
-            total_event_fee += fee;
-        }
-        self.deduct_gas(InternalGas::from(total_event_fee))
+        let event_bytes: u64 = events
+            .iter()
+            .map(|event| event.event_data.len() as u64)
+            .sum();
+        let fee = event_bytes
+            * self
+                .cost_table
+                .storage_gas_parameter
+                .storage_fee_per_event_byte;
+        self.deduct_storage_gas(fee, |statement, charged| {
+            statement.event_gas_used += charged
+        })
     }
 
     fn charge_change_set(&mut self, change_set: &StateChangeSet) -> PartialVMResult<()> {
-        if !self.charge {
-            return Ok(());
-        }
-
-        let mut total_change_set_fee = 0;
+        let storage_gas_parameter = &self.cost_table.storage_gas_parameter;
+        let (mut new_fee, mut modify_fee, mut delete_fee) = (0u64, 0u64, 0u64);
         for (_, table_change) in change_set.changes.iter() {
             for (key, op) in table_change.entries.iter() {
-                let fee = {
-                    match op {
-                        Op::Modify(value) => {
-                            (key.len() + value.value.len()) as u64
-                                * self
-                                    .cost_table
-                                    .storage_gas_parameter
-                                    .storage_fee_per_op_modify_byte
-                        }
-                        Op::Delete => {
-                            self.cost_table
-                                .storage_gas_parameter
-                                .storage_fee_per_op_delete
-                        }
-                        Op::New(value) => {
-                            (key.len() + value.value.len()) as u64
-                                * self
-                                    .cost_table
-                                    .storage_gas_parameter
-                                    .storage_fee_per_op_new_byte
-                        }
+                match op {
+                    Op::New(value) => {
+                        new_fee += (key.len() + value.value.len()) as u64
+                            * storage_gas_parameter.storage_fee_per_op_new_byte;
                     }
-                };
-                let new_value = self.storage_gas_used.borrow().add(InternalGas::from(fee));
-                *self.storage_gas_used.borrow_mut() = new_value;
-                total_change_set_fee += fee;
+                    Op::Modify(value) => {
+                        modify_fee += (key.len() + value.value.len()) as u64
+                            * storage_gas_parameter.storage_fee_per_op_modify_byte;
+                    }
+                    Op::Delete => {
+                        delete_fee += storage_gas_parameter.storage_fee_per_op_delete;
+                    }
+                }
             }
         }
-        self.deduct_gas(InternalGas::from(total_change_set_fee))
+        self.deduct_storage_gas(new_fee, |statement, charged| {
+            statement.new_state_gas_used += charged
+        })?;
+        self.deduct_storage_gas(modify_fee, |statement, charged| {
+            statement.modify_state_gas_used += charged
+        })?;
+        self.deduct_storage_gas(delete_fee, |statement, charged| {
+            statement.delete_state_gas_used += charged
+        })
     }
 
     fn check_constrains(&self, max_gas_amount: u64) -> PartialVMResult<()> {
@@ -768,9 +777,8 @@ impl ClassifiedGasMeter for MoveOSGasMeter {
             return Ok(());
         }
 
-        let execution_gas_used = *self.execution_gas_used.borrow();
-        let storage_gas_used = *self.storage_gas_used.borrow();
-        if InternalGas::from(gas_used) != execution_gas_used + storage_gas_used {
+        let gas_statement = self.gas_statement();
+        if gas_used != gas_statement.execution_gas_used + gas_statement.storage_gas_used {
             return Err(PartialVMError::new(StatusCode::ABORTED)
                 .with_message("Failed to check the constraints of the gas_used.".to_owned()));
         }
@@ -778,10 +786,14 @@ impl ClassifiedGasMeter for MoveOSGasMeter {
     }
 
     fn gas_statement(&self) -> GasStatement {
-        GasStatement {
-            execution_gas_used: *self.execution_gas_used.borrow(),
-            storage_gas_used: *self.storage_gas_used.borrow(),
-        }
+        let mut gas_statement = self.storage_gas_statement.borrow().clone();
+        let gas_used: u64 = self
+            .budget
+            .checked_sub(self.gas_left)
+            .unwrap_or(InternalGas::from(0))
+            .into();
+        gas_statement.execution_gas_used = gas_used.saturating_sub(gas_statement.storage_gas_used);
+        gas_statement
     }
 }
 
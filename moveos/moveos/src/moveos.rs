@@ -1,7 +1,12 @@
 // Copyright (c) RoochNetwork
 // SPDX-License-Identifier: Apache-2.0
 
-use crate::gas::table::{initial_cost_schedule, MoveOSGasMeter};
+use crate::gas::profiler::GasProfiler;
+use crate::gas::table::{
+    cost_table_from_gas_schedule, initial_cost_schedule, ClassifiedGasMeter, CostTable,
+    MoveOSGasMeter,
+};
+use crate::gas::SwitchableGasMeter;
 use crate::vm::moveos_vm::{MoveOSSession, MoveOSVM};
 use anyhow::{bail, ensure, Result};
 use backtrace::Backtrace;
@@ -23,9 +28,15 @@ use moveos_store::transaction_store::TransactionDBStore;
 use moveos_store::MoveOSStore;
 use moveos_types::addresses::MOVEOS_STD_ADDRESS;
 use moveos_types::function_return_value::FunctionResult;
+use moveos_types::gas_config::GasConfig;
+use moveos_types::gas_profile::GasProfile;
 use moveos_types::module_binding::MoveFunctionCaller;
+use moveos_types::move_std::string::MoveString;
 use moveos_types::move_types::FunctionId;
+use moveos_types::moveos_std::copyable_any::Any;
 use moveos_types::moveos_std::event::EventID;
+use moveos_types::moveos_std::gas_schedule::GasSchedule;
+use moveos_types::moveos_std::simple_map::SimpleMap;
 use moveos_types::moveos_std::tx_context::TxContext;
 use moveos_types::moveos_std::tx_result::TxResult;
 use moveos_types::startup_info::StartupInfo;
@@ -37,7 +48,10 @@ use moveos_types::transaction::{
 };
 use moveos_types::{h256::H256, transaction::FunctionCall};
 use moveos_verifier::metadata::load_module_metadata;
+use parking_lot::RwLock;
 use serde::{Deserialize, Serialize};
+use std::collections::BTreeMap;
+use std::sync::Arc;
 
 #[derive(Copy, Clone, Debug, Serialize, Deserialize)]
 pub struct GasPaymentAccount {
@@ -95,8 +109,31 @@ impl Clone for MoveOSConfig {
     }
 }
 
+/// Build the native functions with the native gas parameters of the on-chain gas schedule.
+/// Return `None` if the gas schedule misses some native gas parameters.
+pub type NativesBuilder = Arc<
+    dyn Fn(
+            &BTreeMap<String, u64>,
+        ) -> Option<Vec<(AccountAddress, Identifier, Identifier, NativeFunction)>>
+        + Send
+        + Sync,
+>;
+
+/// The cost table and the VM built from the on-chain gas schedule of a state root.
+struct GasScheduleCache {
+    state_root: H256,
+    /// The feature version of the on-chain gas schedule, `None` if there is no on-chain gas schedule.
+    feature_version: Option<u64>,
+    cost_table: CostTable,
+    vm: Arc<MoveOSVM>,
+}
+
 pub struct MoveOS {
-    vm: MoveOSVM,
+    /// The VM with the natives passed to `MoveOS::new`, it is used when there is no on-chain gas schedule.
+    vm: Arc<MoveOSVM>,
+    config: MoveOSConfig,
+    natives_builder: Option<NativesBuilder>,
+    gas_schedule_cache: RwLock<Option<GasScheduleCache>>,
     db: MoveOSResolverProxy<MoveOSStore>,
     system_pre_execute_functions: Vec<FunctionCall>,
     system_post_execute_functions: Vec<FunctionCall>,
@@ -110,15 +147,97 @@ impl MoveOS {
         system_pre_execute_functions: Vec<FunctionCall>,
         system_post_execute_functions: Vec<FunctionCall>,
     ) -> Result<Self> {
-        let vm = MoveOSVM::new(natives, config.vm_config)?;
+        let vm = MoveOSVM::new(natives, config.clone().vm_config)?;
         Ok(Self {
-            vm,
+            vm: Arc::new(vm),
+            config,
+            natives_builder: None,
+            gas_schedule_cache: RwLock::new(None),
             db: MoveOSResolverProxy(db),
             system_pre_execute_functions,
             system_post_execute_functions,
         })
     }
 
+    /// Rebuild the native functions with the native gas parameters when the on-chain gas schedule is changed.
+    /// Without the builder, the natives passed to `MoveOS::new` are always used.
+    pub fn with_natives_builder(mut self, natives_builder: NativesBuilder) -> Self {
+        self.natives_builder = Some(natives_builder);
+        self
+    }
+
+    /// Load the cost table and the VM of the on-chain gas schedule at the current state root.
+    /// The initial cost table and the default VM are used if there is no on-chain gas schedule.
+    fn load_gas_schedule(&self) -> VMResult<(CostTable, Arc<MoveOSVM>)> {
+        let state_root = self.state().state_root();
+        if let Some(cache) = self.gas_schedule_cache.read().as_ref() {
+            if cache.state_root == state_root {
+                return Ok((cache.cost_table.clone(), cache.vm.clone()));
+            }
+        }
+
+        let gas_schedule = self
+            .state()
+            .get_as_object::<GasSchedule>(GasSchedule::object_id())
+            .map_err(|e| {
+                PartialVMError::new(StatusCode::STORAGE_ERROR)
+                    .with_message(format!("Load gas schedule failed: {}", e))
+                    .finish(Location::Undefined)
+            })?
+            .map(|object| object.value);
+        let feature_version = gas_schedule
+            .as_ref()
+            .map(|schedule| schedule.feature_version);
+
+        let mut cache = self.gas_schedule_cache.write();
+        let (cost_table, vm) = match cache.as_ref() {
+            // The gas schedule is not changed, only the state root is changed.
+            Some(cache) if cache.feature_version == feature_version => {
+                (cache.cost_table.clone(), cache.vm.clone())
+            }
+            _ => self.build_gas_schedule(gas_schedule)?,
+        };
+        *cache = Some(GasScheduleCache {
+            state_root,
+            feature_version,
+            cost_table: cost_table.clone(),
+            vm: vm.clone(),
+        });
+        Ok((cost_table, vm))
+    }
+
+    fn build_gas_schedule(
+        &self,
+        gas_schedule: Option<GasSchedule>,
+    ) -> VMResult<(CostTable, Arc<MoveOSVM>)> {
+        let gas_schedule = match gas_schedule {
+            Some(gas_schedule) => gas_schedule.to_map(),
+            None => return Ok((initial_cost_schedule(), self.vm.clone())),
+        };
+        let invalid_gas_schedule = |message: &str| {
+            PartialVMError::new(StatusCode::VM_STARTUP_FAILURE)
+                .with_message(message.to_owned())
+                .finish(Location::Undefined)
+        };
+        let cost_table = cost_table_from_gas_schedule(&gas_schedule).ok_or_else(|| {
+            invalid_gas_schedule(
+                "The on-chain gas schedule misses some instruction or storage gas parameters",
+            )
+        })?;
+        let vm = match &self.natives_builder {
+            Some(natives_builder) => {
+                let natives = natives_builder(&gas_schedule).ok_or_else(|| {
+                    invalid_gas_schedule(
+                        "The on-chain gas schedule misses some native gas parameters",
+                    )
+                })?;
+                Arc::new(MoveOSVM::new(natives, self.config.clone().vm_config)?)
+            }
+            None => self.vm.clone(),
+        };
+        Ok((cost_table, vm))
+    }
+
     pub fn init_genesis<
         T: Into<MoveOSTransaction>,
         GT: MoveState + Clone,
@@ -208,12 +327,11 @@ impl MoveOS {
             post_execute_functions,
         } = tx;
 
-        let cost_table = initial_cost_schedule();
+        let (cost_table, vm) = self.load_gas_schedule()?;
+        Self::check_tx_size_gas(&cost_table, &ctx)?;
         let mut gas_meter = MoveOSGasMeter::new(cost_table, ctx.max_gas_amount);
         gas_meter.set_metering(false);
-        let session = self
-            .vm
-            .new_readonly_session(&self.db, ctx.clone(), gas_meter);
+        let session = vm.new_readonly_session(&self.db, ctx.clone(), gas_meter);
 
         let verified_action = session.verify_move_action(action)?;
         let (_, _) = session.finish_with_extensions(KeptVMStatus::Executed)?;
@@ -225,7 +343,57 @@ impl MoveOS {
         })
     }
 
+    /// The max gas amount of the transaction should at least cover the storage gas of the transaction bytes.
+    fn check_tx_size_gas(cost_table: &CostTable, ctx: &TxContext) -> VMResult<()> {
+        let tx_size_gas = cost_table
+            .storage_gas_parameter
+            .storage_fee_per_transaction_byte
+            .checked_mul(ctx.tx_size);
+        match tx_size_gas {
+            Some(tx_size_gas) if tx_size_gas <= ctx.max_gas_amount => Ok(()),
+            _ => Err(PartialVMError::new(
+                StatusCode::MAX_GAS_UNITS_BELOW_MIN_TRANSACTION_GAS_UNITS,
+            )
+            .with_message(format!(
+                "The max gas amount {} is not enough to pay the storage gas of {} transaction bytes",
+                ctx.max_gas_amount, ctx.tx_size
+            ))
+            .finish(Location::Undefined)),
+        }
+    }
+
     pub fn execute(&self, tx: VerifiedMoveOSTransaction) -> Result<RawTransactionOutput> {
+        let (cost_table, vm) = self.load_gas_schedule()?;
+        let gas_meter = MoveOSGasMeter::new(cost_table, tx.ctx.max_gas_amount);
+        self.execute_with_gas_meter(&vm, tx, gas_meter)
+    }
+
+    /// Execute the transaction with the gas profiler, return the output and the gas profile.
+    pub fn execute_with_gas_profiler(
+        &self,
+        tx: VerifiedMoveOSTransaction,
+    ) -> Result<(RawTransactionOutput, GasProfile)> {
+        let (cost_table, vm) = self.load_gas_schedule()?;
+        let gas_meter = MoveOSGasMeter::new(cost_table, tx.ctx.max_gas_amount);
+        let root = match &tx.action {
+            VerifiedMoveAction::Function { call } => call.function_id.to_string(),
+            VerifiedMoveAction::Script { .. } => "script".to_owned(),
+            VerifiedMoveAction::ModuleBundle { .. } => "module_bundle".to_owned(),
+        };
+        let gas_profiler = GasProfiler::new(gas_meter, root);
+        let output = self.execute_with_gas_meter(&vm, tx, gas_profiler.clone())?;
+        Ok((output, gas_profiler.gas_profile()))
+    }
+
+    fn execute_with_gas_meter<G>(
+        &self,
+        vm: &MoveOSVM,
+        tx: VerifiedMoveOSTransaction,
+        mut gas_meter: G,
+    ) -> Result<RawTransactionOutput>
+    where
+        G: SwitchableGasMeter + ClassifiedGasMeter + Clone,
+    {
         let VerifiedMoveOSTransaction {
             ctx,
             action,
@@ -247,13 +415,14 @@ impl MoveOS {
         // So we keep a backup here, and then insert to the TxContext kv store when session respawed.
         let system_env = ctx.map.clone();
 
-        let cost_table = initial_cost_schedule();
-        let gas_meter = MoveOSGasMeter::new(cost_table, ctx.max_gas_amount);
+        // Charge the storage gas for the transaction bytes before execution,
+        // so the fee is included in the gas paid by the transaction.
+        let tx_size = ctx.tx_size;
+        gas_meter
+            .charge_io_write(tx_size)
+            .map_err(|e| e.finish(Location::Undefined))?;
 
-        // Temporary behavior, will enable this in the future.
-        // gas_meter.charge_io_write(ctx.tx_size)?;
-
-        let mut session = self.vm.new_session(&self.db, ctx, gas_meter);
+        let mut session = vm.new_session(&self.db, ctx, gas_meter);
 
         // system pre_execute
         // we do not charge gas for system_pre_execute function
@@ -275,7 +444,7 @@ impl MoveOS {
                         status
                     );
                 }
-                self.execution_cleanup(session, status, Some(action))
+                self.execution_cleanup(session, status, Some(action), system_env)
             }
             Err((vm_err, need_respawn)) => {
                 if log::log_enabled!(log::Level::Warn) {
@@ -287,7 +456,7 @@ impl MoveOS {
                     );
                 }
                 if need_respawn {
-                    let mut s = session.respawn(system_env);
+                    let mut s = session.respawn(system_env.clone());
                     //Because the session is respawned, the pre_execute function should be called again.
                     s.execute_function_call(self.system_pre_execute_functions.clone(), false)
                         .expect("system_pre_execute should not fail.");
@@ -300,19 +469,22 @@ impl MoveOS {
                     // We just cleanup with the VM error return by `execute_user_action`, ignore
                     // the result of `execute_pre_and_post`
                     // TODO: do we need to handle the result of `execute_pre_and_post` after respawn?
-                    self.execution_cleanup(s, vm_err.into_vm_status(), None)
+                    self.execution_cleanup(s, vm_err.into_vm_status(), None, system_env)
                 } else {
-                    self.execution_cleanup(session, vm_err.into_vm_status(), None)
+                    self.execution_cleanup(session, vm_err.into_vm_status(), None, system_env)
                 }
             }
         }
     }
 
-    fn execute_gas_charge_post(
+    fn execute_gas_charge_post<G>(
         &self,
-        session: &mut MoveOSSession<'_, '_, MoveOSResolverProxy<MoveOSStore>, MoveOSGasMeter>,
+        session: &mut MoveOSSession<'_, '_, MoveOSResolverProxy<MoveOSStore>, G>,
         action: &VerifiedMoveAction,
-    ) -> VMResult<Option<bool>> {
+    ) -> VMResult<Option<bool>>
+    where
+        G: SwitchableGasMeter + ClassifiedGasMeter + Clone,
+    {
         match action {
             VerifiedMoveAction::Function { call } => {
                 let module_id = &call.function_id.module_id;
@@ -399,15 +571,46 @@ impl MoveOS {
         tx: VerifiedMoveOSTransaction,
     ) -> Result<(H256, TransactionOutput)> {
         let raw_output = self.execute(tx)?;
-        let (state_root, event_ids) = self.apply_transaction_output(raw_output.clone())?;
+        let (state_root, event_ids) = self.apply_transaction_output(raw_output.clone(), None)?;
         let output = TransactionOutput::new(raw_output, event_ids);
 
         Ok((state_root, output))
     }
 
+    /// Execute the transaction and apply the output only if the state root after execution is
+    /// the `expected_state_root`, otherwise the state is restored and an error is returned.
+    /// It is used by the follower to replay the transactions executed by the upstream node.
+    pub fn execute_and_apply_with_state_root(
+        &mut self,
+        tx: VerifiedMoveOSTransaction,
+        expected_state_root: H256,
+    ) -> Result<(H256, TransactionOutput)> {
+        let raw_output = self.execute(tx)?;
+        let (state_root, event_ids) =
+            self.apply_transaction_output(raw_output.clone(), Some(expected_state_root))?;
+        let output = TransactionOutput::new(raw_output, event_ids);
+
+        Ok((state_root, output))
+    }
+
+    /// Execute the transaction with the gas profiler and apply the output.
+    pub fn execute_and_apply_with_gas_profiler(
+        &mut self,
+        tx: VerifiedMoveOSTransaction,
+    ) -> Result<(H256, TransactionOutput, GasProfile)> {
+        let (raw_output, gas_profile) = self.execute_with_gas_profiler(tx)?;
+        let (state_root, event_ids) = self.apply_transaction_output(raw_output.clone(), None)?;
+        let output = TransactionOutput::new(raw_output, event_ids);
+
+        Ok((state_root, output, gas_profile))
+    }
+
+    /// Apply the output, if the `expected_state_root` is given and the new state root mismatches,
+    /// the state root is restored and nothing else is saved.
     fn apply_transaction_output(
         &mut self,
         output: RawTransactionOutput,
+        expected_state_root: Option<H256>,
     ) -> Result<(H256, Vec<EventID>)> {
         //TODO move apply change set to a suitable place, and make MoveOS stateless?
         let RawTransactionOutput {
@@ -416,18 +619,28 @@ impl MoveOS {
             state_changeset,
             events,
             gas_used: _,
+            gas_statement: _,
             is_upgrade: _,
         } = output;
-        let new_state_root = self
-            .db
-            .0
-            .get_state_store()
+        let state_store = self.db.0.get_state_store();
+        let pre_state_root = state_store.state_root();
+        let new_state_root = state_store
             .apply_change_set(changeset, state_changeset)
             .map_err(|e| {
                 PartialVMError::new(StatusCode::STORAGE_ERROR)
                     .with_message(e.to_string())
                     .finish(Location::Undefined)
             })?;
+        if let Some(expected_state_root) = expected_state_root {
+            if new_state_root != expected_state_root {
+                state_store.update_state_root(pre_state_root)?;
+                bail!(
+                    "State root mismatch, expected {:?}, got {:?}",
+                    expected_state_root,
+                    new_state_root
+                );
+            }
+        }
         let event_ids = self
             .db
             .0
@@ -450,12 +663,35 @@ impl MoveOS {
         Ok((new_state_root, event_ids))
     }
 
-    /// Execute readonly view function
+    /// Execute readonly view function with the zero sender and the default max gas amount
     pub fn execute_view_function(&self, function_call: FunctionCall) -> FunctionResult {
-        //TODO allow user to specify the sender
-        let tx_context = TxContext::new_readonly_ctx(AccountAddress::ZERO);
-        //TODO verify the view function
-        self.execute_readonly_function(&tx_context, function_call)
+        self.execute_view_function_with_options(function_call, None, None)
+    }
+
+    /// Execute readonly view function as the given sender.
+    /// The gas is metered and limited by `max_gas_amount`, which can not exceed the default max gas amount.
+    pub fn execute_view_function_with_options(
+        &self,
+        function_call: FunctionCall,
+        sender: Option<AccountAddress>,
+        max_gas_amount: Option<u64>,
+    ) -> FunctionResult {
+        let max_gas_amount = max_gas_amount
+            .unwrap_or(GasConfig::DEFAULT_MAX_GAS_AMOUNT)
+            .min(GasConfig::DEFAULT_MAX_GAS_AMOUNT);
+        let mut tx_context = TxContext::new_readonly_ctx(sender.unwrap_or(AccountAddress::ZERO));
+        tx_context.max_gas_amount = max_gas_amount;
+
+        let (cost_table, vm) = match self.load_gas_schedule() {
+            Ok(gas_schedule) => gas_schedule,
+            Err(e) => return FunctionResult::err(e),
+        };
+        let gas_meter = MoveOSGasMeter::new(cost_table, max_gas_amount);
+        let mut session = vm.new_readonly_session(&self.db, tx_context, gas_meter);
+        if let Err(e) = session.verify_view_function(&function_call) {
+            return FunctionResult::err(e);
+        }
+        Self::execute_function_in_readonly_session(session, function_call)
     }
 
     pub fn execute_readonly_function(
@@ -463,20 +699,28 @@ impl MoveOS {
         tx_context: &TxContext,
         function_call: FunctionCall,
     ) -> FunctionResult {
-        //TODO limit the view function max gas usage
-        let cost_table = initial_cost_schedule();
+        let (cost_table, vm) = match self.load_gas_schedule() {
+            Ok(gas_schedule) => gas_schedule,
+            Err(e) => return FunctionResult::err(e),
+        };
         let mut gas_meter = MoveOSGasMeter::new(cost_table, tx_context.max_gas_amount);
         gas_meter.set_metering(false);
-        let mut session = self
-            .vm
-            .new_readonly_session(&self.db, tx_context.clone(), gas_meter);
+        let session = vm.new_readonly_session(&self.db, tx_context.clone(), gas_meter);
+        Self::execute_function_in_readonly_session(session, function_call)
+    }
 
+    fn execute_function_in_readonly_session(
+        mut session: MoveOSSession<'_, '_, MoveOSResolverProxy<MoveOSStore>, MoveOSGasMeter>,
+        function_call: FunctionCall,
+    ) -> FunctionResult {
         let result = session.execute_function_bypass_visibility(function_call);
         match result {
             Ok(return_values) => {
                 // if execute success, finish the session to check if it change the state
                 match session.finish_with_extensions(KeptVMStatus::Executed) {
-                    Ok(_) => FunctionResult::ok(return_values),
+                    Ok((_, output)) => {
+                        FunctionResult::ok(return_values).with_gas_used(output.gas_used)
+                    }
                     Err(e) => FunctionResult::err(e),
                 }
             }
@@ -487,13 +731,16 @@ impl MoveOS {
     // Execute use action with pre_execute and post_execute.
     // Return the user action execution status if success,
     // else return VMError and a bool which indicate if we should respawn the session.
-    fn execute_user_action(
+    fn execute_user_action<G>(
         &self,
-        session: &mut MoveOSSession<'_, '_, MoveOSResolverProxy<MoveOSStore>, MoveOSGasMeter>,
+        session: &mut MoveOSSession<'_, '_, MoveOSResolverProxy<MoveOSStore>, G>,
         action: VerifiedMoveAction,
         pre_execute_functions: Vec<FunctionCall>,
         post_execute_functions: Vec<FunctionCall>,
-    ) -> Result<VMStatus, (VMError, bool)> {
+    ) -> Result<VMStatus, (VMError, bool)>
+    where
+        G: SwitchableGasMeter + ClassifiedGasMeter + Clone,
+    {
         // user pre_execute
         // If the pre_execute failed, we finish the session directly and return the TransactionOutput.
         session
@@ -530,24 +777,31 @@ impl MoveOS {
     }
 
     // Execute pre_execute and post_execute only.
-    fn execute_pre_and_post(
+    fn execute_pre_and_post<G>(
         &self,
-        session: &mut MoveOSSession<'_, '_, MoveOSResolverProxy<MoveOSStore>, MoveOSGasMeter>,
+        session: &mut MoveOSSession<'_, '_, MoveOSResolverProxy<MoveOSStore>, G>,
         pre_execute_functions: Vec<FunctionCall>,
         post_execute_functions: Vec<FunctionCall>,
-    ) -> VMResult<()> {
+    ) -> VMResult<()>
+    where
+        G: SwitchableGasMeter + ClassifiedGasMeter + Clone,
+    {
         session.execute_function_call(pre_execute_functions, true)?;
         session.execute_function_call(post_execute_functions, true)?;
         Ok(())
     }
 
-    fn execution_cleanup(
+    fn execution_cleanup<G>(
         &self,
-        mut session: MoveOSSession<'_, '_, MoveOSResolverProxy<MoveOSStore>, MoveOSGasMeter>,
+        mut session: MoveOSSession<'_, '_, MoveOSResolverProxy<MoveOSStore>, G>,
         status: VMStatus,
         action_opt: Option<VerifiedMoveAction>,
-    ) -> Result<RawTransactionOutput> {
-        let kept_status = match status.keep_or_discard() {
+        system_env: SimpleMap<MoveString, Any>,
+    ) -> Result<RawTransactionOutput>
+    where
+        G: SwitchableGasMeter + ClassifiedGasMeter + Clone,
+    {
+        let mut kept_status = match status.keep_or_discard() {
             Ok(kept_status) => kept_status,
             Err(discard_status) => {
                 //This should not happen, if it happens, it means that the VM or verifer has a bug
@@ -556,6 +810,20 @@ impl MoveOS {
             }
         };
 
+        // Charge the storage gas before the tx_result is recorded and the gas is paid,
+        // so the storage gas is included in the gas used by the transaction.
+        // If the transaction runs out of gas, we respawn the session to discard the state changes.
+        if let Err(vm_err) = session.charge_storage_gas() {
+            if vm_err.major_status() != StatusCode::OUT_OF_GAS {
+                return Err(vm_err.into());
+            }
+            session = session.respawn(system_env);
+            session
+                .execute_function_call(self.system_pre_execute_functions.clone(), false)
+                .expect("system_pre_execute should not fail.");
+            kept_status = KeptVMStatus::OutOfGas;
+        }
+
         let mut pay_gas = false;
         let gas_payment_account_opt = session
             .storage_context_mut()
@@ -595,6 +863,9 @@ impl MoveOS {
 
         if is_upgrade {
             self.vm.mark_loader_cache_as_invalid();
+            if let Some(cache) = self.gas_schedule_cache.read().as_ref() {
+                cache.vm.mark_loader_cache_as_invalid();
+            }
         };
         Ok(())
     }
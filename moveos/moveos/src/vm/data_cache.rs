@@ -322,6 +322,51 @@ pub fn into_change_set(table_data: Arc<RwLock<TableData>>) -> PartialVMResult<St
     })
 }
 
+/// Build the state change set from the table data without consuming it.
+/// The entries are compared with the states in the resolver, the unchanged entries are skipped.
+/// It is used to charge the storage gas before the session is finished.
+pub fn peek_change_set<S: MoveOSResolver>(
+    table_data: &TableData,
+    resolver: &S,
+) -> PartialVMResult<StateChangeSet> {
+    let mut changes = BTreeMap::new();
+    for (handle, table) in table_data.tables() {
+        let mut entries = BTreeMap::new();
+        for (key, table_value) in table.content() {
+            let original = resolver.resolve_table_item(handle, key).map_err(|e| {
+                PartialVMError::new(StatusCode::STORAGE_ERROR).with_message(e.to_string())
+            })?;
+            let op = match (original, table_value.serialize_box_value()?) {
+                (None, Some((value_type, value))) => Op::New(State::new(value, value_type)),
+                (Some(original), Some((value_type, value))) => {
+                    if original.value == value && original.value_type == value_type {
+                        continue;
+                    }
+                    Op::Modify(State::new(value, value_type))
+                }
+                (Some(_), None) => Op::Delete,
+                (None, None) => continue,
+            };
+            entries.insert(key.clone(), op);
+        }
+        if !entries.is_empty() {
+            changes.insert(
+                *handle,
+                TableChange {
+                    entries,
+                    size_increment: table.size_increment(),
+                    key_type: table.key_type().clone(),
+                },
+            );
+        }
+    }
+    Ok(StateChangeSet {
+        new_tables: BTreeMap::new(),
+        removed_tables: BTreeSet::new(),
+        changes,
+    })
+}
+
 // Unbox a value of `moveos_std::raw_table::Box<V>` to V and serialize it.
 fn unbox_and_serialize(layout: &MoveTypeLayout, box_val: Value) -> PartialVMResult<Vec<u8>> {
     let mut fields = box_val.value_as::<Struct>()?.unpack()?;
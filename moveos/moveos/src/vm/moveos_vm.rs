@@ -1,7 +1,7 @@
 // Copyright (c) RoochNetwork
 // SPDX-License-Identifier: Apache-2.0
 
-use std::{borrow::Borrow, sync::Arc};
+use std::{borrow::Borrow, collections::BTreeSet, sync::Arc};
 
 use move_binary_format::{
     compatibility::Compatibility,
@@ -42,16 +42,22 @@ use moveos_types::{
     moveos_std::simple_map::SimpleMap,
     moveos_std::tx_context::TxContext,
     moveos_std::{event::TransactionEvent, module_upgrade_flag::ModuleUpgradeFlag},
-    state_resolver::MoveOSResolver,
+    moveos_std::{
+        move_module::UpgradePolicies,
+        object::{NamedTableID, ObjectID},
+    },
+    state::MoveStructType,
+    state_resolver::{resource_tag_to_key, MoveOSResolver},
     transaction::{FunctionCall, MoveAction, VerifiedMoveAction},
 };
+use moveos_verifier::metadata::load_module_view_functions;
 use moveos_verifier::verifier::INIT_FN_NAME_IDENTIFIER;
 
 use crate::gas::table::{initial_cost_schedule, ClassifiedGasMeter};
 use crate::gas::{table::MoveOSGasMeter, SwitchableGasMeter};
 use crate::vm::tx_argument_resolver;
 
-use super::data_cache::{into_change_set, MoveosDataCache};
+use super::data_cache::{into_change_set, peek_change_set, MoveosDataCache};
 
 /// MoveOSVM is a wrapper of MoveVM with MoveOS specific features.
 pub struct MoveOSVM {
@@ -123,6 +129,8 @@ pub struct MoveOSSession<'r, 'l, S, G> {
     pub(crate) table_data: Arc<RwLock<TableData>>,
     pub(crate) gas_meter: G,
     pub(crate) read_only: bool,
+    /// The objects passed as arguments and loaded before the execution.
+    pub(crate) object_arguments: BTreeSet<ObjectID>,
 }
 
 #[allow(clippy::arc_with_non_send_sync)]
@@ -148,6 +156,7 @@ where
             table_data,
             gas_meter,
             read_only,
+            object_arguments: BTreeSet::new(),
         }
     }
 
@@ -163,6 +172,7 @@ where
             session: Self::new_inner_session(self.vm, self.remote, table_data.clone()),
             ctx,
             table_data,
+            object_arguments: BTreeSet::new(),
             ..self
         }
     }
@@ -215,6 +225,7 @@ where
             }
             MoveAction::ModuleBundle(module_bundle) => {
                 let compiled_modules = deserialize_modules(&module_bundle)?;
+                self.check_module_bundle(&compiled_modules)?;
 
                 let mut init_function_modules = vec![];
                 for module in &compiled_modules {
@@ -250,7 +261,7 @@ where
                     .load_script(call.code.as_slice(), call.ty_args.clone())?;
 
                 let resolved_args = self.resolve_argument(&loaded_function, call.args)?;
-                self.load_argument(&loaded_function, &resolved_args);
+                self.load_argument(&loaded_function, &resolved_args)?;
                 self.session
                     .execute_script(call.code, call.ty_args, resolved_args, &mut self.gas_meter)
                     .map(|ret| {
@@ -269,7 +280,7 @@ where
                 )?;
 
                 let resolved_args = self.resolve_argument(&loaded_function, call.args)?;
-                self.load_argument(&loaded_function, &resolved_args);
+                self.load_argument(&loaded_function, &resolved_args)?;
                 self.session
                     .execute_entry_function(
                         &call.function_id.module_id,
@@ -290,14 +301,16 @@ where
                 module_bundle,
                 init_function_modules,
             } => {
-                //TODO check the modules package address with the sender
                 let sender = self.ctx.tx_context.sender();
+                // The upgrade policy may be changed after the transaction is verified, so check it again.
+                let compiled_modules = deserialize_modules(&module_bundle)?;
+                self.check_module_bundle(&compiled_modules)?;
                 // Check if module is first published. Only the first published module can run init function
                 let modules_with_init = init_function_modules
                     .into_iter()
                     .filter(|m| self.session.get_data_store().exists_module(m) == Ok(false))
                     .collect();
-                //TODO check the compatiblity
+                // The upgrade policy is checked above, the VM still does the full compatibility check.
                 let compat_config = Compatibility::full_check();
 
                 self.session.publish_module_bundle_with_compat_config(
@@ -331,6 +344,81 @@ where
         action_result
     }
 
+    /// Check the modules in the bundle are under the sender's address,
+    /// and the modules are allowed to be published or upgraded by the upgrade policies of the sender's packages.
+    /// The modules in the bundle are a package, every existing module is checked under its own policy,
+    /// and the new modules are checked under the strictest policy of the existing ones.
+    fn check_module_bundle(&self, compiled_modules: &[CompiledModule]) -> VMResult<()> {
+        let sender = self.ctx.tx_context.sender();
+        let policies = self.upgrade_policies(sender)?;
+        let data_store = self.session.get_data_store();
+        let mut existing_modules = vec![];
+        for module in compiled_modules {
+            let module_id = module.self_id();
+            if *module_id.address() != sender {
+                return Err(
+                    PartialVMError::new(StatusCode::MODULE_ADDRESS_DOES_NOT_MATCH_SENDER)
+                        .with_message(format!(
+                            "Module {} is not under the sender {} address",
+                            module_id, sender
+                        ))
+                        .finish(Location::Module(module_id)),
+                );
+            }
+            if data_store.exists_module(&module_id)? {
+                existing_modules.push(module_id);
+            }
+        }
+        let package_policy = existing_modules
+            .iter()
+            .map(|module_id| policies.module_upgrade_policy(module_id.name().as_str()))
+            .max()
+            .unwrap_or_default();
+        for module in compiled_modules {
+            let module_id = module.self_id();
+            if existing_modules.contains(&module_id) {
+                let old_module_bytes = data_store.load_module(&module_id)?;
+                let old_module = CompiledModule::deserialize(&old_module_bytes)
+                    .map_err(|e| e.finish(Location::Module(module_id.clone())))?;
+                policies
+                    .module_upgrade_policy(module_id.name().as_str())
+                    .check_upgrade(&old_module, module)
+                    .map_err(|e| e.finish(Location::Module(module_id.clone())))?;
+            } else if !package_policy.allow_new_module() {
+                return Err(
+                    PartialVMError::new(StatusCode::BACKWARD_INCOMPATIBLE_MODULE_UPDATE)
+                        .with_message(format!(
+                            "Can not add new module {} to the package under the {} upgrade policy",
+                            module_id, package_policy
+                        ))
+                        .finish(Location::Module(module_id)),
+                );
+            }
+        }
+        Ok(())
+    }
+
+    /// Get the upgrade policies of the account's modules, the module without policy uses the compatible policy.
+    fn upgrade_policies(&self, account: AccountAddress) -> VMResult<UpgradePolicies> {
+        let resource_table_id = NamedTableID::Resource(account).to_object_id();
+        let key = resource_tag_to_key(&UpgradePolicies::struct_tag());
+        let storage_error = |e: anyhow::Error| {
+            PartialVMError::new(StatusCode::STORAGE_ERROR)
+                .with_message(e.to_string())
+                .finish(Location::Undefined)
+        };
+        match self
+            .remote
+            .resolve_table_item(&resource_table_id, &key)
+            .map_err(storage_error)?
+        {
+            Some(state) => state.cast::<UpgradePolicies>().map_err(storage_error),
+            None => Ok(UpgradePolicies {
+                policies: SimpleMap::create(),
+            }),
+        }
+    }
+
     /// Resolve pending init functions request registered via the NativeModuleContext.
     fn resolve_pending_init_functions(&mut self) -> VMResult<()> {
         let ctx = self
@@ -393,6 +481,23 @@ where
         }
     }
 
+    /// Verify the function call can be executed as a view function.
+    pub fn verify_view_function(&mut self, call: &FunctionCall) -> VMResult<()> {
+        let module_id = &call.function_id.module_id;
+        let loaded_function = self.session.load_function(
+            module_id,
+            &call.function_id.function_name,
+            call.ty_args.as_slice(),
+        )?;
+        let loaded_module_bytes = self.session.get_data_store().load_module(module_id);
+        let view_functions = load_module_view_functions(module_id, loaded_module_bytes)?;
+        moveos_verifier::verifier::verify_view_function(
+            &loaded_function,
+            &call.function_id.function_name,
+            view_functions.as_ref(),
+        )
+    }
+
     pub fn execute_function_bypass_visibility(
         &mut self,
         call: FunctionCall,
@@ -403,7 +508,7 @@ where
             call.ty_args.as_slice(),
         )?;
         let resolved_args = self.resolve_argument(&loaded_function, call.args)?;
-        self.load_argument(&loaded_function, &resolved_args);
+        self.load_argument(&loaded_function, &resolved_args)?;
         let return_values = self.session.execute_function_bypass_visibility(
             &call.function_id.module_id,
             &call.function_id.function_name,
@@ -451,19 +556,46 @@ where
         Ok(())
     }
 
+    /// Charge the storage gas for the state changes and the events of the session so far.
+    /// It should be called before the gas is paid, so the storage gas is included in the gas used.
+    pub(crate) fn charge_storage_gas(&mut self) -> VMResult<()> {
+        let state_changeset = peek_change_set(&self.table_data.read(), self.remote)
+            .map_err(|e| e.finish(Location::Undefined))?;
+        self.gas_meter
+            .charge_change_set(&state_changeset)
+            .map_err(|e| {
+                e.with_message("An error occurred during the charging of the change set".to_owned())
+                    .finish(Location::Undefined)
+            })?;
+
+        let events: Vec<_> = self
+            .session
+            .get_native_extensions()
+            .get::<NativeEventContext>()
+            .events()
+            .iter()
+            .enumerate()
+            .map(|(i, (struct_tag, event_data))| {
+                TransactionEvent::new(struct_tag.clone(), event_data.clone(), i as u64)
+            })
+            .collect();
+        self.gas_meter.charge_event(events.as_slice()).map_err(|e| {
+            e.with_message("An error occurred during the charging of the events".to_owned())
+                .finish(Location::Undefined)
+        })
+    }
+
     pub fn finish_with_extensions(
         self,
         status: KeptVMStatus,
     ) -> VMResult<(TxContext, RawTransactionOutput)> {
-        let gas_used = self.query_gas_used();
-        // let is_read_only_execution = self.read_only;
         let MoveOSSession {
             vm: _,
             remote: _,
             session,
             ctx,
             table_data,
-            mut gas_meter,
+            gas_meter,
             read_only,
         } = self;
         let (changeset, raw_events, mut extensions) = session.finish_with_extensions()?;
@@ -513,28 +645,6 @@ where
         })?;
         let is_upgrade = module_flag.map_or(false, |flag| flag.is_upgrade);
 
-        match gas_meter.charge_change_set(&state_changeset) {
-            Ok(_) => {}
-            Err(partial_vm_error) => {
-                return Err(partial_vm_error
-                    .with_message(
-                        "An error occurred during the charging of the change set".to_owned(),
-                    )
-                    .finish(Location::Undefined));
-            }
-        }
-
-        // Temporary behavior, will enable this in the future.
-        /*
-        match gas_meter.charge_event(events.as_slice()) {
-            Ok(_) => {}
-            Err(partial_vm_error) => {
-                return Err(partial_vm_error
-                    .with_message("An error occurred during the charging of the events".to_owned())
-                    .finish(Location::Undefined));
-            }
-        }
-
         match gas_meter.check_constrains(ctx.tx_context.max_gas_amount) {
             Ok(_) => {}
             Err(partial_vm_err) => {
@@ -542,12 +652,8 @@ where
             }
         };
 
-        let mut gas_statement = gas_meter.gas_statement();
-        if is_read_only_execution {
-            gas_statement.execution_gas_used = 0;
-            gas_statement.storage_gas_used = 0;
-        }
-         */
+        let gas_statement = gas_meter.gas_statement();
+        let gas_used = gas_statement.execution_gas_used + gas_statement.storage_gas_used;
 
         Ok((
             ctx.tx_context,
@@ -557,6 +663,7 @@ where
                 state_changeset,
                 events,
                 gas_used,
+                gas_statement,
                 is_upgrade,
             },
         ))
@@ -587,16 +694,11 @@ where
     }
 
     pub(crate) fn query_gas_used(&self) -> u64 {
-        if self.read_only {
-            //TODO calculate readonly function gas usage
-            0
-        } else {
-            let max_gas_amount = self.ctx.tx_context.max_gas_amount;
-            let gas_left: u64 = self.gas_meter.balance_internal().into();
-            max_gas_amount.checked_sub(gas_left).unwrap_or_else(
-                || panic!("gas_left({gas_left}) should always be less than or equal to max gas amount({max_gas_amount})")
-            )
-        }
+        let max_gas_amount = self.ctx.tx_context.max_gas_amount;
+        let gas_left: u64 = self.gas_meter.balance_internal().into();
+        max_gas_amount.checked_sub(gas_left).unwrap_or_else(
+            || panic!("gas_left({gas_left}) should always be less than or equal to max gas amount({max_gas_amount})")
+        )
     }
 
     /// Load a script and all of its types into cache
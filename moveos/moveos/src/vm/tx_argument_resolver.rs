@@ -3,9 +3,9 @@
 
 use super::moveos_vm::MoveOSSession;
 use crate::gas::SwitchableGasMeter;
-use move_binary_format::errors::{Location, PartialVMError, VMResult};
+use move_binary_format::errors::{Location, PartialVMError, VMError, VMResult};
 use move_core_types::{
-    language_storage::{StructTag, TypeTag},
+    language_storage::{ModuleId, StructTag, TypeTag},
     value::MoveValue,
     vm_status::StatusCode,
 };
@@ -13,15 +13,23 @@ use move_vm_runtime::data_cache::TransactionCache;
 use move_vm_runtime::session::{LoadedFunctionInstantiation, Session};
 use move_vm_types::loaded_data::runtime_types::{StructType, Type};
 use moveos_types::{
+    addresses::MOVEOS_STD_ADDRESS,
     moveos_std::{
         context::Context,
-        object::{Object, ObjectID},
+        object::{self, Object, ObjectEntity, ObjectID},
     },
-    state::{MoveStructType, PlaceholderStruct},
-    state_resolver::MoveOSResolver,
+    state::{MoveState, MoveStructType, MoveType, PlaceholderStruct},
+    state_resolver::{MoveOSResolver, GLOBAL_OBJECT_STORAGE_HANDLE},
 };
+use std::collections::{btree_map::Entry, BTreeMap};
 use std::sync::Arc;
 
+/// The error codes of `moveos_std::object` for the object arguments of a transaction.
+const ERROR_OBJECT_ARGUMENT_NOT_FOUND: u64 = 4;
+const ERROR_OBJECT_ARGUMENT_TYPE_MISMATCH: u64 = 5;
+const ERROR_OBJECT_ARGUMENT_NOT_MUTABLE: u64 = 6;
+const ERROR_OBJECT_ARGUMENT_MUTABLY_BORROWED: u64 = 7;
+
 impl<'r, 'l, S, G> MoveOSSession<'r, 'l, S, G>
 where
     S: MoveOSResolver,
@@ -61,8 +69,8 @@ where
                     .finish(Location::Undefined),
             );
         }
-        for (paramter, arg) in func.parameters.iter().zip(args.iter()) {
-            let type_tag_opt = get_type_tag(&self.session, paramter)?;
+        for (parameter, arg) in func.parameters.iter().zip(args.iter()) {
+            let type_tag_opt = get_type_tag(&self.session, parameter)?;
             if let Some(t) = type_tag_opt {
                 if let Some(object_type) = get_object_type(&t) {
                     let object_id = ObjectID::from_bytes(arg).map_err(|e| {
@@ -79,9 +87,10 @@ where
                                 .finish(Location::Undefined)
                         })?
                         .ok_or_else(|| {
-                            PartialVMError::new(StatusCode::FAILED_TO_DESERIALIZE_ARGUMENT)
-                                .with_message(format!("Object not found: {:?}", object_id))
-                                .finish(Location::Undefined)
+                            object_argument_error(
+                                ERROR_OBJECT_ARGUMENT_NOT_FOUND,
+                                format!("Object not found: {:?}", object_id),
+                            )
                         })?;
                     let object = state.as_raw_object().map_err(|e| {
                         PartialVMError::new(StatusCode::FAILED_TO_DESERIALIZE_ARGUMENT)
@@ -90,44 +99,41 @@ where
                     })?;
                     if let TypeTag::Struct(s) = object_type {
                         if s.as_ref() != &object.value.struct_tag {
-                            return Err(PartialVMError::new(
-                                StatusCode::TYPE_MISMATCH,
-                            )
-                            .with_message(format!(
-                                "Invalid object type, object type in argument:{:?}, object type in store:{:?}",
-                                s, object.value.struct_tag
-                            )).finish(Location::Undefined));
+                            return Err(object_argument_error(
+                                ERROR_OBJECT_ARGUMENT_TYPE_MISMATCH,
+                                format!(
+                                    "Invalid object type, object type in argument:{:?}, object type in store:{:?}",
+                                    s, object.value.struct_tag
+                                ),
+                            ));
                         }
                     } else {
-                        return Err(PartialVMError::new(StatusCode::TYPE_MISMATCH)
-                            .with_message(format!(
-                                "Object type should be struct, got:{:?}",
-                                object_type
-                            ))
-                            .finish(Location::Undefined));
+                        return Err(object_argument_error(
+                            ERROR_OBJECT_ARGUMENT_TYPE_MISMATCH,
+                            format!("Object type should be struct, got:{:?}", object_type),
+                        ));
                     }
-                    match paramter {
+                    match parameter {
                         Type::Reference(_r) => {
                             // Any one can get any &Object<T>
                         }
                         Type::MutableReference(_r) => {
                             // Only the owner can get &mut Object<T>
                             if object.is_frozen() {
-                                return Err(PartialVMError::new(StatusCode::NO_ACCOUNT_ROLE)
-                                    .with_message(format!(
-                                        "Object is frozen, object id:{:?}",
-                                        object_id
-                                    ))
-                                    .finish(Location::Undefined));
+                                return Err(object_argument_error(
+                                    ERROR_OBJECT_ARGUMENT_NOT_MUTABLE,
+                                    format!("Object is frozen, object id:{:?}", object_id),
+                                ));
                             }
                             if !object.is_shared() && object.owner != self.ctx.tx_context.sender() {
-                                return Err(PartialVMError::new(StatusCode::NO_ACCOUNT_ROLE)
-                                    .with_message(format!(
+                                return Err(object_argument_error(
+                                    ERROR_OBJECT_ARGUMENT_NOT_MUTABLE,
+                                    format!(
                                         "Object owner mismatch, object owner:{:?}, sender:{:?}",
                                         object.owner,
                                         self.ctx.tx_context.sender()
-                                    ))
-                                    .finish(Location::Undefined));
+                                    ),
+                                ));
                             }
                         }
                         _ => {
@@ -143,12 +149,113 @@ where
         Ok(args)
     }
 
-    pub fn load_argument(&mut self, _func: &LoadedFunctionInstantiation, _args: &[Vec<u8>]) {
-        //TODO load the object argument to the session
-        // We need to refactor the raw table, migrate the TableData to StorageContext.
+    /// Load the object arguments into the session before the execution.
+    /// The arguments should be checked by `resolve_argument` first, this function loads the objects
+    /// into the object storage table of the session, and ensures that an object passed as `&mut Object<T>`
+    /// is not passed again as another argument of the same call.
+    pub fn load_argument(
+        &mut self,
+        func: &LoadedFunctionInstantiation,
+        args: &[Vec<u8>],
+    ) -> VMResult<()> {
+        let mut borrowed_objects = BTreeMap::new();
+        for (parameter, arg) in func.parameters.iter().zip(args.iter()) {
+            let object_type = match get_type_tag(&self.session, parameter)?
+                .as_ref()
+                .and_then(get_object_type)
+            {
+                Some(object_type) => object_type,
+                None => continue,
+            };
+            let object_id = ObjectID::from_bytes(arg).map_err(|e| {
+                PartialVMError::new(StatusCode::FAILED_TO_DESERIALIZE_ARGUMENT)
+                    .with_message(format!("Invalid object id: {:?}", e))
+                    .finish(Location::Undefined)
+            })?;
+            let is_mut = matches!(parameter, Type::MutableReference(_));
+            match borrowed_objects.entry(object_id) {
+                Entry::Occupied(entry) => {
+                    if is_mut || *entry.get() {
+                        return Err(object_argument_error(
+                            ERROR_OBJECT_ARGUMENT_MUTABLY_BORROWED,
+                            format!(
+                                "Object {:?} is passed as mutable reference more than once",
+                                object_id
+                            ),
+                        ));
+                    }
+                }
+                Entry::Vacant(entry) => {
+                    entry.insert(is_mut);
+                }
+            }
+            self.load_object(object_id, object_type)?;
+        }
+        Ok(())
+    }
+
+    fn load_object(&mut self, object_id: ObjectID, object_type: TypeTag) -> VMResult<()> {
+        if self.object_arguments.contains(&object_id) {
+            return Ok(());
+        }
+        let entity_type = TypeTag::Struct(Box::new(StructTag {
+            address: ObjectEntity::<PlaceholderStruct>::ADDRESS,
+            module: ObjectEntity::<PlaceholderStruct>::module_identifier(),
+            name: ObjectEntity::<PlaceholderStruct>::struct_identifier(),
+            type_params: vec![object_type],
+        }));
+        // Get the layout before locking the table data, the loader may read the modules from it.
+        let entity_layout = self.session.get_type_layout(&entity_type)?;
+        let mut table_data = self.table_data.write();
+        let table = table_data
+            .get_or_create_table_with_key_type_and_key_layout(
+                GLOBAL_OBJECT_STORAGE_HANDLE,
+                ObjectID::type_tag(),
+                ObjectID::type_layout(),
+            )
+            .map_err(|e| e.finish(Location::Undefined))?;
+        let (value, _) = table
+            .get_or_create_global_value_with_layout_fn(
+                self.remote,
+                object_id.to_bytes(),
+                |value_type| {
+                    // The type is checked by `resolve_argument` before, so it should not mismatch
+                    if value_type == &entity_type {
+                        Ok(entity_layout)
+                    } else {
+                        Err(
+                            PartialVMError::new(StatusCode::TYPE_MISMATCH).with_message(format!(
+                                "Invalid object type, expect:{:?}, got:{:?}",
+                                entity_type, value_type
+                            )),
+                        )
+                    }
+                },
+            )
+            .map_err(|e| e.finish(Location::Undefined))?;
+        if !value.exists().map_err(|e| e.finish(Location::Undefined))? {
+            return Err(object_argument_error(
+                ERROR_OBJECT_ARGUMENT_NOT_FOUND,
+                format!("Object not found: {:?}", object_id),
+            ));
+        }
+        self.object_arguments.insert(object_id);
+        Ok(())
     }
 }
 
+/// The object argument errors abort in `moveos_std::object`, so the transaction output keeps the
+/// error code instead of a miscellaneous error.
+fn object_argument_error(code: u64, message: String) -> VMError {
+    PartialVMError::new(StatusCode::ABORTED)
+        .with_sub_status(code)
+        .with_message(message)
+        .finish(Location::Module(ModuleId::new(
+            MOVEOS_STD_ADDRESS,
+            object::MODULE_NAME.to_owned(),
+        )))
+}
+
 fn is_signer(t: &Type) -> bool {
     matches!(t, Type::Signer) || matches!(t, Type::Reference(r) if matches!(**r, Type::Signer))
 }
@@ -5,6 +5,7 @@ use anyhow::Result;
 use move_binary_format::access::ModuleAccess;
 use move_binary_format::file_format::FunctionHandleIndex;
 use move_binary_format::CompiledModule;
+use move_core_types::errmap::ErrorMapping;
 use move_core_types::resolver::MoveResolver;
 use move_core_types::vm_status::AbortLocation;
 use move_core_types::vm_status::VMStatus;
@@ -17,6 +18,37 @@ pub struct MoveAbortExplain {
     pub category_name: Option<String>,
     pub reason_code: u64,
     pub reason_name: Option<String>,
+    pub reason_description: Option<String>,
+}
+
+/// Decode an abort code into its category and module specific reason with the given error map.
+pub fn explain_move_abort(
+    location: &AbortLocation,
+    abort_code: u64,
+    error_mapping: &ErrorMapping,
+) -> MoveAbortExplain {
+    let category_code = abort_code >> 16;
+    let reason_code = abort_code & 0xffff;
+    let category_name = error_mapping
+        .error_categories
+        .get(&category_code)
+        .map(|description| description.code_name.clone());
+    let reason = match location {
+        AbortLocation::Module(module_id) => {
+            let module_name = module_id.to_string();
+            error_mapping
+                .get_explanation(module_name.as_str(), abort_code)
+                .or_else(|| error_mapping.get_explanation(module_name.as_str(), reason_code))
+        }
+        AbortLocation::Script => None,
+    };
+    MoveAbortExplain {
+        category_code,
+        category_name,
+        reason_code,
+        reason_name: reason.as_ref().map(|r| r.code_name.clone()),
+        reason_description: reason.map(|r| r.code_description),
+    }
 }
 
 #[derive(Debug, Serialize, Deserialize, Clone, Hash, Eq, PartialEq)]
@@ -59,14 +91,10 @@ where
         VMStatus::Error { status_code, .. } => {
             VMStatusExplainView::Error(format!("{:?}", status_code))
         }
-        VMStatus::MoveAbort(location, abort_code) => {
-            //TODO find a way to include the description
-            //Define a error code description trait, and let caller pass the error description files as argument.
-            VMStatusExplainView::MoveAbort {
-                location: location.clone(),
-                reason_code: *abort_code,
-            }
-        }
+        VMStatus::MoveAbort(location, abort_code) => VMStatusExplainView::MoveAbort {
+            location: location.clone(),
+            reason_code: *abort_code,
+        },
         VMStatus::ExecutionFailure {
             status_code,
             location,
@@ -96,7 +124,7 @@ where
     match location {
         AbortLocation::Module(module_id) => {
             let module_name = module_id.short_str_lossless();
-            let module_bytes = module_resolver.get_module(module_id).ok()?.unwrap();
+            let module_bytes = module_resolver.get_module(module_id).ok()??;
             let module = CompiledModule::deserialize(&module_bytes).ok()?;
             let func_handle = module.function_handle_at(FunctionHandleIndex::new(*function));
             let func_name = module.identifier_at(func_handle.name).to_string();
@@ -160,8 +160,8 @@ impl RocksDB {
         Ok(())
     }
 
-    /// Flushes all memtable data. This is only used for testing `get_approximate_sizes_cf` in unit
-    /// tests.
+    /// Flushes all memtable data to the SST files, it is used by the admin `flushStore` API and
+    /// for testing `get_approximate_sizes_cf` in unit tests.
     pub fn flush_all(&self) -> Result<()> {
         for cf_name in &self.cfs {
             let cf_handle = self.get_cf_handle(cf_name);